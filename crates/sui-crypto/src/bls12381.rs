@@ -1,7 +1,746 @@
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Bls12381PrivateKey([u8; Self::LENGTH]);
+//! Verification of signatures produced by validators.
+//!
+//! Validators sign with min-sig bls12381 keys, i.e. signatures are points in G1 and public keys
+//! are points in G2. Certificates, like the one attached to every checkpoint, are made of the
+//! aggregation of the signatures of a quorum of the validator committee along with a bitmap of
+//! the members which contributed to it. [`ValidatorCommitteeSignatureVerifier`] checks such
+//! certificates against a [`ValidatorCommittee`].
+
+use crate::SignatureError;
+use crate::Signer;
+use crate::Verifier;
+use sui_sdk_types::Bls12381PublicKey;
+use sui_sdk_types::Bls12381Signature;
+use sui_sdk_types::CertifiedTransactionEffects;
+use sui_sdk_types::CheckpointSummary;
+use sui_sdk_types::EpochId;
+use sui_sdk_types::Intent;
+use sui_sdk_types::IntentMessage;
+use sui_sdk_types::SignatureScheme;
+use sui_sdk_types::SignedCheckpointSummary;
+use sui_sdk_types::SignedTransactionEffects;
+use sui_sdk_types::StakeUnit;
+use sui_sdk_types::TransactionEffects;
+use sui_sdk_types::ValidatorAggregatedSignature;
+use sui_sdk_types::ValidatorCommittee;
+use sui_sdk_types::ValidatorSignature;
+
+use blst::min_sig;
+use blst::BLST_ERROR;
+
+/// The domain separation tag used when hashing messages to G1.
+const DST_G1: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+pub struct Bls12381PrivateKey(min_sig::SecretKey);
+
+impl std::fmt::Debug for Bls12381PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Bls12381PrivateKey")
+            .field(&"__elided__")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+impl proptest::arbitrary::Arbitrary for Bls12381PrivateKey {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::arbitrary::any::<[u8; 32]>()
+            .prop_map(|ikm| Self(min_sig::SecretKey::key_gen(&ikm, &[]).unwrap()))
+            .boxed()
+    }
+}
 
 impl Bls12381PrivateKey {
     /// The length of an bls12381 private key in bytes.
     pub const LENGTH: usize = 32;
+
+    /// Construct a private key from its big-endian encoding.
+    ///
+    /// Fails if `bytes` is not the encoding of a valid, non-zero, scalar.
+    pub fn new(bytes: [u8; Self::LENGTH]) -> Result<Self, SignatureError> {
+        min_sig::SecretKey::from_bytes(&bytes)
+            .map(Self)
+            .map_err(blst_error)
+    }
+
+    pub fn scheme(&self) -> SignatureScheme {
+        SignatureScheme::Bls12381
+    }
+
+    pub fn verifying_key(&self) -> Bls12381VerifyingKey {
+        Bls12381VerifyingKey(self.0.sk_to_pk())
+    }
+
+    pub fn public_key(&self) -> Bls12381PublicKey {
+        self.verifying_key().public_key()
+    }
+
+    pub fn generate<R>(mut rng: R) -> Self
+    where
+        R: rand_core::RngCore + rand_core::CryptoRng,
+    {
+        let mut ikm = [0; 32];
+        rng.fill_bytes(&mut ikm);
+        // Key generation only fails if the input keying material is shorter than 32 bytes
+        Self(min_sig::SecretKey::key_gen(&ikm, &[]).expect("ikm is 32 bytes long"))
+    }
+
+    /// Sign `message` on behalf of a validator of `epoch`, the way validators sign the messages
+    /// they certify.
+    pub fn sign_validator_message(&self, message: &[u8], epoch: EpochId) -> ValidatorSignature {
+        ValidatorSignature {
+            epoch,
+            public_key: self.public_key(),
+            signature: self.sign(&validator_message(message, epoch)),
+        }
+    }
+
+    /// Sign `checkpoint` on behalf of a validator of the checkpoint's epoch.
+    pub fn sign_checkpoint_summary(&self, checkpoint: &CheckpointSummary) -> ValidatorSignature {
+        self.sign_validator_message(&checkpoint_message(checkpoint), checkpoint.epoch)
+    }
+
+    /// Sign `effects` on behalf of a validator of the epoch the transaction was executed in.
+    pub fn sign_transaction_effects(
+        &self,
+        effects: &TransactionEffects,
+    ) -> SignedTransactionEffects {
+        SignedTransactionEffects {
+            effects: effects.clone(),
+            signature: self.sign_validator_message(&effects_message(effects), effects.epoch()),
+        }
+    }
+}
+
+impl Signer<Bls12381Signature> for Bls12381PrivateKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Bls12381Signature, SignatureError> {
+        let signature = self.0.sign(msg, DST_G1, &[]);
+        Ok(Bls12381Signature::new(signature.to_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Bls12381VerifyingKey(min_sig::PublicKey);
+
+impl Bls12381VerifyingKey {
+    /// Construct a verifying key from a public key, checking that it is a valid point of the
+    /// prime order subgroup of G2 which isn't the identity.
+    pub fn new(public_key: &Bls12381PublicKey) -> Result<Self, SignatureError> {
+        min_sig::PublicKey::key_validate(public_key.inner())
+            .map(Self)
+            .map_err(blst_error)
+    }
+
+    pub fn public_key(&self) -> Bls12381PublicKey {
+        Bls12381PublicKey::new(self.0.to_bytes())
+    }
+}
+
+impl Verifier<Bls12381Signature> for Bls12381VerifyingKey {
+    fn verify(&self, message: &[u8], signature: &Bls12381Signature) -> Result<(), SignatureError> {
+        let signature =
+            min_sig::Signature::sig_validate(signature.inner(), true).map_err(blst_error)?;
+
+        match signature.verify(false, message, DST_G1, &[], &self.0, false) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(blst_error(e)),
+        }
+    }
+}
+
+impl Verifier<ValidatorSignature> for Bls12381VerifyingKey {
+    /// Verify a signature produced with [`Bls12381PrivateKey::sign_validator_message`].
+    fn verify(&self, message: &[u8], signature: &ValidatorSignature) -> Result<(), SignatureError> {
+        if signature.public_key != self.public_key() {
+            return Err(SignatureError::from_source(
+                "signature was produced by a different public key",
+            ));
+        }
+
+        <Self as Verifier<Bls12381Signature>>::verify(
+            self,
+            &validator_message(message, signature.epoch),
+            &signature.signature,
+        )
+    }
+}
+
+impl crate::PublicKeyVerifier<Bls12381Signature> for Bls12381PublicKey {
+    fn verify(&self, message: &[u8], signature: &Bls12381Signature) -> Result<(), SignatureError> {
+        Bls12381VerifyingKey::new(self)?.verify(message, signature)
+    }
+}
+
+/// Verifier of certificates produced by a [`ValidatorCommittee`].
+#[derive(Debug)]
+pub struct ValidatorCommitteeSignatureVerifier {
+    committee: ValidatorCommittee,
+    verifying_keys: Vec<Bls12381VerifyingKey>,
+    total_stake: StakeUnit,
+}
+
+impl ValidatorCommitteeSignatureVerifier {
+    /// Construct a verifier for `committee`, validating the public keys of all of its members.
+    ///
+    /// The bitmaps of the certificates checked by this verifier are interpreted as indices into
+    /// `committee.members`, which must therefore be in the same order as the one used by the
+    /// network.
+    pub fn new(committee: ValidatorCommittee) -> Result<Self, SignatureError> {
+        let verifying_keys = committee
+            .members
+            .iter()
+            .map(|member| Bls12381VerifyingKey::new(&member.public_key))
+            .collect::<Result<Vec<_>, _>>()?;
+        let total_stake = committee
+            .members
+            .iter()
+            .try_fold(0u64, |total, member| total.checked_add(member.stake))
+            .ok_or_else(|| SignatureError::from_source("total committee stake overflows"))?;
+
+        Ok(Self {
+            committee,
+            verifying_keys,
+            total_stake,
+        })
+    }
+
+    pub fn committee(&self) -> &ValidatorCommittee {
+        &self.committee
+    }
+
+    pub fn total_stake(&self) -> StakeUnit {
+        self.total_stake
+    }
+
+    /// The minimum amount of stake which needs to sign a message for it to be certified.
+    pub fn quorum_threshold(&self) -> StakeUnit {
+        // Computed in u128 so that committees with a total stake close to u64::MAX don't overflow
+        (2 * self.total_stake as u128 / 3 + 1) as StakeUnit
+    }
+
+    /// Verify a signature produced by a single member of the committee, with
+    /// [`Bls12381PrivateKey::sign_validator_message`].
+    pub fn verify_signature(
+        &self,
+        message: &[u8],
+        signature: &ValidatorSignature,
+    ) -> Result<(), SignatureError> {
+        self.check_epoch(signature.epoch)?;
+
+        let verifying_key = self
+            .verifying_keys
+            .iter()
+            .find(|key| key.public_key() == signature.public_key)
+            .ok_or_else(|| {
+                SignatureError::from_source("signature was produced by a non-committee member")
+            })?;
+
+        verifying_key.verify(message, signature)
+    }
+
+    /// Verify that `signature` certifies `message`, i.e. that it is a valid aggregated signature
+    /// produced by members of the committee holding at least a quorum of the stake.
+    pub fn verify_aggregated(
+        &self,
+        message: &[u8],
+        signature: &ValidatorAggregatedSignature,
+    ) -> Result<(), SignatureError> {
+        self.check_epoch(signature.epoch)?;
+
+        let mut signed_stake: StakeUnit = 0;
+        let mut public_keys = Vec::with_capacity(signature.bitmap.len() as usize);
+        for index in &signature.bitmap {
+            let (member, verifying_key) = self
+                .committee
+                .members
+                .get(index as usize)
+                .zip(self.verifying_keys.get(index as usize))
+                .ok_or_else(|| {
+                    SignatureError::from_source(format!(
+                        "signer index {index} is out of range for a committee of {} members",
+                        self.committee.members.len()
+                    ))
+                })?;
+
+            // Can't overflow since the total stake of the committee doesn't
+            signed_stake += member.stake;
+            public_keys.push(&verifying_key.0);
+        }
+
+        let threshold = self.quorum_threshold();
+        if signed_stake < threshold {
+            return Err(SignatureError::from_source(format!(
+                "insufficient stake: signers hold {signed_stake} of the {threshold} required"
+            )));
+        }
+
+        let aggregated_signature =
+            min_sig::Signature::sig_validate(signature.signature.inner(), true)
+                .map_err(blst_error)?;
+        match aggregated_signature.fast_aggregate_verify(
+            false,
+            &validator_message(message, signature.epoch),
+            DST_G1,
+            &public_keys,
+        ) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(blst_error(e)),
+        }
+    }
+
+    /// Verify that `signature` is a valid certificate of `checkpoint` produced by the committee.
+    pub fn verify_checkpoint_summary(
+        &self,
+        checkpoint: &CheckpointSummary,
+        signature: &ValidatorAggregatedSignature,
+    ) -> Result<(), SignatureError> {
+        if checkpoint.epoch != signature.epoch {
+            return Err(SignatureError::from_source(format!(
+                "checkpoint of epoch {} certified for epoch {}",
+                checkpoint.epoch, signature.epoch
+            )));
+        }
+
+        self.verify_aggregated(&checkpoint_message(checkpoint), signature)
+    }
+
+    /// Verify that `checkpoint` is a certified end-of-epoch checkpoint of the committee's epoch,
+    /// and return the committee of the next epoch it designates.
+    ///
+    /// The members of the returned committee are in the order the end-of-epoch data lists them,
+    /// which is the one the bitmaps of certificates of the next epoch refer to.
+    pub fn verify_committee_transition(
+        &self,
+        checkpoint: &SignedCheckpointSummary,
+    ) -> Result<ValidatorCommittee, SignatureError> {
+        self.verify_checkpoint_summary(&checkpoint.checkpoint, &checkpoint.signature)?;
+
+        let end_of_epoch_data = checkpoint
+            .checkpoint
+            .end_of_epoch_data
+            .as_ref()
+            .ok_or_else(|| {
+                SignatureError::from_source(format!(
+                    "checkpoint {} isn't the last checkpoint of epoch {}",
+                    checkpoint.checkpoint.sequence_number, checkpoint.checkpoint.epoch
+                ))
+            })?;
+
+        let members = &end_of_epoch_data.next_epoch_committee;
+        if members.is_empty() {
+            return Err(SignatureError::from_source("next epoch committee is empty"));
+        }
+        let mut public_keys = std::collections::BTreeSet::new();
+        for member in members {
+            if !public_keys.insert(member.public_key) {
+                return Err(SignatureError::from_source(format!(
+                    "validator {} is a member of the next epoch committee more than once",
+                    member.public_key
+                )));
+            }
+        }
+
+        let next = ValidatorCommittee {
+            epoch: self.committee.epoch + 1,
+            members: members.clone(),
+        };
+        // Validates the public keys and total stake of the new committee
+        let next = Self::new(next)?;
+        if next.total_stake == 0 {
+            return Err(SignatureError::from_source(
+                "next epoch committee has no stake",
+            ));
+        }
+        Ok(next.committee)
+    }
+
+    /// Verify that `effects` were signed by a member of the committee.
+    pub fn verify_signed_transaction_effects(
+        &self,
+        effects: &SignedTransactionEffects,
+    ) -> Result<(), SignatureError> {
+        check_effects_epoch(&effects.effects, effects.signature.epoch)?;
+        self.verify_signature(&effects_message(&effects.effects), &effects.signature)
+    }
+
+    /// Verify that `effects` are certified by the committee, proving the finality of the
+    /// transaction they are for.
+    pub fn verify_certified_transaction_effects(
+        &self,
+        effects: &CertifiedTransactionEffects,
+    ) -> Result<(), SignatureError> {
+        check_effects_epoch(&effects.effects, effects.signature.epoch)?;
+        self.verify_aggregated(&effects_message(&effects.effects), &effects.signature)
+    }
+
+    fn check_epoch(&self, epoch: EpochId) -> Result<(), SignatureError> {
+        if epoch != self.committee.epoch {
+            return Err(SignatureError::from_source(format!(
+                "signature for epoch {epoch} checked against the committee of epoch {}",
+                self.committee.epoch
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Walk a chain of trust from `committee` to the committee of a later epoch.
+///
+/// `checkpoints` must hold the certified end-of-epoch checkpoint of each epoch from the one of
+/// `committee` onwards, in order. Starting from a trusted committee, e.g. the one of genesis, this
+/// establishes trust in the committee of the epoch following the last checkpoint.
+pub fn verify_committee_chain<'a, I>(
+    committee: ValidatorCommittee,
+    checkpoints: I,
+) -> Result<ValidatorCommittee, SignatureError>
+where
+    I: IntoIterator<Item = &'a SignedCheckpointSummary>,
+{
+    checkpoints
+        .into_iter()
+        .try_fold(committee, |committee, checkpoint| {
+            ValidatorCommitteeSignatureVerifier::new(committee)?
+                .verify_committee_transition(checkpoint)
+        })
+}
+
+/// Aggregate the signatures of multiple validators into a certificate.
+///
+/// The `bitmap` of the resulting signature is built based on the position of each signer in
+/// `committee`. Every signature must be for the committee's epoch, and signed by one of its
+/// members.
+pub fn aggregate_validator_signatures<'a, I>(
+    committee: &ValidatorCommittee,
+    signatures: I,
+) -> Result<ValidatorAggregatedSignature, SignatureError>
+where
+    I: IntoIterator<Item = &'a ValidatorSignature>,
+{
+    let mut bitmap = roaring::RoaringBitmap::new();
+    let mut blst_signatures = Vec::new();
+
+    for signature in signatures {
+        if signature.epoch != committee.epoch {
+            return Err(SignatureError::from_source(format!(
+                "signature for epoch {} aggregated for the committee of epoch {}",
+                signature.epoch, committee.epoch
+            )));
+        }
+
+        let index = committee
+            .members
+            .iter()
+            .position(|member| member.public_key == signature.public_key)
+            .ok_or_else(|| {
+                SignatureError::from_source("signature was produced by a non-committee member")
+            })?;
+        if !bitmap.insert(index as u32) {
+            return Err(SignatureError::from_source(format!(
+                "duplicate signature from committee member {index}"
+            )));
+        }
+
+        blst_signatures.push(
+            min_sig::Signature::sig_validate(signature.signature.inner(), true)
+                .map_err(blst_error)?,
+        );
+    }
+
+    let blst_signatures = blst_signatures.iter().collect::<Vec<_>>();
+    let signature = min_sig::AggregateSignature::aggregate(&blst_signatures, false)
+        .map_err(blst_error)?
+        .to_signature();
+
+    Ok(ValidatorAggregatedSignature {
+        epoch: committee.epoch,
+        signature: Bls12381Signature::new(signature.to_bytes()),
+        bitmap,
+    })
+}
+
+/// The message actually signed by validators: the message itself followed by the epoch.
+pub(crate) fn validator_message(message: &[u8], epoch: EpochId) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(message.len() + std::mem::size_of::<EpochId>());
+    buf.extend_from_slice(message);
+    buf.extend_from_slice(&epoch.to_le_bytes());
+    buf
+}
+
+pub(crate) fn checkpoint_message(checkpoint: &CheckpointSummary) -> Vec<u8> {
+    IntentMessage::new(Intent::checkpoint_summary(), checkpoint).to_bytes()
+}
+
+pub(crate) fn effects_message(effects: &TransactionEffects) -> Vec<u8> {
+    IntentMessage::new(Intent::transaction_effects(), effects).to_bytes()
+}
+
+fn check_effects_epoch(effects: &TransactionEffects, epoch: EpochId) -> Result<(), SignatureError> {
+    if effects.epoch() != epoch {
+        return Err(SignatureError::from_source(format!(
+            "effects of epoch {} signed for epoch {epoch}",
+            effects.epoch()
+        )));
+    }
+
+    Ok(())
+}
+
+fn blst_error(error: BLST_ERROR) -> SignatureError {
+    SignatureError::from_source(format!("bls12381 error: {error:?}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::validator_committee;
+    use crate::test_util::validator_keys;
+    use sui_sdk_types::ExecutionStatus;
+    use sui_sdk_types::GasCostSummary;
+    use sui_sdk_types::TransactionEffectsV2;
+    use sui_sdk_types::ValidatorCommitteeMember;
+    use test_strategy::proptest;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn checkpoint() -> CheckpointSummary {
+        CheckpointSummary {
+            epoch: 7,
+            sequence_number: 42,
+            network_total_transactions: 100,
+            content_digest: Default::default(),
+            previous_digest: None,
+            epoch_rolling_gas_cost_summary: GasCostSummary::default(),
+            timestamp_ms: 0,
+            checkpoint_commitments: vec![],
+            end_of_epoch_data: None,
+            version_specific_data: vec![],
+        }
+    }
+
+    #[proptest(cases = 8)]
+    fn raw_public_key_verification(key: Bls12381PrivateKey, message: Vec<u8>) {
+        let signature: Bls12381Signature = key.sign(&message);
+        crate::test_util::check_raw_verification(&key.public_key(), &message, &signature);
+    }
+
+    #[test]
+    fn checkpoint_certificate() {
+        let keys = validator_keys(1);
+        let verifier =
+            ValidatorCommitteeSignatureVerifier::new(validator_committee(7, &keys, &[2500; 4]))
+                .unwrap();
+        assert_eq!(verifier.quorum_threshold(), 6667);
+
+        let checkpoint = checkpoint();
+        let signatures = keys
+            .iter()
+            .map(|key| key.sign_checkpoint_summary(&checkpoint))
+            .collect::<Vec<_>>();
+        verifier
+            .verify_signature(&checkpoint_message(&checkpoint), &signatures[0])
+            .unwrap();
+
+        // A quorum of signatures certifies the checkpoint
+        let certificate =
+            aggregate_validator_signatures(verifier.committee(), &signatures[1..]).unwrap();
+        assert_eq!(certificate.bitmap.iter().collect::<Vec<_>>(), [1, 2, 3]);
+        verifier
+            .verify_checkpoint_summary(&checkpoint, &certificate)
+            .unwrap();
+
+        // But not another checkpoint
+        let mut other = checkpoint.clone();
+        other.sequence_number += 1;
+        verifier
+            .verify_checkpoint_summary(&other, &certificate)
+            .unwrap_err();
+
+        // Not enough stake
+        let certificate =
+            aggregate_validator_signatures(verifier.committee(), &signatures[..2]).unwrap();
+        verifier
+            .verify_checkpoint_summary(&checkpoint, &certificate)
+            .unwrap_err();
+
+        // Bitmap claiming a signer that didn't sign
+        let mut certificate =
+            aggregate_validator_signatures(verifier.committee(), &signatures[1..]).unwrap();
+        certificate.bitmap.insert(0);
+        verifier
+            .verify_checkpoint_summary(&checkpoint, &certificate)
+            .unwrap_err();
+
+        // Bitmap index out of range
+        certificate.bitmap.insert(4);
+        verifier
+            .verify_checkpoint_summary(&checkpoint, &certificate)
+            .unwrap_err();
+
+        // Duplicate signers
+        aggregate_validator_signatures(
+            verifier.committee(),
+            [&signatures[0], &signatures[1], &signatures[0]],
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn effects_certificate() {
+        let keys = validator_keys(1);
+        let verifier =
+            ValidatorCommitteeSignatureVerifier::new(validator_committee(7, &keys, &[2500; 4]))
+                .unwrap();
+
+        let effects = TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            status: ExecutionStatus::Success,
+            epoch: 7,
+            gas_used: GasCostSummary::default(),
+            transaction_digest: Default::default(),
+            gas_object_index: None,
+            events_digest: None,
+            dependencies: vec![],
+            lamport_version: 1,
+            changed_objects: vec![],
+            unchanged_shared_objects: vec![],
+            auxiliary_data_digest: None,
+        }));
+        let signed = keys
+            .iter()
+            .map(|key| key.sign_transaction_effects(&effects))
+            .collect::<Vec<_>>();
+        verifier
+            .verify_signed_transaction_effects(&signed[0])
+            .unwrap();
+
+        let mut certified = CertifiedTransactionEffects {
+            effects: effects.clone(),
+            signature: aggregate_validator_signatures(
+                verifier.committee(),
+                signed[..3].iter().map(|signed| &signed.signature),
+            )
+            .unwrap(),
+        };
+        verifier
+            .verify_certified_transaction_effects(&certified)
+            .unwrap();
+
+        // A checkpoint signature can't be passed off as an effects signature
+        let mut forged = signed[0].clone();
+        forged.signature = keys[0].sign_checkpoint_summary(&checkpoint());
+        verifier
+            .verify_signed_transaction_effects(&forged)
+            .unwrap_err();
+
+        // Effects of a different transaction
+        let TransactionEffects::V2(inner) = &mut certified.effects else {
+            unreachable!()
+        };
+        inner.lamport_version += 1;
+        verifier
+            .verify_certified_transaction_effects(&certified)
+            .unwrap_err();
+    }
+
+    fn end_of_epoch(
+        signers: &[Bls12381PrivateKey],
+        committee: &ValidatorCommittee,
+        next_epoch_committee: Vec<ValidatorCommitteeMember>,
+    ) -> SignedCheckpointSummary {
+        let mut checkpoint = checkpoint();
+        checkpoint.epoch = committee.epoch;
+        checkpoint.end_of_epoch_data = Some(sui_sdk_types::EndOfEpochData {
+            next_epoch_committee,
+            next_epoch_protocol_version: 1,
+            epoch_commitments: vec![],
+        });
+        let signatures = signers
+            .iter()
+            .map(|key| key.sign_checkpoint_summary(&checkpoint))
+            .collect::<Vec<_>>();
+        SignedCheckpointSummary {
+            signature: aggregate_validator_signatures(committee, &signatures).unwrap(),
+            checkpoint,
+        }
+    }
+
+    #[test]
+    fn committee_transition() {
+        let keys = validator_keys(1);
+        let genesis = validator_committee(7, &keys[..3], &[1; 3]);
+
+        // Epoch 7 hands over to a committee with a new member, which then hands over to a committee
+        // without one of the original members
+        let epoch_8 = validator_committee(7, &keys, &[1; 4]).members;
+        let first = end_of_epoch(&keys[..3], &genesis, epoch_8.clone());
+        let committee_8 = ValidatorCommitteeSignatureVerifier::new(genesis.clone())
+            .unwrap()
+            .verify_committee_transition(&first)
+            .unwrap();
+        assert_eq!(committee_8.epoch, 8);
+        assert_eq!(committee_8.members, epoch_8);
+
+        let epoch_9 = validator_committee(7, &keys[1..], &[5; 3]).members;
+        let second = end_of_epoch(&keys[1..], &committee_8, epoch_9.clone());
+        let committee_9 = verify_committee_chain(genesis.clone(), [&first, &second]).unwrap();
+        assert_eq!(committee_9.epoch, 9);
+        assert_eq!(committee_9.members, epoch_9);
+
+        // Skipping an epoch breaks the chain
+        verify_committee_chain(genesis.clone(), [&second]).unwrap_err();
+
+        // A committee can't be handed over by a minority of the current one, e.g. by the new member
+        // of the next one
+        let forged = end_of_epoch(&keys[2..], &committee_8, epoch_8.clone());
+        verify_committee_chain(genesis.clone(), [&first, &forged]).unwrap_err();
+
+        let verifier = ValidatorCommitteeSignatureVerifier::new(genesis.clone()).unwrap();
+        // Not an end-of-epoch checkpoint
+        let mut regular = end_of_epoch(&keys[..3], &genesis, epoch_8.clone());
+        regular.checkpoint.end_of_epoch_data = None;
+        let signatures = keys[..3]
+            .iter()
+            .map(|key| key.sign_checkpoint_summary(&regular.checkpoint))
+            .collect::<Vec<_>>();
+        regular.signature = aggregate_validator_signatures(&genesis, &signatures).unwrap();
+        verifier.verify_committee_transition(&regular).unwrap_err();
+
+        // Invalid next committees
+        let duplicated = vec![epoch_8[0].clone(), epoch_8[0].clone()];
+        let mut no_stake = epoch_8.clone();
+        no_stake.iter_mut().for_each(|member| member.stake = 0);
+        for next in [vec![], duplicated, no_stake] {
+            let checkpoint = end_of_epoch(&keys[..3], &genesis, next);
+            verifier
+                .verify_committee_transition(&checkpoint)
+                .unwrap_err();
+        }
+    }
+
+    #[test]
+    fn wrong_epoch() {
+        let keys = validator_keys(1);
+        let verifier =
+            ValidatorCommitteeSignatureVerifier::new(validator_committee(7, &keys, &[1; 4]))
+                .unwrap();
+
+        let mut checkpoint = checkpoint();
+        checkpoint.epoch = 8;
+        let signatures = keys
+            .iter()
+            .map(|key| key.sign_checkpoint_summary(&checkpoint))
+            .collect::<Vec<_>>();
+
+        let mut committee = verifier.committee().clone();
+        committee.epoch = 8;
+        let certificate = aggregate_validator_signatures(&committee, &signatures).unwrap();
+        verifier
+            .verify_checkpoint_summary(&checkpoint, &certificate)
+            .unwrap_err();
+    }
 }
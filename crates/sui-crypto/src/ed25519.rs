@@ -229,6 +229,12 @@ impl Verifier<UserSignature> for Ed25519VerifyingKey {
     }
 }
 
+impl crate::PublicKeyVerifier<Ed25519Signature> for Ed25519PublicKey {
+    fn verify(&self, message: &[u8], signature: &Ed25519Signature) -> Result<(), SignatureError> {
+        Ed25519VerifyingKey::new(self)?.verify(message, signature)
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct Ed25519Verifier {}
 
@@ -285,6 +291,12 @@ mod test {
     //         .unwrap();
     // }
 
+    #[proptest]
+    fn raw_public_key_verification(signer: Ed25519PrivateKey, message: Vec<u8>) {
+        let signature: Ed25519Signature = signer.try_sign(&message).unwrap();
+        crate::test_util::check_raw_verification(&signer.public_key(), &message, &signature);
+    }
+
     #[proptest]
     fn personal_message_signing(signer: Ed25519PrivateKey, message: Vec<u8>) {
         let message = PersonalMessage(message.into());
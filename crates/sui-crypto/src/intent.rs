@@ -0,0 +1,260 @@
+//! Signing of values under an explicit intent scope.
+//!
+//! Every signature in Sui commits to an [`Intent`], whose scope says what kind of message was
+//! signed, so that a signature over one kind of message can never be replayed as a signature
+//! over another. [`sign_with_intent`] and [`verify_with_intent`] sign and verify any kind of
+//! message through a single API, taking a [`SigningScope`] marker which fixes both the type of
+//! the value signed and the type of the signature produced:
+//!
+//! ```
+//! use sui_crypto::intent;
+//! use sui_crypto::SignatureError;
+//! use sui_crypto::Signer;
+//! use sui_sdk_types::Transaction;
+//! use sui_sdk_types::UserSignature;
+//!
+//! fn sign(
+//!     key: &impl Signer<UserSignature>,
+//!     transaction: &Transaction,
+//! ) -> Result<UserSignature, SignatureError> {
+//!     intent::sign_with_intent(key, intent::TransactionData, transaction)
+//! }
+//! ```
+//!
+//! A value can't be signed under the scope of another kind of message, e.g. a transaction can't be
+//! signed as a personal message:
+//!
+//! ```compile_fail
+//! use sui_crypto::intent;
+//! use sui_crypto::SignatureError;
+//! use sui_crypto::Signer;
+//! use sui_sdk_types::Transaction;
+//! use sui_sdk_types::UserSignature;
+//!
+//! fn sign(
+//!     key: &impl Signer<UserSignature>,
+//!     transaction: &Transaction,
+//! ) -> Result<UserSignature, SignatureError> {
+//!     intent::sign_with_intent(key, intent::PersonalMessage, transaction)
+//! }
+//! ```
+//!
+//! User scopes sign the [`signing_digest`] of their intent message, while validator scopes sign
+//! the intent message bytes followed by the little-endian epoch of the signature, as BLS12-381
+//! does the hashing itself.
+//!
+//! [`signing_digest`]: sui_sdk_types::IntentMessage::signing_digest
+
+use sui_sdk_types::hash::Signable;
+use sui_sdk_types::Intent;
+use sui_sdk_types::Transaction;
+use sui_sdk_types::UserSignature;
+
+use crate::SignatureError;
+use crate::Signer;
+use crate::Verifier;
+
+#[cfg(feature = "bls12381")]
+use crate::bls12381::checkpoint_message;
+#[cfg(feature = "bls12381")]
+use crate::bls12381::effects_message;
+#[cfg(feature = "bls12381")]
+use crate::bls12381::validator_message;
+
+/// The scope a value is signed under, as a type.
+///
+/// This trait is sealed: it is implemented for [`TransactionData`], [`PersonalMessage`],
+/// [`CheckpointSummary`], [`TransactionEffects`] and [`ProofOfPossession`] only, the latter three
+/// requiring the `bls12381` feature.
+pub trait SigningScope: private::Sealed {
+    /// The type of the values signed under this scope.
+    type Value: ?Sized;
+
+    /// The type of the signatures produced under this scope.
+    type Signature;
+
+    /// The intent signatures under this scope are made with.
+    const INTENT: Intent;
+
+    /// The exact bytes a signature over `value` is made on.
+    fn signing_message(value: &Self::Value) -> Vec<u8>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The scope of a user signature over a [`Transaction`].
+#[derive(Clone, Copy, Debug)]
+pub struct TransactionData;
+
+/// The scope of a user signature over a personal message, given as its raw bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct PersonalMessage;
+
+#[cfg(feature = "bls12381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bls12381")))]
+/// The scope of a validator signature over a
+/// [`CheckpointSummary`](sui_sdk_types::CheckpointSummary), for the epoch of the checkpoint.
+#[derive(Clone, Copy, Debug)]
+pub struct CheckpointSummary;
+
+#[cfg(feature = "bls12381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bls12381")))]
+/// The scope of a validator signature over
+/// [`TransactionEffects`](sui_sdk_types::TransactionEffects), for the epoch the transaction was
+/// executed in.
+#[derive(Clone, Copy, Debug)]
+pub struct TransactionEffects;
+
+#[cfg(feature = "bls12381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bls12381")))]
+/// The scope of a validator's proof of possession of its protocol key, made at genesis or when
+/// joining the committee over the validator's protocol public key and address.
+#[derive(Clone, Copy, Debug)]
+pub struct ProofOfPossession;
+
+impl private::Sealed for TransactionData {}
+impl private::Sealed for PersonalMessage {}
+#[cfg(feature = "bls12381")]
+impl private::Sealed for CheckpointSummary {}
+#[cfg(feature = "bls12381")]
+impl private::Sealed for TransactionEffects {}
+#[cfg(feature = "bls12381")]
+impl private::Sealed for ProofOfPossession {}
+
+impl SigningScope for TransactionData {
+    type Value = Transaction;
+    type Signature = UserSignature;
+    const INTENT: Intent = Intent::sui_transaction();
+
+    fn signing_message(value: &Transaction) -> Vec<u8> {
+        Signable::signing_digest(value).to_vec()
+    }
+}
+
+impl SigningScope for PersonalMessage {
+    type Value = [u8];
+    type Signature = UserSignature;
+    const INTENT: Intent = Intent::personal_message();
+
+    fn signing_message(value: &[u8]) -> Vec<u8> {
+        Signable::signing_digest(&sui_sdk_types::PersonalMessage(value.into())).to_vec()
+    }
+}
+
+#[cfg(feature = "bls12381")]
+impl SigningScope for CheckpointSummary {
+    type Value = sui_sdk_types::CheckpointSummary;
+    type Signature = sui_sdk_types::Bls12381Signature;
+    const INTENT: Intent = Intent::checkpoint_summary();
+
+    fn signing_message(value: &sui_sdk_types::CheckpointSummary) -> Vec<u8> {
+        validator_message(&checkpoint_message(value), value.epoch)
+    }
+}
+
+#[cfg(feature = "bls12381")]
+impl SigningScope for TransactionEffects {
+    type Value = sui_sdk_types::TransactionEffects;
+    type Signature = sui_sdk_types::Bls12381Signature;
+    const INTENT: Intent = Intent::transaction_effects();
+
+    fn signing_message(value: &sui_sdk_types::TransactionEffects) -> Vec<u8> {
+        validator_message(&effects_message(value), value.epoch())
+    }
+}
+
+#[cfg(feature = "bls12381")]
+impl SigningScope for ProofOfPossession {
+    type Value = (sui_sdk_types::Bls12381PublicKey, sui_sdk_types::Address);
+    type Signature = sui_sdk_types::Bls12381Signature;
+    const INTENT: Intent = Intent::proof_of_possession();
+
+    fn signing_message(
+        (public_key, address): &(sui_sdk_types::Bls12381PublicKey, sui_sdk_types::Address),
+    ) -> Vec<u8> {
+        let mut message = public_key.as_bytes().to_vec();
+        message.extend_from_slice(address.as_bytes());
+        // Proofs of possession aren't tied to an epoch and are always made for epoch 0
+        validator_message(
+            &sui_sdk_types::IntentMessage::new(Self::INTENT, message).to_bytes(),
+            0,
+        )
+    }
+}
+
+/// Sign `value` under `scope`.
+pub fn sign_with_intent<S: SigningScope>(
+    signer: &impl Signer<S::Signature>,
+    _scope: S,
+    value: &S::Value,
+) -> Result<S::Signature, SignatureError> {
+    signer.try_sign(&S::signing_message(value))
+}
+
+/// Verify a signature over `value` made under `scope`.
+pub fn verify_with_intent<S: SigningScope>(
+    verifier: &impl Verifier<S::Signature>,
+    _scope: S,
+    value: &S::Value,
+    signature: &S::Signature,
+) -> Result<(), SignatureError> {
+    verifier.verify(&S::signing_message(value), signature)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_strategy::proptest;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[cfg(feature = "ed25519")]
+    #[proptest]
+    fn user_scopes(key: crate::ed25519::Ed25519PrivateKey, message: Vec<u8>) {
+        use crate::SuiSigner;
+
+        let signature = sign_with_intent(&key, PersonalMessage, &message).unwrap();
+        let expected = key
+            .sign_personal_message(&sui_sdk_types::PersonalMessage(message.as_slice().into()))
+            .unwrap();
+        assert_eq!(signature, expected);
+
+        let verifier = key.verifying_key();
+        verify_with_intent(&verifier, PersonalMessage, &message, &signature).unwrap();
+        verify_with_intent(&verifier, PersonalMessage, b"other", &signature).unwrap_err();
+    }
+
+    #[cfg(feature = "bls12381")]
+    #[proptest(cases = 8)]
+    fn validator_scopes(key: crate::bls12381::Bls12381PrivateKey) {
+        let checkpoint = sui_sdk_types::CheckpointSummary {
+            epoch: 7,
+            sequence_number: 42,
+            network_total_transactions: 100,
+            content_digest: Default::default(),
+            previous_digest: None,
+            epoch_rolling_gas_cost_summary: Default::default(),
+            timestamp_ms: 0,
+            checkpoint_commitments: vec![],
+            end_of_epoch_data: None,
+            version_specific_data: vec![],
+        };
+        let signature = sign_with_intent(&key, CheckpointSummary, &checkpoint).unwrap();
+        assert_eq!(
+            signature,
+            key.sign_checkpoint_summary(&checkpoint).signature
+        );
+
+        let verifier = key.verifying_key();
+        verify_with_intent(&verifier, CheckpointSummary, &checkpoint, &signature).unwrap();
+
+        let value = (key.public_key(), sui_sdk_types::Address::TWO);
+        let proof = sign_with_intent(&key, ProofOfPossession, &value).unwrap();
+        verify_with_intent(&verifier, ProofOfPossession, &value, &proof).unwrap();
+        // A proof of possession isn't a valid checkpoint signature
+        verify_with_intent(&verifier, CheckpointSummary, &checkpoint, &proof).unwrap_err();
+    }
+}
@@ -0,0 +1,86 @@
+//! Signing with secp256k1 keys held in AWS KMS.
+
+use aws_sdk_kms::primitives::Blob;
+use aws_sdk_kms::types::KeySpec;
+use aws_sdk_kms::types::MessageType;
+use aws_sdk_kms::types::SigningAlgorithmSpec;
+use aws_sdk_kms::Client;
+use sui_sdk_types::SigningDigest;
+
+use super::ecdsa_sha256_digest;
+use super::secp256k1_signature_from_der;
+use crate::remote::RemotePublicKey;
+use crate::remote::RemoteSigner;
+use crate::secp256k1::Secp256k1VerifyingKey;
+use crate::SignatureError;
+
+/// A [`RemoteSigner`] for an asymmetric `ECC_SECG_P256K1` key in AWS KMS.
+///
+/// The key can be referred to by any of the identifiers accepted by KMS, i.e. its key ID, its ARN,
+/// or an alias.
+#[derive(Clone, Debug)]
+pub struct AwsKmsSigner {
+    client: Client,
+    key_id: String,
+}
+
+impl AwsKmsSigner {
+    pub fn new<T: Into<String>>(client: Client, key_id: T) -> Self {
+        Self {
+            client,
+            key_id: key_id.into(),
+        }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+}
+
+impl RemoteSigner for AwsKmsSigner {
+    async fn public_key(&self) -> Result<RemotePublicKey, SignatureError> {
+        let output = self
+            .client
+            .get_public_key()
+            .key_id(&self.key_id)
+            .send()
+            .await
+            .map_err(SignatureError::from_source)?;
+
+        if output.key_spec() != Some(&KeySpec::EccSecgP256K1) {
+            return Err(SignatureError::from_source(format!(
+                "unsupported key spec {:?}, expected ECC_SECG_P256K1",
+                output.key_spec()
+            )));
+        }
+        let der = output
+            .public_key()
+            .ok_or_else(|| SignatureError::from_source("missing public key"))?;
+
+        Secp256k1VerifyingKey::from_der(der.as_ref())
+            .map(|verifying_key| RemotePublicKey::Secp256k1(verifying_key.public_key()))
+    }
+
+    async fn sign_digest(&self, digest: &SigningDigest) -> Result<Vec<u8>, SignatureError> {
+        let output = self
+            .client
+            .sign()
+            .key_id(&self.key_id)
+            .message(Blob::new(ecdsa_sha256_digest(digest)))
+            .message_type(MessageType::Digest)
+            .signing_algorithm(SigningAlgorithmSpec::EcdsaSha256)
+            .send()
+            .await
+            .map_err(SignatureError::from_source)?;
+
+        let der = output
+            .signature()
+            .ok_or_else(|| SignatureError::from_source("missing signature"))?;
+
+        secp256k1_signature_from_der(der.as_ref()).map(|signature| signature.as_bytes().to_vec())
+    }
+}
@@ -0,0 +1,137 @@
+//! Signing with secp256k1 keys held in GCP Cloud KMS.
+//!
+//! Requests are made against the Cloud KMS REST API, authenticated with an OAuth 2.0 access token
+//! obtained from an [`AccessTokenProvider`].
+
+use base64ct::Base64;
+use base64ct::Encoding;
+use serde_derive::Deserialize;
+use sui_sdk_types::SigningDigest;
+
+use super::ecdsa_sha256_digest;
+use super::secp256k1_signature_from_der;
+use crate::remote::RemotePublicKey;
+use crate::remote::RemoteSigner;
+use crate::secp256k1::Secp256k1VerifyingKey;
+use crate::SignatureError;
+
+/// The endpoint of the Cloud KMS REST API.
+pub const DEFAULT_ENDPOINT: &str = "https://cloudkms.googleapis.com/v1";
+
+/// The Cloud KMS algorithm of secp256k1 signing keys.
+const SECP256K1_ALGORITHM: &str = "EC_SIGN_SECP256K1_SHA256";
+
+/// A source of OAuth 2.0 access tokens for the Cloud KMS API.
+///
+/// Access tokens are short lived, so a provider is asked for a token before every request. This
+/// is implemented for `String` for the simple case of a token obtained out of band, e.g. with
+/// `gcloud auth print-access-token`.
+pub trait AccessTokenProvider {
+    fn access_token(
+        &self,
+    ) -> impl std::future::Future<Output = Result<String, SignatureError>> + Send;
+}
+
+impl AccessTokenProvider for String {
+    fn access_token(
+        &self,
+    ) -> impl std::future::Future<Output = Result<String, SignatureError>> + Send {
+        std::future::ready(Ok(self.clone()))
+    }
+}
+
+/// A [`RemoteSigner`] for an `EC_SIGN_SECP256K1_SHA256` key version in GCP Cloud KMS.
+///
+/// The key version is referred to by its resource name, i.e.
+/// `projects/*/locations/*/keyRings/*/cryptoKeys/*/cryptoKeyVersions/*`.
+#[derive(Clone, Debug)]
+pub struct GcpKmsSigner<T> {
+    client: reqwest::Client,
+    endpoint: String,
+    key_version: String,
+    token_provider: T,
+}
+
+#[derive(Deserialize)]
+struct PublicKeyResponse {
+    pem: String,
+    algorithm: String,
+}
+
+#[derive(Deserialize)]
+struct AsymmetricSignResponse {
+    signature: String,
+}
+
+impl<T: AccessTokenProvider> GcpKmsSigner<T> {
+    pub fn new<K: Into<String>>(key_version: K, token_provider: T) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            endpoint: DEFAULT_ENDPOINT.to_owned(),
+            key_version: key_version.into(),
+            token_provider,
+        }
+    }
+
+    /// Use `client` to make requests, e.g. to configure timeouts or proxies.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Make requests against `endpoint` instead of [`DEFAULT_ENDPOINT`], e.g. to use a regional
+    /// or private endpoint.
+    pub fn with_endpoint<E: Into<String>>(mut self, endpoint: E) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    pub fn key_version(&self) -> &str {
+        &self.key_version
+    }
+
+    async fn request<R: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<R, SignatureError> {
+        let token = self.token_provider.access_token().await?;
+        request
+            .bearer_auth(token)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SignatureError::from_source)?
+            .json()
+            .await
+            .map_err(SignatureError::from_source)
+    }
+}
+
+impl<T: AccessTokenProvider + Sync> RemoteSigner for GcpKmsSigner<T> {
+    async fn public_key(&self) -> Result<RemotePublicKey, SignatureError> {
+        let url = format!("{}/{}/publicKey", self.endpoint, self.key_version);
+        let response: PublicKeyResponse = self.request(self.client.get(url)).await?;
+
+        if response.algorithm != SECP256K1_ALGORITHM {
+            return Err(SignatureError::from_source(format!(
+                "unsupported key algorithm {}, expected {SECP256K1_ALGORITHM}",
+                response.algorithm
+            )));
+        }
+
+        Secp256k1VerifyingKey::from_pem(&response.pem)
+            .map(|verifying_key| RemotePublicKey::Secp256k1(verifying_key.public_key()))
+    }
+
+    async fn sign_digest(&self, digest: &SigningDigest) -> Result<Vec<u8>, SignatureError> {
+        let url = format!("{}/{}:asymmetricSign", self.endpoint, self.key_version);
+        let body = serde_json::json!({
+            "digest": { "sha256": Base64::encode_string(&ecdsa_sha256_digest(digest)) },
+        });
+        let response: AsymmetricSignResponse =
+            self.request(self.client.post(url).json(&body)).await?;
+
+        let der = Base64::decode_vec(&response.signature).map_err(SignatureError::from_source)?;
+        secp256k1_signature_from_der(&der).map(|signature| signature.as_bytes().to_vec())
+    }
+}
@@ -0,0 +1,72 @@
+//! [`RemoteSigner`] implementations backed by cloud key management services.
+//!
+//! Both AWS KMS and GCP Cloud KMS support secp256k1 keys, but return ECDSA signatures in their
+//! ASN.1 DER encoding and without normalizing `s`, whereas Sui only accepts the compact `r || s`
+//! encoding with `s` in the lower half of the curve order. [`secp256k1_signature_from_der`]
+//! performs this conversion and can be reused for other services with the same behavior.
+//!
+//! [`RemoteSigner`]: crate::remote::RemoteSigner
+
+use crate::SignatureError;
+use sui_sdk_types::Secp256k1Signature;
+use sui_sdk_types::SigningDigest;
+
+#[cfg(feature = "aws-kms")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "aws-kms")))]
+pub mod aws;
+
+#[cfg(feature = "gcp-kms")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "gcp-kms")))]
+pub mod gcp;
+
+/// Convert a DER encoded secp256k1 ECDSA signature to the compact encoding used by Sui,
+/// normalizing it to its low-s form.
+pub fn secp256k1_signature_from_der(der: &[u8]) -> Result<Secp256k1Signature, SignatureError> {
+    let signature = k256::ecdsa::Signature::from_der(der)?;
+    let signature = signature.normalize_s().unwrap_or(signature);
+    Ok(Secp256k1Signature::new(signature.to_bytes().into()))
+}
+
+/// The digest to have a KMS sign in order to produce an ECDSA signature over `digest`.
+///
+/// ECDSA signatures in Sui are over the SHA-256 hash of the signing digest, which is what KMS
+/// services expect to be given when signing a precomputed digest.
+fn ecdsa_sha256_digest(digest: &SigningDigest) -> [u8; 32] {
+    use sha2::Digest;
+
+    sha2::Sha256::digest(digest).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::secp256k1::Secp256k1PrivateKey;
+    use crate::secp256k1::Secp256k1VerifyingKey;
+    use signature::Signer;
+    use signature::Verifier;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn der_signature_normalization() {
+        let key = Secp256k1PrivateKey::new([9; 32]).unwrap();
+        let verifying_key = Secp256k1VerifyingKey::new(&key.public_key()).unwrap();
+        let message = b"hello";
+        let signature: Secp256k1Signature = key.sign(message);
+
+        let low_s = k256::ecdsa::Signature::from_bytes(signature.inner().into()).unwrap();
+        let high_s =
+            k256::ecdsa::Signature::from_scalars(low_s.r().to_bytes(), (-*low_s.s()).to_bytes())
+                .unwrap();
+        assert!(high_s.normalize_s().is_some());
+
+        for der in [low_s.to_der(), high_s.to_der()] {
+            let converted = secp256k1_signature_from_der(der.as_bytes()).unwrap();
+            assert_eq!(converted, signature);
+            verifying_key.verify(message, &converted).unwrap();
+        }
+
+        secp256k1_signature_from_der(signature.as_bytes()).unwrap_err();
+    }
+}
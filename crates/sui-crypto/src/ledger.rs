@@ -0,0 +1,589 @@
+//! Signing with a Ledger hardware wallet running the Sui app.
+//!
+//! Communication with the device is abstracted behind the [`LedgerTransport`] trait so that any
+//! transport (USB HID, Bluetooth, Speculos, etc) can be plugged in. [`LedgerSigner`] implements
+//! the Sui app's APDU protocol on top of a transport and implements [`AsyncSigner`].
+
+use std::collections::HashMap;
+
+use crate::ed25519::Ed25519VerifyingKey;
+use crate::AsyncSigner;
+use crate::SignatureError;
+use signature::Verifier;
+use sui_sdk_types::Address;
+use sui_sdk_types::Ed25519PublicKey;
+use sui_sdk_types::Ed25519Signature;
+use sui_sdk_types::Intent;
+use sui_sdk_types::IntentAppId;
+use sui_sdk_types::IntentScope;
+use sui_sdk_types::IntentVersion;
+use sui_sdk_types::PersonalMessage;
+use sui_sdk_types::SimpleSignature;
+use sui_sdk_types::Transaction;
+use sui_sdk_types::UserSignature;
+
+const CLA: u8 = 0x00;
+const INS_GET_VERSION: u8 = 0x00;
+const INS_VERIFY_ADDRESS: u8 = 0x01;
+const INS_GET_PUBLIC_KEY: u8 = 0x02;
+const INS_SIGN: u8 = 0x03;
+
+const STATUS_OK: u16 = 0x9000;
+
+/// Maximum number of payload bytes included in a single block of the block protocol.
+const CHUNK_SIZE: usize = 180;
+
+/// Instructions sent from the host to the device in the block protocol.
+#[repr(u8)]
+enum HostToLedger {
+    Start = 0,
+    GetChunkResponseSuccess = 1,
+    GetChunkResponseFailure = 2,
+    PutChunkResponse = 3,
+    ResultAccumulatingResponse = 4,
+}
+
+/// Instructions sent from the device to the host in the block protocol.
+#[repr(u8)]
+enum LedgerToHost {
+    ResultAccumulating = 0,
+    ResultFinal = 1,
+    GetChunk = 2,
+    PutChunk = 3,
+}
+
+/// A transport able to exchange APDUs with a Ledger device.
+pub trait LedgerTransport {
+    /// Send a single APDU command to the device, returning its response including the trailing
+    /// 2-byte status word.
+    fn exchange(
+        &self,
+        apdu: &[u8],
+    ) -> impl std::future::Future<Output = Result<Vec<u8>, SignatureError>> + Send;
+}
+
+/// A BIP-32 derivation path, e.g. `m/44'/784'/0'/0'/0'`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    const HARDENED: u32 = 0x8000_0000;
+
+    /// The derivation path for the ed25519 key with the given account index,
+    /// `m/44'/784'/{account}'/0'/0'`.
+    pub fn ed25519(account: u32) -> Self {
+        Self(vec![
+            44 | Self::HARDENED,
+            784 | Self::HARDENED,
+            account | Self::HARDENED,
+            Self::HARDENED,
+            Self::HARDENED,
+        ])
+    }
+
+    /// The components of the path, with the hardened bit set where applicable.
+    pub fn components(&self) -> &[u32] {
+        &self.0
+    }
+
+    fn to_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(1 + self.0.len() * 4);
+        payload.push(self.0.len() as u8);
+        for component in &self.0 {
+            payload.extend_from_slice(&component.to_le_bytes());
+        }
+        payload
+    }
+}
+
+impl Default for DerivationPath {
+    fn default() -> Self {
+        Self::ed25519(0)
+    }
+}
+
+impl std::str::FromStr for DerivationPath {
+    type Err = SignatureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("m/").unwrap_or(s);
+
+        let components = s
+            .split('/')
+            .map(|component| {
+                let (index, hardened) = match component.strip_suffix('\'') {
+                    Some(index) => (index, true),
+                    None => (component, false),
+                };
+                let index: u32 = index
+                    .parse()
+                    .ok()
+                    .filter(|index| index & Self::HARDENED == 0)
+                    .ok_or_else(|| {
+                        SignatureError::from_source(format!(
+                            "invalid derivation path component '{component}'"
+                        ))
+                    })?;
+
+                Ok(if hardened {
+                    index | Self::HARDENED
+                } else {
+                    index
+                })
+            })
+            .collect::<Result<Vec<_>, SignatureError>>()?;
+
+        if components.is_empty() || components.len() > u8::MAX as usize {
+            return Err(SignatureError::from_source(
+                "invalid derivation path length",
+            ));
+        }
+
+        Ok(Self(components))
+    }
+}
+
+impl std::fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("m")?;
+        for component in &self.0 {
+            if component & Self::HARDENED != 0 {
+                write!(f, "/{}'", component & !Self::HARDENED)?;
+            } else {
+                write!(f, "/{component}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A client for the Sui app running on a Ledger device.
+#[derive(Clone, Debug)]
+pub struct Ledger<T> {
+    transport: T,
+}
+
+impl<T: LedgerTransport> Ledger<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Return the `(major, minor, patch)` version of the Sui app.
+    pub async fn app_version(&self) -> Result<(u8, u8, u8), SignatureError> {
+        let response = self.send_chunks(INS_GET_VERSION, &[]).await?;
+        match response.as_slice() {
+            [major, minor, patch, ..] => Ok((*major, *minor, *patch)),
+            _ => Err(SignatureError::from_source("invalid version response")),
+        }
+    }
+
+    /// Return the public key and address for the key at `path`.
+    ///
+    /// If `display` is set the address is also shown on the device for the user to confirm.
+    pub async fn get_public_key(
+        &self,
+        path: &DerivationPath,
+        display: bool,
+    ) -> Result<(Ed25519PublicKey, Address), SignatureError> {
+        let ins = if display {
+            INS_VERIFY_ADDRESS
+        } else {
+            INS_GET_PUBLIC_KEY
+        };
+        let response = self.send_chunks(ins, &[&path.to_payload()]).await?;
+
+        let (&key_len, rest) = response
+            .split_first()
+            .ok_or_else(|| SignatureError::from_source("empty public key response"))?;
+        let public_key = rest
+            .get(..key_len as usize)
+            .and_then(|bytes| Ed25519PublicKey::from_bytes(bytes).ok())
+            .ok_or_else(|| SignatureError::from_source("invalid public key response"))?;
+        let address = public_key.derive_address();
+
+        // Newer versions of the app also return the address, make sure it matches
+        if let Some((&address_len, reported)) = rest[key_len as usize..].split_first() {
+            if reported.get(..address_len as usize) != Some(address.as_bytes()) {
+                return Err(SignatureError::from_source(
+                    "address reported by the device doesn't match its public key",
+                ));
+            }
+        }
+
+        Ok((public_key, address))
+    }
+
+    /// Sign an intent message with the key at `path`, returning the raw signature.
+    ///
+    /// The device parses and displays the message for the user to approve before signing it.
+    async fn sign_intent_message(
+        &self,
+        path: &DerivationPath,
+        intent_message: &[u8],
+    ) -> Result<Ed25519Signature, SignatureError> {
+        let mut message = Vec::with_capacity(4 + intent_message.len());
+        message.extend_from_slice(&(intent_message.len() as u32).to_le_bytes());
+        message.extend_from_slice(intent_message);
+
+        let response = self
+            .send_chunks(INS_SIGN, &[&message, &path.to_payload()])
+            .await?;
+
+        Ed25519Signature::from_bytes(&response)
+            .map_err(|_| SignatureError::from_source("invalid signature response"))
+    }
+
+    /// Send a command using the block protocol.
+    ///
+    /// Each parameter is split into chunks which are linked together into a list by prefixing
+    /// each chunk with the sha256 hash of the following one. The command itself only contains
+    /// the hash of the head of each parameter's list and the device then requests the individual
+    /// blocks as it needs them.
+    async fn send_chunks(&self, ins: u8, parameters: &[&[u8]]) -> Result<Vec<u8>, SignatureError> {
+        use sha2::Digest;
+
+        let mut blocks: HashMap<[u8; 32], Vec<u8>> = HashMap::new();
+        let mut payload = vec![HostToLedger::Start as u8];
+
+        for parameter in parameters {
+            let mut next_hash = [0; 32];
+            for chunk in parameter.chunks(CHUNK_SIZE).rev() {
+                let mut block = next_hash.to_vec();
+                block.extend_from_slice(chunk);
+                next_hash = sha2::Sha256::digest(&block).into();
+                blocks.insert(next_hash, block);
+            }
+            payload.extend_from_slice(&next_hash);
+        }
+
+        let mut result = Vec::new();
+        loop {
+            let response = self.exchange(ins, &payload).await?;
+            let (&instruction, data) = response
+                .split_first()
+                .ok_or_else(|| SignatureError::from_source("empty response from device"))?;
+
+            payload = match instruction {
+                i if i == LedgerToHost::ResultAccumulating as u8 => {
+                    result.extend_from_slice(data);
+                    vec![HostToLedger::ResultAccumulatingResponse as u8]
+                }
+                i if i == LedgerToHost::ResultFinal as u8 => {
+                    result.extend_from_slice(data);
+                    return Ok(result);
+                }
+                i if i == LedgerToHost::GetChunk as u8 => {
+                    match <[u8; 32]>::try_from(data).ok().and_then(|h| blocks.get(&h)) {
+                        Some(block) => {
+                            let mut payload = vec![HostToLedger::GetChunkResponseSuccess as u8];
+                            payload.extend_from_slice(block);
+                            payload
+                        }
+                        None => vec![HostToLedger::GetChunkResponseFailure as u8],
+                    }
+                }
+                i if i == LedgerToHost::PutChunk as u8 => {
+                    blocks.insert(sha2::Sha256::digest(data).into(), data.to_vec());
+                    vec![HostToLedger::PutChunkResponse as u8]
+                }
+                i => {
+                    return Err(SignatureError::from_source(format!(
+                        "unknown instruction {i} from device"
+                    )))
+                }
+            };
+        }
+    }
+
+    /// Exchange a single APDU, returning the response data with the status word stripped.
+    async fn exchange(&self, ins: u8, data: &[u8]) -> Result<Vec<u8>, SignatureError> {
+        let len = u8::try_from(data.len())
+            .map_err(|_| SignatureError::from_source("apdu payload too large"))?;
+        let mut apdu = vec![CLA, ins, 0, 0, len];
+        apdu.extend_from_slice(data);
+
+        let mut response = self.transport.exchange(&apdu).await?;
+        if response.len() < 2 {
+            return Err(SignatureError::from_source("response missing status word"));
+        }
+        let status = response.split_off(response.len() - 2);
+        let status = u16::from_be_bytes([status[0], status[1]]);
+        if status != STATUS_OK {
+            return Err(SignatureError::from_source(format!(
+                "device returned error status {status:#06x}"
+            )));
+        }
+
+        Ok(response)
+    }
+}
+
+/// A signer backed by a key held on a Ledger device.
+#[derive(Clone, Debug)]
+pub struct LedgerSigner<T> {
+    ledger: Ledger<T>,
+    path: DerivationPath,
+    public_key: Ed25519PublicKey,
+}
+
+impl<T: LedgerTransport> LedgerSigner<T> {
+    /// Create a signer for the key at `path`, retrieving its public key from the device.
+    pub async fn new(ledger: Ledger<T>, path: DerivationPath) -> Result<Self, SignatureError> {
+        let (public_key, _address) = ledger.get_public_key(&path, false).await?;
+        Ok(Self {
+            ledger,
+            path,
+            public_key,
+        })
+    }
+
+    pub fn ledger(&self) -> &Ledger<T> {
+        &self.ledger
+    }
+
+    pub fn path(&self) -> &DerivationPath {
+        &self.path
+    }
+
+    pub fn public_key(&self) -> &Ed25519PublicKey {
+        &self.public_key
+    }
+
+    pub fn address(&self) -> Address {
+        self.public_key.derive_address()
+    }
+
+    async fn sign<M: serde::Serialize + ?Sized>(
+        &self,
+        scope: IntentScope,
+        message: &M,
+        signing_digest: &[u8],
+    ) -> Result<UserSignature, SignatureError> {
+        let intent = Intent::new(scope, IntentVersion::V0, IntentAppId::Sui);
+        let mut intent_message = intent.to_bytes().to_vec();
+        bcs::serialize_into(&mut intent_message, message).map_err(SignatureError::from_source)?;
+
+        let signature = self
+            .ledger
+            .sign_intent_message(&self.path, &intent_message)
+            .await?;
+
+        // Don't trust the device to have signed what we asked for
+        Ed25519VerifyingKey::new(&self.public_key)?.verify(signing_digest, &signature)?;
+
+        Ok(UserSignature::Simple(SimpleSignature::Ed25519 {
+            signature,
+            public_key: self.public_key,
+        }))
+    }
+}
+
+impl<T: LedgerTransport + Sync> AsyncSigner for LedgerSigner<T> {
+    async fn sign_transaction_async(
+        &self,
+        transaction: &Transaction,
+    ) -> Result<UserSignature, SignatureError> {
+        self.sign(
+            IntentScope::TransactionData,
+            transaction,
+            &transaction.signing_digest(),
+        )
+        .await
+    }
+
+    async fn sign_personal_message_async(
+        &self,
+        message: &PersonalMessage<'_>,
+    ) -> Result<UserSignature, SignatureError> {
+        self.sign(
+            IntentScope::PersonalMessage,
+            message.0.as_ref(),
+            &message.signing_digest(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ed25519::Ed25519PrivateKey;
+    use crate::test_util::block_on;
+    use crate::SuiVerifier;
+    use signature::Signer;
+    use std::sync::Mutex;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    /// An in-memory emulation of the Sui Ledger app.
+    struct MockDevice {
+        key: Ed25519PrivateKey,
+        state: Mutex<Option<MockCommand>>,
+    }
+
+    struct MockCommand {
+        ins: u8,
+        pending: Vec<[u8; 32]>,
+        parameters: Vec<Vec<u8>>,
+        current: Vec<u8>,
+    }
+
+    impl MockDevice {
+        fn respond(&self, instruction: LedgerToHost, data: &[u8]) -> Vec<u8> {
+            let mut response = vec![instruction as u8];
+            response.extend_from_slice(data);
+            response.extend_from_slice(&STATUS_OK.to_be_bytes());
+            response
+        }
+
+        fn execute(&self, command: MockCommand) -> Vec<u8> {
+            match command.ins {
+                INS_GET_VERSION => self.respond(LedgerToHost::ResultFinal, &[1, 2, 3]),
+                INS_GET_PUBLIC_KEY | INS_VERIFY_ADDRESS => {
+                    let public_key = self.key.public_key();
+                    let mut data = vec![32];
+                    data.extend_from_slice(public_key.as_bytes());
+                    data.push(32);
+                    data.extend_from_slice(public_key.derive_address().as_bytes());
+                    self.respond(LedgerToHost::ResultFinal, &data)
+                }
+                INS_SIGN => {
+                    let message = &command.parameters[0];
+                    let len = u32::from_le_bytes(message[..4].try_into().unwrap()) as usize;
+                    assert_eq!(len, message.len() - 4);
+                    let digest = sui_sdk_types::hash::Hasher::digest(&message[4..]);
+                    let signature: Ed25519Signature = self.key.sign(digest.as_bytes());
+                    self.respond(LedgerToHost::ResultFinal, signature.as_bytes())
+                }
+                _ => vec![0x6d, 0x00],
+            }
+        }
+
+        fn request_next_chunk(&self, command: MockCommand) -> Vec<u8> {
+            match command.pending.first().copied() {
+                Some(hash) => {
+                    *self.state.lock().unwrap() = Some(command);
+                    self.respond(LedgerToHost::GetChunk, &hash)
+                }
+                None => self.execute(command),
+            }
+        }
+    }
+
+    impl LedgerTransport for MockDevice {
+        async fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, SignatureError> {
+            use sha2::Digest;
+
+            assert_eq!(apdu[0], CLA);
+            assert_eq!(apdu[4] as usize, apdu.len() - 5);
+            let ins = apdu[1];
+            let data = &apdu[5..];
+
+            let response = match data[0] {
+                // Start a new command, requesting the head block of each parameter
+                0 => self.request_next_chunk(MockCommand {
+                    ins,
+                    pending: data[1..]
+                        .chunks(32)
+                        .map(|hash| hash.try_into().unwrap())
+                        .collect(),
+                    parameters: Vec::new(),
+                    current: Vec::new(),
+                }),
+                // A requested block, follow the link to the next one
+                1 => {
+                    let mut command = self.state.lock().unwrap().take().unwrap();
+                    let block = &data[1..];
+                    let hash: [u8; 32] = sha2::Sha256::digest(block).into();
+                    assert_eq!(hash, command.pending.remove(0));
+
+                    let (next, chunk) = block.split_at(32);
+                    command.current.extend_from_slice(chunk);
+                    if next == [0; 32] {
+                        let parameter = std::mem::take(&mut command.current);
+                        command.parameters.push(parameter);
+                    } else {
+                        command.pending.insert(0, next.try_into().unwrap());
+                    }
+                    self.request_next_chunk(command)
+                }
+                _ => vec![0x6a, 0x80],
+            };
+
+            Ok(response)
+        }
+    }
+
+    fn mock_ledger() -> Ledger<MockDevice> {
+        Ledger::new(MockDevice {
+            key: Ed25519PrivateKey::new([3; 32]),
+            state: Mutex::new(None),
+        })
+    }
+
+    #[test]
+    fn derivation_path() {
+        let path: DerivationPath = "m/44'/784'/0'/0'/0'".parse().unwrap();
+        assert_eq!(path, DerivationPath::default());
+        assert_eq!(path.to_string(), "m/44'/784'/0'/0'/0'");
+        assert_eq!(
+            path.to_payload(),
+            [5, 44, 0, 0, 128, 16, 3, 0, 128, 0, 0, 0, 128, 0, 0, 0, 128, 0, 0, 0, 128]
+        );
+
+        let path: DerivationPath = "44'/784'/1'/0/7".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/784'/1'/0/7");
+
+        "m/44'/x'".parse::<DerivationPath>().unwrap_err();
+        "m/2147483648".parse::<DerivationPath>().unwrap_err();
+    }
+
+    #[test]
+    fn get_public_key() {
+        let ledger = mock_ledger();
+        let expected = ledger.transport().key.public_key();
+
+        assert_eq!(block_on(ledger.app_version()).unwrap(), (1, 2, 3));
+
+        let (public_key, address) =
+            block_on(ledger.get_public_key(&DerivationPath::default(), true)).unwrap();
+        assert_eq!(public_key, expected);
+        assert_eq!(address, expected.derive_address());
+    }
+
+    #[test]
+    fn sign_with_ledger() {
+        let signer = block_on(LedgerSigner::new(mock_ledger(), DerivationPath::default())).unwrap();
+        let verifier = crate::simple::SimpleVerifier;
+
+        // A message large enough to be split across multiple blocks
+        let message = PersonalMessage(vec![42; 1000].into());
+        let signature = block_on(signer.sign_personal_message_async(&message)).unwrap();
+        verifier
+            .verify_personal_message(&message, &signature)
+            .unwrap();
+
+        let signature = UserSignature::Simple(SimpleSignature::Ed25519 {
+            signature: Ed25519Signature::new([0; 64]),
+            public_key: *signer.public_key(),
+        });
+        verifier
+            .verify_personal_message(&message, &signature)
+            .unwrap_err();
+
+        let transaction: Transaction = {
+            use base64ct::Encoding;
+            let bytes = base64ct::Base64::decode_vec("AAAAACdZawPnpJRjmVcwDu6xrIumtq5NLO+6GHbs0iGdCoD7AQ0T0TolicYERdSvyCRjSSduDZLbSpBsZBoib+lF48EBcgAAAAAAAAAgpQr/Mudl9BdzyBdkbqTlqBw4/aJ21kAD/jpJKa05im4nWWsD56SUY5lXMA7usayLprauTSzvuhh27NIhnQqA++gDAAAAAAAAgIQeAAAAAAAA").unwrap();
+            bcs::from_bytes(&bytes).unwrap()
+        };
+        let signature = block_on(signer.sign_transaction_async(&transaction)).unwrap();
+        verifier
+            .verify_transaction(&transaction, &signature)
+            .unwrap();
+    }
+}
@@ -8,12 +8,27 @@ pub use signature::Error as SignatureError;
 pub use signature::Signer;
 pub use signature::Verifier;
 
+pub mod intent;
+
+#[cfg(any(test, feature = "test-utils"))]
+#[doc(hidden)]
+pub mod test_util;
+
 #[cfg(feature = "ed25519")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "ed25519")))]
 pub mod ed25519;
 
-#[allow(unused)]
-mod bls12381;
+#[cfg(feature = "bls12381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bls12381")))]
+pub mod bls12381;
+
+#[cfg(feature = "bls12381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bls12381")))]
+pub mod quorum;
+
+#[cfg(feature = "test-utils")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test-utils")))]
+pub mod test_signer;
 
 #[cfg(feature = "secp256k1")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "secp256k1")))]
@@ -31,6 +46,14 @@ pub mod passkey;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "zklogin")))]
 pub mod zklogin;
 
+#[cfg(feature = "ledger")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "ledger")))]
+pub mod ledger;
+
+#[cfg(any(feature = "aws-kms", feature = "gcp-kms"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "aws-kms", feature = "gcp-kms"))))]
+pub mod kms;
+
 #[cfg(any(
     feature = "ed25519",
     feature = "secp256r1",
@@ -83,6 +106,55 @@ pub mod multisig;
 #[doc(inline)]
 pub use multisig::UserSignatureVerifier;
 
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+mod signed_message;
+
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(
+        feature = "ed25519",
+        feature = "secp256r1",
+        feature = "secp256k1",
+        feature = "zklogin"
+    )))
+)]
+#[doc(inline)]
+pub use signed_message::AnySignedMessageVerifier;
+
+#[cfg(any(feature = "ed25519", feature = "secp256r1", feature = "secp256k1"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "ed25519", feature = "secp256r1", feature = "secp256k1")))
+)]
+pub mod remote;
+
+/// Verification of signatures over raw messages directly with the public key types defined in
+/// `sui-sdk-types`.
+///
+/// This is implemented for [`Ed25519PublicKey`], [`Secp256k1PublicKey`], [`Secp256r1PublicKey`],
+/// and [`Bls12381PublicKey`] when their respective features are enabled. The message is the exact
+/// byte string that was signed; no intent is prepended to it and, for the ECDSA schemes, it is
+/// hashed with SHA-256 as part of verification.
+///
+/// [`Ed25519PublicKey`]: sui_sdk_types::Ed25519PublicKey
+/// [`Bls12381PublicKey`]: sui_sdk_types::Bls12381PublicKey
+/// [`Secp256k1PublicKey`]: sui_sdk_types::Secp256k1PublicKey
+/// [`Secp256r1PublicKey`]: sui_sdk_types::Secp256r1PublicKey
+pub trait PublicKeyVerifier<S> {
+    fn verify(&self, message: &[u8], signature: &S) -> Result<(), SignatureError>;
+}
+
 /// Interface for signing user transactions and messages in Sui
 ///
 /// # Note
@@ -154,3 +226,87 @@ impl<T: Verifier<UserSignature>> SuiVerifier for T {
         self.verify(&message, signature)
     }
 }
+
+/// Verification of the signatures attached to a [`SignedTransaction`].
+///
+/// This is implemented for [`SignedTransaction`] itself, checking that it carries exactly one valid
+/// signature, over its intent message, from each of its required signers as done by
+/// [`UserSignatureVerifier::verify_signed_transaction`].
+///
+/// [`SignedTransaction`]: sui_sdk_types::SignedTransaction
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(
+        feature = "ed25519",
+        feature = "secp256r1",
+        feature = "secp256k1",
+        feature = "zklogin"
+    )))
+)]
+pub trait SignedTransactionVerifier {
+    /// Verify the signatures with a default [`UserSignatureVerifier`].
+    ///
+    /// Such a verifier has no zklogin verifier configured and so rejects zklogin signatures, use
+    /// [`SignedTransactionVerifier::verify_with`] to verify those.
+    fn verify(&self) -> Result<(), SignatureError> {
+        self.verify_with(&UserSignatureVerifier::new())
+    }
+
+    fn verify_with(&self, verifier: &UserSignatureVerifier) -> Result<(), SignatureError>;
+}
+
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+impl SignedTransactionVerifier for sui_sdk_types::SignedTransaction {
+    fn verify_with(&self, verifier: &UserSignatureVerifier) -> Result<(), SignatureError> {
+        verifier.verify_signed_transaction(self)
+    }
+}
+
+/// Interface for asynchronously signing user transactions and messages in Sui
+///
+/// This is intended for signers which need to perform IO in order to produce a signature, e.g. a
+/// hardware wallet or a remote signing service.
+///
+/// The returned futures are `Send` so that signing can be driven from multi-threaded runtimes.
+///
+/// # Note
+///
+/// There is a blanket implementation of `AsyncSigner` for all `T: `[`SuiSigner`] so that code
+/// written against `AsyncSigner` also works with local keys.
+pub trait AsyncSigner {
+    fn sign_transaction_async(
+        &self,
+        transaction: &Transaction,
+    ) -> impl std::future::Future<Output = Result<UserSignature, SignatureError>> + Send;
+    fn sign_personal_message_async(
+        &self,
+        message: &PersonalMessage<'_>,
+    ) -> impl std::future::Future<Output = Result<UserSignature, SignatureError>> + Send;
+}
+
+impl<T: SuiSigner> AsyncSigner for T {
+    fn sign_transaction_async(
+        &self,
+        transaction: &Transaction,
+    ) -> impl std::future::Future<Output = Result<UserSignature, SignatureError>> + Send {
+        std::future::ready(self.sign_transaction(transaction))
+    }
+
+    fn sign_personal_message_async(
+        &self,
+        message: &PersonalMessage<'_>,
+    ) -> impl std::future::Future<Output = Result<UserSignature, SignatureError>> + Send {
+        std::future::ready(self.sign_personal_message(message))
+    }
+}
@@ -1,9 +1,13 @@
 use crate::SignatureError;
 use crate::Verifier;
+use sui_sdk_types::Address;
 use sui_sdk_types::MultisigAggregatedSignature;
 use sui_sdk_types::MultisigCommittee;
 use sui_sdk_types::MultisigMemberPublicKey;
 use sui_sdk_types::MultisigMemberSignature;
+use sui_sdk_types::SignedTransaction;
+use sui_sdk_types::SigningDigest;
+use sui_sdk_types::Transaction;
 use sui_sdk_types::UserSignature;
 
 #[derive(Default)]
@@ -113,9 +117,10 @@ impl Verifier<MultisigAggregatedSignature> for MultisigVerifier {
         message: &[u8],
         signature: &MultisigAggregatedSignature,
     ) -> Result<(), SignatureError> {
-        if !signature.committee().is_valid() {
-            return Err(SignatureError::from_source("invalid MultisigCommittee"));
-        }
+        signature
+            .committee()
+            .validate()
+            .map_err(SignatureError::from_source)?;
 
         if signature.signatures().len() != signature.bitmap().count_ones() as usize {
             return Err(SignatureError::from_source(
@@ -256,6 +261,138 @@ impl Verifier<UserSignature> for UserSignatureVerifier {
     }
 }
 
+impl UserSignatureVerifier {
+    /// Verify that `signature` is a valid signature over `message` produced by `signer`.
+    ///
+    /// In addition to the checks performed by [`Verifier::verify`], this checks that the address
+    /// derived from the public key, multisig committee, or zklogin identifier of the signature is
+    /// `signer`.
+    pub fn verify_signer(
+        &self,
+        message: &[u8],
+        signature: &UserSignature,
+        signer: &Address,
+    ) -> Result<(), SignatureError> {
+        if !signature_addresses(signature)?.contains(signer) {
+            return Err(SignatureError::from_source(format!(
+                "signature was not produced by {signer}"
+            )));
+        }
+
+        self.verify(message, signature)
+    }
+
+    /// Verify that `signature` is a valid signature over `transaction` produced by `signer`.
+    pub fn verify_transaction_signer(
+        &self,
+        transaction: &Transaction,
+        signature: &UserSignature,
+        signer: &Address,
+    ) -> Result<(), SignatureError> {
+        self.verify_signer(&transaction.signing_digest(), signature, signer)
+    }
+
+    /// Verify that a signed transaction carries exactly one valid signature from each of its
+    /// required signers: the sender and, for sponsored transactions, the gas owner.
+    pub fn verify_signed_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<(), SignatureError> {
+        let signers = transaction.transaction.required_signers();
+
+        if transaction.signatures.len() != signers.len() {
+            return Err(SignatureError::from_source(format!(
+                "expected {} signatures, found {}",
+                signers.len(),
+                transaction.signatures.len()
+            )));
+        }
+
+        let signatures = transaction
+            .signatures
+            .iter()
+            .map(|signature| Ok((signature, signature_addresses(signature)?)))
+            .collect::<Result<Vec<_>, SignatureError>>()?;
+
+        let message = transaction.transaction.signing_digest();
+        for signer in &signers {
+            let (signature, _) = signatures
+                .iter()
+                .find(|(_, addresses)| addresses.contains(signer))
+                .ok_or_else(|| {
+                    SignatureError::from_source(format!("missing signature from {signer}"))
+                })?;
+
+            self.verify(&message, signature)?;
+        }
+
+        Ok(())
+    }
+
+    /// Verify a batch of independent signatures, each over a signing digest and produced by a
+    /// signer, as checked by [`UserSignatureVerifier::verify_signer`].
+    ///
+    /// The result of each verification is returned in the same order as `batch`. With the `rayon`
+    /// feature enabled, the signatures are verified in parallel.
+    pub fn verify_batch(
+        &self,
+        batch: &[(SigningDigest, UserSignature, Address)],
+    ) -> Vec<Result<(), SignatureError>> {
+        let verify = |(digest, signature, signer): &(SigningDigest, UserSignature, Address)| {
+            self.verify_signer(digest, signature, signer)
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            batch.par_iter().map(verify).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            batch.iter().map(verify).collect()
+        }
+    }
+}
+
+/// The set of addresses which a signature can be on behalf of.
+///
+/// This is a single address for all signature schemes except for zklogin, where an address seed
+/// with a leading zero byte maps to two addresses.
+fn signature_addresses(signature: &UserSignature) -> Result<Vec<Address>, SignatureError> {
+    use sui_sdk_types::SimpleSignature;
+
+    let addresses = match signature {
+        UserSignature::Simple(SimpleSignature::Ed25519 { public_key, .. }) => {
+            vec![public_key.derive_address()]
+        }
+        UserSignature::Simple(SimpleSignature::Secp256k1 { public_key, .. }) => {
+            vec![public_key.derive_address()]
+        }
+        UserSignature::Simple(SimpleSignature::Secp256r1 { public_key, .. }) => {
+            vec![public_key.derive_address()]
+        }
+        UserSignature::Multisig(multisig) => vec![multisig.committee().derive_address()],
+
+        #[cfg(not(feature = "zklogin"))]
+        UserSignature::ZkLogin(_) => {
+            return Err(SignatureError::from_source(
+                "support for zklogin is not enabled",
+            ))
+        }
+        #[cfg(feature = "zklogin")]
+        UserSignature::ZkLogin(zklogin_authenticator) => {
+            crate::zklogin::zklogin_identifier_from_inputs(&zklogin_authenticator.inputs)?
+                .derive_address()
+                .collect()
+        }
+        UserSignature::Passkey(passkey_authenticator) => {
+            vec![passkey_authenticator.public_key().derive_address()]
+        }
+    };
+
+    Ok(addresses)
+}
+
 pub struct MultisigAggregator {
     committee: MultisigCommittee,
     signatures: std::collections::BTreeMap<usize, MultisigMemberSignature>,
@@ -334,6 +471,10 @@ impl MultisigAggregator {
     }
 
     pub fn finish(&mut self) -> Result<MultisigAggregatedSignature, SignatureError> {
+        self.committee
+            .validate()
+            .map_err(SignatureError::from_source)?;
+
         if self.signed_weight < self.committee.threshold() {
             return Err(SignatureError::from_source(
                 "insufficient signature weight to reach threshold",
@@ -403,3 +544,142 @@ fn multisig_pubkey_and_signature_from_user_signature(
         }
     }
 }
+
+#[cfg(all(test, feature = "ed25519"))]
+mod test {
+    use super::*;
+    use crate::ed25519::Ed25519PrivateKey;
+    #[cfg(feature = "secp256k1")]
+    use crate::secp256k1::Secp256k1PrivateKey;
+    #[cfg(feature = "secp256k1")]
+    use crate::SignedTransactionVerifier;
+    use crate::SuiSigner;
+    use sui_sdk_types::GasPayment;
+    use sui_sdk_types::ProgrammableTransaction;
+    use sui_sdk_types::TransactionExpiration;
+    use sui_sdk_types::TransactionKind;
+    #[cfg(feature = "secp256k1")]
+    use test_strategy::proptest;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn transaction(sender: Address, gas_owner: Address) -> Transaction {
+        Transaction {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs: vec![],
+                commands: vec![],
+            }),
+            sender,
+            gas_payment: GasPayment {
+                objects: vec![],
+                owner: gas_owner,
+                price: 1000,
+                budget: 1_000_000,
+            },
+            expiration: TransactionExpiration::None,
+        }
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[proptest(cases = 20)]
+    fn verify_signers(sender: Ed25519PrivateKey, sponsor: Secp256k1PrivateKey) {
+        let sender_address = sender.public_key().derive_address();
+        let sponsor_address = sponsor.public_key().derive_address();
+        let verifier = UserSignatureVerifier::new();
+
+        // Simple transaction
+        let tx = transaction(sender_address, sender_address);
+        let signature = sender.sign_transaction(&tx).unwrap();
+        verifier
+            .verify_transaction_signer(&tx, &signature, &sender_address)
+            .unwrap();
+        verifier
+            .verify_transaction_signer(&tx, &signature, &sponsor_address)
+            .unwrap_err();
+        verifier
+            .verify_signed_transaction(&SignedTransaction {
+                transaction: tx.clone(),
+                signatures: vec![signature.clone()],
+            })
+            .unwrap();
+
+        // Signature over a different transaction
+        let other = transaction(sender_address, sponsor_address);
+        verifier
+            .verify_transaction_signer(&other, &signature, &sender_address)
+            .unwrap_err();
+
+        // Sponsored transaction
+        let sender_signature = sender.sign_transaction(&other).unwrap();
+        let sponsor_signature = sponsor.sign_transaction(&other).unwrap();
+        let sponsored = SignedTransaction::new(
+            other.clone(),
+            vec![sponsor_signature.clone(), sender_signature.clone()],
+        );
+        verifier.verify_signed_transaction(&sponsored).unwrap();
+        sponsored.verify().unwrap();
+        verifier
+            .verify_signed_transaction(&SignedTransaction {
+                transaction: other.clone(),
+                signatures: vec![sender_signature.clone()],
+            })
+            .unwrap_err();
+        verifier
+            .verify_signed_transaction(&SignedTransaction {
+                transaction: other,
+                signatures: vec![sender_signature.clone(), sender_signature],
+            })
+            .unwrap_err();
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn verify_batch() {
+        let alice = Ed25519PrivateKey::new([1; 32]);
+        let bob = Secp256k1PrivateKey::new([2; 32]).unwrap();
+        let alice_address = alice.public_key().derive_address();
+        let bob_address = bob.public_key().derive_address();
+
+        let tx = transaction(alice_address, bob_address);
+        let digest = tx.signing_digest();
+        let alice_signature = alice.sign_transaction(&tx).unwrap();
+        let bob_signature = bob.sign_transaction(&tx).unwrap();
+
+        let results = UserSignatureVerifier::new().verify_batch(&[
+            (digest, alice_signature.clone(), alice_address),
+            (digest, bob_signature.clone(), bob_address),
+            // Wrong signer
+            (digest, bob_signature, alice_address),
+            // Wrong digest
+            ([0; 32], alice_signature, alice_address),
+        ]);
+        assert_eq!(
+            results.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            [true, true, false, false]
+        );
+    }
+
+    #[test]
+    fn aggregate_invalid_committee() {
+        let alice = Ed25519PrivateKey::new([1; 32]);
+        let alice_address = alice.public_key().derive_address();
+        let member = sui_sdk_types::MultisigMember::new(
+            MultisigMemberPublicKey::Ed25519(alice.public_key()),
+            1,
+        );
+        let committee = MultisigCommittee::new(vec![member.clone(), member], 1);
+        let tx = transaction(alice_address, alice_address);
+
+        let mut aggregator = MultisigAggregator::new_with_transaction(committee, &tx);
+        aggregator
+            .add_signature(alice.sign_transaction(&tx).unwrap())
+            .unwrap();
+        // The signature weight reaches the threshold, but the committee is invalid
+        let error = aggregator.finish().unwrap_err();
+        assert_eq!(
+            std::error::Error::source(&error).unwrap().to_string(),
+            sui_sdk_types::InvalidMultisigCommittee::DuplicateMember { index: 1 }.to_string()
+        );
+    }
+}
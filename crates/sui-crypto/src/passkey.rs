@@ -34,16 +34,10 @@ impl Verifier<PasskeyAuthenticator> for PasskeyVerifier {
             ));
         }
 
-        // Construct passkey signing message = authenticator_data || sha256(client_data_json).
-        let mut message = authenticator.authenticator_data().to_owned();
-        let client_data_hash = {
-            use sha2::Digest;
-
-            let mut hasher = sha2::Sha256::new();
-            hasher.update(authenticator.client_data_json().as_bytes());
-            hasher.finalize()
-        };
-        message.extend_from_slice(&client_data_hash);
+        let message = signing_message(
+            authenticator.authenticator_data(),
+            authenticator.client_data_json(),
+        );
 
         let verifying_key = Secp256r1VerifyingKey::new(&public_key)?;
 
@@ -51,6 +45,16 @@ impl Verifier<PasskeyAuthenticator> for PasskeyVerifier {
     }
 }
 
+/// Construct the message signed by a passkey:
+/// `authenticator_data || sha256(client_data_json)`.
+fn signing_message(authenticator_data: &[u8], client_data_json: &str) -> Vec<u8> {
+    use sha2::Digest;
+
+    let mut message = authenticator_data.to_owned();
+    message.extend_from_slice(&sha2::Sha256::digest(client_data_json.as_bytes()));
+    message
+}
+
 impl Verifier<UserSignature> for PasskeyVerifier {
     fn verify(&self, message: &[u8], signature: &UserSignature) -> Result<(), SignatureError> {
         let UserSignature::Passkey(authenticator) = signature else {
@@ -87,4 +91,44 @@ mod test {
             .verify_transaction(&transaction, &signature)
             .unwrap();
     }
+
+    #[test]
+    fn construct_and_verify_passkey_signature() {
+        use crate::secp256r1::Secp256r1PrivateKey;
+        use base64ct::Base64UrlUnpadded;
+        use base64ct::Encoding;
+        use signature::Signer;
+        use sui_sdk_types::PersonalMessage;
+
+        let message = PersonalMessage(b"hello passkey".into());
+        let private_key = Secp256r1PrivateKey::new([7; 32]);
+
+        // Act as the authenticator: embed the signing digest as the challenge and sign over
+        // `authenticator_data || sha256(client_data_json)`
+        let authenticator_data = vec![0; 37];
+        let client_data_json = format!(
+            r#"{{"type":"webauthn.get","challenge":"{}","origin":"http://localhost:5173","crossOrigin":false}}"#,
+            Base64UrlUnpadded::encode_string(&message.signing_digest()),
+        );
+        let signature: SimpleSignature =
+            private_key.sign(&signing_message(&authenticator_data, &client_data_json));
+
+        let authenticator =
+            PasskeyAuthenticator::new(authenticator_data, client_data_json, signature).unwrap();
+        assert_eq!(
+            authenticator.public_key().inner(),
+            &private_key.public_key()
+        );
+
+        let signature = UserSignature::Passkey(authenticator);
+        let signature = UserSignature::from_base64(&signature.to_base64()).unwrap();
+
+        let verifier = PasskeyVerifier::new();
+        verifier
+            .verify_personal_message(&message, &signature)
+            .unwrap();
+        verifier
+            .verify_personal_message(&PersonalMessage(b"other message".into()), &signature)
+            .unwrap_err();
+    }
 }
@@ -0,0 +1,420 @@
+//! Formation of transaction certificates from the signatures of individual validators.
+//!
+//! A transaction is certified once members of the validator committee holding a quorum (`2f +
+//! 1`) of stake signed it. [`QuorumDriver`] is a state machine tracking the responses of each
+//! member to the submission of a transaction: it verifies their signatures, keeps track of the
+//! validators which should be retried, detects when a quorum can no longer be reached because of
+//! conflicting transactions, and flags validators giving contradictory responses. It doesn't do
+//! any I/O itself, so it can be embedded in any network stack.
+
+use std::collections::BTreeMap;
+
+use sui_sdk_types::Bls12381PublicKey;
+use sui_sdk_types::StakeUnit;
+use sui_sdk_types::TransactionDigest;
+use sui_sdk_types::ValidatorAggregatedSignature;
+use sui_sdk_types::ValidatorCommittee;
+use sui_sdk_types::ValidatorSignature;
+
+use crate::bls12381::aggregate_validator_signatures;
+use crate::bls12381::ValidatorCommitteeSignatureVerifier;
+use crate::SignatureError;
+
+/// The response of a validator to the submission of a transaction.
+#[derive(Clone, Debug)]
+pub enum QuorumResponse {
+    /// The validator signed the transaction.
+    Signed(ValidatorSignature),
+
+    /// The validator refused to sign the transaction as some of its owned inputs are already
+    /// locked by another transaction.
+    Conflict(TransactionDigest),
+
+    /// The request failed, e.g. because the validator was unreachable.
+    ///
+    /// Retryable errors make the validator available for another attempt, as long as the
+    /// driver's retry limit isn't reached.
+    Error { retryable: bool },
+}
+
+/// The state of a single member of the committee, as tracked by a [`QuorumDriver`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MemberState {
+    /// No response was recorded yet.
+    Pending,
+
+    /// The last attempt failed with a retryable error and the member should be contacted again.
+    Retry { attempts: u32 },
+
+    /// The member signed the transaction.
+    Signed(ValidatorSignature),
+
+    /// The member reported that the inputs of the transaction are locked by another transaction.
+    Conflict(TransactionDigest),
+
+    /// The member can't contribute to the certificate, either because of a permanent error, an
+    /// invalid signature, or because it ran out of retries.
+    Failed,
+
+    /// The member gave contradictory responses, e.g. signed the transaction and also reported a
+    /// conflicting one. Its stake is excluded from the certificate.
+    Equivocated,
+}
+
+/// The overall status of a [`QuorumDriver`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuorumStatus {
+    /// A quorum can still be reached, but hasn't been yet.
+    Collecting,
+
+    /// Members holding a quorum of stake signed the transaction.
+    Certified(ValidatorAggregatedSignature),
+
+    /// A quorum can no longer be reached.
+    ///
+    /// `conflicts` lists the transactions reported as holding locks on the inputs, along with the
+    /// stake of the members which reported them, by decreasing stake.
+    Failed {
+        conflicts: Vec<(TransactionDigest, StakeUnit)>,
+    },
+}
+
+/// A state machine collecting the signatures of a committee over a transaction until they form a
+/// certificate.
+#[derive(Debug)]
+pub struct QuorumDriver {
+    verifier: ValidatorCommitteeSignatureVerifier,
+    message: Vec<u8>,
+    members: Vec<MemberState>,
+    max_retries: u32,
+    status: QuorumStatus,
+}
+
+impl QuorumDriver {
+    /// The number of times a member is retried after a retryable error by default.
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// Create a driver collecting the signatures of `committee` over `message`, the bytes signed
+    /// by validators for the transaction being certified.
+    pub fn new(committee: ValidatorCommittee, message: Vec<u8>) -> Result<Self, SignatureError> {
+        let verifier = ValidatorCommitteeSignatureVerifier::new(committee)?;
+        let members = vec![MemberState::Pending; verifier.committee().members.len()];
+        let mut driver = Self {
+            verifier,
+            message,
+            members,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            status: QuorumStatus::Collecting,
+        };
+        driver.update_status();
+        Ok(driver)
+    }
+
+    /// Set the number of times a member is retried after a retryable error.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn committee(&self) -> &ValidatorCommittee {
+        self.verifier.committee()
+    }
+
+    pub fn status(&self) -> &QuorumStatus {
+        &self.status
+    }
+
+    /// Whether the driver reached a final status, i.e. further responses won't change it.
+    pub fn is_done(&self) -> bool {
+        !matches!(self.status, QuorumStatus::Collecting)
+    }
+
+    /// The state of the member with `public_key`, if it is part of the committee.
+    pub fn member_state(&self, public_key: &Bls12381PublicKey) -> Option<&MemberState> {
+        self.index_of(public_key).map(|index| &self.members[index])
+    }
+
+    /// The members which haven't responded yet.
+    pub fn pending(&self) -> impl Iterator<Item = &Bls12381PublicKey> {
+        self.members_in(|state| matches!(state, MemberState::Pending))
+    }
+
+    /// The members which should be contacted again after a retryable error.
+    pub fn retries(&self) -> impl Iterator<Item = &Bls12381PublicKey> {
+        self.members_in(|state| matches!(state, MemberState::Retry { .. }))
+    }
+
+    /// The members which gave contradictory responses.
+    pub fn equivocators(&self) -> impl Iterator<Item = &Bls12381PublicKey> {
+        self.members_in(|state| matches!(state, MemberState::Equivocated))
+    }
+
+    /// The stake of the members whose valid signature was recorded.
+    pub fn signed_stake(&self) -> StakeUnit {
+        self.stake_of(|state| matches!(state, MemberState::Signed(_)))
+    }
+
+    /// Record the response of the member with `public_key`, returning the updated status.
+    ///
+    /// Responses received once the driver is done are still recorded, which may flag further
+    /// equivocations, but don't change its status. An error is returned if `public_key` isn't a
+    /// member of the committee or if the response contains an invalid signature, in which case
+    /// the member is marked as failed.
+    pub fn handle_response(
+        &mut self,
+        public_key: &Bls12381PublicKey,
+        response: QuorumResponse,
+    ) -> Result<&QuorumStatus, SignatureError> {
+        let index = self.index_of(public_key).ok_or_else(|| {
+            SignatureError::from_source(format!(
+                "response from {public_key}, which isn't a member of the committee"
+            ))
+        })?;
+
+        let mut result = Ok(());
+        let current = &self.members[index];
+        let next = match response {
+            QuorumResponse::Signed(signature) => {
+                if let Err(e) = self.check_signature(public_key, &signature) {
+                    result = Err(e);
+                    MemberState::Failed
+                } else {
+                    match current {
+                        MemberState::Conflict(_) | MemberState::Equivocated => {
+                            MemberState::Equivocated
+                        }
+                        _ => MemberState::Signed(signature),
+                    }
+                }
+            }
+            QuorumResponse::Conflict(digest) => match current {
+                MemberState::Signed(_) | MemberState::Equivocated => MemberState::Equivocated,
+                MemberState::Conflict(previous) if *previous != digest => MemberState::Equivocated,
+                _ => MemberState::Conflict(digest),
+            },
+            QuorumResponse::Error { retryable } => match current {
+                // A failure to respond doesn't invalidate a previous answer
+                MemberState::Signed(_)
+                | MemberState::Conflict(_)
+                | MemberState::Failed
+                | MemberState::Equivocated => current.clone(),
+                MemberState::Pending if retryable && self.max_retries > 0 => {
+                    MemberState::Retry { attempts: 1 }
+                }
+                MemberState::Retry { attempts } if retryable && *attempts < self.max_retries => {
+                    MemberState::Retry {
+                        attempts: attempts + 1,
+                    }
+                }
+                _ => MemberState::Failed,
+            },
+        };
+
+        self.members[index] = next;
+        if !self.is_done() {
+            self.update_status();
+        }
+
+        result.map(|()| &self.status)
+    }
+
+    fn check_signature(
+        &self,
+        public_key: &Bls12381PublicKey,
+        signature: &ValidatorSignature,
+    ) -> Result<(), SignatureError> {
+        if signature.public_key != *public_key {
+            return Err(SignatureError::from_source(format!(
+                "response from {public_key} carries a signature from {}",
+                signature.public_key
+            )));
+        }
+        self.verifier.verify_signature(&self.message, signature)
+    }
+
+    fn update_status(&mut self) {
+        let threshold = self.verifier.quorum_threshold();
+
+        if self.signed_stake() >= threshold {
+            let signatures = self.members.iter().filter_map(|state| match state {
+                MemberState::Signed(signature) => Some(signature),
+                _ => None,
+            });
+            // Every signature was checked to be from a distinct member for the committee's epoch
+            let certificate = aggregate_validator_signatures(self.verifier.committee(), signatures)
+                .expect("signatures were verified");
+            self.status = QuorumStatus::Certified(certificate);
+            return;
+        }
+
+        let reachable = self.stake_of(|state| {
+            matches!(
+                state,
+                MemberState::Pending | MemberState::Retry { .. } | MemberState::Signed(_)
+            )
+        });
+        if reachable < threshold {
+            let mut conflicts: BTreeMap<_, StakeUnit> = BTreeMap::new();
+            for (member, state) in self.verifier.committee().members.iter().zip(&self.members) {
+                if let MemberState::Conflict(digest) = state {
+                    *conflicts.entry(*digest).or_default() += member.stake;
+                }
+            }
+            let mut conflicts: Vec<_> = conflicts.into_iter().collect();
+            conflicts.sort_by_key(|(_, stake)| std::cmp::Reverse(*stake));
+            self.status = QuorumStatus::Failed { conflicts };
+        }
+    }
+
+    fn index_of(&self, public_key: &Bls12381PublicKey) -> Option<usize> {
+        self.verifier
+            .committee()
+            .members
+            .iter()
+            .position(|member| member.public_key == *public_key)
+    }
+
+    fn members_in<F>(&self, predicate: F) -> impl Iterator<Item = &Bls12381PublicKey>
+    where
+        F: Fn(&MemberState) -> bool,
+    {
+        self.verifier
+            .committee()
+            .members
+            .iter()
+            .zip(&self.members)
+            .filter(move |(_, state)| predicate(state))
+            .map(|(member, _)| &member.public_key)
+    }
+
+    fn stake_of<F>(&self, predicate: F) -> StakeUnit
+    where
+        F: Fn(&MemberState) -> bool,
+    {
+        // Can't overflow since the verifier checked that the total stake of the committee doesn't
+        self.verifier
+            .committee()
+            .members
+            .iter()
+            .zip(&self.members)
+            .filter(|(_, state)| predicate(state))
+            .map(|(member, _)| member.stake)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bls12381::Bls12381PrivateKey;
+    use crate::test_util::validator_committee;
+    use crate::test_util::validator_keys;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    const MESSAGE: &[u8] = b"transaction";
+
+    fn driver(keys: &[Bls12381PrivateKey]) -> QuorumDriver {
+        let committee = validator_committee(7, keys, &[2500; 4]);
+        QuorumDriver::new(committee, MESSAGE.to_vec()).unwrap()
+    }
+
+    fn signed(key: &Bls12381PrivateKey) -> QuorumResponse {
+        QuorumResponse::Signed(key.sign_validator_message(MESSAGE, 7))
+    }
+
+    #[test]
+    fn certificate_from_quorum() {
+        let keys = validator_keys(1);
+        let mut driver = driver(&keys);
+
+        driver
+            .handle_response(&keys[0].public_key(), signed(&keys[0]))
+            .unwrap();
+        driver
+            .handle_response(
+                &keys[1].public_key(),
+                QuorumResponse::Error { retryable: true },
+            )
+            .unwrap();
+        assert_eq!(driver.retries().count(), 1);
+        driver
+            .handle_response(&keys[2].public_key(), signed(&keys[2]))
+            .unwrap();
+        assert_eq!(driver.status(), &QuorumStatus::Collecting);
+
+        // An invalid signature is rejected and doesn't count towards the quorum
+        let forged = QuorumResponse::Signed(keys[3].sign_validator_message(b"other", 7));
+        driver
+            .handle_response(&keys[3].public_key(), forged)
+            .unwrap_err();
+        assert_eq!(
+            driver.member_state(&keys[3].public_key()),
+            Some(&MemberState::Failed)
+        );
+
+        let status = driver
+            .handle_response(&keys[1].public_key(), signed(&keys[1]))
+            .unwrap();
+        let QuorumStatus::Certified(certificate) = status.clone() else {
+            panic!("expected a certificate, got {status:?}");
+        };
+        driver
+            .verifier
+            .verify_aggregated(MESSAGE, &certificate)
+            .unwrap();
+        assert_eq!(certificate.bitmap.iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn conflicts_and_equivocation() {
+        let keys = validator_keys(1);
+        let mut driver = driver(&keys);
+        let other = TransactionDigest::new([1; 32]);
+
+        driver
+            .handle_response(&keys[0].public_key(), signed(&keys[0]))
+            .unwrap();
+        driver
+            .handle_response(&keys[0].public_key(), QuorumResponse::Conflict(other))
+            .unwrap();
+        assert_eq!(
+            driver.equivocators().collect::<Vec<_>>(),
+            [&keys[0].public_key()]
+        );
+        assert_eq!(driver.signed_stake(), 0);
+        assert_eq!(driver.status(), &QuorumStatus::Collecting);
+
+        // With one member equivocating and another locked on a different transaction, the two
+        // remaining members don't hold a quorum
+        let status = driver
+            .handle_response(&keys[1].public_key(), QuorumResponse::Conflict(other))
+            .unwrap();
+        assert_eq!(
+            status,
+            &QuorumStatus::Failed {
+                conflicts: vec![(other, 2500)]
+            }
+        );
+        assert!(driver.is_done());
+    }
+
+    #[test]
+    fn retry_limit() {
+        let keys = validator_keys(1);
+        let mut driver = driver(&keys).with_max_retries(1);
+        let public_key = keys[0].public_key();
+
+        let error = QuorumResponse::Error { retryable: true };
+        driver.handle_response(&public_key, error.clone()).unwrap();
+        assert_eq!(
+            driver.member_state(&public_key),
+            Some(&MemberState::Retry { attempts: 1 })
+        );
+        driver.handle_response(&public_key, error).unwrap();
+        assert_eq!(driver.member_state(&public_key), Some(&MemberState::Failed));
+        assert_eq!(driver.pending().count(), 3);
+        assert_eq!(driver.retries().count(), 0);
+    }
+}
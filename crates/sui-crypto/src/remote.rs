@@ -0,0 +1,218 @@
+//! Signing with keys held by a remote service, e.g. a KMS or an HSM.
+//!
+//! A backend only needs to implement [`RemoteSigner`], which exposes the two operations such
+//! services generally offer: retrieving the public key and signing a digest. Wrapping a backend
+//! in a [`RemoteSuiSigner`] takes care of computing the digests of transactions and personal
+//! messages and of assembling the returned raw signature into a [`UserSignature`], so that it can
+//! be used anywhere an [`AsyncSigner`] is accepted.
+
+use crate::simple::SimpleVerifier;
+use crate::AsyncSigner;
+use crate::SignatureError;
+use signature::Verifier;
+use sui_sdk_types::Address;
+use sui_sdk_types::Ed25519PublicKey;
+use sui_sdk_types::Ed25519Signature;
+use sui_sdk_types::PersonalMessage;
+use sui_sdk_types::Secp256k1PublicKey;
+use sui_sdk_types::Secp256k1Signature;
+use sui_sdk_types::Secp256r1PublicKey;
+use sui_sdk_types::Secp256r1Signature;
+use sui_sdk_types::SignatureScheme;
+use sui_sdk_types::SigningDigest;
+use sui_sdk_types::SimpleSignature;
+use sui_sdk_types::Transaction;
+use sui_sdk_types::UserSignature;
+
+/// The public key of a key held by a [`RemoteSigner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RemotePublicKey {
+    Ed25519(Ed25519PublicKey),
+    Secp256k1(Secp256k1PublicKey),
+    Secp256r1(Secp256r1PublicKey),
+}
+
+impl RemotePublicKey {
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            Self::Ed25519(_) => SignatureScheme::Ed25519,
+            Self::Secp256k1(_) => SignatureScheme::Secp256k1,
+            Self::Secp256r1(_) => SignatureScheme::Secp256r1,
+        }
+    }
+
+    pub fn derive_address(&self) -> Address {
+        match self {
+            Self::Ed25519(public_key) => public_key.derive_address(),
+            Self::Secp256k1(public_key) => public_key.derive_address(),
+            Self::Secp256r1(public_key) => public_key.derive_address(),
+        }
+    }
+
+    /// Assemble a raw signature produced by this key into a [`SimpleSignature`].
+    ///
+    /// The signature is expected in the same fixed-size encoding as used by Sui, i.e. the 64-byte
+    /// ed25519 signature, or the 64-byte `r || s` encoding of an ECDSA signature.
+    pub fn to_simple_signature(&self, signature: &[u8]) -> Result<SimpleSignature, SignatureError> {
+        let invalid_length = |_| {
+            SignatureError::from_source(format!(
+                "invalid {} signature length {}",
+                self.scheme().name(),
+                signature.len()
+            ))
+        };
+
+        Ok(match *self {
+            Self::Ed25519(public_key) => SimpleSignature::Ed25519 {
+                signature: Ed25519Signature::from_bytes(signature).map_err(invalid_length)?,
+                public_key,
+            },
+            Self::Secp256k1(public_key) => SimpleSignature::Secp256k1 {
+                signature: Secp256k1Signature::from_bytes(signature).map_err(invalid_length)?,
+                public_key,
+            },
+            Self::Secp256r1(public_key) => SimpleSignature::Secp256r1 {
+                signature: Secp256r1Signature::from_bytes(signature).map_err(invalid_length)?,
+                public_key,
+            },
+        })
+    }
+}
+
+/// A backend able to sign with a key held outside of this process.
+///
+/// The returned futures are `Send`, like those of [`AsyncSigner`].
+pub trait RemoteSigner {
+    /// Retrieve the public key of the key used for signing.
+    fn public_key(
+        &self,
+    ) -> impl std::future::Future<Output = Result<RemotePublicKey, SignatureError>> + Send;
+
+    /// Sign `digest`, returning the raw signature.
+    ///
+    /// `digest` is the message Sui expects to be signed: ed25519 signs it as is while, as for any
+    /// other message, ECDSA schemes sign its SHA-256 hash. The signature must be returned in the
+    /// encoding expected by [`RemotePublicKey::to_simple_signature`].
+    fn sign_digest(
+        &self,
+        digest: &SigningDigest,
+    ) -> impl std::future::Future<Output = Result<Vec<u8>, SignatureError>> + Send;
+}
+
+/// An [`AsyncSigner`] backed by a [`RemoteSigner`].
+#[derive(Clone, Debug)]
+pub struct RemoteSuiSigner<S> {
+    signer: S,
+    public_key: RemotePublicKey,
+}
+
+impl<S: RemoteSigner> RemoteSuiSigner<S> {
+    /// Create a signer for the key held by `signer`, retrieving its public key.
+    pub async fn new(signer: S) -> Result<Self, SignatureError> {
+        let public_key = signer.public_key().await?;
+        Ok(Self { signer, public_key })
+    }
+
+    pub fn signer(&self) -> &S {
+        &self.signer
+    }
+
+    pub fn public_key(&self) -> &RemotePublicKey {
+        &self.public_key
+    }
+
+    pub fn address(&self) -> Address {
+        self.public_key.derive_address()
+    }
+
+    async fn sign(&self, digest: &SigningDigest) -> Result<UserSignature, SignatureError> {
+        let signature = self.signer.sign_digest(digest).await?;
+        let signature = self.public_key.to_simple_signature(&signature)?;
+
+        // Don't trust the service to have signed what we asked for, or with the key we expect
+        SimpleVerifier.verify(digest, &signature)?;
+
+        Ok(UserSignature::Simple(signature))
+    }
+}
+
+impl<S: RemoteSigner + Sync> AsyncSigner for RemoteSuiSigner<S> {
+    async fn sign_transaction_async(
+        &self,
+        transaction: &Transaction,
+    ) -> Result<UserSignature, SignatureError> {
+        self.sign(&transaction.signing_digest()).await
+    }
+
+    async fn sign_personal_message_async(
+        &self,
+        message: &PersonalMessage<'_>,
+    ) -> Result<UserSignature, SignatureError> {
+        self.sign(&message.signing_digest()).await
+    }
+}
+
+#[cfg(all(test, feature = "ed25519"))]
+mod test {
+    use super::*;
+    use crate::ed25519::Ed25519PrivateKey;
+    use crate::test_util::block_on;
+    use crate::SuiVerifier;
+    use signature::Signer;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    /// A "remote" service holding a local key.
+    struct MockService {
+        key: Ed25519PrivateKey,
+        corrupt: bool,
+    }
+
+    impl RemoteSigner for MockService {
+        async fn public_key(&self) -> Result<RemotePublicKey, SignatureError> {
+            Ok(RemotePublicKey::Ed25519(self.key.public_key()))
+        }
+
+        async fn sign_digest(&self, digest: &SigningDigest) -> Result<Vec<u8>, SignatureError> {
+            let mut signature: Ed25519Signature = self.key.try_sign(digest)?;
+            if self.corrupt {
+                signature = Ed25519Signature::new([7; 64]);
+            }
+            Ok(signature.as_bytes().to_vec())
+        }
+    }
+
+    #[test]
+    fn sign_with_remote_signer() {
+        let key = Ed25519PrivateKey::new([5; 32]);
+        let address = key.public_key().derive_address();
+        let signer = block_on(RemoteSuiSigner::new(MockService {
+            key,
+            corrupt: false,
+        }))
+        .unwrap();
+        assert_eq!(signer.address(), address);
+
+        let message = PersonalMessage(b"hello".into());
+        let signature = block_on(signer.sign_personal_message_async(&message)).unwrap();
+        SimpleVerifier
+            .verify_personal_message(&message, &signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn reject_invalid_signatures() {
+        let signer = block_on(RemoteSuiSigner::new(MockService {
+            key: Ed25519PrivateKey::new([5; 32]),
+            corrupt: true,
+        }))
+        .unwrap();
+
+        let message = PersonalMessage(b"hello".into());
+        block_on(signer.sign_personal_message_async(&message)).unwrap_err();
+
+        let public_key = *signer.public_key();
+        public_key.to_simple_signature(&[0; 63]).unwrap_err();
+    }
+}
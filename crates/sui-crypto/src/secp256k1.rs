@@ -4,6 +4,7 @@ use k256::ecdsa::VerifyingKey;
 use k256::elliptic_curve::group::GroupEncoding;
 use signature::Signer;
 use signature::Verifier;
+use sui_sdk_types::Address;
 use sui_sdk_types::Secp256k1PublicKey;
 use sui_sdk_types::Secp256k1Signature;
 use sui_sdk_types::SignatureScheme;
@@ -135,6 +136,119 @@ impl Signer<UserSignature> for Secp256k1PrivateKey {
     }
 }
 
+impl Signer<Secp256k1RecoverableSignature> for Secp256k1PrivateKey {
+    fn try_sign(&self, message: &[u8]) -> Result<Secp256k1RecoverableSignature, SignatureError> {
+        let (signature, recovery_id) = self.0.sign_recoverable(message)?;
+
+        let mut bytes = [0; Secp256k1RecoverableSignature::LENGTH];
+        bytes[..Secp256k1Signature::LENGTH].copy_from_slice(&signature.to_bytes());
+        bytes[Secp256k1Signature::LENGTH] = recovery_id.to_byte();
+        Ok(Secp256k1RecoverableSignature(bytes))
+    }
+}
+
+/// A secp256k1 signature along with the recovery id needed to recover the public key which
+/// produced it.
+///
+/// The 65-byte form is the 64-byte compact signature, `r || s`, followed by the recovery id. Sui
+/// itself doesn't use recoverable signatures, but they are commonly used by other chains, e.g. by
+/// Ethereum, and bridges between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Secp256k1RecoverableSignature([u8; Self::LENGTH]);
+
+impl Secp256k1RecoverableSignature {
+    /// The length of a recoverable secp256k1 signature in bytes.
+    pub const LENGTH: usize = Secp256k1Signature::LENGTH + 1;
+
+    /// Construct a recoverable signature from its 65-byte form.
+    ///
+    /// The recovery id may be either in the `0..=3` range, or offset by 27 as is customary on
+    /// Ethereum, in which case it is normalized.
+    pub fn new(mut bytes: [u8; Self::LENGTH]) -> Result<Self, SignatureError> {
+        let recovery_id = &mut bytes[Secp256k1Signature::LENGTH];
+        if (27..=30).contains(recovery_id) {
+            *recovery_id -= 27;
+        }
+
+        if k256::ecdsa::RecoveryId::from_byte(*recovery_id).is_none() {
+            return Err(SignatureError::from_source(format!(
+                "invalid recovery id {recovery_id}"
+            )));
+        }
+
+        Ok(Self(bytes))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        <[u8; Self::LENGTH]>::try_from(bytes)
+            .map_err(SignatureError::from_source)
+            .and_then(Self::new)
+    }
+
+    pub fn from_parts(
+        signature: &Secp256k1Signature,
+        recovery_id: u8,
+    ) -> Result<Self, SignatureError> {
+        let mut bytes = [0; Self::LENGTH];
+        bytes[..Secp256k1Signature::LENGTH].copy_from_slice(signature.inner());
+        bytes[Secp256k1Signature::LENGTH] = recovery_id;
+        Self::new(bytes)
+    }
+
+    /// Return the underlying byte array of this signature.
+    pub fn inner(&self) -> &[u8; Self::LENGTH] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> [u8; Self::LENGTH] {
+        self.0
+    }
+
+    /// The signature, without the recovery id.
+    pub fn signature(&self) -> Secp256k1Signature {
+        let mut bytes = [0; Secp256k1Signature::LENGTH];
+        bytes.copy_from_slice(&self.0[..Secp256k1Signature::LENGTH]);
+        Secp256k1Signature::new(bytes)
+    }
+
+    /// The recovery id, in the `0..=3` range.
+    pub fn recovery_id(&self) -> u8 {
+        self.0[Secp256k1Signature::LENGTH]
+    }
+
+    /// Recover the public key which produced this signature over `message`.
+    ///
+    /// As with regular secp256k1 signatures, `message` is hashed with SHA-256.
+    pub fn recover_public_key(&self, message: &[u8]) -> Result<Secp256k1PublicKey, SignatureError> {
+        self.recover_verifying_key(message)
+            .map(|verifying_key| verifying_key.public_key())
+    }
+
+    /// Recover the Sui address of the key which produced this signature over `message`.
+    pub fn recover_address(&self, message: &[u8]) -> Result<Address, SignatureError> {
+        self.recover_public_key(message)
+            .map(|public_key| public_key.derive_address())
+    }
+
+    fn recover_verifying_key(
+        &self,
+        message: &[u8],
+    ) -> Result<Secp256k1VerifyingKey, SignatureError> {
+        let signature = k256::ecdsa::Signature::from_bytes(self.signature().inner().into())?;
+        // Validated on construction
+        let recovery_id =
+            k256::ecdsa::RecoveryId::from_byte(self.recovery_id()).expect("recovery id is valid");
+
+        VerifyingKey::recover_from_msg(message, &signature, recovery_id).map(Secp256k1VerifyingKey)
+    }
+}
+
+impl AsRef<[u8]> for Secp256k1RecoverableSignature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 #[derive(Debug)]
 pub struct Secp256k1VerifyingKey(VerifyingKey);
 
@@ -147,6 +261,19 @@ impl Secp256k1VerifyingKey {
         Secp256k1PublicKey::new(self.0.as_ref().to_bytes().into())
     }
 
+    /// Parse a public key from its SEC1 encoding, either compressed (33 bytes) or uncompressed
+    /// (65 bytes), e.g. as exported by an HSM or a cloud KMS.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        VerifyingKey::from_sec1_bytes(bytes).map(Self)
+    }
+
+    /// Serialize this public key with the uncompressed SEC1 encoding, of 65 bytes.
+    ///
+    /// Sui uses the compressed encoding, which is the one of [`Secp256k1PublicKey`].
+    pub fn to_sec1_uncompressed_bytes(&self) -> Vec<u8> {
+        self.0.to_encoded_point(false).as_bytes().to_vec()
+    }
+
     #[cfg(feature = "pem")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "pem")))]
     /// Deserialize public key from ASN.1 DER-encoded data (binary format).
@@ -201,6 +328,24 @@ impl Verifier<Secp256k1Signature> for Secp256k1VerifyingKey {
     }
 }
 
+impl Verifier<Secp256k1RecoverableSignature> for Secp256k1VerifyingKey {
+    fn verify(
+        &self,
+        message: &[u8],
+        signature: &Secp256k1RecoverableSignature,
+    ) -> Result<(), SignatureError> {
+        <Self as Verifier<Secp256k1Signature>>::verify(self, message, &signature.signature())?;
+
+        if signature.recover_verifying_key(message)?.0 != self.0 {
+            return Err(SignatureError::from_source(
+                "recovery id does not match the public key",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Verifier<SimpleSignature> for Secp256k1VerifyingKey {
     fn verify(&self, message: &[u8], signature: &SimpleSignature) -> Result<(), SignatureError> {
         let SimpleSignature::Secp256k1 {
@@ -231,6 +376,12 @@ impl Verifier<UserSignature> for Secp256k1VerifyingKey {
     }
 }
 
+impl crate::PublicKeyVerifier<Secp256k1Signature> for Secp256k1PublicKey {
+    fn verify(&self, message: &[u8], signature: &Secp256k1Signature) -> Result<(), SignatureError> {
+        Secp256k1VerifyingKey::new(self)?.verify(message, signature)
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct Secp256k1Verifier {}
 
@@ -277,6 +428,29 @@ mod test {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
+    #[proptest]
+    fn sec1_public_keys(signer: Secp256k1PrivateKey) {
+        let verifying_key = signer.verifying_key();
+        let public_key = signer.public_key();
+
+        let uncompressed = verifying_key.to_sec1_uncompressed_bytes();
+        assert_eq!(uncompressed.len(), 65);
+        for bytes in [uncompressed.as_slice(), public_key.inner().as_slice()] {
+            let parsed = Secp256k1VerifyingKey::from_sec1_bytes(bytes).unwrap();
+            assert_eq!(parsed.public_key(), public_key);
+        }
+        Secp256k1VerifyingKey::from_sec1_bytes(&uncompressed[1..]).unwrap_err();
+
+        #[cfg(feature = "pem")]
+        {
+            let der = verifying_key.to_der().unwrap();
+            assert_eq!(
+                Secp256k1VerifyingKey::from_der(&der).unwrap().public_key(),
+                public_key
+            );
+        }
+    }
+
     // TODO need to export proptest impl from core crate
     // #[proptest]
     // fn transaction_signing(signer: Secp256k1PrivateKey, transaction: Transaction) {
@@ -287,6 +461,68 @@ mod test {
     //         .unwrap();
     // }
 
+    #[proptest]
+    fn raw_public_key_verification(signer: Secp256k1PrivateKey, message: Vec<u8>) {
+        let signature: Secp256k1Signature = signer.try_sign(&message).unwrap();
+        crate::test_util::check_raw_verification(&signer.public_key(), &message, &signature);
+    }
+
+    #[proptest]
+    fn recoverable_signatures(signer: Secp256k1PrivateKey, message: Vec<u8>) {
+        let signature: Secp256k1RecoverableSignature = signer.try_sign(&message).unwrap();
+        assert_eq!(
+            signature.recover_public_key(&message).unwrap(),
+            signer.public_key()
+        );
+        assert_eq!(
+            signature.recover_address(&message).unwrap(),
+            signer.public_key().derive_address()
+        );
+        signer.verifying_key().verify(&message, &signature).unwrap();
+
+        // The signature part is a regular signature
+        signer
+            .verifying_key()
+            .verify(&message, &signature.signature())
+            .unwrap();
+
+        // Roundtrip through the 65-byte form
+        assert_eq!(
+            Secp256k1RecoverableSignature::from_bytes(signature.as_ref()).unwrap(),
+            signature
+        );
+
+        // Ethereum style recovery ids are normalized
+        let mut bytes = signature.into_inner();
+        bytes[64] += 27;
+        assert_eq!(
+            Secp256k1RecoverableSignature::new(bytes).unwrap(),
+            signature
+        );
+
+        // A wrong recovery id recovers a different key, if any
+        let wrong = Secp256k1RecoverableSignature::from_parts(
+            &signature.signature(),
+            signature.recovery_id() ^ 1,
+        )
+        .unwrap();
+        assert_ne!(
+            wrong.recover_public_key(&message).ok(),
+            Some(signer.public_key())
+        );
+        signer.verifying_key().verify(&message, &wrong).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_recoverable_signatures() {
+        let mut bytes = [1; Secp256k1RecoverableSignature::LENGTH];
+        bytes[64] = 4;
+        Secp256k1RecoverableSignature::new(bytes).unwrap_err();
+        bytes[64] = 31;
+        Secp256k1RecoverableSignature::new(bytes).unwrap_err();
+        Secp256k1RecoverableSignature::from_bytes(&[0; 64]).unwrap_err();
+    }
+
     #[proptest]
     fn personal_message_signing(signer: Secp256k1PrivateKey, message: Vec<u8>) {
         let message = PersonalMessage(message.into());
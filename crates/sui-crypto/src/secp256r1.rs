@@ -147,6 +147,19 @@ impl Secp256r1VerifyingKey {
         Secp256r1PublicKey::new(self.0.as_ref().to_bytes().into())
     }
 
+    /// Parse a public key from its SEC1 encoding, either compressed (33 bytes) or uncompressed
+    /// (65 bytes), e.g. as exported by an HSM or a cloud KMS.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        VerifyingKey::from_sec1_bytes(bytes).map(Self)
+    }
+
+    /// Serialize this public key with the uncompressed SEC1 encoding, of 65 bytes.
+    ///
+    /// Sui uses the compressed encoding, which is the one of [`Secp256r1PublicKey`].
+    pub fn to_sec1_uncompressed_bytes(&self) -> Vec<u8> {
+        self.0.to_encoded_point(false).as_bytes().to_vec()
+    }
+
     #[cfg(feature = "pem")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "pem")))]
     /// Deserialize public key from ASN.1 DER-encoded data (binary format).
@@ -231,6 +244,12 @@ impl Verifier<UserSignature> for Secp256r1VerifyingKey {
     }
 }
 
+impl crate::PublicKeyVerifier<Secp256r1Signature> for Secp256r1PublicKey {
+    fn verify(&self, message: &[u8], signature: &Secp256r1Signature) -> Result<(), SignatureError> {
+        Secp256r1VerifyingKey::new(self)?.verify(message, signature)
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct Secp256r1Verifier {}
 
@@ -277,6 +296,29 @@ mod test {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
+    #[proptest]
+    fn sec1_public_keys(signer: Secp256r1PrivateKey) {
+        let verifying_key = signer.verifying_key();
+        let public_key = signer.public_key();
+
+        let uncompressed = verifying_key.to_sec1_uncompressed_bytes();
+        assert_eq!(uncompressed.len(), 65);
+        for bytes in [uncompressed.as_slice(), public_key.inner().as_slice()] {
+            let parsed = Secp256r1VerifyingKey::from_sec1_bytes(bytes).unwrap();
+            assert_eq!(parsed.public_key(), public_key);
+        }
+        Secp256r1VerifyingKey::from_sec1_bytes(&uncompressed[1..]).unwrap_err();
+
+        #[cfg(feature = "pem")]
+        {
+            let der = verifying_key.to_der().unwrap();
+            assert_eq!(
+                Secp256r1VerifyingKey::from_der(&der).unwrap().public_key(),
+                public_key
+            );
+        }
+    }
+
     // TODO need to export proptest impl from core crate
     // #[proptest]
     // fn transaction_signing(signer: Secp256r1PrivateKey, transaction: Transaction) {
@@ -287,6 +329,12 @@ mod test {
     //         .unwrap();
     // }
 
+    #[proptest]
+    fn raw_public_key_verification(signer: Secp256r1PrivateKey, message: Vec<u8>) {
+        let signature: Secp256r1Signature = signer.try_sign(&message).unwrap();
+        crate::test_util::check_raw_verification(&signer.public_key(), &message, &signature);
+    }
+
     #[proptest]
     fn personal_message_signing(signer: Secp256r1PrivateKey, message: Vec<u8>) {
         let message = PersonalMessage(message.into());
@@ -0,0 +1,152 @@
+use sui_sdk_types::AnySignedMessage;
+use sui_sdk_types::SignedCheckpointSummary;
+
+use crate::SignatureError;
+use crate::SuiVerifier;
+use crate::UserSignatureVerifier;
+
+/// Verifier of the signatures of any [`AnySignedMessage`].
+///
+/// Transactions and personal messages are verified with a [`UserSignatureVerifier`]. Checkpoint
+/// summaries are verified against the committee of their epoch, which needs to be configured
+/// with `with_committee_verifier`; without one they are rejected.
+#[derive(Default)]
+pub struct AnySignedMessageVerifier {
+    user: UserSignatureVerifier,
+    #[cfg(feature = "bls12381")]
+    committee: Option<crate::bls12381::ValidatorCommitteeSignatureVerifier>,
+}
+
+impl AnySignedMessageVerifier {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_user_verifier(&mut self, user_verifier: UserSignatureVerifier) {
+        self.user = user_verifier;
+    }
+
+    pub fn user_verifier(&self) -> &UserSignatureVerifier {
+        &self.user
+    }
+
+    /// Verify the signatures of `message`.
+    pub fn verify(&self, message: &AnySignedMessage) -> Result<(), SignatureError> {
+        match message {
+            AnySignedMessage::Transaction(transaction) => {
+                self.user.verify_signed_transaction(transaction)
+            }
+            AnySignedMessage::PersonalMessage(message) => self
+                .user
+                .verify_personal_message(&message.personal_message(), &message.signature),
+            AnySignedMessage::CheckpointSummary(checkpoint) => {
+                self.verify_checkpoint_summary(checkpoint)
+            }
+        }
+    }
+
+    #[cfg(feature = "bls12381")]
+    fn verify_checkpoint_summary(
+        &self,
+        checkpoint: &SignedCheckpointSummary,
+    ) -> Result<(), SignatureError> {
+        self.committee
+            .as_ref()
+            .ok_or_else(no_committee)?
+            .verify_checkpoint_summary(&checkpoint.checkpoint, &checkpoint.signature)
+    }
+
+    #[cfg(not(feature = "bls12381"))]
+    fn verify_checkpoint_summary(
+        &self,
+        _checkpoint: &SignedCheckpointSummary,
+    ) -> Result<(), SignatureError> {
+        Err(no_committee())
+    }
+}
+
+fn no_committee() -> SignatureError {
+    SignatureError::from_source("no committee configured to verify checkpoint summaries")
+}
+
+#[cfg(feature = "bls12381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bls12381")))]
+impl AnySignedMessageVerifier {
+    /// Verify checkpoint summaries against the committee of `committee_verifier`, which only
+    /// accepts checkpoints of its epoch.
+    pub fn with_committee_verifier(
+        &mut self,
+        committee_verifier: crate::bls12381::ValidatorCommitteeSignatureVerifier,
+    ) {
+        self.committee = Some(committee_verifier);
+    }
+
+    pub fn committee_verifier(
+        &self,
+    ) -> Option<&crate::bls12381::ValidatorCommitteeSignatureVerifier> {
+        self.committee.as_ref()
+    }
+}
+
+#[cfg(all(test, feature = "ed25519", feature = "bls12381"))]
+mod test {
+    use super::*;
+    use crate::bls12381::aggregate_validator_signatures;
+    use crate::bls12381::ValidatorCommitteeSignatureVerifier;
+    use crate::ed25519::Ed25519PrivateKey;
+    use crate::test_util::validator_committee;
+    use crate::test_util::validator_keys;
+    use crate::SuiSigner;
+    use sui_sdk_types::CheckpointSummary;
+    use sui_sdk_types::PersonalMessage;
+    use sui_sdk_types::SignedPersonalMessage;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn any_signed_message() {
+        let mut verifier = AnySignedMessageVerifier::new();
+
+        let key = Ed25519PrivateKey::new([1; Ed25519PrivateKey::LENGTH]);
+        let message = b"hello".to_vec();
+        let signature = key
+            .sign_personal_message(&PersonalMessage(message.as_slice().into()))
+            .unwrap();
+        let mut signed = SignedPersonalMessage { message, signature };
+        verifier.verify(&signed.clone().into()).unwrap();
+        signed.message = b"other".to_vec();
+        verifier.verify(&signed.into()).unwrap_err();
+
+        let validator = validator_keys(1).remove(0);
+        let committee = validator_committee(7, std::slice::from_ref(&validator), &[1]);
+        let checkpoint = CheckpointSummary {
+            epoch: 7,
+            sequence_number: 42,
+            network_total_transactions: 100,
+            content_digest: Default::default(),
+            previous_digest: None,
+            epoch_rolling_gas_cost_summary: Default::default(),
+            timestamp_ms: 0,
+            checkpoint_commitments: vec![],
+            end_of_epoch_data: None,
+            version_specific_data: vec![],
+        };
+        let signature = aggregate_validator_signatures(
+            &committee,
+            [&validator.sign_checkpoint_summary(&checkpoint)],
+        )
+        .unwrap();
+        let signed = SignedCheckpointSummary {
+            checkpoint,
+            signature,
+        }
+        .into();
+
+        // Checkpoints can't be verified without a committee
+        verifier.verify(&signed).unwrap_err();
+        verifier
+            .with_committee_verifier(ValidatorCommitteeSignatureVerifier::new(committee).unwrap());
+        verifier.verify(&signed).unwrap();
+    }
+}
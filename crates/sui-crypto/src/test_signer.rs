@@ -0,0 +1,128 @@
+//! Deterministic signers for use in tests.
+//!
+//! A [`TestSigner`] derives its key from a seed instead of randomness, so that the signatures it
+//! produces, and therefore the bytes of any `SignedTransaction` built with them, are stable
+//! across test runs. This makes it possible to check in snapshots of signed fixtures.
+//!
+//! The signatures produced are real ed25519 signatures which verify like any other. However,
+//! since the key of a `TestSigner` can be recomputed by anyone from its seed, it must never be
+//! used to control anything of value. This module is only available with the `test-utils`
+//! feature, which is meant to be enabled for dev-dependencies.
+
+use crate::ed25519::Ed25519PrivateKey;
+use crate::SignatureError;
+use crate::Signer;
+use sui_sdk_types::hash::Hasher;
+use sui_sdk_types::Address;
+use sui_sdk_types::Ed25519PublicKey;
+use sui_sdk_types::UserSignature;
+
+/// Domain separator mixed into the seed, so that the keys of test signers don't collide with keys
+/// derived from the same bytes by other means.
+const DOMAIN: &[u8] = b"sui-crypto::TestSigner";
+
+/// A signer whose key is deterministically derived from a seed.
+///
+/// **For tests only**: the private key of a test signer is trivially recoverable from its seed.
+pub struct TestSigner {
+    seed: Vec<u8>,
+    private_key: Ed25519PrivateKey,
+}
+
+impl TestSigner {
+    /// Construct a test signer from an arbitrary seed.
+    pub fn from_seed<T: AsRef<[u8]>>(seed: T) -> Self {
+        let seed = seed.as_ref();
+        let mut hasher = Hasher::new();
+        hasher.update(DOMAIN);
+        hasher.update(seed);
+        let private_key = Ed25519PrivateKey::new(hasher.finalize().into_inner());
+
+        Self {
+            seed: seed.to_owned(),
+            private_key,
+        }
+    }
+
+    /// Construct the `index`-th test signer, for tests which need a handful of distinct signers.
+    pub fn from_index(index: u64) -> Self {
+        Self::from_seed(index.to_le_bytes())
+    }
+
+    pub fn seed(&self) -> &[u8] {
+        &self.seed
+    }
+
+    pub fn public_key(&self) -> Ed25519PublicKey {
+        self.private_key.public_key()
+    }
+
+    /// The address of this signer, to be used as the sender of the transactions it signs.
+    pub fn address(&self) -> Address {
+        self.public_key().derive_address()
+    }
+
+    /// Return the underlying private key, e.g. to use it in a multisig fixture.
+    pub fn private_key(&self) -> &Ed25519PrivateKey {
+        &self.private_key
+    }
+}
+
+impl std::fmt::Debug for TestSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestSigner")
+            .field("seed", &hex_seed(&self.seed))
+            .field("address", &self.address())
+            .finish()
+    }
+}
+
+fn hex_seed(seed: &[u8]) -> String {
+    seed.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+impl Signer<UserSignature> for TestSigner {
+    fn try_sign(&self, msg: &[u8]) -> Result<UserSignature, SignatureError> {
+        self.private_key.try_sign(msg)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SuiSigner;
+    use crate::SuiVerifier;
+    use crate::UserSignatureVerifier;
+    use sui_sdk_types::PersonalMessage;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn deterministic_signatures() {
+        let message = PersonalMessage(b"hello".into());
+
+        let signer = TestSigner::from_index(0);
+        let signature = signer.sign_personal_message(&message).unwrap();
+        assert_eq!(
+            TestSigner::from_index(0)
+                .sign_personal_message(&message)
+                .unwrap(),
+            signature
+        );
+        assert_ne!(TestSigner::from_index(1).address(), signer.address());
+
+        UserSignatureVerifier::new()
+            .verify_personal_message(&message, &signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn stable_keys() {
+        // Changing the derivation of test keys would break the fixtures of downstream users
+        assert_eq!(
+            hex::encode(TestSigner::from_seed(b"sui").public_key().inner()),
+            "76e74a9b68b7967a14fa1030fdab1e7d8d6c8075d42efb0a7532e6abb33c6017"
+        );
+    }
+}
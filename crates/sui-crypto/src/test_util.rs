@@ -0,0 +1,75 @@
+//! Helpers shared by the tests of this crate and of the crates depending on it.
+//!
+//! Only the deterministic validator keys and committees are public, with the `test-utils` and
+//! `bls12381` features, and they must never be used outside of tests since their private keys are
+//! trivially recoverable.
+
+/// Drive `future` to completion on the current thread.
+///
+/// The mocks used in tests never wait on IO, so their futures are ready after a few polls.
+#[cfg(all(test, feature = "ed25519"))]
+pub(crate) fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Check that `public_key` accepts `signature` over `message`, and rejects it over any other
+/// message.
+#[cfg(all(
+    test,
+    any(
+        feature = "ed25519",
+        feature = "secp256k1",
+        feature = "secp256r1",
+        feature = "bls12381"
+    )
+))]
+pub(crate) fn check_raw_verification<K, S>(public_key: &K, message: &[u8], signature: &S)
+where
+    K: crate::PublicKeyVerifier<S>,
+{
+    public_key.verify(message, signature).unwrap();
+
+    let mut tampered = message.to_vec();
+    tampered.push(0);
+    public_key.verify(&tampered, signature).unwrap_err();
+}
+
+/// Four validator keys, whose scalars are `first` to `first + 3`.
+#[cfg(feature = "bls12381")]
+pub fn validator_keys(first: u8) -> Vec<crate::bls12381::Bls12381PrivateKey> {
+    use crate::bls12381::Bls12381PrivateKey;
+
+    (first..first + 4)
+        .map(|i| {
+            let mut bytes = [0; Bls12381PrivateKey::LENGTH];
+            bytes[31] = i;
+            Bls12381PrivateKey::new(bytes).unwrap()
+        })
+        .collect()
+}
+
+/// The committee of `epoch` made of the validators with `keys`, holding `stakes`.
+#[cfg(feature = "bls12381")]
+pub fn validator_committee(
+    epoch: sui_sdk_types::EpochId,
+    keys: &[crate::bls12381::Bls12381PrivateKey],
+    stakes: &[sui_sdk_types::StakeUnit],
+) -> sui_sdk_types::ValidatorCommittee {
+    sui_sdk_types::ValidatorCommittee {
+        epoch,
+        members: keys
+            .iter()
+            .zip(stakes)
+            .map(|(key, stake)| sui_sdk_types::ValidatorCommitteeMember {
+                public_key: key.public_key(),
+                stake: *stake,
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,268 @@
+use crate::SignatureError;
+use sui_sdk_types::JwkId;
+use sui_sdk_types::ZkLoginClaim;
+
+use super::JwtHeader;
+
+/// A decoded JWT as issued by an OpenID provider.
+///
+/// This type only decodes the token and extracts the pieces of information that are needed to
+/// build the inputs of a zkLogin proof; the signature of the token is not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jwt {
+    header_base64: String,
+    header: JwtHeader,
+    payload_base64: String,
+    payload: String,
+    iss: String,
+    aud: String,
+    sub: String,
+}
+
+impl Jwt {
+    /// Decode a JWT in its compact `header.payload.signature` form.
+    pub fn parse(token: &str) -> Result<Self, SignatureError> {
+        use base64ct::Base64UrlUnpadded;
+        use base64ct::Encoding;
+
+        #[derive(serde_derive::Deserialize)]
+        struct Claims {
+            iss: String,
+            aud: Audience,
+            sub: String,
+        }
+
+        // The JWT spec allows `aud` to be either a single string or an array of them
+        #[derive(serde_derive::Deserialize)]
+        #[serde(untagged)]
+        enum Audience {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        let mut parts = token.split('.');
+        let (Some(header_base64), Some(payload_base64), Some(_signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(SignatureError::from_source(
+                "jwt must consist of three '.' separated parts",
+            ));
+        };
+
+        let header = JwtHeader::from_base64(header_base64)?;
+
+        let payload = Base64UrlUnpadded::decode_vec(payload_base64)
+            .map_err(|e| SignatureError::from_source(e.to_string()))?;
+        let payload = String::from_utf8(payload)
+            .map_err(|_| SignatureError::from_source("jwt payload is not valid UTF8"))?;
+        let Claims { iss, aud, sub } =
+            serde_json::from_str(&payload).map_err(SignatureError::from_source)?;
+        let aud = match aud {
+            Audience::One(aud) => aud,
+            Audience::Many(mut auds) if auds.len() == 1 => auds.remove(0),
+            Audience::Many(_) => {
+                return Err(SignatureError::from_source(
+                    "jwt must have a single audience",
+                ))
+            }
+        };
+
+        Ok(Self {
+            header_base64: header_base64.to_owned(),
+            header,
+            payload_base64: payload_base64.to_owned(),
+            payload,
+            iss,
+            aud,
+            sub,
+        })
+    }
+
+    /// The base64url encoded header, as used for `ZkLoginInputs::header_base64`.
+    pub fn header_base64(&self) -> &str {
+        &self.header_base64
+    }
+
+    /// The `alg` field of the header.
+    pub fn alg(&self) -> &str {
+        &self.header.alg
+    }
+
+    /// The `kid` field of the header, identifying the `Jwk` which signed this token.
+    pub fn kid(&self) -> &str {
+        &self.header.kid
+    }
+
+    /// The `typ` field of the header, if present.
+    pub fn typ(&self) -> Option<&str> {
+        self.header.typ.as_deref()
+    }
+
+    /// The `iss` claim, identifying the OpenID provider.
+    pub fn iss(&self) -> &str {
+        &self.iss
+    }
+
+    /// The `aud` claim, identifying the client the token was issued for.
+    ///
+    /// A token whose `aud` is an array is accepted as long as it names a single client.
+    pub fn aud(&self) -> &str {
+        &self.aud
+    }
+
+    /// The `sub` claim, identifying the user.
+    pub fn sub(&self) -> &str {
+        &self.sub
+    }
+
+    /// The id of the `Jwk` needed to verify a zkLogin proof built from this token.
+    pub fn jwk_id(&self) -> JwkId {
+        JwkId {
+            iss: self.iss.clone(),
+            kid: self.header.kid.clone(),
+        }
+    }
+
+    /// The `iss` claim in the form used for `ZkLoginInputs::iss_base64_details`.
+    pub fn iss_base64_details(&self) -> Result<ZkLoginClaim, SignatureError> {
+        self.extended_claim("iss")
+    }
+
+    /// Extract the base64url encoded extended claim for `key` from the payload.
+    ///
+    /// The extended claim is the `"key":value` segment of the payload, including the `,` or `}`
+    /// that follows it. The returned value is the minimal substring of the base64url encoded
+    /// payload that covers the segment, along with the position modulo 4 of its first character,
+    /// which the zkLogin circuit needs in order to decode it.
+    pub fn extended_claim(&self, key: &str) -> Result<ZkLoginClaim, SignatureError> {
+        let (start, end) = find_extended_claim(&self.payload, key).ok_or_else(|| {
+            SignatureError::from_source(format!("unable to find claim '{key}' in jwt payload"))
+        })?;
+
+        // Each base64 character encodes 6 bits so the byte range [start, end) of the decoded
+        // payload is covered by the characters [4 * start / 3, (8 * end - 1) / 6].
+        let first = 4 * start / 3;
+        let last = (8 * end - 1) / 6;
+
+        Ok(ZkLoginClaim {
+            value: self.payload_base64[first..=last].to_owned(),
+            index_mod_4: (first % 4) as u8,
+        })
+    }
+}
+
+/// Find the byte range of the `"key":"value",` segment in a JSON object, returning `None` if the
+/// key isn't present or its value isn't a string.
+fn find_extended_claim(payload: &str, key: &str) -> Option<(usize, usize)> {
+    let needle = format!("\"{key}\"");
+
+    payload.match_indices(&needle).find_map(|(start, _)| {
+        let mut chars = payload[start + needle.len()..].char_indices().peekable();
+        let offset = start + needle.len();
+
+        let skip_whitespace = |chars: &mut std::iter::Peekable<std::str::CharIndices>| {
+            while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        };
+
+        skip_whitespace(&mut chars);
+        chars.next_if(|(_, c)| *c == ':')?;
+        skip_whitespace(&mut chars);
+        chars.next_if(|(_, c)| *c == '"')?;
+
+        // Consume the string value, honoring escapes
+        loop {
+            match chars.next()?.1 {
+                '\\' => {
+                    chars.next()?;
+                }
+                '"' => break,
+                _ => {}
+            }
+        }
+
+        skip_whitespace(&mut chars);
+        let (i, _) = chars.next_if(|(_, c)| *c == ',' || *c == '}')?;
+
+        Some((start, offset + i + 1))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use base64ct::Base64UrlUnpadded;
+    use base64ct::Encoding;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn encode_jwt(payload: &str) -> String {
+        format!(
+            "{}.{}.c2lnbmF0dXJl",
+            Base64UrlUnpadded::encode_string(br#"{"alg":"RS256","typ":"JWT","kid":"1"}"#),
+            Base64UrlUnpadded::encode_string(payload.as_bytes()),
+        )
+    }
+
+    #[test]
+    fn parse_jwt() {
+        let token = encode_jwt(
+            r#"{"aud":"rs1bh065i9ya4ydvifixl4kss0uhpt","exp":1698300000,"iss":"https://id.twitch.tv/oauth2","sub":"904448692"}"#,
+        );
+        let jwt = Jwt::parse(&token).unwrap();
+
+        assert_eq!(jwt.alg(), "RS256");
+        assert_eq!(jwt.kid(), "1");
+        assert_eq!(jwt.typ(), Some("JWT"));
+        assert_eq!(
+            jwt.header_base64(),
+            "eyJhbGciOiJSUzI1NiIsInR5cCI6IkpXVCIsImtpZCI6IjEifQ"
+        );
+        assert_eq!(jwt.iss(), "https://id.twitch.tv/oauth2");
+        assert_eq!(jwt.aud(), "rs1bh065i9ya4ydvifixl4kss0uhpt");
+        assert_eq!(jwt.sub(), "904448692");
+        assert_eq!(
+            jwt.jwk_id(),
+            JwkId {
+                iss: "https://id.twitch.tv/oauth2".to_owned(),
+                kid: "1".to_owned(),
+            }
+        );
+
+        let token = encode_jwt(r#"{"aud":["client"],"iss":"a","sub":"b"}"#);
+        assert_eq!(Jwt::parse(&token).unwrap().aud(), "client");
+        Jwt::parse(&encode_jwt(r#"{"aud":["a","b"],"iss":"a","sub":"b"}"#)).unwrap_err();
+        Jwt::parse(&encode_jwt(r#"{"aud":[],"iss":"a","sub":"b"}"#)).unwrap_err();
+
+        Jwt::parse("not.a-jwt").unwrap_err();
+        Jwt::parse(&encode_jwt(r#"{"iss":"a","sub":"b"}"#)).unwrap_err();
+    }
+
+    #[test]
+    fn extended_claim_round_trips() {
+        // Vary the offset of the claims so that every index_mod_4 is exercised
+        for padding in 0..6 {
+            let payload = format!(
+                r#"{{"nonce":"{}","iss":"https://accounts.google.com", "sub" : "1062940\"49","exp":1698300000,"aud":"client"}}"#,
+                "x".repeat(padding),
+            );
+            let jwt = Jwt::parse(&encode_jwt(&payload)).unwrap();
+
+            for (key, value) in [
+                ("iss", "https://accounts.google.com"),
+                ("sub", "1062940\"49"),
+                ("aud", "client"),
+            ] {
+                let claim = jwt.extended_claim(key).unwrap();
+                assert!(claim.index_mod_4 < 3);
+                assert_eq!(
+                    super::super::verify_extended_claim(&claim, key).unwrap(),
+                    value
+                );
+            }
+
+            jwt.extended_claim("missing").unwrap_err();
+            jwt.extended_claim("exp").unwrap_err();
+        }
+    }
+}
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use crate::SignatureError;
 use poseidon::POSEIDON;
 use signature::Verifier;
+use sui_sdk_types::ActiveJwk;
+use sui_sdk_types::EpochId;
 use sui_sdk_types::Jwk;
 use sui_sdk_types::JwkId;
 use sui_sdk_types::UserSignature;
@@ -10,9 +12,12 @@ use sui_sdk_types::ZkLoginAuthenticator;
 use sui_sdk_types::ZkLoginClaim;
 use sui_sdk_types::ZkLoginInputs;
 
+mod jwt;
 mod poseidon;
 mod verify;
 
+pub use jwt::Jwt;
+
 #[cfg(test)]
 mod tests;
 
@@ -84,6 +89,124 @@ impl Verifier<UserSignature> for ZkloginVerifier {
     }
 }
 
+/// Find the JWK a zkLogin authenticator must be verified with among `jwks`, e.g. the
+/// `new_active_jwks` of an `AuthenticatorStateUpdate`, explaining why it would be rejected if
+/// there is none.
+///
+/// JWKs last validated before `min_epoch` are considered expired, as they are by
+/// `sui::authenticator_state::expire_jwks`. If the same JWK was validated in several epochs, the
+/// latest one is used.
+pub fn check_active_jwk<'a>(
+    authenticator: &ZkLoginAuthenticator,
+    jwks: &'a [ActiveJwk],
+    min_epoch: EpochId,
+) -> Result<&'a ActiveJwk, JwkMismatch> {
+    let header = JwtHeader::parse_base64(&authenticator.inputs.header_base64)
+        .map_err(|e| JwkMismatch::InvalidInputs(e.to_string()))?;
+    let iss = verify_extended_claim(&authenticator.inputs.iss_base64_details, "iss")
+        .map_err(|e| JwkMismatch::InvalidInputs(e.to_string()))?;
+    let id = JwkId {
+        iss,
+        kid: header.kid,
+    };
+
+    let active = jwks
+        .iter()
+        .filter(|active| active.jwk_id == id)
+        .max_by_key(|active| active.epoch)
+        .ok_or_else(|| {
+            if jwks.iter().any(|active| active.jwk_id.iss == id.iss) {
+                JwkMismatch::UnknownKid(id.clone())
+            } else {
+                JwkMismatch::UnknownIssuer(id.iss.clone())
+            }
+        })?;
+
+    if active.epoch < min_epoch {
+        return Err(JwkMismatch::Expired {
+            jwk_id: id,
+            epoch: active.epoch,
+            min_epoch,
+        });
+    }
+
+    if active.jwk.alg != header.alg || header.alg != "RS256" {
+        return Err(JwkMismatch::AlgorithmMismatch {
+            jwt: header.alg,
+            jwk: active.jwk.alg.clone(),
+        });
+    }
+
+    Ok(active)
+}
+
+/// The reason a zkLogin authenticator doesn't match any of a set of JWKs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JwkMismatch {
+    /// The JWT header or `iss` claim of the authenticator can't be parsed.
+    InvalidInputs(String),
+
+    /// No JWK of the OIDC provider is known.
+    UnknownIssuer(String),
+
+    /// JWKs of the OIDC provider are known, but not the one with this `kid`.
+    UnknownKid(JwkId),
+
+    /// The JWK was last validated in `epoch`, before `min_epoch`.
+    Expired {
+        jwk_id: JwkId,
+        epoch: EpochId,
+        min_epoch: EpochId,
+    },
+
+    /// The algorithm of the JWT isn't the one of the JWK, or isn't `RS256`.
+    AlgorithmMismatch { jwt: String, jwk: String },
+}
+
+impl std::fmt::Display for JwkMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidInputs(e) => write!(f, "invalid zklogin inputs: {e}"),
+            Self::UnknownIssuer(iss) => write!(f, "no jwk is known for issuer '{iss}'"),
+            Self::UnknownKid(id) => {
+                write!(f, "no jwk with kid '{}' is known for issuer '{}'", id.kid, id.iss)
+            }
+            Self::Expired {
+                jwk_id,
+                epoch,
+                min_epoch,
+            } => write!(
+                f,
+                "jwk with kid '{}' of issuer '{}' was last validated in epoch {epoch}, before epoch {min_epoch}",
+                jwk_id.kid, jwk_id.iss
+            ),
+            Self::AlgorithmMismatch { jwt, jwk } => {
+                write!(f, "jwt alg '{jwt}' doesn't match jwk alg '{jwk}', expected RS256")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JwkMismatch {}
+
+impl sui_sdk_types::ErrorCode for JwkMismatch {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidInputs(_) => "invalid_zklogin_inputs",
+            Self::UnknownIssuer(_) => "unknown_jwk_issuer",
+            Self::UnknownKid(_) => "unknown_jwk_kid",
+            Self::Expired { .. } => "expired_jwk",
+            Self::AlgorithmMismatch { .. } => "jwk_algorithm_mismatch",
+        }
+    }
+}
+
+impl serde::Serialize for JwkMismatch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        sui_sdk_types::error::serialize(self, serializer)
+    }
+}
+
 /// A structed of parsed JWT details, consists of kid, header, iss.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct JwtDetails {
@@ -115,6 +238,15 @@ struct JwtHeader {
 
 impl JwtHeader {
     fn from_base64(s: &str) -> Result<Self, SignatureError> {
+        let header = Self::parse_base64(s)?;
+        if header.alg != "RS256" {
+            return Err(SignatureError::from_source("jwt alg must be RS256"));
+        }
+        Ok(header)
+    }
+
+    /// Parse a header without checking its algorithm.
+    fn parse_base64(s: &str) -> Result<Self, SignatureError> {
         use base64ct::Base64UrlUnpadded;
         use base64ct::Encoding;
 
@@ -130,9 +262,6 @@ impl JwtHeader {
             .map_err(|e| SignatureError::from_source(e.to_string()))?;
         let Header { alg, kid, typ } =
             serde_json::from_slice(&header_bytes).map_err(SignatureError::from_source)?;
-        if alg != "RS256" {
-            return Err(SignatureError::from_source("jwt alg must be RS256"));
-        }
         Ok(Self { alg, kid, typ })
     }
 }
@@ -166,7 +295,7 @@ fn verify_extended_claim(
     /// Convert a bitarray (each bit is represented by a u8) to a byte array by taking each 8 bits as a
     /// byte in big-endian format.
     fn bitarray_to_bytearray(bits: &[u8]) -> Result<Vec<u8>, SignatureError> {
-        if bits.len() % 8 != 0 {
+        if !bits.len().is_multiple_of(8) {
             return Err(SignatureError::from_source(
                 "bitarray_to_bytearray invalid input",
             ));
@@ -216,7 +345,7 @@ fn verify_extended_claim(
             }
         }
 
-        if bits.len() % 8 != 0 {
+        if !bits.len().is_multiple_of(8) {
             return Err(SignatureError::from_source("Invalid bits length"));
         }
 
@@ -79,3 +79,58 @@ fn zklogin_sign_personal_message() {
         .verify_personal_message(&message, &user_signature)
         .unwrap();
 }
+
+#[test]
+fn check_active_jwks() {
+    let (jwk, jwk_id, inputs, key, max_epoch) = test_zklogin_material();
+    let authenticator = ZkLoginAuthenticator {
+        inputs,
+        max_epoch,
+        signature: key.sign(b"hello world"),
+    };
+    let active = |jwk_id: JwkId, jwk: Jwk, epoch| ActiveJwk { jwk_id, jwk, epoch };
+
+    let jwks = [
+        active(jwk_id.clone(), jwk.clone(), 1),
+        active(jwk_id.clone(), jwk.clone(), 5),
+    ];
+    assert_eq!(check_active_jwk(&authenticator, &jwks, 3), Ok(&jwks[1]));
+    assert_eq!(
+        check_active_jwk(&authenticator, &jwks, 6),
+        Err(JwkMismatch::Expired {
+            jwk_id: jwk_id.clone(),
+            epoch: 5,
+            min_epoch: 6,
+        })
+    );
+
+    let other_kid = JwkId {
+        kid: "2".to_owned(),
+        ..jwk_id.clone()
+    };
+    let jwks = [active(other_kid, jwk.clone(), 1)];
+    assert_eq!(
+        check_active_jwk(&authenticator, &jwks, 0),
+        Err(JwkMismatch::UnknownKid(jwk_id.clone()))
+    );
+    assert_eq!(
+        check_active_jwk(&authenticator, &[], 0),
+        Err(JwkMismatch::UnknownIssuer(jwk_id.iss.clone()))
+    );
+
+    let jwks = [active(
+        jwk_id,
+        Jwk {
+            alg: "RS512".to_owned(),
+            ..jwk
+        },
+        1,
+    )];
+    assert_eq!(
+        check_active_jwk(&authenticator, &jwks, 0),
+        Err(JwkMismatch::AlgorithmMismatch {
+            jwt: "RS256".to_owned(),
+            jwk: "RS512".to_owned(),
+        })
+    );
+}
@@ -257,8 +257,8 @@ impl VerifyingKey {
         let modulus = Base64UrlUnpadded::decode_vec(&jwk.n)
             .map_err(|e| SignatureError::from_source(e.to_string()))?;
 
-        let proof = zklogin_proof_to_arkworks(&inputs.proof_points).unwrap();
-        let input_hash = calculate_all_inputs_hash(inputs, signature, &modulus, max_epoch).unwrap();
+        let proof = zklogin_proof_to_arkworks(&inputs.proof_points)?;
+        let input_hash = calculate_all_inputs_hash(inputs, signature, &modulus, max_epoch)?;
 
         self.verify_proof(&proof, &[input_hash])
     }
@@ -465,7 +465,9 @@ pub fn calculate_all_inputs_hash(
 
     let iss_base64_f = hash_ascii_str_to_field(&inputs.iss_base64_details.value, MAX_ISS_LEN_B64)?;
     let header_f = hash_ascii_str_to_field(&inputs.header_base64, MAX_HEADER_LEN)?;
-    let modulus_f = hash_to_field(&[U2048::from_be_slice(modulus).unwrap()], 2048, PACK_WIDTH)?;
+    let modulus = U2048::from_be_slice(modulus)
+        .ok_or_else(|| SignatureError::from_source("jwk modulus too long"))?;
+    let modulus_f = hash_to_field(&[modulus], 2048, PACK_WIDTH)?;
 
     POSEIDON
         .hash(&[
@@ -528,8 +530,7 @@ mod test {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
-    #[test]
-    fn test_verify_zklogin_google() {
+    fn google_fixture() -> (Jwk, ZkLoginInputs, SimpleSignature) {
         let user_salt = "206703048842351542647799591018316385612";
 
         let pubkey = Ed25519PublicKey::new([
@@ -591,11 +592,53 @@ mod test {
             alg: "RS256".to_string(),
         };
 
+        (jwk, zklogin_inputs, signature)
+    }
+
+    #[test]
+    fn test_verify_zklogin_google() {
+        let (jwk, zklogin_inputs, signature) = google_fixture();
+
         VerifyingKey::new_mainnet()
             .verify_zklogin(&jwk, &zklogin_inputs, &signature, 10)
             .unwrap();
     }
 
+    #[test]
+    fn test_verify_zklogin_wrong_max_epoch() {
+        let (jwk, zklogin_inputs, signature) = google_fixture();
+
+        VerifyingKey::new_mainnet()
+            .verify_zklogin(&jwk, &zklogin_inputs, &signature, 11)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_verify_zklogin_malformed_inputs() {
+        let (jwk, mut zklogin_inputs, signature) = google_fixture();
+        let verifying_key = VerifyingKey::new_mainnet();
+
+        // A proof point which isn't on the curve is rejected instead of panicking
+        let mut bad_proof = zklogin_inputs.clone();
+        bad_proof.proof_points.a = build_circom_g1(["1", "1", "1"]);
+        verifying_key
+            .verify_zklogin(&jwk, &bad_proof, &signature, 10)
+            .unwrap_err();
+
+        // A jwk modulus larger than 2048 bits is rejected instead of panicking
+        let mut bad_jwk = jwk.clone();
+        bad_jwk.n.push_str("AAAAAAAA");
+        verifying_key
+            .verify_zklogin(&bad_jwk, &zklogin_inputs, &signature, 10)
+            .unwrap_err();
+
+        // An oversized header is rejected
+        zklogin_inputs.header_base64 = "a".repeat(MAX_HEADER_LEN as usize + 1);
+        verifying_key
+            .verify_zklogin(&jwk, &zklogin_inputs, &signature, 10)
+            .unwrap_err();
+    }
+
     #[test]
     fn test_public_key_to_frs() {
         let pubkey = Ed25519PublicKey::new([
@@ -0,0 +1,105 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed pagination cursors.
+//!
+//! Cursors returned by the GraphQL server are opaque strings which are only meaningful to the
+//! connection that produced them. Endpoints paginating over objects, transactions, and events use
+//! a dedicated cursor type each, so that a cursor obtained from one of them can't accidentally be
+//! passed to another. Endpoints without a dedicated cursor type use plain `String`s.
+//!
+//! Cursors can be persisted and restored through their `serde` implementations, which represent
+//! them as plain strings.
+
+/// A cursor used to resume pagination over a connection.
+///
+/// This trait is sealed and only implemented by `String` and the cursor types in this module.
+pub trait Cursor: Clone + private::Sealed {
+    #[doc(hidden)]
+    fn from_raw(cursor: String) -> Self;
+
+    #[doc(hidden)]
+    fn into_raw(self) -> String;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+impl private::Sealed for String {}
+
+impl Cursor for String {
+    fn from_raw(cursor: String) -> Self {
+        cursor
+    }
+
+    fn into_raw(self) -> String {
+        self
+    }
+}
+
+macro_rules! cursor {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Return the cursor in the form used by the GraphQL server.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl private::Sealed for $name {}
+
+        impl Cursor for $name {
+            fn from_raw(cursor: String) -> Self {
+                Self(cursor)
+            }
+
+            fn into_raw(self) -> String {
+                self.0
+            }
+        }
+    };
+}
+
+cursor! {
+    /// Cursor for paginating over objects, including coins.
+    ObjectCursor
+}
+
+cursor! {
+    /// Cursor for paginating over transactions, their effects, or both.
+    TransactionCursor
+}
+
+cursor! {
+    /// Cursor for paginating over events.
+    EventCursor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_serde_is_transparent() {
+        let cursor = ObjectCursor::from_raw("eyJjIjoxLCJ0IjoyfQ".to_owned());
+
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(json, r#""eyJjIjoxLCJ0IjoyfQ""#);
+        assert_eq!(serde_json::from_str::<ObjectCursor>(&json).unwrap(), cursor);
+
+        assert_eq!(cursor.to_string(), cursor.as_str());
+        assert_eq!(cursor.into_raw(), "eyJjIjoxLCJ0IjoyfQ");
+    }
+}
@@ -38,6 +38,10 @@ pub enum Kind {
     Deserialization,
     Parse,
     Query,
+    /// The request didn't complete before the timeout or deadline of the client.
+    Timeout,
+    /// The request was cancelled by the cancellation token of the client.
+    Cancelled,
     Other,
 }
 
@@ -102,6 +106,8 @@ impl std::fmt::Display for Kind {
             Kind::Deserialization => write!(f, "Deserialization error:"),
             Kind::Parse => write!(f, "Parse error:"),
             Kind::Query => write!(f, "Query error:"),
+            Kind::Timeout => write!(f, "Timeout error:"),
+            Kind::Cancelled => write!(f, "Cancelled:"),
             Kind::Other => write!(f, "Error:"),
         }
     }
@@ -118,6 +124,44 @@ impl std::fmt::Display for Error {
     }
 }
 
+impl sui_types::ErrorCode for Error {
+    fn code(&self) -> &'static str {
+        match self.inner.kind {
+            Kind::Deserialization => "deserialization_error",
+            Kind::Parse => "parse_error",
+            Kind::Query => "query_error",
+            Kind::Timeout => "timeout",
+            Kind::Cancelled => "cancelled",
+            Kind::Other => "other",
+        }
+    }
+}
+
+/// Errors are serialized with their `code` and `message`, along with the messages of the errors
+/// returned by the GraphQL server, if any.
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        use sui_types::ErrorCode;
+
+        let graphql_errors = self.graphql_errors().map(|errors| {
+            errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+        });
+        let mut state = serializer.serialize_struct("Error", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", self.to_string().trim_end())?;
+        if let Some(graphql_errors) = graphql_errors {
+            state.serialize_field("graphql_errors", &graphql_errors)?;
+        } else {
+            state.skip_field("graphql_errors")?;
+        }
+        state.end()
+    }
+}
+
 impl From<bcs::Error> for Error {
     fn from(error: bcs::Error) -> Self {
         Self::from_error(Kind::Deserialization, error)
@@ -177,3 +221,30 @@ impl From<TypeParseError> for Error {
         Self::from_error(Kind::Parse, error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_as_json() {
+        let error = Error::from(AddressParseError);
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({
+                "code": "parse_error",
+                "message": format!("Parse error: {AddressParseError}"),
+            })
+        );
+
+        let error = Error::graphql_error(vec![GraphQlError::new(
+            "unknown field".to_owned(),
+            None,
+            None,
+            None,
+        )]);
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], "query_error");
+        assert_eq!(json["graphql_errors"], serde_json::json!(["unknown field"]));
+    }
+}
@@ -60,11 +60,6 @@ pub struct FaucetReceipt {
     pub sent: Vec<CoinInfo>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct BatchFaucetReceipt {
-    pub task: String,
-}
-
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CoinInfo {
@@ -0,0 +1,359 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Incremental deserialization of GraphQL connection responses.
+//!
+//! A page of a connection is normally deserialized in full before any of its nodes are looked at,
+//! which for very large pages means holding every node in its raw form alongside the values it is
+//! converted into. The helpers in this module instead hand each node to a callback as soon as it
+//! has been parsed, so it can be converted and the raw form dropped right away.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use cynic::GraphQlError;
+use serde::de::DeserializeOwned;
+use serde::de::DeserializeSeed;
+use serde::de::IgnoredAny;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::Visitor;
+use serde::Deserializer;
+
+use crate::error::Error;
+use crate::error::Kind;
+use crate::error::Result;
+use crate::query_types::PageInfo;
+
+/// Parse a GraphQL response of the form `{"data": {<connection>: {"nodes": [..], "pageInfo": ..}}}`
+/// calling `f` with each node, in order, as soon as it has been deserialized.
+///
+/// Returns the page info of the connection, which is the default one if the response has no data.
+pub(crate) fn for_each_connection_node<N, F>(
+    body: &[u8],
+    connection: &'static str,
+    mut f: F,
+) -> Result<PageInfo>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    let mut callback_error = None;
+    let seed = ResponseSeed {
+        connection,
+        callback: NodeCallback {
+            f: &mut f,
+            error: &mut callback_error,
+            _node: PhantomData,
+        },
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let result = seed
+        .deserialize(&mut deserializer)
+        .and_then(|response| deserializer.end().map(|()| response));
+
+    if let Some(error) = callback_error {
+        return Err(error);
+    }
+
+    let (page_info, errors) = result.map_err(|e| Error::from_error(Kind::Deserialization, e))?;
+    if let Some(errors) = errors {
+        return Err(Error::graphql_error(errors));
+    }
+
+    Ok(page_info.unwrap_or_default())
+}
+
+struct NodeCallback<'a, N, F> {
+    f: &'a mut F,
+    error: &'a mut Option<Error>,
+    _node: PhantomData<fn() -> N>,
+}
+
+impl<N, F> NodeCallback<'_, N, F> {
+    fn reborrow(&mut self) -> NodeCallback<'_, N, F> {
+        NodeCallback {
+            f: self.f,
+            error: self.error,
+            _node: PhantomData,
+        }
+    }
+}
+
+/// The top level response object, made of `data` and `errors`.
+struct ResponseSeed<'a, N, F> {
+    connection: &'static str,
+    callback: NodeCallback<'a, N, F>,
+}
+
+impl<'de, N, F> DeserializeSeed<'de> for ResponseSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = (Option<PageInfo>, Option<Vec<GraphQlError>>);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, N, F> Visitor<'de> for ResponseSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = (Option<PageInfo>, Option<Vec<GraphQlError>>);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a GraphQL response")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut page_info = None;
+        let mut errors = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "data" => {
+                    page_info = map.next_value_seed(OptionSeed(DataSeed {
+                        connection: self.connection,
+                        callback: self.callback.reborrow(),
+                    }))?;
+                }
+                "errors" => errors = map.next_value()?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok((page_info.flatten(), errors))
+    }
+}
+
+/// The `data` object, in which only the requested connection is of interest.
+struct DataSeed<'a, N, F> {
+    connection: &'static str,
+    callback: NodeCallback<'a, N, F>,
+}
+
+impl<'de, N, F> DeserializeSeed<'de> for DataSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = Option<PageInfo>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, N, F> Visitor<'de> for DataSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = Option<PageInfo>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the data of a GraphQL response")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut page_info = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.connection {
+                page_info = Some(map.next_value_seed(ConnectionSeed(self.callback.reborrow()))?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        Ok(page_info)
+    }
+}
+
+/// A connection, made of `nodes` and `pageInfo`.
+struct ConnectionSeed<'a, N, F>(NodeCallback<'a, N, F>);
+
+impl<'de, N, F> DeserializeSeed<'de> for ConnectionSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = PageInfo;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, N, F> Visitor<'de> for ConnectionSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = PageInfo;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a GraphQL connection")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut page_info = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "nodes" => map.next_value_seed(NodesSeed(self.0.reborrow()))?,
+                "pageInfo" => page_info = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        page_info.ok_or_else(|| serde::de::Error::missing_field("pageInfo"))
+    }
+}
+
+/// The `nodes` list of a connection, each of which is handed to the callback.
+struct NodesSeed<'a, N, F>(NodeCallback<'a, N, F>);
+
+impl<'de, N, F> DeserializeSeed<'de> for NodesSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, N, F> Visitor<'de> for NodesSeed<'_, N, F>
+where
+    N: DeserializeOwned,
+    F: FnMut(N) -> Result<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of nodes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while let Some(node) = seq.next_element::<N>()? {
+            if let Err(e) = (self.0.f)(node) {
+                *self.0.error = Some(e);
+                return Err(serde::de::Error::custom("failed to process node"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Allows `null` in place of the value of the inner seed.
+struct OptionSeed<S>(S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for OptionSeed<S> {
+    type Value = Option<S::Value>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> Visitor<'de> for OptionSeed<S> {
+    type Value = Option<S::Value>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize(deserializer).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Node {
+        bcs: Option<String>,
+    }
+
+    #[test]
+    fn connection_nodes() {
+        let body = br#"{
+            "data": {
+                "other": [1, 2, 3],
+                "objects": {
+                    "nodes": [{"bcs": "AA=="}, {"bcs": null}, {"bcs": "AQ=="}],
+                    "pageInfo": {
+                        "hasPreviousPage": false,
+                        "hasNextPage": true,
+                        "startCursor": "a",
+                        "endCursor": "b"
+                    }
+                }
+            },
+            "extensions": {}
+        }"#;
+
+        let mut nodes = Vec::new();
+        let page_info = for_each_connection_node(body, "objects", |node: Node| {
+            nodes.push(node.bcs);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![Some("AA==".to_owned()), None, Some("AQ==".to_owned())]
+        );
+        assert!(page_info.has_next_page);
+        assert_eq!(page_info.end_cursor.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn null_data_and_errors() {
+        let page_info =
+            for_each_connection_node(br#"{"data": null}"#, "objects", |_: Node| Ok(())).unwrap();
+        assert!(!page_info.has_next_page);
+
+        let body = br#"{"data": null, "errors": [{"message": "boom"}]}"#;
+        let err = for_each_connection_node(body, "objects", |_: Node| Ok(())).unwrap_err();
+        assert_eq!(err.graphql_errors().unwrap()[0].message, "boom");
+    }
+
+    #[test]
+    fn callback_errors_stop_parsing() {
+        let body = br#"{"data": {"objects": {"nodes": [{"bcs": null}, {"bcs": null}], "pageInfo": {"hasPreviousPage": false, "hasNextPage": false}}}}"#;
+
+        let mut calls = 0;
+        let err = for_each_connection_node(body, "objects", |_: Node| {
+            calls += 1;
+            Err(Error::from_error(Kind::Other, "stop"))
+        })
+        .unwrap_err();
+
+        assert_eq!(calls, 1);
+        assert!(matches!(err.kind(), Kind::Other));
+    }
+}
@@ -3,12 +3,25 @@
 
 #![doc = include_str!("../README.md")]
 
+pub mod cursor;
 pub mod error;
 pub mod faucet;
+mod json_stream;
+pub mod network_info;
+pub mod nft;
 pub mod query_types;
 pub mod streams;
+pub mod verification;
 
+pub use tokio_util::sync::CancellationToken;
+
+use cursor::Cursor;
+use cursor::EventCursor;
+use cursor::ObjectCursor;
+use cursor::TransactionCursor;
 use error::Error;
+use json_stream::for_each_connection_node;
+use network_info::NetworkInfo;
 use query_types::ActiveValidatorsArgs;
 use query_types::ActiveValidatorsQuery;
 use query_types::BalanceArgs;
@@ -44,6 +57,7 @@ use query_types::LatestPackageQuery;
 use query_types::MoveFunction;
 use query_types::MoveModule;
 use query_types::MovePackageVersionFilter;
+use query_types::NetworkInfoQuery;
 use query_types::NormalizedMoveFunctionQuery;
 use query_types::NormalizedMoveFunctionQueryArgs;
 use query_types::NormalizedMoveModuleQuery;
@@ -73,6 +87,7 @@ use query_types::ServiceConfigQuery;
 use query_types::TransactionBlockArgs;
 use query_types::TransactionBlockEffectsQuery;
 use query_types::TransactionBlockQuery;
+use query_types::TransactionBlockWithCheckpointQuery;
 use query_types::TransactionBlocksEffectsQuery;
 use query_types::TransactionBlocksQuery;
 use query_types::TransactionBlocksQueryArgs;
@@ -80,12 +95,21 @@ use query_types::TransactionMetadata;
 use query_types::TransactionsFilter;
 use query_types::Validator;
 use streams::stream_paginated_query;
+use verification::verify_certificate;
+use verification::verify_checkpoint_inclusion;
+use verification::CheckpointCertificates;
+use verification::CommitteeStore;
+use verification::TransactionWithEffects;
+use verification::Verified;
 
 use sui_types::framework::Coin;
+use sui_types::postcondition::ExecutionOutcome;
 use sui_types::Address;
+use sui_types::CheckpointContents;
 use sui_types::CheckpointDigest;
 use sui_types::CheckpointSequenceNumber;
 use sui_types::CheckpointSummary;
+use sui_types::CheckpointTransactionInfo;
 use sui_types::Event;
 use sui_types::MovePackage;
 use sui_types::Object;
@@ -107,7 +131,12 @@ use futures::Stream;
 use reqwest::Url;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::Instant;
 
 use crate::error::Kind;
 use crate::error::Result;
@@ -134,6 +163,25 @@ static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_V
 pub struct DryRunResult {
     pub effects: Option<TransactionEffects>,
     pub error: Option<String>,
+    /// The state of the objects changed by the dry run, before it executed.
+    pub input_objects: Vec<Object>,
+    /// The state of the objects written by the dry run, after it executed.
+    pub output_objects: Vec<Object>,
+    /// The BCS serialized values returned by each command of the dry run, up to the command
+    /// which failed, if any.
+    pub return_values: Vec<Vec<Vec<u8>>>,
+}
+
+impl DryRunResult {
+    /// The outcome of the dry run, against which postconditions can be checked, if it produced
+    /// effects.
+    pub fn outcome(&self) -> Option<ExecutionOutcome<'_>> {
+        Some(ExecutionOutcome {
+            effects: self.effects.as_ref()?,
+            input_objects: &self.input_objects,
+            output_objects: &self.output_objects,
+        })
+    }
 }
 
 pub struct TransactionDataEffects {
@@ -141,6 +189,18 @@ pub struct TransactionDataEffects {
     pub effects: TransactionEffects,
 }
 
+/// The result of an idempotent submission with [`Client::execute_tx_idempotent`].
+#[derive(Clone, Debug)]
+pub struct IdempotentExecution {
+    /// The digest of the transaction, which keys the submission.
+    pub digest: TransactionDigest,
+    /// The effects of the transaction.
+    pub effects: TransactionEffects,
+    /// Whether the transaction had already been executed before this submission, e.g. by an
+    /// earlier attempt of the same job, in which case it wasn't submitted again.
+    pub already_executed: bool,
+}
+
 /// The name part of a dynamic field, including its type, bcs, and json representation.
 #[derive(Clone, Debug)]
 pub struct DynamicFieldName {
@@ -171,15 +231,16 @@ pub struct NameValue(Vec<u8>);
 pub struct BcsName(pub Vec<u8>);
 
 #[derive(Clone, Debug)]
-/// A page of items returned by the GraphQL server.
-pub struct Page<T> {
+/// A page of items returned by the GraphQL server, paginated with cursors of type `C`.
+pub struct Page<T, C = String> {
     /// Information about the page, such as the cursor and whether there are more pages.
     page_info: PageInfo,
     /// The data returned by the server.
     data: Vec<T>,
+    _cursor: PhantomData<fn() -> C>,
 }
 
-impl<T> Page<T> {
+impl<T, C> Page<T, C> {
     /// Return the page information.
     pub fn page_info(&self) -> &PageInfo {
         &self.page_info
@@ -192,7 +253,11 @@ impl<T> Page<T> {
 
     /// Create a new page with the provided data and page information.
     pub fn new(page_info: PageInfo, data: Vec<T>) -> Self {
-        Self { page_info, data }
+        Self {
+            page_info,
+            data,
+            _cursor: PhantomData,
+        }
     }
 
     /// Check if the page has no data.
@@ -211,6 +276,18 @@ impl<T> Page<T> {
     }
 }
 
+impl<T, C: Cursor> Page<T, C> {
+    /// The cursor to use to fetch the page before this one.
+    pub fn start_cursor(&self) -> Option<C> {
+        self.page_info.start_cursor.clone().map(C::from_raw)
+    }
+
+    /// The cursor to use to fetch the page after this one.
+    pub fn end_cursor(&self) -> Option<C> {
+        self.page_info.end_cursor.clone().map(C::from_raw)
+    }
+}
+
 /// Pagination direction.
 #[derive(Clone, Debug, Default)]
 pub enum Direction {
@@ -221,17 +298,27 @@ pub enum Direction {
 
 /// Pagination options for querying the GraphQL server. It defaults to forward pagination with the
 /// GraphQL server's max page size.
-#[derive(Clone, Debug, Default)]
-pub struct PaginationFilter {
+#[derive(Clone, Debug)]
+pub struct PaginationFilter<C = String> {
     /// The direction of pagination.
     pub direction: Direction,
     /// An opaque cursor used for pagination.
-    pub cursor: Option<String>,
+    pub cursor: Option<C>,
     /// The maximum number of items to return. If this is ommitted, it will lazily query the
     /// service configuration for the max page size.
     pub limit: Option<i32>,
 }
 
+impl<C> Default for PaginationFilter<C> {
+    fn default() -> Self {
+        Self {
+            direction: Direction::default(),
+            cursor: None,
+            limit: None,
+        }
+    }
+}
+
 impl<T: Serialize> From<T> for NameValue {
     fn from(value: T) -> Self {
         NameValue(bcs::to_bytes(&value).unwrap())
@@ -285,6 +372,13 @@ pub struct Client {
     inner: reqwest::Client,
 
     service_config: std::sync::OnceLock<ServiceConfig>,
+
+    /// The maximum duration of each request.
+    timeout: Option<Duration>,
+    /// The instant after which requests fail.
+    deadline: Option<Instant>,
+    /// A token cancelling in flight and future requests.
+    cancellation: Option<CancellationToken>,
 }
 
 impl Client {
@@ -300,6 +394,9 @@ impl Client {
             rpc,
             inner: reqwest::Client::builder().user_agent(USER_AGENT).build()?,
             service_config: Default::default(),
+            timeout: None,
+            deadline: None,
+            cancellation: None,
         };
         Ok(client)
     }
@@ -338,19 +435,69 @@ impl Client {
         self.rpc.as_str()
     }
 
+    /// Set the maximum duration of each request made by the client, including reading the
+    /// response. Requests taking longer fail with an error of kind [`Kind::Timeout`].
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Set an instant after which requests made by the client fail with an error of kind
+    /// [`Kind::Timeout`].
+    ///
+    /// Unlike [`Client::set_timeout`], the deadline bounds operations made of several requests as
+    /// a whole, such as paginating through a stream.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
+    /// Set a token which, once cancelled, makes in flight and future requests made by the client
+    /// fail with an error of kind [`Kind::Cancelled`].
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation = token;
+    }
+
+    /// Run a request, enforcing the timeout, deadline and cancellation token of the client.
+    async fn controlled<T, F>(&self, request: F) -> Result<T>
+    where
+        F: std::future::Future<Output = Result<T>>,
+    {
+        let timeout = self.timeout.map(|timeout| Instant::now() + timeout);
+        let deadline = match (timeout, self.deadline) {
+            (Some(timeout), Some(deadline)) => Some(timeout.min(deadline)),
+            (timeout, deadline) => timeout.or(deadline),
+        };
+        let request = async {
+            match deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, request)
+                    .await
+                    .map_err(|_| Error::from_error(Kind::Timeout, "request deadline exceeded"))?,
+                None => request.await,
+            }
+        };
+
+        match &self.cancellation {
+            Some(token) => token
+                .run_until_cancelled(request)
+                .await
+                .unwrap_or_else(|| Err(Error::from_error(Kind::Cancelled, "request cancelled"))),
+            None => request.await,
+        }
+    }
+
     /// Handle pagination filters and return the appropriate values (after, before, first, last).
     /// If limit is omitted, it will use the max page size from the service config.
-    pub async fn pagination_filter(
+    pub async fn pagination_filter<C: Cursor>(
         &self,
-        pagination_filter: PaginationFilter,
+        pagination_filter: PaginationFilter<C>,
     ) -> (Option<String>, Option<String>, Option<i32>, Option<i32>) {
         let limit = pagination_filter
             .limit
             .unwrap_or(self.max_page_size().await.unwrap_or(DEFAULT_ITEMS_PER_PAGE));
 
+        let cursor = pagination_filter.cursor.map(C::into_raw);
         let (after, before, first, last) = match pagination_filter.direction {
-            Direction::Forward => (pagination_filter.cursor, None, Some(limit), None),
-            Direction::Backward => (None, pagination_filter.cursor, None, Some(limit)),
+            Direction::Forward => (cursor, None, Some(limit), None),
+            Direction::Backward => (None, cursor, None, Some(limit)),
         };
         (after, before, first, last)
     }
@@ -367,14 +514,29 @@ impl Client {
     where
         T: serde::de::DeserializeOwned,
         V: serde::Serialize,
+    {
+        self.controlled(async {
+            let res = self
+                .send_query(operation)
+                .await?
+                .json::<GraphQlResponse<T>>()
+                .await?;
+            Ok(res)
+        })
+        .await
+    }
+
+    /// Send the operation to the GraphQL server, returning the raw response for the caller to
+    /// parse.
+    async fn send_query<T, V>(&self, operation: &Operation<T, V>) -> Result<reqwest::Response>
+    where
+        V: serde::Serialize,
     {
         let res = self
             .inner
             .post(self.rpc_server())
             .json(&operation)
             .send()
-            .await?
-            .json::<GraphQlResponse<T>>()
             .await?;
         Ok(res)
     }
@@ -398,6 +560,33 @@ impl Client {
             .ok_or_else(Error::empty_response_error)
     }
 
+    /// Get the chain identifier, current epoch, protocol version, reference gas price, and latest
+    /// checkpoint in a single request.
+    pub async fn network_info(&self) -> Result<NetworkInfo> {
+        let operation = NetworkInfoQuery::build(());
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let data = response.data.ok_or_else(Error::empty_response_error)?;
+        let epoch = data.epoch.ok_or_else(Error::empty_response_error)?;
+        let checkpoint = data.checkpoint.ok_or_else(Error::empty_response_error)?;
+        let reference_gas_price = epoch
+            .reference_gas_price
+            .ok_or_else(Error::empty_response_error)?
+            .try_into()?;
+
+        Ok(NetworkInfo {
+            chain_id: data.chain_identifier,
+            epoch: epoch.epoch_id,
+            protocol_version: epoch.protocol_configs.protocol_version,
+            reference_gas_price,
+            latest_checkpoint: checkpoint.sequence_number,
+        })
+    }
+
     /// Get the reference gas price for the provided epoch or the last known one if no epoch is
     /// provided.
     ///
@@ -587,8 +776,8 @@ impl Client {
         &self,
         owner: Address,
         coin_type: Option<&str>,
-        pagination_filter: PaginationFilter,
-    ) -> Result<Page<Coin>> {
+        pagination_filter: PaginationFilter<ObjectCursor>,
+    ) -> Result<Page<Coin<'_>, ObjectCursor>> {
         let response = self
             .objects(
                 Some(ObjectFilter {
@@ -620,7 +809,7 @@ impl Client {
         address: Address,
         coin_type: Option<&'static str>,
         streaming_direction: Direction,
-    ) -> impl Stream<Item = Result<Coin>> {
+    ) -> impl Stream<Item = Result<Coin<'_>>> {
         stream_paginated_query(
             move |filter| self.coins(address, coin_type, filter),
             streaming_direction,
@@ -738,6 +927,39 @@ impl Client {
             .map(|c| c.sequence_number))
     }
 
+    /// Get a stream of the [`CheckpointSummary`] of every checkpoint from `start` onwards, as
+    /// they are produced, or from the latest checkpoint if `start` is `None`.
+    ///
+    /// Once the stream has caught up with the chain, the next checkpoint is polled for every
+    /// `poll_interval`. This only relies on GraphQL queries, for services which can't subscribe
+    /// to checkpoints otherwise.
+    ///
+    /// The stream never ends on its own: errors, like transient network failures, are yielded and
+    /// polling then resumes from the same checkpoint after `poll_interval`. Callers which want to
+    /// give up on errors can stop consuming the stream, e.g. with
+    /// [`TryStreamExt::try_take_while`](futures::TryStreamExt::try_take_while).
+    pub fn watch_checkpoints(
+        &self,
+        start: Option<CheckpointSequenceNumber>,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<CheckpointSummary>> + '_ {
+        futures::stream::unfold((start, false), move |(next, failed)| async move {
+            if failed {
+                tokio::time::sleep(poll_interval).await;
+            }
+            loop {
+                match self.checkpoint(None, next).await {
+                    Ok(Some(checkpoint)) => {
+                        let following = checkpoint.sequence_number + 1;
+                        return Some((Ok(checkpoint), (Some(following), false)));
+                    }
+                    Ok(None) => tokio::time::sleep(poll_interval).await,
+                    Err(error) => return Some((Err(error), (next, true))),
+                }
+            }
+        })
+    }
+
     // ===========================================================================
     // Dynamic Field(s) API
     // ===========================================================================
@@ -978,8 +1200,8 @@ impl Client {
     pub async fn events(
         &self,
         filter: Option<EventFilter>,
-        pagination_filter: PaginationFilter,
-    ) -> Result<Page<(Event, TransactionDigest)>> {
+        pagination_filter: PaginationFilter<EventCursor>,
+    ) -> Result<Page<(Event, TransactionDigest), EventCursor>> {
         let (after, before, first, last) = self.pagination_filter(pagination_filter).await;
 
         let operation = EventsQuery::build(EventsQueryArgs {
@@ -1095,8 +1317,34 @@ impl Client {
     pub async fn objects(
         &self,
         filter: Option<ObjectFilter<'_>>,
-        pagination_filter: PaginationFilter,
-    ) -> Result<Page<Object>> {
+        pagination_filter: PaginationFilter<ObjectCursor>,
+    ) -> Result<Page<Object, ObjectCursor>> {
+        let mut objects = Vec::new();
+        let page_info = self
+            .objects_for_each(filter, pagination_filter, |object| {
+                objects.push(object);
+                Ok(())
+            })
+            .await?;
+
+        Ok(Page::new(page_info, objects))
+    }
+
+    /// Call `f` with each of the objects in a page of objects based on the provided parameters,
+    /// returning the page's pagination information.
+    ///
+    /// Unlike [`Client::objects`], the response is parsed incrementally and each object is
+    /// decoded and handed to `f` as soon as it has been read, without first deserializing the
+    /// whole page. This keeps memory usage low when requesting very large pages.
+    pub async fn objects_for_each<F>(
+        &self,
+        filter: Option<ObjectFilter<'_>>,
+        pagination_filter: PaginationFilter<ObjectCursor>,
+        mut f: F,
+    ) -> Result<PageInfo>
+    where
+        F: FnMut(Object) -> Result<()>,
+    {
         let (after, before, first, last) = self.pagination_filter(pagination_filter).await;
         let operation = ObjectsQuery::build(ObjectsQueryArgs {
             after: after.as_deref(),
@@ -1106,32 +1354,17 @@ impl Client {
             last,
         });
 
-        let response = self.run_query(&operation).await?;
-        if let Some(errors) = response.errors {
-            return Err(Error::graphql_error(errors));
-        }
-
-        if let Some(objects) = response.data {
-            let oc = objects.objects;
-            let page_info = oc.page_info;
-            let bcs = oc
-                .nodes
-                .iter()
-                .map(|o| &o.bcs)
-                .filter_map(|b64| {
-                    b64.as_ref()
-                        .map(|b| base64ct::Base64::decode_vec(b.0.as_str()))
-                })
-                .collect::<Result<Vec<_>, base64ct::Error>>()?;
-            let objects = bcs
-                .iter()
-                .map(|b| bcs::from_bytes::<sui_types::Object>(b))
-                .collect::<Result<Vec<_>, bcs::Error>>()?;
+        let body = self
+            .controlled(async { Ok(self.send_query(&operation).await?.bytes().await?) })
+            .await?;
 
-            Ok(Page::new(page_info, objects))
-        } else {
-            Ok(Page::new_empty())
-        }
+        for_each_connection_node(&body, "objects", |node: query_types::ObjectNode| {
+            if let Some(bcs) = node.bcs {
+                let bcs = base64ct::Base64::decode_vec(bcs.0.as_str())?;
+                f(bcs::from_bytes::<Object>(&bcs)?)?;
+            }
+            Ok(())
+        })
     }
 
     /// Return a stream of objects based on the (optional) object filter.
@@ -1454,12 +1687,13 @@ impl Client {
         tx_meta: Option<TransactionMetadata>,
     ) -> Result<DryRunResult> {
         let skip_checks = skip_checks.unwrap_or(false);
-        let operation = DryRunQuery::build(DryRunArgs {
-            tx_bytes,
+        let args = |after| DryRunArgs {
+            tx_bytes: tx_bytes.clone(),
             skip_checks,
-            tx_meta,
-        });
-        let response = self.run_query(&operation).await?;
+            tx_meta: tx_meta.clone(),
+            after,
+        };
+        let response = self.run_query(&DryRunQuery::build(args(None))).await?;
 
         // Query errors
         if let Some(errors) = response.errors {
@@ -1471,19 +1705,92 @@ impl Client {
             .data
             .as_ref()
             .and_then(|tx| tx.dry_run_transaction_block.error.clone());
+        let return_values = response
+            .data
+            .as_ref()
+            .and_then(|tx| tx.dry_run_transaction_block.results.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|result| {
+                result
+                    .return_values
+                    .iter()
+                    .flatten()
+                    .map(|value| base64ct::Base64::decode_vec(&value.bcs.0))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-        let effects = response
+        let Some(dry_run_effects) = response
             .data
             .map(|tx| tx.dry_run_transaction_block)
             .and_then(|tx| tx.transaction)
             .and_then(|tx| tx.effects)
-            .and_then(|bcs| bcs.bcs)
-            .map(|bcs| base64ct::Base64::decode_vec(bcs.0.as_str()))
+        else {
+            return Ok(DryRunResult {
+                effects: None,
+                error,
+                input_objects: vec![],
+                output_objects: vec![],
+                return_values,
+            });
+        };
+
+        let effects_bcs = dry_run_effects.bcs.map(|bcs| bcs.0);
+        let effects = effects_bcs
+            .as_deref()
+            .map(base64ct::Base64::decode_vec)
             .transpose()?
             .map(|bcs| bcs::from_bytes::<TransactionEffects>(&bcs))
             .transpose()?;
 
-        Ok(DryRunResult { effects, error })
+        let decode = |object: Option<query_types::ObjectBcs>| -> Result<Option<Object>> {
+            object
+                .and_then(|object| object.bcs)
+                .map(|bcs| Ok(bcs::from_bytes(&base64ct::Base64::decode_vec(&bcs.0)?)?))
+                .transpose()
+        };
+        let mut input_objects = Vec::new();
+        let mut output_objects = Vec::new();
+        let mut changes = dry_run_effects.object_changes;
+        loop {
+            for change in changes.nodes {
+                input_objects.extend(decode(change.input_state)?);
+                output_objects.extend(decode(change.output_state)?);
+            }
+            if !changes.page_info.has_next_page {
+                break;
+            }
+
+            // The results of dry runs aren't persisted, so the following pages of object changes
+            // are fetched by dry running the transaction again
+            let response = self
+                .run_query(&DryRunQuery::build(args(changes.page_info.end_cursor)))
+                .await?;
+            if let Some(errors) = response.errors {
+                return Err(Error::graphql_error(errors));
+            }
+            let page = response
+                .data
+                .and_then(|tx| tx.dry_run_transaction_block.transaction)
+                .and_then(|tx| tx.effects)
+                .ok_or_else(|| Error::from_error(Kind::Other, "missing effects of dry run"))?;
+            if page.bcs.map(|bcs| bcs.0) != effects_bcs {
+                return Err(Error::from_error(
+                    Kind::Other,
+                    "the effects of the dry run changed while paging through its object changes",
+                ));
+            }
+            changes = page.object_changes;
+        }
+
+        Ok(DryRunResult {
+            effects,
+            error,
+            input_objects,
+            output_objects,
+            return_values,
+        })
     }
 
     // ===========================================================================
@@ -1567,8 +1874,8 @@ impl Client {
     pub async fn transactions(
         &self,
         filter: Option<TransactionsFilter<'_>>,
-        pagination_filter: PaginationFilter,
-    ) -> Result<Page<SignedTransaction>> {
+        pagination_filter: PaginationFilter<TransactionCursor>,
+    ) -> Result<Page<SignedTransaction, TransactionCursor>> {
         let (after, before, first, last) = self.pagination_filter(pagination_filter).await;
 
         let operation = TransactionBlocksQuery::build(TransactionBlocksQueryArgs {
@@ -1605,8 +1912,8 @@ impl Client {
     pub async fn transactions_effects(
         &self,
         filter: Option<TransactionsFilter<'_>>,
-        pagination_filter: PaginationFilter,
-    ) -> Result<Page<TransactionEffects>> {
+        pagination_filter: PaginationFilter<TransactionCursor>,
+    ) -> Result<Page<TransactionEffects, TransactionCursor>> {
         let (after, before, first, last) = self.pagination_filter(pagination_filter).await;
 
         let operation = TransactionBlocksEffectsQuery::build(TransactionBlocksQueryArgs {
@@ -1639,8 +1946,8 @@ impl Client {
     pub async fn transactions_data_effects(
         &self,
         filter: Option<TransactionsFilter<'_>>,
-        pagination_filter: PaginationFilter,
-    ) -> Result<Page<TransactionDataEffects>> {
+        pagination_filter: PaginationFilter<TransactionCursor>,
+    ) -> Result<Page<TransactionDataEffects, TransactionCursor>> {
         let (after, before, first, last) = self.pagination_filter(pagination_filter).await;
 
         let operation = TransactionBlocksWithEffectsQuery::build(TransactionBlocksQueryArgs {
@@ -1725,6 +2032,172 @@ impl Client {
         )
     }
 
+    /// Get the transactions identified by `digests` along with their effects, verifying that each
+    /// of them has been included in a checkpoint certified by the validator committee.
+    ///
+    /// For every transaction this checks that the transaction and its effects are the ones
+    /// identified by the requested digest, that they are committed to by the contents of the
+    /// checkpoint they were finalized in, that those contents are committed to by the checkpoint
+    /// summary, and that the summary is certified by a quorum of the committee of its epoch, as
+    /// known to `committee_store`.
+    ///
+    /// The GraphQL service doesn't expose which validators certified a checkpoint, so the
+    /// certificates of the checkpoints are looked up in `certificates` instead.
+    ///
+    /// The contents of a checkpoint are rebuilt from all the transactions it includes, so this
+    /// issues a number of requests proportional to the size of the checkpoints involved. Each
+    /// checkpoint is only fetched and verified once.
+    ///
+    /// Returns an error if any of the transactions can't be found, isn't yet part of a checkpoint,
+    /// or fails verification.
+    pub async fn get_transactions_verified<S: CommitteeStore, C: CheckpointCertificates>(
+        &self,
+        digests: &[TransactionDigest],
+        committee_store: &S,
+        certificates: &C,
+    ) -> Result<Vec<Verified<TransactionWithEffects>>> {
+        let mut checkpoints = HashMap::new();
+        let mut verified = Vec::with_capacity(digests.len());
+
+        for digest in digests {
+            let (transaction, effects, sequence_number) =
+                self.transaction_with_checkpoint(*digest).await?;
+
+            let (checkpoint, contents) = match checkpoints.entry(sequence_number) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let certificate = certificates
+                        .checkpoint_certificate(sequence_number)
+                        .await?
+                        .filter(|certificate| {
+                            certificate.checkpoint.sequence_number == sequence_number
+                        })
+                        .ok_or_else(|| {
+                            Error::from_error(
+                                Kind::Other,
+                                format!(
+                                    "the certificate of checkpoint {sequence_number} is not \
+                                     available"
+                                ),
+                            )
+                        })?;
+                    verify_certificate(committee_store, &certificate)?;
+                    let checkpoint = certificate.checkpoint;
+                    let contents = self.checkpoint_contents(sequence_number).await?;
+                    entry.insert((checkpoint, contents))
+                }
+            };
+
+            verify_checkpoint_inclusion(digest, &transaction, &effects, checkpoint, contents)?;
+
+            verified.push(Verified::new_unchecked(TransactionWithEffects {
+                transaction,
+                effects,
+                checkpoint: checkpoint.clone(),
+            }));
+        }
+
+        Ok(verified)
+    }
+
+    /// Get a transaction, its effects, and the sequence number of the checkpoint it was finalized
+    /// in.
+    async fn transaction_with_checkpoint(
+        &self,
+        digest: TransactionDigest,
+    ) -> Result<(
+        SignedTransaction,
+        TransactionEffects,
+        CheckpointSequenceNumber,
+    )> {
+        let operation = TransactionBlockWithCheckpointQuery::build(TransactionBlockArgs {
+            digest: digest.to_string(),
+        });
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let tx = response
+            .data
+            .and_then(|d| d.transaction_block)
+            .ok_or_else(|| {
+                Error::from_error(Kind::Query, format!("Transaction {digest} not found"))
+            })?;
+
+        let (Some(bcs), Some(effects), Some(sigs)) = (tx.bcs, tx.effects, tx.signatures) else {
+            return Err(Error::empty_response_error());
+        };
+        let checkpoint = effects.checkpoint.ok_or_else(|| {
+            Error::from_error(
+                Kind::Other,
+                format!("Transaction {digest} is not yet included in a checkpoint"),
+            )
+        })?;
+        let effects = effects.bcs.ok_or_else(Error::empty_response_error)?;
+
+        let bcs = base64ct::Base64::decode_vec(bcs.0.as_str())?;
+        let effects = base64ct::Base64::decode_vec(effects.0.as_str())?;
+        let signatures = sigs
+            .iter()
+            .map(|s| UserSignature::from_base64(&s.0))
+            .collect::<Result<Vec<_>, _>>()?;
+        let transaction = SignedTransaction {
+            transaction: bcs::from_bytes(&bcs)?,
+            signatures,
+        };
+        let effects: TransactionEffects = bcs::from_bytes(&effects)?;
+
+        Ok((transaction, effects, checkpoint.sequence_number))
+    }
+
+    /// Rebuild the [`CheckpointContents`] of a checkpoint from the transactions it includes.
+    async fn checkpoint_contents(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<CheckpointContents> {
+        let filter = TransactionsFilter {
+            at_checkpoint: Some(sequence_number),
+            ..Default::default()
+        };
+
+        let mut transactions = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .transactions_data_effects(
+                    Some(filter.clone()),
+                    PaginationFilter {
+                        direction: Direction::Forward,
+                        cursor,
+                        limit: None,
+                    },
+                )
+                .await?;
+            let next_cursor = page.end_cursor();
+            let (page_info, data) = page.into_parts();
+
+            transactions.extend(
+                data.into_iter()
+                    .map(
+                        |TransactionDataEffects { tx, effects }| CheckpointTransactionInfo {
+                            transaction: tx.transaction.digest(),
+                            effects: effects.digest(),
+                            signatures: tx.signatures,
+                        },
+                    ),
+            );
+
+            if !page_info.has_next_page {
+                break;
+            }
+            cursor = next_cursor;
+        }
+
+        Ok(CheckpointContents::new(transactions))
+    }
+
     /// Execute a transaction.
     pub async fn execute_tx(
         &self,
@@ -1753,6 +2226,55 @@ impl Client {
         }
     }
 
+    /// Execute a transaction, keyed by its digest, so that it can safely be submitted more than
+    /// once, e.g. by a job runner retrying a job which may or may not have completed.
+    ///
+    /// The effects of the transaction are looked up by its digest first, and it is only submitted
+    /// if it hasn't been executed yet. Since the resubmission of an executed transaction can
+    /// either fail or succeed, e.g. when validators still hold its effects, the effects are looked
+    /// up again when the submission fails or returns no effects, and the submission succeeds with
+    /// them if the transaction has been executed in the meantime. Otherwise, the original error
+    /// is returned and the submission can be retried. Errors looking up the effects are returned
+    /// as is, since whether the transaction was executed is then unknown.
+    ///
+    /// Lookups go through the indexer of the service, so a transaction executed moments before
+    /// can be reported as newly executed.
+    pub async fn execute_tx_idempotent(
+        &self,
+        signatures: Vec<UserSignature>,
+        tx: &Transaction,
+    ) -> Result<IdempotentExecution> {
+        let digest = tx.digest();
+        if let Some(effects) = self.transaction_effects(digest).await? {
+            return Ok(IdempotentExecution {
+                digest,
+                effects,
+                already_executed: true,
+            });
+        }
+
+        let error = match self.execute_tx(signatures, tx).await {
+            Ok(Some(effects)) => {
+                return Ok(IdempotentExecution {
+                    digest,
+                    effects,
+                    already_executed: false,
+                })
+            }
+            Ok(None) => Error::empty_response_error(),
+            Err(error) => error,
+        };
+
+        match self.transaction_effects(digest).await? {
+            Some(effects) => Ok(IdempotentExecution {
+                digest,
+                effects,
+                already_executed: false,
+            }),
+            None => Err(error),
+        }
+    }
+
     // ===========================================================================
     // Normalized Move Package API
     // ===========================================================================
@@ -1917,6 +2439,39 @@ mod tests {
         assert!(client.set_rpc_server("9125/graphql").is_err());
     }
 
+    #[tokio::test]
+    async fn test_request_deadlines() {
+        use crate::error::Kind;
+        use crate::CancellationToken;
+        use std::time::Duration;
+
+        let pending = || std::future::pending::<crate::error::Result<()>>();
+        let mut client = Client::new_localhost();
+        client.controlled(async { Ok(()) }).await.unwrap();
+
+        client.set_timeout(Some(Duration::from_millis(10)));
+        let error = client.controlled(pending()).await.unwrap_err();
+        assert!(matches!(error.kind(), Kind::Timeout));
+
+        client.set_timeout(None);
+        client.set_deadline(Some(time::Instant::now() + Duration::from_millis(10)));
+        let error = client.controlled(pending()).await.unwrap_err();
+        assert!(matches!(error.kind(), Kind::Timeout));
+
+        client.set_deadline(None);
+        let token = CancellationToken::new();
+        client.set_cancellation_token(Some(token.clone()));
+        let cancel = async {
+            time::sleep(Duration::from_millis(10)).await;
+            token.cancel();
+        };
+        let (result, ()) = futures::join!(client.controlled(pending()), cancel);
+        assert!(matches!(result.unwrap_err().kind(), Kind::Cancelled));
+        // Requests made after the cancellation fail right away
+        let error = client.controlled(async { Ok(()) }).await.unwrap_err();
+        assert!(matches!(error.kind(), Kind::Cancelled));
+    }
+
     #[tokio::test]
     async fn test_balance_query() {
         let client = test_client();
@@ -1935,6 +2490,17 @@ mod tests {
         assert!(chain_id.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_network_info() {
+        let client = test_client();
+        let info = client.network_info().await.unwrap();
+        assert_eq!(info.chain_id, client.chain_id().await.unwrap());
+        assert_eq!(
+            Some(info.reference_gas_price),
+            client.reference_gas_price(Some(info.epoch)).await.unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_reference_gas_price_query() {
         let client = test_client();
@@ -2032,6 +2598,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_watch_checkpoints() {
+        use futures::StreamExt;
+
+        let client = test_client();
+        let latest = client
+            .latest_checkpoint_sequence_number()
+            .await
+            .unwrap()
+            .unwrap();
+        let start = latest.saturating_sub(1);
+        let checkpoints: Vec<_> = client
+            .watch_checkpoints(Some(start), std::time::Duration::from_millis(100))
+            .take(3)
+            .collect()
+            .await;
+        for (offset, checkpoint) in checkpoints.into_iter().enumerate() {
+            assert_eq!(checkpoint.unwrap().sequence_number, start + offset as u64);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch_checkpoints_after_errors() {
+        use futures::StreamExt;
+
+        // Nothing listens on this port, so every query fails right away
+        let client = Client::new("http://127.0.0.1:1/graphql").unwrap();
+        let checkpoints: Vec<_> = client
+            .watch_checkpoints(Some(0), std::time::Duration::from_millis(10))
+            .take(3)
+            .collect()
+            .await;
+        assert_eq!(checkpoints.len(), 3);
+        assert!(checkpoints.iter().all(Result::is_err));
+    }
+
     #[tokio::test]
     async fn test_latest_checkpoint_sequence_number_query() {
         let client = test_client();
@@ -0,0 +1,218 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use sui_types::CheckpointSequenceNumber;
+use sui_types::EpochId;
+use sui_types::ProtocolVersion;
+
+use crate::error::Result;
+use crate::Client;
+
+/// A snapshot of the state of the network that most applications need to keep track of.
+///
+/// Use [`Client::network_info`] to fetch it with a single request, and a [`NetworkInfoWatcher`]
+/// to be notified when it changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkInfo {
+    /// The chain identifier, i.e. the digest of the genesis checkpoint.
+    pub chain_id: String,
+    /// The current epoch.
+    pub epoch: EpochId,
+    /// The protocol version of the current epoch.
+    pub protocol_version: ProtocolVersion,
+    /// The reference gas price of the current epoch.
+    pub reference_gas_price: u64,
+    /// The sequence number of the latest checkpoint known to the service.
+    pub latest_checkpoint: CheckpointSequenceNumber,
+}
+
+/// A change between two successive snapshots of the [`NetworkInfo`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkInfoChange {
+    /// The service is now reporting on a different chain, e.g. after a network wipe.
+    ChainId { from: String, to: String },
+    /// A new epoch started.
+    Epoch { from: EpochId, to: EpochId },
+    /// The protocol version was upgraded.
+    ProtocolVersion {
+        from: ProtocolVersion,
+        to: ProtocolVersion,
+    },
+    /// The reference gas price changed.
+    ReferenceGasPrice { from: u64, to: u64 },
+    /// New checkpoints were produced.
+    LatestCheckpoint {
+        from: CheckpointSequenceNumber,
+        to: CheckpointSequenceNumber,
+    },
+}
+
+impl NetworkInfo {
+    /// Return the list of changes going from `previous` to `self`.
+    pub fn changes_since(&self, previous: &NetworkInfo) -> Vec<NetworkInfoChange> {
+        let mut changes = Vec::new();
+
+        if self.chain_id != previous.chain_id {
+            changes.push(NetworkInfoChange::ChainId {
+                from: previous.chain_id.clone(),
+                to: self.chain_id.clone(),
+            });
+        }
+        if self.epoch != previous.epoch {
+            changes.push(NetworkInfoChange::Epoch {
+                from: previous.epoch,
+                to: self.epoch,
+            });
+        }
+        if self.protocol_version != previous.protocol_version {
+            changes.push(NetworkInfoChange::ProtocolVersion {
+                from: previous.protocol_version,
+                to: self.protocol_version,
+            });
+        }
+        if self.reference_gas_price != previous.reference_gas_price {
+            changes.push(NetworkInfoChange::ReferenceGasPrice {
+                from: previous.reference_gas_price,
+                to: self.reference_gas_price,
+            });
+        }
+        if self.latest_checkpoint != previous.latest_checkpoint {
+            changes.push(NetworkInfoChange::LatestCheckpoint {
+                from: previous.latest_checkpoint,
+                to: self.latest_checkpoint,
+            });
+        }
+
+        changes
+    }
+}
+
+type ChangeHook = Box<dyn FnMut(&NetworkInfoChange, &NetworkInfo) + Send>;
+
+/// Keeps track of the latest [`NetworkInfo`] and notifies registered hooks of every change
+/// observed when refreshing it.
+///
+/// The watcher doesn't poll on its own; call [`NetworkInfoWatcher::refresh`] at whatever interval
+/// suits the application.
+#[derive(Default)]
+pub struct NetworkInfoWatcher {
+    current: Option<NetworkInfo>,
+    hooks: Vec<ChangeHook>,
+}
+
+impl NetworkInfoWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hook called with each change, along with the updated info, every time a
+    /// refresh observes one.
+    pub fn on_change<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnMut(&NetworkInfoChange, &NetworkInfo) + Send + 'static,
+    {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// The latest info observed, if any refresh has happened yet.
+    pub fn current(&self) -> Option<&NetworkInfo> {
+        self.current.as_ref()
+    }
+
+    /// Fetch the latest info from the network and notify the hooks of any changes.
+    pub async fn refresh(&mut self, client: &Client) -> Result<&NetworkInfo> {
+        let info = client.network_info().await?;
+        Ok(self.update(info))
+    }
+
+    /// Record `info` as the latest info, notifying the hooks of any changes since the previous
+    /// one. The first info recorded isn't considered a change.
+    pub fn update(&mut self, info: NetworkInfo) -> &NetworkInfo {
+        if let Some(previous) = &self.current {
+            for change in info.changes_since(previous) {
+                for hook in &mut self.hooks {
+                    hook(&change, &info);
+                }
+            }
+        }
+
+        self.current.insert(info)
+    }
+}
+
+impl std::fmt::Debug for NetworkInfoWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetworkInfoWatcher")
+            .field("current", &self.current)
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    fn info() -> NetworkInfo {
+        NetworkInfo {
+            chain_id: "4c78adac".to_owned(),
+            epoch: 10,
+            protocol_version: 60,
+            reference_gas_price: 750,
+            latest_checkpoint: 1000,
+        }
+    }
+
+    #[test]
+    fn changes_since() {
+        let previous = info();
+        assert!(previous.changes_since(&previous).is_empty());
+
+        let next = NetworkInfo {
+            epoch: 11,
+            protocol_version: 61,
+            latest_checkpoint: 1200,
+            ..previous.clone()
+        };
+        assert_eq!(
+            next.changes_since(&previous),
+            vec![
+                NetworkInfoChange::Epoch { from: 10, to: 11 },
+                NetworkInfoChange::ProtocolVersion { from: 60, to: 61 },
+                NetworkInfoChange::LatestCheckpoint {
+                    from: 1000,
+                    to: 1200
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn watcher_hooks() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut watcher = NetworkInfoWatcher::new();
+        watcher.on_change({
+            let seen = seen.clone();
+            move |change, _| seen.lock().unwrap().push(change.clone())
+        });
+
+        watcher.update(info());
+        assert!(seen.lock().unwrap().is_empty());
+
+        watcher.update(NetworkInfo {
+            reference_gas_price: 1000,
+            ..info()
+        });
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![NetworkInfoChange::ReferenceGasPrice {
+                from: 750,
+                to: 1000
+            }]
+        );
+        assert_eq!(watcher.current().unwrap().reference_gas_price, 1000);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for the NFT standards of the Sui framework.
+//!
+//! Marketplaces and wallets displaying an NFT usually need more than the object itself: its
+//! [Display](https://docs.sui.io/standards/display) rendered from the `sui::display` templates of
+//! its type, whether it is placed in a `sui::kiosk::Kiosk` and listed for sale, and the royalty
+//! enforced on trades by the `sui::transfer_policy::TransferPolicy` of its type.
+//! [`NftInfo::load`] gathers all of these in a single call.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use base64ct::Base64;
+use base64ct::Encoding;
+use cynic::QueryBuilder;
+use futures::StreamExt;
+use sui_types::Address;
+use sui_types::Identifier;
+use sui_types::Object;
+use sui_types::ObjectId;
+use sui_types::Owner;
+use sui_types::StructTag;
+use sui_types::TypeTag;
+
+use crate::error::Error;
+use crate::error::Kind;
+use crate::error::Result;
+use crate::query_types::DisplayEntry;
+use crate::query_types::ObjectDisplayQuery;
+use crate::query_types::ObjectFilter;
+use crate::query_types::ObjectQueryArgs;
+use crate::Client;
+use crate::Direction;
+
+/// An NFT along with the metadata needed to display and trade it.
+#[derive(Clone, Debug)]
+pub struct NftInfo {
+    pub object: Object,
+
+    /// The fields of the Display of the object's type, rendered for this object.
+    ///
+    /// Fields which the server failed to render are omitted.
+    pub display: BTreeMap<String, String>,
+
+    /// The kiosk the object is placed in, if any.
+    pub kiosk: Option<KioskListing>,
+
+    /// The royalty rule of the transfer policy of the object's type, if any.
+    pub royalty: Option<RoyaltyRule>,
+}
+
+impl NftInfo {
+    /// Load the object `object_id` along with its Display, kiosk listing, and royalty rule.
+    ///
+    /// Returns `Ok(None)` if the object doesn't exist, and an error if it isn't a Move object.
+    pub async fn load(client: &Client, object_id: Address) -> Result<Option<Self>> {
+        let Some((object, display)) = object_with_display(client, object_id).await? else {
+            return Ok(None);
+        };
+        let object_type = object
+            .as_struct()
+            .ok_or_else(|| Error::from_error(Kind::Other, "object isn't a Move object"))?
+            .object_type()
+            .clone();
+
+        let kiosk = KioskListing::load(client, &object).await?;
+        let royalty = RoyaltyRule::load(client, object_type.into()).await?;
+
+        Ok(Some(Self {
+            object,
+            display,
+            kiosk,
+            royalty,
+        }))
+    }
+
+    /// The royalty owed when buying the object at its listing price, if it is listed and its
+    /// type has a royalty rule.
+    pub fn royalty_fee(&self) -> Option<u64> {
+        let price = self.kiosk.as_ref()?.price?;
+        Some(self.royalty.as_ref()?.fee(price))
+    }
+}
+
+/// The state of an object placed in a kiosk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KioskListing {
+    pub kiosk_id: ObjectId,
+
+    /// The price the object is listed at, in MIST, or `None` if it isn't listed.
+    pub price: Option<u64>,
+
+    /// Whether the object is listed exclusively, i.e. can only be bought through the extension
+    /// which listed it.
+    pub is_exclusive: bool,
+
+    /// Whether the object is locked in the kiosk, i.e. can only leave it when sold.
+    pub is_locked: bool,
+}
+
+impl KioskListing {
+    /// Load the state of `object` in the kiosk it is placed in, or `None` if it isn't in a kiosk.
+    ///
+    /// Objects placed in a kiosk are dynamic object fields of the kiosk, and so are owned by a
+    /// `sui::dynamic_field::Field` which is itself owned by the kiosk.
+    pub async fn load(client: &Client, object: &Object) -> Result<Option<Self>> {
+        let Owner::Object(field_id) = object.owner() else {
+            return Ok(None);
+        };
+        let Some(field) = client.object((*field_id).into(), None).await? else {
+            return Ok(None);
+        };
+        let Owner::Object(kiosk_id) = field.owner() else {
+            return Ok(None);
+        };
+        let Some(kiosk) = client.object((*kiosk_id).into(), None).await? else {
+            return Ok(None);
+        };
+        if kiosk.as_struct().map(|s| s.object_type()) != Some(&kiosk_type("Kiosk")) {
+            return Ok(None);
+        }
+
+        let kiosk_id = *kiosk_id;
+        let id = object.object_id();
+        let mut listing = Self {
+            kiosk_id,
+            price: None,
+            is_exclusive: false,
+            is_locked: false,
+        };
+        for is_exclusive in [false, true] {
+            let name = ListingName { id, is_exclusive };
+            let price = client
+                .dynamic_field(kiosk_id.into(), kiosk_type("Listing").into(), name)
+                .await?
+                .and_then(|field| field.value)
+                .map(|(_, bcs)| bcs::from_bytes::<u64>(&bcs))
+                .transpose()?;
+            if price.is_some() {
+                listing.price = price;
+                listing.is_exclusive = is_exclusive;
+                break;
+            }
+        }
+        listing.is_locked = client
+            .dynamic_field(kiosk_id.into(), kiosk_type("Lock").into(), id)
+            .await?
+            .is_some();
+
+        Ok(Some(listing))
+    }
+}
+
+/// The configuration of the royalty rule of a transfer policy, as defined by the `royalty_rule`
+/// module of the kiosk package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyRule {
+    /// The transfer policy the rule was added to.
+    pub policy_id: ObjectId,
+
+    /// The royalty, in basis points of the price paid.
+    pub amount_bp: u16,
+
+    /// The minimum royalty, in MIST.
+    pub min_amount: u64,
+}
+
+impl RoyaltyRule {
+    /// Load the royalty rule of a transfer policy for `object_type`, or `None` if no transfer
+    /// policy for the type has a royalty rule.
+    ///
+    /// A type can have several transfer policies, so all of them are paged through until one with
+    /// a royalty rule is found.
+    pub async fn load(client: &Client, object_type: TypeTag) -> Result<Option<Self>> {
+        let policy_type = StructTag {
+            type_params: vec![object_type],
+            ..framework_type("transfer_policy", "TransferPolicy")
+        }
+        .to_string();
+        let filter = ObjectFilter {
+            type_: Some(&policy_type),
+            ..Default::default()
+        };
+        let policies = client
+            .objects_stream(Some(filter), Direction::Forward)
+            .await;
+        let mut policies = std::pin::pin!(policies);
+
+        while let Some(policy) = policies.next().await {
+            let policy = policy?;
+            let Some(contents) = policy.as_struct().map(|s| s.contents()) else {
+                continue;
+            };
+            let policy_contents: TransferPolicyContents = bcs::from_bytes(contents)?;
+            let Some(rule) = royalty_rule_type(&policy_contents.rules) else {
+                continue;
+            };
+
+            let rule_key = StructTag {
+                type_params: vec![rule.into()],
+                ..framework_type("transfer_policy", "RuleKey")
+            };
+            let config = client
+                .dynamic_field(policy.object_id().into(), rule_key.into(), false)
+                .await?
+                .and_then(|field| field.value)
+                .map(|(_, bcs)| bcs::from_bytes::<RoyaltyConfig>(&bcs))
+                .transpose()?;
+            if let Some(config) = config {
+                return Ok(Some(Self {
+                    policy_id: policy.object_id(),
+                    amount_bp: config.amount_bp,
+                    min_amount: config.min_amount,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The royalty owed when paying `price`, computed the same way as `royalty_rule::fee_amount`.
+    pub fn fee(&self, price: u64) -> u64 {
+        let amount = (price as u128 * self.amount_bp as u128 / 10_000) as u64;
+        amount.max(self.min_amount)
+    }
+}
+
+/// The BCS layout of the name of the dynamic field listing an object in a kiosk.
+#[derive(serde::Serialize)]
+struct ListingName {
+    id: ObjectId,
+    is_exclusive: bool,
+}
+
+/// The BCS layout of a `sui::transfer_policy::TransferPolicy`.
+#[derive(serde::Deserialize)]
+struct TransferPolicyContents {
+    #[allow(unused)]
+    id: ObjectId,
+    #[allow(unused)]
+    balance: u64,
+    /// The `std::type_name::TypeName`s of the rules of the policy.
+    rules: Vec<String>,
+}
+
+/// The BCS layout of a `royalty_rule::Config`.
+#[derive(serde::Deserialize)]
+struct RoyaltyConfig {
+    amount_bp: u16,
+    min_amount: u64,
+}
+
+/// Find the royalty rule among the type names of the rules of a transfer policy.
+///
+/// The kiosk package, and so the royalty rule, is published at a different address on each
+/// network, so rules are recognized by their module and name.
+fn royalty_rule_type(rules: &[String]) -> Option<StructTag> {
+    rules.iter().find_map(|rule| {
+        // Type names have no `0x` prefix
+        let rule = StructTag::from_str(&format!("0x{rule}")).ok()?;
+        (rule.module.as_str() == "royalty_rule" && rule.name.as_str() == "Rule").then_some(rule)
+    })
+}
+
+async fn object_with_display(
+    client: &Client,
+    address: Address,
+) -> Result<Option<(Object, BTreeMap<String, String>)>> {
+    let operation = ObjectDisplayQuery::build(ObjectQueryArgs {
+        address,
+        version: None,
+    });
+    let response = client.run_query(&operation).await?;
+
+    if let Some(errors) = response.errors {
+        return Err(Error::graphql_error(errors));
+    }
+
+    let Some(object) = response.data.and_then(|data| data.object) else {
+        return Ok(None);
+    };
+    let Some(bcs) = object.bcs else {
+        return Ok(None);
+    };
+    let display = display_fields(object.display.unwrap_or_default());
+    let object = bcs::from_bytes(&Base64::decode_vec(&bcs.0)?)?;
+    Ok(Some((object, display)))
+}
+
+fn display_fields(entries: Vec<DisplayEntry>) -> BTreeMap<String, String> {
+    entries
+        .into_iter()
+        .filter_map(|entry| Some((entry.key, entry.value?)))
+        .collect()
+}
+
+fn framework_type(module: &str, name: &str) -> StructTag {
+    StructTag {
+        address: Address::TWO,
+        module: Identifier::new(module).unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params: vec![],
+    }
+}
+
+fn kiosk_type(name: &str) -> StructTag {
+    framework_type("kiosk", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn royalty_fee() {
+        let rule = RoyaltyRule {
+            policy_id: ObjectId::ZERO,
+            amount_bp: 250,
+            min_amount: 1_000,
+        };
+        assert_eq!(rule.fee(1_000_000), 25_000);
+        // Small prices pay the minimum royalty
+        assert_eq!(rule.fee(10_000), 1_000);
+        assert_eq!(rule.fee(u64::MAX), (u64::MAX as u128 * 250 / 10_000) as u64);
+    }
+
+    #[test]
+    fn find_royalty_rule() {
+        let rules = [
+            "434b5bd8f6a7b05fede0ff46c6e511d71ea326ed38056e3bcd681d2d7c2a7879::kiosk_lock_rule::Rule"
+                .to_owned(),
+            "434b5bd8f6a7b05fede0ff46c6e511d71ea326ed38056e3bcd681d2d7c2a7879::royalty_rule::Rule"
+                .to_owned(),
+        ];
+        let rule = royalty_rule_type(&rules).unwrap();
+        assert_eq!(rule.to_string(), format!("0x{}", rules[1]));
+        assert!(royalty_rule_type(&rules[..1]).is_none());
+    }
+
+    #[test]
+    fn display_fields_skip_errors() {
+        let entries = vec![
+            DisplayEntry {
+                key: "name".to_owned(),
+                value: Some("Capy #1".to_owned()),
+                error: None,
+            },
+            DisplayEntry {
+                key: "image_url".to_owned(),
+                value: None,
+                error: Some("missing field `url`".to_owned()),
+            },
+        ];
+        let display = display_fields(entries);
+        assert_eq!(display.len(), 1);
+        assert_eq!(display["name"], "Capy #1");
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use crate::query_types::schema;
+use crate::query_types::BigInt;
 
 // ===========================================================================
 // Chain Identifier Query
@@ -11,3 +12,35 @@ use crate::query_types::schema;
 pub struct ChainIdentifierQuery {
     pub chain_identifier: String,
 }
+
+// ===========================================================================
+// Network Info Query
+// ===========================================================================
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Query")]
+pub struct NetworkInfoQuery {
+    pub chain_identifier: String,
+    pub epoch: Option<NetworkInfoEpoch>,
+    pub checkpoint: Option<NetworkInfoCheckpoint>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Epoch")]
+pub struct NetworkInfoEpoch {
+    pub epoch_id: u64,
+    pub reference_gas_price: Option<BigInt>,
+    pub protocol_configs: NetworkInfoProtocolConfigs,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "ProtocolConfigs")]
+pub struct NetworkInfoProtocolConfigs {
+    pub protocol_version: u64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Checkpoint")]
+pub struct NetworkInfoCheckpoint {
+    pub sequence_number: u64,
+}
@@ -5,8 +5,8 @@ use sui_types::ObjectReference;
 
 use crate::query_types::schema;
 use crate::query_types::Address;
-
-use super::transaction::TxBlockEffects;
+use crate::query_types::Base64;
+use crate::query_types::PageInfo;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "Query", variables = "DryRunArgs")]
@@ -16,10 +16,73 @@ pub struct DryRunQuery {
 }
 
 #[derive(cynic::QueryFragment, Debug)]
-#[cynic(schema = "rpc", graphql_type = "DryRunResult")]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "DryRunResult",
+    variables = "DryRunArgs"
+)]
 pub struct DryRunResult {
     pub error: Option<String>,
-    pub transaction: Option<TxBlockEffects>,
+    pub results: Option<Vec<DryRunEffect>>,
+    pub transaction: Option<DryRunTransactionBlock>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "DryRunEffect")]
+pub struct DryRunEffect {
+    pub return_values: Option<Vec<DryRunReturn>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "DryRunReturn")]
+pub struct DryRunReturn {
+    pub bcs: Base64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "TransactionBlock",
+    variables = "DryRunArgs"
+)]
+pub struct DryRunTransactionBlock {
+    pub effects: Option<DryRunEffects>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "TransactionBlockEffects",
+    variables = "DryRunArgs"
+)]
+pub struct DryRunEffects {
+    pub bcs: Option<Base64>,
+    #[arguments(after: $after)]
+    pub object_changes: ObjectChangeConnection,
+}
+
+/// A page of the objects changed by a dry run.
+///
+/// The results of dry runs aren't persisted, so following pages are fetched by dry running the
+/// transaction again with the cursor of the previous page.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "ObjectChangeConnection")]
+pub struct ObjectChangeConnection {
+    pub page_info: PageInfo,
+    pub nodes: Vec<ObjectChange>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "ObjectChange")]
+pub struct ObjectChange {
+    pub input_state: Option<ObjectBcs>,
+    pub output_state: Option<ObjectBcs>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Object")]
+pub struct ObjectBcs {
+    pub bcs: Option<Base64>,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
@@ -27,9 +90,11 @@ pub struct DryRunArgs {
     pub tx_bytes: String,
     pub skip_checks: bool,
     pub tx_meta: Option<TransactionMetadata>,
+    /// The cursor of the page of object changes to return.
+    pub after: Option<String>,
 }
 
-#[derive(cynic::InputObject, Debug)]
+#[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema = "rpc", graphql_type = "TransactionMetadata")]
 pub struct TransactionMetadata {
     pub gas_budget: Option<u64>,
@@ -39,7 +104,7 @@ pub struct TransactionMetadata {
     pub sender: Option<Address>,
 }
 
-#[derive(cynic::InputObject, Debug)]
+#[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema = "rpc", graphql_type = "ObjectRef")]
 pub struct ObjectRef {
     pub address: Address,
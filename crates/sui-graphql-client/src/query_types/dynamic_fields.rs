@@ -57,11 +57,6 @@ pub struct DynamicFieldArgs {
     pub name: DynamicFieldName,
 }
 
-#[derive(cynic::QueryVariables, Debug)]
-pub struct DynamicFieldsQueryArgs {
-    pub address: Address,
-}
-
 #[derive(cynic::QueryVariables, Debug)]
 pub struct DynamicFieldConnectionArgs<'a> {
     pub address: Address,
@@ -1,6 +1,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::error;
+use crate::error::Error;
+use crate::error::Kind;
 use crate::query_types::schema;
 use crate::query_types::transaction::TransactionBlockDigest;
 use crate::query_types::Address;
@@ -51,9 +54,80 @@ pub struct EventFilter {
     pub transaction_digest: Option<String>,
 }
 
+/// Convert an [`sui_types::EventFilter`] into the filter of an events query.
+///
+/// GraphQL can't filter events by the time of their transaction, so
+/// [`sui_types::EventFilter::TimeRange`] filters are rejected.
+impl TryFrom<sui_types::EventFilter> for EventFilter {
+    type Error = error::Error;
+
+    fn try_from(value: sui_types::EventFilter) -> Result<Self, Self::Error> {
+        let mut filter = EventFilter {
+            emitting_module: None,
+            event_type: None,
+            sender: None,
+            transaction_digest: None,
+        };
+        match value {
+            sui_types::EventFilter::Sender(sender) => filter.sender = Some(sender),
+            sui_types::EventFilter::Package(package) => {
+                filter.emitting_module = Some(package.to_string())
+            }
+            sui_types::EventFilter::MoveModule { package, module } => {
+                filter.emitting_module = Some(format!("{package}::{module}"))
+            }
+            sui_types::EventFilter::MoveEventType(event_type) => {
+                filter.event_type = Some(event_type.to_string())
+            }
+            sui_types::EventFilter::TimeRange { .. } => {
+                return Err(Error::from_error(
+                    Kind::Other,
+                    "Cannot filter events by time range with GraphQL",
+                ))
+            }
+        }
+        Ok(filter)
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "Event")]
 pub struct Event {
     pub bcs: Base64,
     pub transaction_block: Option<TransactionBlockDigest>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn from_event_filter() {
+        let package = sui_types::ObjectId::from_str("0x2").unwrap();
+
+        let filter = EventFilter::try_from(sui_types::EventFilter::MoveModule {
+            package,
+            module: "coin".parse().unwrap(),
+        })
+        .unwrap();
+        assert_eq!(filter.emitting_module, Some(format!("{package}::coin")));
+        assert_eq!(filter.event_type, None);
+
+        let event_type = sui_types::StructTag::from_str("0x2::coin::CoinEvent").unwrap();
+        let filter =
+            EventFilter::try_from(sui_types::EventFilter::MoveEventType(event_type.clone()))
+                .unwrap();
+        assert_eq!(filter.event_type, Some(event_type.to_string()));
+
+        let filter = EventFilter::try_from(sui_types::EventFilter::Sender(Address::TWO)).unwrap();
+        assert_eq!(filter.sender, Some(Address::TWO));
+
+        EventFilter::try_from(sui_types::EventFilter::TimeRange {
+            start_time: 0,
+            end_time: 1,
+        })
+        .unwrap_err();
+    }
+}
@@ -30,6 +30,7 @@ pub use balance::BalanceArgs;
 pub use balance::BalanceQuery;
 pub use balance::Owner;
 pub use chain::ChainIdentifierQuery;
+pub use chain::NetworkInfoQuery;
 pub use checkpoint::CheckpointArgs;
 pub use checkpoint::CheckpointId;
 pub use checkpoint::CheckpointQuery;
@@ -42,6 +43,7 @@ pub use coin::CoinMetadataQuery;
 pub use dry_run::DryRunArgs;
 pub use dry_run::DryRunQuery;
 pub use dry_run::DryRunResult;
+pub use dry_run::ObjectBcs;
 pub use dry_run::TransactionMetadata;
 pub use dynamic_fields::DynamicFieldArgs;
 pub use dynamic_fields::DynamicFieldConnectionArgs;
@@ -73,8 +75,12 @@ pub use normalized_move::NormalizedMoveFunctionQueryArgs;
 pub use normalized_move::NormalizedMoveModuleQuery;
 pub use normalized_move::NormalizedMoveModuleQueryArgs;
 pub use normalized_move::OpenMoveType;
+pub use object::DisplayEntry;
+pub use object::ObjectDisplay;
+pub use object::ObjectDisplayQuery;
 pub use object::ObjectFilter;
 pub use object::ObjectKey;
+pub use object::ObjectNode;
 pub use object::ObjectQuery;
 pub use object::ObjectQueryArgs;
 pub use object::ObjectsQuery;
@@ -106,6 +112,7 @@ pub use transaction::TransactionBlock;
 pub use transaction::TransactionBlockArgs;
 pub use transaction::TransactionBlockEffectsQuery;
 pub use transaction::TransactionBlockQuery;
+pub use transaction::TransactionBlockWithCheckpointQuery;
 pub use transaction::TransactionBlockWithEffects;
 pub use transaction::TransactionBlockWithEffectsQuery;
 pub use transaction::TransactionBlocksEffectsQuery;
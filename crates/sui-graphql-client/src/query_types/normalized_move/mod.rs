@@ -21,6 +21,17 @@ pub enum MoveAbility {
     Store,
 }
 
+impl From<MoveAbility> for sui_types::Ability {
+    fn from(ability: MoveAbility) -> Self {
+        match ability {
+            MoveAbility::Copy => Self::Copy,
+            MoveAbility::Drop => Self::Drop,
+            MoveAbility::Key => Self::Key,
+            MoveAbility::Store => Self::Store,
+        }
+    }
+}
+
 #[derive(cynic::Enum, Clone, Copy, Debug)]
 #[cynic(schema = "rpc", graphql_type = "MoveVisibility")]
 pub enum MoveVisibility {
@@ -6,6 +6,7 @@ use crate::query_types::Address;
 use crate::query_types::MoveAbility;
 use crate::query_types::MoveFunction;
 use crate::query_types::PageInfo;
+use sui_types::DatatypeAbilities;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
@@ -86,6 +87,12 @@ pub struct MoveStruct {
     pub type_parameters: Option<Vec<MoveStructTypeParameter>>,
 }
 
+impl From<&MoveStruct> for DatatypeAbilities {
+    fn from(value: &MoveStruct) -> Self {
+        declared_abilities(&value.abilities, &value.type_parameters)
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "MoveModuleConnection")]
 pub struct MoveModuleConnection {
@@ -122,6 +129,26 @@ pub struct MoveEnum {
     pub variants: Option<Vec<MoveEnumVariant>>,
 }
 
+impl From<&MoveEnum> for DatatypeAbilities {
+    fn from(value: &MoveEnum) -> Self {
+        declared_abilities(&value.abilities, &value.type_parameters)
+    }
+}
+
+fn declared_abilities(
+    abilities: &Option<Vec<MoveAbility>>,
+    type_parameters: &Option<Vec<MoveStructTypeParameter>>,
+) -> DatatypeAbilities {
+    DatatypeAbilities {
+        abilities: abilities.iter().flatten().map(|a| (*a).into()).collect(),
+        phantom_type_params: type_parameters
+            .iter()
+            .flatten()
+            .map(|param| param.is_phantom)
+            .collect(),
+    }
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "MoveEnumVariant")]
 pub struct MoveEnumVariant {
@@ -18,6 +18,13 @@ pub struct ObjectQuery {
     pub object: Option<Object>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Query", variables = "ObjectQueryArgs")]
+pub struct ObjectDisplayQuery {
+    #[arguments(address: $address, version: $version)]
+    pub object: Option<ObjectDisplay>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "Query", variables = "ObjectsQueryArgs")]
 pub struct ObjectsQuery {
@@ -55,6 +62,30 @@ pub struct Object {
     pub bcs: Option<Base64>,
 }
 
+/// An object along with its [`Display`](https://docs.sui.io/standards/display) rendered by the
+/// server.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Object")]
+pub struct ObjectDisplay {
+    pub bcs: Option<Base64>,
+    pub display: Option<Vec<DisplayEntry>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "DisplayEntry")]
+pub struct DisplayEntry {
+    pub key: String,
+    pub value: Option<String>,
+    pub error: Option<String>,
+}
+
+/// The part of an [`Object`] node needed to decode it, which [`ObjectsQuery`] responses can be
+/// parsed into one node at a time.
+#[derive(serde::Deserialize, Debug)]
+pub struct ObjectNode {
+    pub bcs: Option<Base64>,
+}
+
 #[derive(Clone, Default, cynic::InputObject, Debug)]
 #[cynic(schema = "rpc", graphql_type = "ObjectFilter")]
 pub struct ObjectFilter<'a> {
@@ -52,6 +52,17 @@ pub struct TransactionBlockEffectsQuery {
     pub transaction_block: Option<TxBlockEffects>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "Query",
+    variables = "TransactionBlockArgs"
+)]
+pub struct TransactionBlockWithCheckpointQuery {
+    #[arguments(digest: $digest)]
+    pub transaction_block: Option<TransactionBlockWithCheckpoint>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema = "rpc",
@@ -122,6 +133,27 @@ pub struct TransactionBlockWithEffects {
     pub signatures: Option<Vec<Base64>>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "TransactionBlock")]
+pub struct TransactionBlockWithCheckpoint {
+    pub bcs: Option<Base64>,
+    pub effects: Option<TransactionBlockEffectsWithCheckpoint>,
+    pub signatures: Option<Vec<Base64>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "TransactionBlockEffects")]
+pub struct TransactionBlockEffectsWithCheckpoint {
+    pub bcs: Option<Base64>,
+    pub checkpoint: Option<CheckpointSequenceNumber>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Checkpoint")]
+pub struct CheckpointSequenceNumber {
+    pub sequence_number: u64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "TransactionBlock")]
 pub struct TransactionBlockDigest {
@@ -151,7 +183,7 @@ pub enum TransactionBlockKindInput {
     ProgrammableTx,
 }
 
-#[derive(Clone, cynic::InputObject, Debug)]
+#[derive(Clone, Default, cynic::InputObject, Debug)]
 #[cynic(schema = "rpc", graphql_type = "TransactionBlockFilter")]
 pub struct TransactionsFilter<'a> {
     pub function: Option<String>,
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::cursor::Cursor;
 use crate::error;
 use crate::query_types::PageInfo;
 use crate::Direction;
@@ -9,21 +10,23 @@ use crate::PaginationFilter;
 
 use futures::Stream;
 use std::future::Future;
+use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 
 /// A stream that yields items from a paginated query with support for bidirectional pagination.
-pub struct PageStream<T, F, Fut> {
+pub struct PageStream<T, C, F, Fut> {
     query_fn: F,
     direction: Direction,
     current_page: Option<(PageInfo, std::vec::IntoIter<T>)>,
     current_future: Option<Pin<Box<Fut>>>,
     finished: bool,
     is_first_page: bool,
+    _cursor: PhantomData<fn() -> C>,
 }
 
-impl<T, F, Fut> PageStream<T, F, Fut> {
+impl<T, C, F, Fut> PageStream<T, C, F, Fut> {
     pub fn new(query_fn: F, direction: Direction) -> Self {
         Self {
             query_fn,
@@ -32,16 +35,18 @@ impl<T, F, Fut> PageStream<T, F, Fut> {
             current_future: None,
             finished: false,
             is_first_page: true,
+            _cursor: PhantomData,
         }
     }
 }
 
-impl<T, F, Fut> Stream for PageStream<T, F, Fut>
+impl<T, C, F, Fut> Stream for PageStream<T, C, F, Fut>
 where
     T: Clone + Unpin,
-    F: Fn(PaginationFilter) -> Fut,
+    C: Cursor,
+    F: Fn(PaginationFilter<C>) -> Fut,
     F: Unpin,
-    Fut: Future<Output = Result<Page<T>, error::Error>>,
+    Fut: Future<Output = Result<Page<T, C>, error::Error>>,
 {
     type Item = Result<T, error::Error>;
 
@@ -101,7 +106,7 @@ where
                 }
                 let filter = PaginationFilter {
                     direction: self.direction.clone(),
-                    cursor: current_cursor,
+                    cursor: current_cursor.map(C::from_raw),
                     limit: None,
                 };
                 let future = (self.query_fn)(filter);
@@ -171,10 +176,13 @@ where
 ///    }
 /// }
 /// ```
-pub fn stream_paginated_query<T, F, Fut>(query_fn: F, direction: Direction) -> PageStream<T, F, Fut>
+pub fn stream_paginated_query<T, C, F, Fut>(
+    query_fn: F,
+    direction: Direction,
+) -> PageStream<T, C, F, Fut>
 where
-    F: Fn(PaginationFilter) -> Fut,
-    Fut: Future<Output = Result<Page<T>, error::Error>>,
+    F: Fn(PaginationFilter<C>) -> Fut,
+    Fut: Future<Output = Result<Page<T, C>, error::Error>>,
 {
     PageStream::new(query_fn, direction)
 }
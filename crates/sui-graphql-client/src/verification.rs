@@ -0,0 +1,642 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use futures::Stream;
+use sui_crypto::bls12381::ValidatorCommitteeSignatureVerifier;
+use sui_types::CheckpointContents;
+use sui_types::CheckpointSequenceNumber;
+use sui_types::CheckpointSummary;
+use sui_types::EpochId;
+use sui_types::SignedCheckpointSummary;
+use sui_types::SignedTransaction;
+use sui_types::TransactionDigest;
+use sui_types::TransactionEffects;
+use sui_types::ValidatorCommittee;
+
+use crate::error::Error;
+use crate::error::Kind;
+use crate::error::Result;
+
+/// A value which has been verified by the client against data certified by the validator
+/// committee.
+///
+/// A `Verified` value can only be constructed by this crate, once all of the checks tying it back
+/// to a certified checkpoint have passed.
+#[derive(Clone, Debug)]
+pub struct Verified<T>(T);
+
+impl<T> Verified<T> {
+    pub(crate) fn new_unchecked(inner: T) -> Self {
+        Self(inner)
+    }
+
+    /// Return a reference to the verified value.
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Consume the wrapper, returning the verified value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Verified<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A transaction along with its effects and the checkpoint it was included in.
+#[derive(Clone, Debug)]
+pub struct TransactionWithEffects {
+    pub transaction: SignedTransaction,
+    pub effects: TransactionEffects,
+    pub checkpoint: CheckpointSummary,
+}
+
+/// A source of certified checkpoints.
+///
+/// The GraphQL service only exposes the aggregated signature of a checkpoint, and not the bitmap
+/// of the validators which contributed to it, so it can't be used to check that a checkpoint was
+/// certified by a quorum of the committee. Certificates need to come from a source which records
+/// them in full instead, e.g. the checkpoint archive of a network, whose
+/// [`CheckpointData`](sui_types::CheckpointData) include the [`SignedCheckpointSummary`] of each
+/// checkpoint.
+pub trait CheckpointCertificates {
+    /// The certificate of the checkpoint with the provided sequence number, or `None` if it isn't
+    /// available yet.
+    fn checkpoint_certificate(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> impl std::future::Future<Output = Result<Option<SignedCheckpointSummary>>> + Send;
+}
+
+impl CheckpointCertificates for BTreeMap<CheckpointSequenceNumber, SignedCheckpointSummary> {
+    fn checkpoint_certificate(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> impl std::future::Future<Output = Result<Option<SignedCheckpointSummary>>> + Send {
+        std::future::ready(Ok(self.get(&sequence_number).cloned()))
+    }
+}
+
+/// A source of trust for checkpoint certificates.
+///
+/// A store holds the validator committee of each epoch it knows about. Certificates are checked by
+/// this crate against the committee of their epoch, so a store only needs to be trusted to hold
+/// the right committees. [`AutoVerifiedStream`] inserts the committee of the next epoch once it
+/// has verified the end-of-epoch checkpoint designating it.
+pub trait CommitteeStore {
+    /// The committee of `epoch`, if known.
+    fn committee(&self, epoch: EpochId) -> Option<&ValidatorCommitteeSignatureVerifier>;
+
+    /// Record `committee`, which has been verified to be the committee of its epoch.
+    fn insert_committee(&mut self, committee: ValidatorCommitteeSignatureVerifier);
+}
+
+/// A verifier is a store of the single committee it verifies the certificates of.
+///
+/// Inserting a committee replaces it, so such a store only knows about the latest epoch.
+impl CommitteeStore for ValidatorCommitteeSignatureVerifier {
+    fn committee(&self, epoch: EpochId) -> Option<&ValidatorCommitteeSignatureVerifier> {
+        (self.committee().epoch == epoch).then_some(self)
+    }
+
+    fn insert_committee(&mut self, committee: ValidatorCommitteeSignatureVerifier) {
+        *self = committee;
+    }
+}
+
+/// A [`CommitteeStore`] holding the committees of every epoch from a trusted one onwards.
+#[derive(Debug)]
+pub struct Committees {
+    committees: BTreeMap<EpochId, ValidatorCommitteeSignatureVerifier>,
+}
+
+impl Committees {
+    /// Create a store trusting `committee`, e.g. the committee of genesis.
+    pub fn new(committee: ValidatorCommittee) -> Result<Self> {
+        let verifier = ValidatorCommitteeSignatureVerifier::new(committee)
+            .map_err(|e| Error::from_error(Kind::Other, format!("invalid committee: {e}")))?;
+        Ok(Self {
+            committees: BTreeMap::from([(verifier.committee().epoch, verifier)]),
+        })
+    }
+
+    /// Create a store trusting `committee`, and the committees of the following epochs designated
+    /// by `checkpoints`.
+    ///
+    /// `checkpoints` must hold the certified end-of-epoch checkpoint of each epoch from the one of
+    /// `committee` onwards, in order, as for
+    /// [`verify_committee_chain`](sui_crypto::bls12381::verify_committee_chain).
+    pub fn from_chain<'a, I>(committee: ValidatorCommittee, checkpoints: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a SignedCheckpointSummary>,
+    {
+        let mut committees = Self::new(committee)?;
+        for checkpoint in checkpoints {
+            verify_committee_transition(&mut committees, checkpoint)?;
+        }
+        Ok(committees)
+    }
+
+    /// The committee of the latest epoch known to the store.
+    pub fn latest(&self) -> &ValidatorCommitteeSignatureVerifier {
+        self.committees
+            .last_key_value()
+            .map(|(_, committee)| committee)
+            .expect("a store always holds its trusted committee")
+    }
+}
+
+impl CommitteeStore for Committees {
+    fn committee(&self, epoch: EpochId) -> Option<&ValidatorCommitteeSignatureVerifier> {
+        self.committees.get(&epoch)
+    }
+
+    fn insert_committee(&mut self, committee: ValidatorCommitteeSignatureVerifier) {
+        self.committees
+            .insert(committee.committee().epoch, committee);
+    }
+}
+
+/// Verify that `certificate` was produced by a quorum of the committee of its epoch, as known to
+/// `committee_store`.
+pub(crate) fn verify_certificate<C: CommitteeStore + ?Sized>(
+    committee_store: &C,
+    certificate: &SignedCheckpointSummary,
+) -> Result<()> {
+    let epoch = certificate.checkpoint.epoch;
+    let committee = committee_store.committee(epoch).ok_or_else(|| {
+        Error::from_error(
+            Kind::Other,
+            format!("the committee of epoch {epoch} is unknown"),
+        )
+    })?;
+    committee
+        .verify_checkpoint_summary(&certificate.checkpoint, &certificate.signature)
+        .map_err(|e| {
+            Error::from_error(
+                Kind::Other,
+                format!(
+                    "invalid certificate for checkpoint {}: {e}",
+                    certificate.checkpoint.sequence_number
+                ),
+            )
+        })
+}
+
+/// Verify that `checkpoint` is the certified end-of-epoch checkpoint of an epoch whose committee
+/// is known to `committee_store`, and record the committee of the next epoch it designates.
+pub(crate) fn verify_committee_transition<C: CommitteeStore + ?Sized>(
+    committee_store: &mut C,
+    checkpoint: &SignedCheckpointSummary,
+) -> Result<()> {
+    let epoch = checkpoint.checkpoint.epoch;
+    let committee = committee_store.committee(epoch).ok_or_else(|| {
+        Error::from_error(
+            Kind::Other,
+            format!("the committee of epoch {epoch} is unknown"),
+        )
+    })?;
+    let next = committee
+        .verify_committee_transition(checkpoint)
+        .and_then(ValidatorCommitteeSignatureVerifier::new)
+        .map_err(|e| {
+            Error::from_error(
+                Kind::Other,
+                format!(
+                    "invalid end-of-epoch checkpoint {}: {e}",
+                    checkpoint.checkpoint.sequence_number
+                ),
+            )
+        })?;
+    committee_store.insert_committee(next);
+    Ok(())
+}
+
+/// A sequence of checkpoints, each verified against the committee of its epoch and checked to
+/// follow the previous one.
+///
+/// The stream starts from a checkpoint of an epoch whose committee the [`CommitteeStore`] knows.
+/// Whenever it verifies the last checkpoint of an epoch, the committee of the next epoch it
+/// designates is inserted into the store, so that the stream can carry on across epochs.
+///
+/// Use [`AutoVerifiedStream::next_checkpoint`] to fetch checkpoints one at a time, or
+/// [`AutoVerifiedStream::into_stream`] to follow the chain as new checkpoints are produced.
+#[derive(Debug)]
+pub struct AutoVerifiedStream<S> {
+    committee_store: S,
+    next: CheckpointSequenceNumber,
+    previous: Option<CheckpointSummary>,
+}
+
+impl<S: CommitteeStore> AutoVerifiedStream<S> {
+    /// Verify the checkpoints starting from `start`, which must be certified by a committee
+    /// `committee_store` knows about.
+    pub fn new(committee_store: S, start: CheckpointSequenceNumber) -> Self {
+        Self {
+            committee_store,
+            next: start,
+            previous: None,
+        }
+    }
+
+    /// The committee store used to verify the checkpoints.
+    pub fn committee_store(&self) -> &S {
+        &self.committee_store
+    }
+
+    /// Consume the stream, returning its committee store with the committees learnt so far.
+    pub fn into_committee_store(self) -> S {
+        self.committee_store
+    }
+
+    /// The sequence number of the next checkpoint to be verified.
+    pub fn next_sequence_number(&self) -> CheckpointSequenceNumber {
+        self.next
+    }
+
+    /// The last checkpoint verified, if any.
+    pub fn latest(&self) -> Option<&CheckpointSummary> {
+        self.previous.as_ref()
+    }
+
+    /// Fetch the certificate of the next checkpoint from `certificates` and verify it, returning
+    /// `None` if it isn't available yet.
+    pub async fn next_checkpoint<C: CheckpointCertificates>(
+        &mut self,
+        certificates: &C,
+    ) -> Result<Option<Verified<CheckpointSummary>>> {
+        match certificates.checkpoint_certificate(self.next).await? {
+            Some(certificate) => self.accept(certificate).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Follow the chain of checkpoints, waiting `poll_interval` before checking again whenever
+    /// the certificate of the next checkpoint isn't available yet.
+    ///
+    /// The stream ends after the first error.
+    pub fn into_stream<'a, C: CheckpointCertificates>(
+        self,
+        certificates: &'a C,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Verified<CheckpointSummary>>> + 'a
+    where
+        S: 'a,
+    {
+        futures::stream::unfold(Some(self), move |state| async move {
+            let mut stream = state?;
+            loop {
+                match stream.next_checkpoint(certificates).await {
+                    Ok(Some(checkpoint)) => return Some((Ok(checkpoint), Some(stream))),
+                    Ok(None) => tokio::time::sleep(poll_interval).await,
+                    Err(error) => return Some((Err(error), None)),
+                }
+            }
+        })
+    }
+
+    /// Verify `certificate`, the certificate of the next checkpoint of the stream.
+    pub fn accept(
+        &mut self,
+        certificate: SignedCheckpointSummary,
+    ) -> Result<Verified<CheckpointSummary>> {
+        let checkpoint = &certificate.checkpoint;
+        if checkpoint.sequence_number != self.next {
+            return Err(Error::from_error(
+                Kind::Other,
+                format!(
+                    "expected checkpoint {} but got {}",
+                    self.next, checkpoint.sequence_number
+                ),
+            ));
+        }
+
+        if let Some(previous) = &self.previous {
+            checkpoint
+                .check_follows(previous)
+                .map_err(|e| Error::from_error(Kind::Other, e))?;
+        }
+        if checkpoint.end_of_epoch_data.is_some() {
+            verify_committee_transition(&mut self.committee_store, &certificate)?;
+        } else {
+            verify_certificate(&self.committee_store, &certificate)?;
+        }
+
+        self.next += 1;
+        self.previous = Some(certificate.checkpoint.clone());
+        Ok(Verified::new_unchecked(certificate.checkpoint))
+    }
+}
+
+/// Verify that `transaction` and `effects` are the ones identified by `digest`, and that they are
+/// committed to by `contents`, which in turn are committed to by `checkpoint`.
+pub(crate) fn verify_checkpoint_inclusion(
+    digest: &TransactionDigest,
+    transaction: &SignedTransaction,
+    effects: &TransactionEffects,
+    checkpoint: &CheckpointSummary,
+    contents: &CheckpointContents,
+) -> Result<()> {
+    let verification_error = |message: String| Error::from_error(Kind::Other, message);
+
+    let transaction_digest = transaction.transaction.digest();
+    if transaction_digest != *digest {
+        return Err(verification_error(format!(
+            "transaction digest mismatch: expected {digest}, got {transaction_digest}"
+        )));
+    }
+
+    if effects.transaction_digest() != digest {
+        return Err(verification_error(format!(
+            "effects of transaction {} returned for transaction {digest}",
+            effects.transaction_digest()
+        )));
+    }
+
+    let info = checkpoint
+        .prove_inclusion(contents, digest)
+        .map_err(|e| {
+            verification_error(format!(
+                "invalid contents for checkpoint {}: {e}",
+                checkpoint.sequence_number
+            ))
+        })?
+        .ok_or_else(|| {
+            verification_error(format!(
+                "transaction {digest} is not included in checkpoint {}",
+                checkpoint.sequence_number
+            ))
+        })?;
+
+    if info.effects != effects.digest() {
+        return Err(verification_error(format!(
+            "effects of transaction {digest} don't match the ones committed to in checkpoint {}",
+            checkpoint.sequence_number
+        )));
+    }
+
+    if info.signatures != transaction.signatures {
+        return Err(verification_error(format!(
+            "signatures of transaction {digest} don't match the ones committed to in checkpoint {}",
+            checkpoint.sequence_number
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_crypto::bls12381::aggregate_validator_signatures;
+    use sui_crypto::bls12381::Bls12381PrivateKey;
+    use sui_crypto::test_util::validator_committee;
+    use sui_crypto::test_util::validator_keys;
+    use sui_types::Address;
+    use sui_types::CheckpointTransactionInfo;
+    use sui_types::EndOfEpochData;
+    use sui_types::ExecutionStatus;
+    use sui_types::GasCostSummary;
+    use sui_types::GasPayment;
+    use sui_types::ProgrammableTransaction;
+    use sui_types::Transaction;
+    use sui_types::TransactionEffectsV2;
+    use sui_types::TransactionExpiration;
+    use sui_types::TransactionKind;
+
+    fn fixture() -> (
+        SignedTransaction,
+        TransactionEffects,
+        CheckpointSummary,
+        CheckpointContents,
+    ) {
+        let transaction = SignedTransaction {
+            transaction: Transaction {
+                kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                    inputs: vec![],
+                    commands: vec![],
+                }),
+                sender: Address::TWO,
+                gas_payment: GasPayment {
+                    objects: vec![],
+                    owner: Address::TWO,
+                    price: 1000,
+                    budget: 1_000_000,
+                },
+                expiration: TransactionExpiration::None,
+            },
+            signatures: vec![],
+        };
+        let effects = TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            status: ExecutionStatus::Success,
+            epoch: 0,
+            gas_used: GasCostSummary::default(),
+            transaction_digest: transaction.transaction.digest(),
+            gas_object_index: None,
+            events_digest: None,
+            dependencies: vec![],
+            lamport_version: 1,
+            changed_objects: vec![],
+            unchanged_shared_objects: vec![],
+            auxiliary_data_digest: None,
+        }));
+        let contents = CheckpointContents::new(vec![CheckpointTransactionInfo {
+            transaction: transaction.transaction.digest(),
+            effects: effects.digest(),
+            signatures: vec![],
+        }]);
+        let checkpoint = CheckpointSummary {
+            epoch: 0,
+            sequence_number: 7,
+            network_total_transactions: 1,
+            content_digest: contents.digest(),
+            previous_digest: None,
+            epoch_rolling_gas_cost_summary: GasCostSummary::default(),
+            timestamp_ms: 0,
+            checkpoint_commitments: vec![],
+            end_of_epoch_data: None,
+            version_specific_data: vec![],
+        };
+
+        (transaction, effects, checkpoint, contents)
+    }
+
+    #[test]
+    fn checkpoint_inclusion() {
+        let (transaction, effects, checkpoint, contents) = fixture();
+        let digest = transaction.transaction.digest();
+
+        verify_checkpoint_inclusion(&digest, &transaction, &effects, &checkpoint, &contents)
+            .unwrap();
+
+        // Wrong digest requested
+        verify_checkpoint_inclusion(
+            &TransactionDigest::ZERO,
+            &transaction,
+            &effects,
+            &checkpoint,
+            &contents,
+        )
+        .unwrap_err();
+
+        // Contents which aren't committed to by the checkpoint
+        let other_contents = CheckpointContents::new(vec![]);
+        verify_checkpoint_inclusion(
+            &digest,
+            &transaction,
+            &effects,
+            &checkpoint,
+            &other_contents,
+        )
+        .unwrap_err();
+
+        // Effects which aren't committed to by the checkpoint
+        let mut other_effects = effects.clone();
+        let TransactionEffects::V2(fx) = &mut other_effects else {
+            unreachable!()
+        };
+        fx.lamport_version = 2;
+        verify_checkpoint_inclusion(
+            &digest,
+            &transaction,
+            &other_effects,
+            &checkpoint,
+            &contents,
+        )
+        .unwrap_err();
+    }
+
+    fn certify(
+        checkpoint: &CheckpointSummary,
+        committee: &ValidatorCommittee,
+        signers: &[Bls12381PrivateKey],
+    ) -> SignedCheckpointSummary {
+        let signatures = signers
+            .iter()
+            .map(|key| key.sign_checkpoint_summary(checkpoint))
+            .collect::<Vec<_>>();
+        SignedCheckpointSummary {
+            checkpoint: checkpoint.clone(),
+            signature: aggregate_validator_signatures(committee, &signatures).unwrap(),
+        }
+    }
+
+    #[test]
+    fn checkpoint_certificates() {
+        let (_, _, checkpoint, _) = fixture();
+        let keys = validator_keys(1);
+        let committee = validator_committee(0, &keys, &[1; 4]);
+        let store = ValidatorCommitteeSignatureVerifier::new(committee.clone()).unwrap();
+
+        verify_certificate(&store, &certify(&checkpoint, &committee, &keys[..3])).unwrap();
+
+        // Signers holding less than a quorum of the stake
+        verify_certificate(&store, &certify(&checkpoint, &committee, &keys[..2])).unwrap_err();
+
+        // A certificate of another checkpoint
+        let mut forged = certify(&checkpoint, &committee, &keys);
+        forged.checkpoint.timestamp_ms = 1;
+        verify_certificate(&store, &forged).unwrap_err();
+
+        // A checkpoint of an epoch the store doesn't know the committee of
+        let next_epoch = CheckpointSummary {
+            epoch: 1,
+            ..checkpoint.clone()
+        };
+        let next_committee = ValidatorCommittee {
+            epoch: 1,
+            ..committee.clone()
+        };
+        verify_certificate(&store, &certify(&next_epoch, &next_committee, &keys)).unwrap_err();
+
+        // Checkpoints must be accepted in order, each following the previous one
+        let next = CheckpointSummary {
+            sequence_number: 8,
+            previous_digest: Some(checkpoint.digest()),
+            ..checkpoint.clone()
+        };
+        let mut stream = AutoVerifiedStream::new(store, 7);
+        stream
+            .accept(certify(&next, &committee, &keys))
+            .unwrap_err();
+        stream
+            .accept(certify(&checkpoint, &committee, &keys))
+            .unwrap();
+        let forked = CheckpointSummary {
+            previous_digest: None,
+            ..next.clone()
+        };
+        stream
+            .accept(certify(&forked, &committee, &keys))
+            .unwrap_err();
+        let verified = stream.accept(certify(&next, &committee, &keys)).unwrap();
+        assert_eq!(verified.inner(), &next);
+        assert_eq!(stream.next_sequence_number(), 9);
+    }
+
+    #[test]
+    fn epoch_rollover() {
+        let (_, _, checkpoint, _) = fixture();
+        let keys = validator_keys(1);
+        let next_keys = validator_keys(5);
+        let next_committee = validator_committee(1, &next_keys, &[1; 4]);
+        let committee = validator_committee(0, &keys, &[1; 4]);
+
+        let last = CheckpointSummary {
+            end_of_epoch_data: Some(EndOfEpochData {
+                next_epoch_committee: next_committee.members.clone(),
+                next_epoch_protocol_version: 1,
+                epoch_commitments: vec![],
+            }),
+            ..checkpoint.clone()
+        };
+        let first = CheckpointSummary {
+            epoch: 1,
+            sequence_number: 8,
+            previous_digest: Some(last.digest()),
+            ..checkpoint.clone()
+        };
+
+        // The committee of the next epoch is only trusted once the end-of-epoch checkpoint is
+        let mut stream = AutoVerifiedStream::new(Committees::new(committee.clone()).unwrap(), 7);
+        stream
+            .accept(certify(&last, &committee, &keys[..2]))
+            .unwrap_err();
+        assert!(stream.committee_store().committee(1).is_none());
+        stream.accept(certify(&last, &committee, &keys)).unwrap();
+        assert_eq!(
+            stream.committee_store().latest().committee(),
+            &next_committee
+        );
+
+        // Checkpoints of the next epoch must be certified by its committee
+        let forged_committee = ValidatorCommittee {
+            epoch: 1,
+            ..committee.clone()
+        };
+        stream
+            .accept(certify(&first, &forged_committee, &keys))
+            .unwrap_err();
+        stream
+            .accept(certify(&first, &next_committee, &next_keys))
+            .unwrap();
+
+        // The same committees can be learnt from the chain of end-of-epoch checkpoints
+        let committees =
+            Committees::from_chain(committee.clone(), [&certify(&last, &committee, &keys)])
+                .unwrap();
+        assert_eq!(committees.latest().committee(), &next_committee);
+        assert!(committees.committee(0).is_some());
+        Committees::from_chain(committee.clone(), [&certify(&last, &committee, &keys[..2])])
+            .unwrap_err();
+    }
+}
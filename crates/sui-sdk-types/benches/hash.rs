@@ -0,0 +1,44 @@
+use base64ct::Base64;
+use base64ct::Encoding;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+use sui_sdk_types::hash::HashBackend;
+use sui_sdk_types::hash::Hasher;
+use sui_sdk_types::hash::PortableBackend;
+use sui_sdk_types::Transaction;
+
+const SIZES: [usize; 4] = [64, 1024, 64 * 1024, 1024 * 1024];
+
+fn bench_backend<B: HashBackend>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(format!("blake2b256/{name}"));
+
+    for size in SIZES {
+        let data = vec![0xab; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter(|| Hasher::<B>::digest_with_backend(data))
+        });
+    }
+
+    group.finish();
+}
+
+fn backends(c: &mut Criterion) {
+    bench_backend::<PortableBackend>(c, "portable");
+    #[cfg(feature = "hash-simd")]
+    bench_backend::<sui_sdk_types::hash::SimdBackend>(c, "simd");
+}
+
+fn transaction_digest(c: &mut Criterion) {
+    const FIXTURE: &str = include_str!("../src/transaction/fixtures/wormhole-pyth-transaction");
+    let bytes = Base64::decode_vec(FIXTURE.trim()).unwrap();
+    let transaction: Transaction = bcs::from_bytes(&bytes).unwrap();
+
+    c.bench_function("transaction_digest", |b| b.iter(|| transaction.digest()));
+}
+
+criterion_group!(benches, backends, transaction_digest);
+criterion_main!(benches);
@@ -0,0 +1,361 @@
+//! Statistics on the activity of addresses, computed from the transactions of a stream of
+//! checkpoints.
+//!
+//! An [`ActivityTracker`] records, for every address sending or receiving objects, when it was
+//! first and last seen, which addresses it interacted with and which packages it used. It is
+//! meant as a foundation for tools scoring the risk of interacting with an address, which can
+//! combine these statistics as they see fit.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::Address;
+use crate::CheckpointData;
+use crate::CheckpointSequenceNumber;
+use crate::CheckpointTimestamp;
+use crate::CheckpointTransaction;
+use crate::Command;
+use crate::ObjectChange;
+use crate::ObjectChangeKind;
+use crate::ObjectId;
+use crate::Owner;
+use crate::TransactionDigest;
+use crate::TransactionKind;
+
+/// Where an address was seen: a transaction and the checkpoint including it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct ActivityPoint {
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub checkpoint: CheckpointSequenceNumber,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub timestamp_ms: CheckpointTimestamp,
+
+    pub transaction: TransactionDigest,
+}
+
+/// The activity of a single address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct AddressActivity {
+    /// The first transaction the address was seen in.
+    pub first_seen: ActivityPoint,
+
+    /// The last transaction the address was seen in.
+    pub last_seen: ActivityPoint,
+
+    /// The number of transactions sent by the address.
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub sent: u64,
+
+    /// The number of transactions, sent by other addresses, which created objects owned by the
+    /// address or transferred objects to it.
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub received: u64,
+
+    /// The addresses the address received objects from, sent objects to, or had its gas paid by.
+    pub counterparties: BTreeSet<Address>,
+
+    /// The packages called by the transactions sent by the address, or which emitted events
+    /// during them.
+    pub packages: BTreeSet<ObjectId>,
+}
+
+impl AddressActivity {
+    fn new(seen: ActivityPoint) -> Self {
+        Self {
+            first_seen: seen,
+            last_seen: seen,
+            sent: 0,
+            received: 0,
+            counterparties: BTreeSet::new(),
+            packages: BTreeSet::new(),
+        }
+    }
+
+    fn see(&mut self, seen: ActivityPoint) {
+        if seen.checkpoint < self.first_seen.checkpoint {
+            self.first_seen = seen;
+        }
+        if seen.checkpoint >= self.last_seen.checkpoint {
+            self.last_seen = seen;
+        }
+    }
+}
+
+/// The activity of the addresses seen in a stream of checkpoints.
+///
+/// Only user transactions are tracked, system transactions being sent by the zero address.
+/// Checkpoints can be observed out of order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ActivityTracker {
+    addresses: BTreeMap<Address, AddressActivity>,
+}
+
+impl ActivityTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the activity of every transaction of a checkpoint.
+    pub fn observe_checkpoint(&mut self, checkpoint: &CheckpointData) {
+        let summary = &checkpoint.checkpoint_summary.checkpoint;
+        for transaction in &checkpoint.transactions {
+            self.observe(summary.sequence_number, summary.timestamp_ms, transaction);
+        }
+    }
+
+    /// Record the activity of a transaction included in the checkpoint `checkpoint`, created at
+    /// `timestamp_ms`.
+    pub fn observe(
+        &mut self,
+        checkpoint: CheckpointSequenceNumber,
+        timestamp_ms: CheckpointTimestamp,
+        transaction: &CheckpointTransaction,
+    ) {
+        let tx = &transaction.transaction.transaction;
+        let TransactionKind::ProgrammableTransaction(ptb) = &tx.kind else {
+            return;
+        };
+        let seen = ActivityPoint {
+            checkpoint,
+            timestamp_ms,
+            transaction: *transaction.effects.transaction_digest(),
+        };
+        let sender = tx.sender;
+
+        // Objects which stay with the same owner, like the gas object, aren't received by it
+        let input_owner = |change: &ObjectChange| {
+            change.input_owner.or_else(|| {
+                transaction
+                    .input_objects
+                    .iter()
+                    .find(|object| object.object_id() == change.object_id)
+                    .map(|object| *object.owner())
+            })
+        };
+        let mut recipients: BTreeSet<_> = transaction
+            .effects
+            .object_changes()
+            .into_iter()
+            .filter_map(|change| {
+                let Owner::Address(owner) = change.output.as_ref()?.owner else {
+                    return None;
+                };
+                let received = match change.kind {
+                    ObjectChangeKind::Created | ObjectChangeKind::Unwrapped => true,
+                    ObjectChangeKind::Mutated => input_owner(&change)
+                        .is_some_and(|input_owner| input_owner != Owner::Address(owner)),
+                    ObjectChangeKind::Deleted | ObjectChangeKind::Wrapped => false,
+                };
+                received.then_some(owner)
+            })
+            .collect();
+        recipients.remove(&sender);
+        let mut counterparties = recipients.clone();
+        counterparties.insert(tx.gas_payment.owner);
+        counterparties.remove(&sender);
+
+        let packages = ptb
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                Command::MoveCall(call) => Some(call.package),
+                _ => None,
+            })
+            .chain(
+                transaction
+                    .events
+                    .iter()
+                    .flat_map(|events| &events.0)
+                    .map(|event| event.package_id),
+            );
+
+        let activity = self.entry(sender, seen);
+        activity.sent += 1;
+        activity.counterparties.extend(&counterparties);
+        activity.packages.extend(packages);
+
+        for counterparty in counterparties {
+            let activity = self.entry(counterparty, seen);
+            if recipients.contains(&counterparty) {
+                activity.received += 1;
+            }
+            activity.counterparties.insert(sender);
+        }
+    }
+
+    fn entry(&mut self, address: Address, seen: ActivityPoint) -> &mut AddressActivity {
+        let activity = self
+            .addresses
+            .entry(address)
+            .or_insert_with(|| AddressActivity::new(seen));
+        activity.see(seen);
+        activity
+    }
+
+    /// The activity of `address`, if it has been seen.
+    pub fn get(&self, address: &Address) -> Option<&AddressActivity> {
+        self.addresses.get(address)
+    }
+
+    /// The activity of every address seen, ordered by address.
+    pub fn iter(&self) -> impl Iterator<Item = (&Address, &AddressActivity)> {
+        self.addresses.iter()
+    }
+
+    /// The number of addresses seen.
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    /// Whether no address has been seen.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::created;
+    use crate::test_util::effects_v2;
+    use crate::test_util::mutated;
+    use crate::Argument;
+    use crate::Event;
+    use crate::GasPayment;
+    use crate::MoveCall;
+    use crate::ObjectDigest;
+    use crate::ObjectOut;
+    use crate::ProgrammableTransaction;
+    use crate::SignedTransaction;
+    use crate::StructTag;
+    use crate::Transaction;
+    use crate::TransactionEffects;
+    use crate::TransactionEffectsV2;
+    use crate::TransactionEvents;
+    use crate::TransactionExpiration;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn address(byte: u8) -> Address {
+        Address::new([byte; Address::LENGTH])
+    }
+
+    fn transaction(
+        sender: Address,
+        gas_owner: Address,
+        recipient: Address,
+        digest: u8,
+    ) -> CheckpointTransaction {
+        let package = ObjectId::from(address(0xee));
+        let transaction = Transaction {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs: vec![],
+                commands: vec![Command::MoveCall(MoveCall {
+                    package,
+                    module: "coin".parse().unwrap(),
+                    function: "mint".parse().unwrap(),
+                    type_arguments: vec![],
+                    arguments: vec![Argument::Gas],
+                })],
+            }),
+            sender,
+            gas_payment: GasPayment {
+                objects: vec![],
+                owner: gas_owner,
+                price: 1_000,
+                budget: 5_000_000,
+            },
+            expiration: TransactionExpiration::None,
+        };
+        let effects = TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            transaction_digest: TransactionDigest::new([digest; 32]),
+            gas_object_index: Some(1),
+            ..effects_v2(
+                2,
+                vec![
+                    created(ObjectId::new([digest; 32]), Owner::Address(recipient)),
+                    // The gas object, charged and left with its owner
+                    mutated(ObjectId::new([0xff; 32]), 1, Owner::Address(gas_owner)),
+                ],
+            )
+        }));
+        let event = Event {
+            package_id: ObjectId::from(Address::TWO),
+            module: "coin".parse().unwrap(),
+            sender,
+            type_: StructTag::gas_coin(),
+            contents: vec![],
+        };
+        CheckpointTransaction {
+            transaction: SignedTransaction {
+                transaction,
+                signatures: vec![],
+            },
+            effects,
+            events: Some(TransactionEvents(vec![event])),
+            input_objects: vec![],
+            output_objects: vec![],
+        }
+    }
+
+    #[test]
+    fn activity_tracker() {
+        let alice = address(0xa);
+        let bob = address(0xb);
+        let sponsor = address(0xc);
+
+        let mut tracker = ActivityTracker::new();
+        tracker.observe(10, 1_000, &transaction(alice, alice, bob, 1));
+        // Checkpoints can be observed out of order
+        tracker.observe(5, 500, &transaction(alice, sponsor, alice, 2));
+        tracker.observe(20, 2_000, &transaction(bob, bob, alice, 3));
+        assert_eq!(tracker.len(), 3);
+
+        let activity = tracker.get(&alice).unwrap();
+        assert_eq!(activity.first_seen.checkpoint, 5);
+        assert_eq!(
+            activity.first_seen.transaction,
+            TransactionDigest::new([2; 32])
+        );
+        assert_eq!(activity.last_seen.timestamp_ms, 2_000);
+        assert_eq!((activity.sent, activity.received), (2, 1));
+        assert_eq!(activity.counterparties, BTreeSet::from([bob, sponsor]));
+        assert_eq!(
+            activity.packages,
+            BTreeSet::from([ObjectId::from(address(0xee)), ObjectId::from(Address::TWO)])
+        );
+
+        // Paying for gas doesn't count as receiving objects
+        let activity = tracker.get(&sponsor).unwrap();
+        assert_eq!((activity.sent, activity.received), (0, 0));
+        assert_eq!(activity.counterparties, BTreeSet::from([alice]));
+        assert!(activity.packages.is_empty());
+
+        let activity = tracker.get(&bob).unwrap();
+        assert_eq!((activity.sent, activity.received), (1, 1));
+        assert_eq!(activity.first_seen.checkpoint, 10);
+        // Objects changing owner are received by their new owner
+        let dave = address(0xd);
+        let mut transfer = transaction(bob, bob, bob, 4);
+        let TransactionEffects::V2(effects) = &mut transfer.effects else {
+            unreachable!()
+        };
+        effects.changed_objects[1].output_state = ObjectOut::ObjectWrite {
+            digest: ObjectDigest::ZERO,
+            owner: Owner::Address(dave),
+        };
+        tracker.observe(30, 3_000, &transfer);
+        let activity = tracker.get(&dave).unwrap();
+        assert_eq!((activity.sent, activity.received), (0, 1));
+    }
+}
@@ -63,6 +63,7 @@ pub struct Address(
 impl Address {
     pub const LENGTH: usize = 32;
     pub const ZERO: Self = Self([0u8; Self::LENGTH]);
+    pub const ONE: Self = Self::from_u8(1);
     pub const TWO: Self = Self::from_u8(2);
     pub const THREE: Self = Self::from_u8(3);
 
@@ -125,6 +126,42 @@ impl Address {
         .map_err(|_| AddressParseError)
     }
 
+    /// Parse an address like [`Address::from_hex`], also returning the form it was written in.
+    ///
+    /// ```
+    /// use sui_sdk_types::Address;
+    ///
+    /// let (address, form) = Address::from_hex_with_form("0x2").unwrap();
+    /// assert_eq!(address, Address::TWO);
+    /// assert!(form.short && !form.is_canonical());
+    /// ```
+    pub fn from_hex_with_form<T: AsRef<[u8]>>(
+        hex: T,
+    ) -> Result<(Self, AddressForm), AddressParseError> {
+        let address = Self::from_hex(&hex)?;
+        let digits = &hex.as_ref()[2..];
+        let form = AddressForm {
+            short: digits.len() < Self::LENGTH * 2,
+            uppercase: digits.iter().any(u8::is_ascii_uppercase),
+        };
+        Ok((address, form))
+    }
+
+    /// Parse an address, rejecting any input which isn't in its canonical form: `0x` followed by
+    /// 64 lowercase hex digits, as produced by the `Display` implementation of `Address`.
+    pub fn from_hex_strict<T: AsRef<[u8]>>(hex: T) -> Result<Self, AddressParseError> {
+        match Self::from_hex_with_form(hex)? {
+            (address, form) if form.is_canonical() => Ok(address),
+            _ => Err(AddressParseError),
+        }
+    }
+
+    /// Rewrite an address in its canonical form, left-padding short addresses with zeros and
+    /// lowercasing hex digits, e.g. `0x2` becomes `0x00..02`.
+    pub fn normalize<T: AsRef<[u8]>>(hex: T) -> Result<String, AddressParseError> {
+        Self::from_hex(hex).map(|address| address.to_string())
+    }
+
     pub fn to_hex(&self) -> String {
         self.to_string()
     }
@@ -227,6 +264,23 @@ impl<'de> serde_with::DeserializeAs<'de, [u8; Address::LENGTH]> for ReadableAddr
     }
 }
 
+/// The form an address was written in, as returned by [`Address::from_hex_with_form`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AddressForm {
+    /// The address had fewer than 64 hex digits, e.g. `0x2`, and was left-padded with zeros.
+    pub short: bool,
+    /// Some of the hex digits of the address were uppercase.
+    pub uppercase: bool,
+}
+
+impl AddressForm {
+    /// Whether the address was written in its canonical form, i.e. `0x` followed by 64 lowercase
+    /// hex digits.
+    pub fn is_canonical(&self) -> bool {
+        !self.short && !self.uppercase
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AddressParseError;
 
@@ -240,6 +294,12 @@ impl std::fmt::Display for AddressParseError {
     }
 }
 
+impl crate::error::ErrorCode for AddressParseError {
+    fn code(&self) -> &'static str {
+        "invalid_address"
+    }
+}
+
 impl std::error::Error for AddressParseError {}
 
 #[cfg(test)]
@@ -258,6 +318,45 @@ mod test {
         assert_eq!(actual.to_string(), expected);
     }
 
+    #[test]
+    fn strict_parsing() {
+        let canonical = "0x0000000000000000000000000000000000000000000000000000000000000002";
+        let uppercase = "0x00000000000000000000000000000000000000000000000000000000000000AB";
+
+        let (address, form) = Address::from_hex_with_form(canonical).unwrap();
+        assert_eq!(address, Address::TWO);
+        assert!(form.is_canonical());
+        assert_eq!(Address::from_hex_strict(canonical), Ok(Address::TWO));
+
+        let (_, form) = Address::from_hex_with_form("0x2").unwrap();
+        assert_eq!(
+            form,
+            AddressForm {
+                short: true,
+                uppercase: false
+            }
+        );
+        assert_eq!(Address::from_hex_strict("0x2"), Err(AddressParseError));
+        assert_eq!(Address::normalize("0x2").unwrap(), canonical);
+
+        let (_, form) = Address::from_hex_with_form(uppercase).unwrap();
+        assert_eq!(
+            form,
+            AddressForm {
+                short: false,
+                uppercase: true
+            }
+        );
+        assert_eq!(Address::from_hex_strict(uppercase), Err(AddressParseError));
+        assert_eq!(
+            Address::normalize("0xAB").unwrap(),
+            uppercase.to_lowercase()
+        );
+
+        Address::from_hex_strict("2").unwrap_err();
+        Address::normalize("0xzz").unwrap_err();
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn formats() {
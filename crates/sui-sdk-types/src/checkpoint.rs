@@ -2,6 +2,7 @@ use super::CheckpointContentsDigest;
 use super::CheckpointDigest;
 use super::Digest;
 use super::GasCostSummary;
+use super::LenientSignedTransaction;
 use super::Object;
 use super::SignedTransaction;
 use super::TransactionDigest;
@@ -193,6 +194,279 @@ impl CheckpointContents {
     pub fn into_v1(self) -> Vec<CheckpointTransactionInfo> {
         self.0
     }
+
+    /// Return the user signatures recorded for the transaction with digest `transaction`, if it is
+    /// included in these contents.
+    pub fn signatures(&self, transaction: &TransactionDigest) -> Option<&[UserSignature]> {
+        self.0
+            .iter()
+            .find(|info| info.transaction == *transaction)
+            .map(CheckpointTransactionInfo::signatures)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+impl CheckpointContents {
+    /// Join decoded transactions with the user signatures recorded for them in these contents.
+    ///
+    /// The signed transactions are returned in the same order as `transactions`. If one of the
+    /// transactions isn't included in these contents, its digest is returned as the error.
+    pub fn signed_transactions<I>(
+        &self,
+        transactions: I,
+    ) -> Result<Vec<SignedTransaction>, TransactionDigest>
+    where
+        I: IntoIterator<Item = crate::Transaction>,
+    {
+        let signatures: std::collections::HashMap<_, _> = self
+            .0
+            .iter()
+            .map(|info| (info.transaction, &info.signatures))
+            .collect();
+
+        transactions
+            .into_iter()
+            .map(|transaction| {
+                let digest = transaction.digest();
+                let signatures = signatures.get(&digest).ok_or(digest)?;
+                Ok(SignedTransaction {
+                    transaction,
+                    signatures: signatures.to_vec(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+impl CheckpointSummary {
+    /// Check that this checkpoint directly follows `previous` in the chain of checkpoints, i.e.
+    /// that its sequence number is the next one and that its `previous_digest` is the digest of
+    /// `previous`, computed locally.
+    ///
+    /// Together with the verification of the certificate of the latest checkpoint, this allows
+    /// trusting a whole chain of checkpoint summaries.
+    pub fn check_follows(&self, previous: &CheckpointSummary) -> Result<(), CheckpointChainError> {
+        let expected = previous.sequence_number.checked_add(1);
+        if expected != Some(self.sequence_number) {
+            return Err(CheckpointChainError::NonConsecutive {
+                previous: previous.sequence_number,
+                actual: self.sequence_number,
+            });
+        }
+
+        let expected = previous.digest();
+        if self.previous_digest != Some(expected) {
+            return Err(CheckpointChainError::PreviousDigestMismatch {
+                expected,
+                actual: self.previous_digest,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check that `contents` are the contents this checkpoint commits to.
+    pub fn check_contents(
+        &self,
+        contents: &CheckpointContents,
+    ) -> Result<(), CheckpointChainError> {
+        let actual = contents.digest();
+        if actual != self.content_digest {
+            return Err(CheckpointChainError::ContentDigestMismatch {
+                expected: self.content_digest,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Prove or refute that `transaction` was executed in this checkpoint.
+    ///
+    /// This checkpoint must already be trusted, e.g. by verifying its certificate, while
+    /// `contents` can come from an untrusted source: they are checked against this checkpoint
+    /// first. Returns the info of the transaction in `contents` if it is included, and `None` if
+    /// it isn't.
+    pub fn prove_inclusion<'a>(
+        &self,
+        contents: &'a CheckpointContents,
+        transaction: &TransactionDigest,
+    ) -> Result<Option<&'a CheckpointTransactionInfo>, CheckpointChainError> {
+        self.check_contents(contents)?;
+        Ok(contents
+            .transactions()
+            .iter()
+            .find(|info| info.transaction == *transaction))
+    }
+
+    /// Verify that `effects` were committed to by this checkpoint, and that they wrote `object`,
+    /// i.e. that `object` was created, mutated or unwrapped at this exact version by the
+    /// transaction.
+    ///
+    /// As with [`prove_inclusion`](Self::prove_inclusion), this checkpoint must already be
+    /// trusted, while `contents` and `effects` can come from an untrusted source.
+    pub fn prove_object(
+        &self,
+        contents: &CheckpointContents,
+        effects: &TransactionEffects,
+        object: &crate::ObjectReference,
+    ) -> Result<(), ObjectProofError> {
+        let transaction = *effects.transaction_digest();
+        let info = self
+            .prove_inclusion(contents, &transaction)
+            .map_err(ObjectProofError::Contents)?
+            .ok_or(ObjectProofError::TransactionNotIncluded(transaction))?;
+
+        let actual = effects.digest();
+        if info.effects != actual {
+            return Err(ObjectProofError::EffectsDigestMismatch {
+                expected: info.effects,
+                actual,
+            });
+        }
+
+        if !effects
+            .written_objects()
+            .iter()
+            .any(|written| written.reference == *object)
+        {
+            return Err(ObjectProofError::ObjectNotWritten(object.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when checkpoint data doesn't chain up with the checkpoint it is checked against.
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckpointChainError {
+    /// The checkpoint doesn't have the sequence number following the previous checkpoint.
+    NonConsecutive {
+        previous: CheckpointSequenceNumber,
+        actual: CheckpointSequenceNumber,
+    },
+
+    /// The `previous_digest` of the checkpoint isn't the digest of the previous checkpoint.
+    PreviousDigestMismatch {
+        expected: CheckpointDigest,
+        actual: Option<CheckpointDigest>,
+    },
+
+    /// The digest of the contents isn't the `content_digest` of the checkpoint.
+    ContentDigestMismatch {
+        expected: CheckpointContentsDigest,
+        actual: CheckpointContentsDigest,
+    },
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::fmt::Display for CheckpointChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonConsecutive { previous, actual } => write!(
+                f,
+                "checkpoint {actual} doesn't directly follow checkpoint {previous}"
+            ),
+            Self::PreviousDigestMismatch { expected, actual } => match actual {
+                Some(actual) => write!(
+                    f,
+                    "previous checkpoint digest is {actual}, expected {expected}"
+                ),
+                None => write!(f, "missing previous checkpoint digest, expected {expected}"),
+            },
+            Self::ContentDigestMismatch { expected, actual } => write!(
+                f,
+                "checkpoint contents digest is {actual}, expected {expected}"
+            ),
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl crate::error::ErrorCode for CheckpointChainError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NonConsecutive { .. } => "non_consecutive_checkpoint",
+            Self::PreviousDigestMismatch { .. } => "previous_checkpoint_digest_mismatch",
+            Self::ContentDigestMismatch { .. } => "checkpoint_contents_digest_mismatch",
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::error::Error for CheckpointChainError {}
+
+/// Error returned when an object can't be proven to have been written in a checkpoint.
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjectProofError {
+    /// The contents aren't the ones committed to by the checkpoint.
+    Contents(CheckpointChainError),
+
+    /// The transaction of the effects isn't included in the checkpoint.
+    TransactionNotIncluded(TransactionDigest),
+
+    /// The digest of the effects isn't the one committed to by the checkpoint.
+    EffectsDigestMismatch {
+        expected: TransactionEffectsDigest,
+        actual: TransactionEffectsDigest,
+    },
+
+    /// The object isn't among the objects written by the transaction.
+    ObjectNotWritten(crate::ObjectReference),
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::fmt::Display for ObjectProofError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Contents(e) => write!(f, "invalid checkpoint contents: {e}"),
+            Self::TransactionNotIncluded(transaction) => {
+                write!(
+                    f,
+                    "transaction {transaction} is not included in the checkpoint"
+                )
+            }
+            Self::EffectsDigestMismatch { expected, actual } => write!(
+                f,
+                "transaction effects digest is {actual}, expected {expected}"
+            ),
+            Self::ObjectNotWritten(object) => write!(
+                f,
+                "object {} at version {} was not written by the transaction",
+                object.object_id(),
+                object.version()
+            ),
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl crate::error::ErrorCode for ObjectProofError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Contents(_) => "invalid_checkpoint_contents",
+            Self::TransactionNotIncluded(_) => "transaction_not_included",
+            Self::EffectsDigestMismatch { .. } => "effects_digest_mismatch",
+            Self::ObjectNotWritten(_) => "object_not_written",
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::error::Error for ObjectProofError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Contents(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 /// Transaction information committed to in a checkpoint
@@ -209,6 +483,24 @@ pub struct CheckpointTransactionInfo {
     pub signatures: Vec<UserSignature>,
 }
 
+impl CheckpointTransactionInfo {
+    /// The digest of the transaction.
+    pub fn transaction(&self) -> &TransactionDigest {
+        &self.transaction
+    }
+
+    /// The digest of the effects of the transaction.
+    pub fn effects(&self) -> &TransactionEffectsDigest {
+        &self.effects
+    }
+
+    /// The user signatures which authorized the transaction, one for each of its required
+    /// signers.
+    pub fn signatures(&self) -> &[UserSignature] {
+        &self.signatures
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -247,6 +539,111 @@ pub struct CheckpointTransaction {
     pub output_objects: Vec<Object>,
 }
 
+/// A [`CheckpointData`] whose transactions may carry signatures of schemes unknown to this
+/// version of the library, see [`LenientSignedTransaction`].
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is the same as the one of [`CheckpointData`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct LenientCheckpointData {
+    pub checkpoint_summary: SignedCheckpointSummary,
+    pub checkpoint_contents: CheckpointContents,
+    #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=1).lift()))]
+    pub transactions: Vec<LenientCheckpointTransaction>,
+}
+
+impl LenientCheckpointData {
+    /// Convert into a [`CheckpointData`], if all the signatures of its transactions use a scheme
+    /// known to this library.
+    pub fn into_known(self) -> Option<CheckpointData> {
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(LenientCheckpointTransaction::into_known)
+            .collect::<Option<_>>()?;
+        Some(CheckpointData {
+            checkpoint_summary: self.checkpoint_summary,
+            checkpoint_contents: self.checkpoint_contents,
+            transactions,
+        })
+    }
+}
+
+impl From<CheckpointData> for LenientCheckpointData {
+    fn from(data: CheckpointData) -> Self {
+        Self {
+            checkpoint_summary: data.checkpoint_summary,
+            checkpoint_contents: data.checkpoint_contents,
+            transactions: data.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A [`CheckpointTransaction`] whose signatures may use schemes unknown to this version of the
+/// library, see [`LenientSignedTransaction`].
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is the same as the one of [`CheckpointTransaction`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct LenientCheckpointTransaction {
+    /// The input Transaction
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "::serde_with::As::<crate::_serde::LenientSignedTransactionWithIntentMessage>"
+        )
+    )]
+    pub transaction: LenientSignedTransaction,
+    /// The effects produced by executing this transaction
+    pub effects: TransactionEffects,
+    /// The events, if any, emitted by this transaction during execution
+    pub events: Option<TransactionEvents>,
+    /// The state of all inputs to this transaction as they were prior to execution.
+    #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
+    pub input_objects: Vec<Object>,
+    /// The state of all output objects created or mutated by this transaction.
+    #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
+    pub output_objects: Vec<Object>,
+}
+
+impl LenientCheckpointTransaction {
+    /// Convert into a [`CheckpointTransaction`], if all the signatures use a scheme known to this
+    /// library.
+    pub fn into_known(self) -> Option<CheckpointTransaction> {
+        Some(CheckpointTransaction {
+            transaction: self.transaction.into_known()?,
+            effects: self.effects,
+            events: self.events,
+            input_objects: self.input_objects,
+            output_objects: self.output_objects,
+        })
+    }
+}
+
+impl From<CheckpointTransaction> for LenientCheckpointTransaction {
+    fn from(transaction: CheckpointTransaction) -> Self {
+        Self {
+            transaction: transaction.transaction.into(),
+            effects: transaction.effects,
+            events: transaction.events,
+            input_objects: transaction.input_objects,
+            output_objects: transaction.output_objects,
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod serialization {
@@ -622,10 +1019,34 @@ mod serialization {
         #[cfg(target_arch = "wasm32")]
         use wasm_bindgen_test::wasm_bindgen_test as test;
 
+        const SIGNED_CHECKPOINT: &str = "CgAAAAAAAAAUAAAAAAAAABUAAAAAAAAAIJ6CIMG/6Un4MKNM8h+R9r8bQ6dNTk0WZxBMUQH1XFQBASCWUVucdQkje+4YbXVpvQZcg74nndL1NK7ccj1dDR04agAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACwAAAAAAAAAAAAAKAAAAAAAAAKOonlp6Vf8dJEjQYa/VyigZruaZwSwu3u/ZZVCsdrS1iaGPIAERZcNnfM75tOh10hI6MAAAAQAAAAAAAAAQAAAAAAA=";
+
+        #[cfg(feature = "hash")]
+        fn checkpoint_fixture() -> CheckpointSummary {
+            bcs::from_bytes::<SignedCheckpointSummary>(
+                &Base64::decode_vec(SIGNED_CHECKPOINT).unwrap(),
+            )
+            .unwrap()
+            .checkpoint
+        }
+
+        const CONTENTS: &str = "AAEgp6oAB8Qadn8+FqtdqeDIp8ViQNOZpMKs44MN0N5y7zIgqn5dKR1+8poL0pLNwRo/2knMnodwMTEDhqYL03kdewQBAWEAgpORkfH6ewjfFQYZJhmjkYq0/B3Set4mLJX/G0wUPb/V4H41gJipYu4I6ToyixnEuPQWxHKLckhNn+0UmI+pAJ9GegzEh0q2HWABmFMpFoPw0229dCfzWNOhHW5bes4H";
+
+        fn checkpoint_contents_fixture() -> CheckpointContents {
+            bcs::from_bytes(&Base64::decode_vec(CONTENTS).unwrap()).unwrap()
+        }
+
+        /// The transaction of the checkpoint contents fixture.
+        fn wormhole_pyth_transaction() -> crate::Transaction {
+            const TRANSACTION: &str =
+                include_str!("transaction/fixtures/wormhole-pyth-transaction");
+            bcs::from_bytes(&Base64::decode_vec(TRANSACTION.trim()).unwrap()).unwrap()
+        }
+
         #[test]
         fn signed_checkpoint_fixture() {
             const FIXTURES: &[&str] = &[
-                "CgAAAAAAAAAUAAAAAAAAABUAAAAAAAAAIJ6CIMG/6Un4MKNM8h+R9r8bQ6dNTk0WZxBMUQH1XFQBASCWUVucdQkje+4YbXVpvQZcg74nndL1NK7ccj1dDR04agAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACwAAAAAAAAAAAAAKAAAAAAAAAKOonlp6Vf8dJEjQYa/VyigZruaZwSwu3u/ZZVCsdrS1iaGPIAERZcNnfM75tOh10hI6MAAAAQAAAAAAAAAQAAAAAAA=",
+                SIGNED_CHECKPOINT,
                 "AgAAAAAAAAAFAAAAAAAAAAYAAAAAAAAAIINaPEm+WRQV2vGcPR9fe6fYhxl48GpqB+DqDYQqRHkuASBe+6BDLHSRCMiWqBkvVMqWXPWUsZnpc2gbOVdre3vnowAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAwAAAAAAAAAAAQFgqGJldzxWMt2CZow1QiLmDf0RdLE6udu0bVdc1xaExX37NByF27rDH5C1DF+mkpLdA6YZnXMvuUw+zoWo71qe2DTdIDU4AcNaSUE3OoEHceuT+fBa6dMib3yDkkhmOZLyECcAAAAAAAAkAAAAAAAAAAAAAgAAAAAAAACvljn+1LWFSpu3PGx4BlIlVZq7blFK+fV7SOPEU0z9nz7lgkv8a12EA9R0tGm8hEYSOjAAAAEAAAAAAAAAEAAAAAAA",
             ];
 
@@ -642,9 +1063,7 @@ mod serialization {
 
         #[test]
         fn contents_fixture() {
-            let fixture ="AAEgp6oAB8Qadn8+FqtdqeDIp8ViQNOZpMKs44MN0N5y7zIgqn5dKR1+8poL0pLNwRo/2knMnodwMTEDhqYL03kdewQBAWEAgpORkfH6ewjfFQYZJhmjkYq0/B3Set4mLJX/G0wUPb/V4H41gJipYu4I6ToyixnEuPQWxHKLckhNn+0UmI+pAJ9GegzEh0q2HWABmFMpFoPw0229dCfzWNOhHW5bes4H";
-
-            let bcs = Base64::decode_vec(fixture).unwrap();
+            let bcs = Base64::decode_vec(CONTENTS).unwrap();
 
             let contents: CheckpointContents = bcs::from_bytes(&bcs).unwrap();
             let bytes = bcs::to_bytes(&contents).unwrap();
@@ -652,5 +1071,217 @@ mod serialization {
             let json = serde_json::to_string_pretty(&contents).unwrap();
             println!("{json}");
         }
+
+        #[cfg(feature = "hash")]
+        #[test]
+        fn signed_transactions() {
+            let transaction = wormhole_pyth_transaction();
+            let fixture = checkpoint_contents_fixture();
+            let signatures = fixture.transactions()[0].signatures().to_vec();
+
+            let contents = CheckpointContents::new(vec![CheckpointTransactionInfo {
+                transaction: transaction.digest(),
+                effects: TransactionEffectsDigest::ZERO,
+                signatures: signatures.clone(),
+            }]);
+            assert_eq!(
+                contents.signatures(&transaction.digest()),
+                Some(&signatures[..])
+            );
+            assert_eq!(contents.signatures(&TransactionDigest::ZERO), None);
+
+            let signed = contents.signed_transactions([transaction.clone()]).unwrap();
+            assert_eq!(
+                signed,
+                [SignedTransaction {
+                    transaction: transaction.clone(),
+                    signatures,
+                }]
+            );
+
+            assert_eq!(
+                fixture.signed_transactions([transaction.clone()]),
+                Err(transaction.digest())
+            );
+        }
+
+        #[test]
+        fn lenient_checkpoint_transaction() {
+            let transaction = wormhole_pyth_transaction();
+            let fixture = checkpoint_contents_fixture();
+            let effects = crate::test_util::effects(1, vec![]);
+            let strict = CheckpointTransaction {
+                transaction: SignedTransaction {
+                    transaction,
+                    signatures: fixture.transactions()[0].signatures().to_vec(),
+                },
+                effects,
+                events: None,
+                input_objects: vec![],
+                output_objects: vec![],
+            };
+
+            // Both forms share the same serialization
+            let bcs = bcs::to_bytes(&strict).unwrap();
+            let lenient: LenientCheckpointTransaction = bcs::from_bytes(&bcs).unwrap();
+            assert_eq!(lenient, strict.clone().into());
+            assert_eq!(bcs::to_bytes(&lenient).unwrap(), bcs);
+            assert_eq!(lenient.clone().into_known(), Some(strict));
+
+            // A signature of an unknown scheme only fails the strict form
+            let mut unknown = lenient;
+            unknown.transaction.signatures[0] = crate::LenientUserSignature::Unknown {
+                flag: 0x2a,
+                signature: vec![1, 2, 3],
+            };
+            let bcs = bcs::to_bytes(&unknown).unwrap();
+            bcs::from_bytes::<CheckpointTransaction>(&bcs).unwrap_err();
+            assert_eq!(
+                bcs::from_bytes::<LenientCheckpointTransaction>(&bcs).unwrap(),
+                unknown
+            );
+            assert_eq!(unknown.into_known(), None);
+        }
+
+        #[cfg(feature = "hash")]
+        #[test]
+        fn checkpoint_chain() {
+            let previous = checkpoint_fixture();
+
+            let contents = CheckpointContents::new(vec![]);
+            let mut next = previous.clone();
+            next.sequence_number += 1;
+            next.previous_digest = Some(previous.digest());
+            next.content_digest = contents.digest();
+            next.check_follows(&previous).unwrap();
+            next.check_contents(&contents).unwrap();
+            assert_eq!(
+                previous.check_contents(&contents),
+                Err(CheckpointChainError::ContentDigestMismatch {
+                    expected: previous.content_digest,
+                    actual: contents.digest(),
+                })
+            );
+
+            // Skipping a checkpoint
+            let mut skipped = next.clone();
+            skipped.sequence_number += 1;
+            assert!(matches!(
+                skipped.check_follows(&previous),
+                Err(CheckpointChainError::NonConsecutive { .. })
+            ));
+
+            // The digest covers every field of the previous checkpoint
+            let mut tampered = previous.clone();
+            tampered.timestamp_ms += 1;
+            assert_eq!(
+                next.check_follows(&tampered),
+                Err(CheckpointChainError::PreviousDigestMismatch {
+                    expected: tampered.digest(),
+                    actual: Some(previous.digest()),
+                })
+            );
+        }
+
+        #[cfg(feature = "hash")]
+        #[test]
+        fn transaction_inclusion() {
+            let mut checkpoint = checkpoint_fixture();
+
+            let included = TransactionDigest::new([1; 32]);
+            let info = CheckpointTransactionInfo {
+                transaction: included,
+                effects: TransactionEffectsDigest::new([2; 32]),
+                signatures: vec![],
+            };
+            let contents = CheckpointContents::new(vec![info.clone()]);
+            checkpoint.content_digest = contents.digest();
+
+            assert_eq!(
+                checkpoint.prove_inclusion(&contents, &included),
+                Ok(Some(&info))
+            );
+            assert_eq!(
+                checkpoint.prove_inclusion(&contents, &TransactionDigest::new([3; 32])),
+                Ok(None)
+            );
+
+            // Contents which aren't committed to by the checkpoint prove nothing
+            let forged = CheckpointContents::new(vec![]);
+            assert_eq!(
+                checkpoint.prove_inclusion(&forged, &included),
+                Err(CheckpointChainError::ContentDigestMismatch {
+                    expected: checkpoint.content_digest,
+                    actual: forged.digest(),
+                })
+            );
+        }
+
+        #[cfg(feature = "hash")]
+        #[test]
+        fn object_proof() {
+            use crate::ChangedObject;
+            use crate::ObjectDigest;
+            use crate::ObjectId;
+            use crate::ObjectOut;
+            use crate::ObjectReference;
+            use crate::Owner;
+            use crate::TransactionEffectsV2;
+
+            let object_id = ObjectId::new([7; 32]);
+            let created = ChangedObject {
+                output_state: ObjectOut::ObjectWrite {
+                    digest: ObjectDigest::new([8; 32]),
+                    owner: Owner::Immutable,
+                },
+                ..crate::test_util::created(object_id, Owner::Immutable)
+            };
+            let effects = TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+                transaction_digest: TransactionDigest::new([1; 32]),
+                ..crate::test_util::effects_v2(5, vec![created])
+            }));
+            let contents = CheckpointContents::new(vec![CheckpointTransactionInfo {
+                transaction: *effects.transaction_digest(),
+                effects: effects.digest(),
+                signatures: vec![],
+            }]);
+            let mut checkpoint = checkpoint_fixture();
+            checkpoint.content_digest = contents.digest();
+
+            let object = ObjectReference::new(object_id, 5, ObjectDigest::new([8; 32]));
+            checkpoint
+                .prove_object(&contents, &effects, &object)
+                .unwrap();
+
+            // A different version of the object wasn't written by the transaction
+            let stale = ObjectReference::new(object_id, 4, ObjectDigest::new([8; 32]));
+            assert_eq!(
+                checkpoint.prove_object(&contents, &effects, &stale),
+                Err(ObjectProofError::ObjectNotWritten(stale.clone()))
+            );
+
+            // Effects which don't match the committed digest prove nothing
+            let mut tampered = effects.clone();
+            let TransactionEffects::V2(e) = &mut tampered else {
+                unreachable!()
+            };
+            e.lamport_version = 4;
+            assert_eq!(
+                checkpoint.prove_object(&contents, &tampered, &stale),
+                Err(ObjectProofError::EffectsDigestMismatch {
+                    expected: effects.digest(),
+                    actual: tampered.digest(),
+                })
+            );
+
+            let empty = CheckpointContents::new(vec![]);
+            checkpoint.content_digest = empty.digest();
+            assert_eq!(
+                checkpoint.prove_object(&empty, &effects, &object),
+                Err(ObjectProofError::TransactionNotIncluded(
+                    *effects.transaction_digest()
+                ))
+            );
+        }
     }
 }
@@ -0,0 +1,350 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::CheckpointSequenceNumber;
+
+/// A non-empty, inclusive range of checkpoint sequence numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "serde", serde(try_from = "RangeFields"))]
+pub struct CheckpointRange {
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    start: CheckpointSequenceNumber,
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    end: CheckpointSequenceNumber,
+}
+
+impl CheckpointRange {
+    /// The checkpoints from `start` to `end`, both included, or `None` if `start` is after `end`.
+    pub fn new(start: CheckpointSequenceNumber, end: CheckpointSequenceNumber) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The range made of the single checkpoint `sequence_number`.
+    pub fn single(sequence_number: CheckpointSequenceNumber) -> Self {
+        Self {
+            start: sequence_number,
+            end: sequence_number,
+        }
+    }
+
+    /// The first checkpoint of the range.
+    pub fn start(&self) -> CheckpointSequenceNumber {
+        self.start
+    }
+
+    /// The last checkpoint of the range.
+    pub fn end(&self) -> CheckpointSequenceNumber {
+        self.end
+    }
+
+    /// The number of checkpoints in the range, saturating at `u64::MAX`.
+    #[allow(clippy::len_without_is_empty)] // ranges are never empty
+    pub fn len(&self) -> u64 {
+        (self.end - self.start).saturating_add(1)
+    }
+
+    /// Whether `sequence_number` is in the range.
+    pub fn contains(&self, sequence_number: CheckpointSequenceNumber) -> bool {
+        self.start <= sequence_number && sequence_number <= self.end
+    }
+
+    /// Iterate over the sequence numbers of the range, in order.
+    pub fn iter(&self) -> RangeInclusive<CheckpointSequenceNumber> {
+        self.start..=self.end
+    }
+
+    /// The range covering both `self` and `other`, or `None` if they neither overlap nor are
+    /// adjacent, i.e. if some checkpoints between them would be in neither.
+    pub fn merge(&self, other: &CheckpointRange) -> Option<CheckpointRange> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if first.end.saturating_add(1) < second.start {
+            return None;
+        }
+        Some(Self {
+            start: first.start,
+            end: first.end.max(second.end),
+        })
+    }
+}
+
+impl IntoIterator for CheckpointRange {
+    type Item = CheckpointSequenceNumber;
+    type IntoIter = RangeInclusive<CheckpointSequenceNumber>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<CheckpointRange> for RangeInclusive<CheckpointSequenceNumber> {
+    fn from(range: CheckpointRange) -> Self {
+        range.iter()
+    }
+}
+
+impl std::fmt::Display for CheckpointRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// The unchecked fields of a deserialized [`CheckpointRange`].
+#[cfg(feature = "serde")]
+#[derive(serde_derive::Deserialize)]
+struct RangeFields {
+    #[serde(with = "crate::_serde::ReadableDisplay")]
+    start: CheckpointSequenceNumber,
+    #[serde(with = "crate::_serde::ReadableDisplay")]
+    end: CheckpointSequenceNumber,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RangeFields> for CheckpointRange {
+    type Error = String;
+
+    fn try_from(fields: RangeFields) -> Result<Self, Self::Error> {
+        Self::new(fields.start, fields.end).ok_or_else(|| {
+            format!(
+                "range start {} is after its end {}",
+                fields.start, fields.end
+            )
+        })
+    }
+}
+
+/// The set of checkpoints processed so far, e.g. by an indexer backfilling the chain out of
+/// order.
+///
+/// Processed checkpoints are kept as disjoint ranges, merged as checkpoints are inserted, so that
+/// the gaps left to process can be found cheaply. The set can be persisted and restored through its
+/// `serde` implementation, which represents it as the list of its ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<CheckpointRange>", into = "Vec<CheckpointRange>")
+)]
+pub struct ProcessedCheckpoints {
+    /// The end of each range, keyed by its start. Ranges neither overlap nor are adjacent.
+    ranges: BTreeMap<CheckpointSequenceNumber, CheckpointSequenceNumber>,
+}
+
+impl ProcessedCheckpoints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no checkpoint has been processed.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Record `sequence_number` as processed.
+    pub fn insert(&mut self, sequence_number: CheckpointSequenceNumber) {
+        self.insert_range(CheckpointRange::single(sequence_number))
+    }
+
+    /// Record all of the checkpoints of `range` as processed.
+    pub fn insert_range(&mut self, range: CheckpointRange) {
+        let mut merged = range;
+        let touching: Vec<_> = self
+            .ranges
+            .range(..=range.end.saturating_add(1))
+            .rev()
+            .map(|(&start, &end)| CheckpointRange { start, end })
+            .take_while(|existing| existing.merge(&range).is_some())
+            .collect();
+        for existing in touching {
+            self.ranges.remove(&existing.start);
+            merged = merged.merge(&existing).expect("ranges touch");
+        }
+        self.ranges.insert(merged.start, merged.end);
+    }
+
+    /// Whether `sequence_number` has been processed.
+    pub fn contains(&self, sequence_number: CheckpointSequenceNumber) -> bool {
+        self.ranges
+            .range(..=sequence_number)
+            .next_back()
+            .is_some_and(|(_, &end)| sequence_number <= end)
+    }
+
+    /// The ranges of processed checkpoints, in order.
+    pub fn ranges(&self) -> impl Iterator<Item = CheckpointRange> + '_ {
+        self.ranges
+            .iter()
+            .map(|(&start, &end)| CheckpointRange { start, end })
+    }
+
+    /// The range from the first to the last processed checkpoint, if any.
+    pub fn span(&self) -> Option<CheckpointRange> {
+        let (&start, _) = self.ranges.first_key_value()?;
+        let (_, &end) = self.ranges.last_key_value()?;
+        Some(CheckpointRange { start, end })
+    }
+
+    /// The gaps between the processed checkpoints, i.e. the unprocessed checkpoints between the
+    /// first and the last processed ones.
+    pub fn gaps(&self) -> Vec<CheckpointRange> {
+        self.span()
+            .map(|span| self.missing(span))
+            .unwrap_or_default()
+    }
+
+    /// The ranges of checkpoints of `range` which haven't been processed, in order.
+    pub fn missing(&self, range: CheckpointRange) -> Vec<CheckpointRange> {
+        let mut missing = Vec::new();
+        let mut next = range.start;
+        for (&start, &end) in self.ranges.range(..=range.end) {
+            if end < next {
+                continue;
+            }
+            if next < start {
+                missing.push(CheckpointRange {
+                    start: next,
+                    end: start - 1,
+                });
+            }
+            match end.checked_add(1) {
+                Some(after) => next = after,
+                None => return missing,
+            }
+        }
+        if next <= range.end {
+            missing.push(CheckpointRange {
+                start: next,
+                end: range.end,
+            });
+        }
+        missing
+    }
+
+    /// The first checkpoint from `start` onwards which hasn't been processed, e.g. to resume
+    /// processing the chain in order.
+    pub fn next_missing(
+        &self,
+        start: CheckpointSequenceNumber,
+    ) -> Option<CheckpointSequenceNumber> {
+        match self.ranges.range(..=start).next_back() {
+            Some((_, &end)) if start <= end => end.checked_add(1),
+            _ => Some(start),
+        }
+    }
+}
+
+impl From<Vec<CheckpointRange>> for ProcessedCheckpoints {
+    fn from(ranges: Vec<CheckpointRange>) -> Self {
+        ranges.into_iter().collect()
+    }
+}
+
+impl From<ProcessedCheckpoints> for Vec<CheckpointRange> {
+    fn from(processed: ProcessedCheckpoints) -> Self {
+        processed.ranges().collect()
+    }
+}
+
+impl FromIterator<CheckpointRange> for ProcessedCheckpoints {
+    fn from_iter<I: IntoIterator<Item = CheckpointRange>>(iter: I) -> Self {
+        let mut processed = Self::new();
+        processed.extend(iter);
+        processed
+    }
+}
+
+impl Extend<CheckpointRange> for ProcessedCheckpoints {
+    fn extend<I: IntoIterator<Item = CheckpointRange>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert_range(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn range(start: u64, end: u64) -> CheckpointRange {
+        CheckpointRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn checkpoint_range() {
+        assert_eq!(CheckpointRange::new(3, 2), None);
+        assert_eq!(range(2, 4).iter().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(range(2, 4).len(), 3);
+        assert_eq!(range(0, u64::MAX).len(), u64::MAX);
+
+        assert_eq!(range(2, 4).merge(&range(5, 7)), Some(range(2, 7)));
+        assert_eq!(range(5, 7).merge(&range(2, 5)), Some(range(2, 7)));
+        assert_eq!(range(2, 9).merge(&range(5, 7)), Some(range(2, 9)));
+        assert_eq!(range(2, 4).merge(&range(6, 7)), None);
+    }
+
+    #[test]
+    fn gap_detection() {
+        let mut processed = ProcessedCheckpoints::new();
+        assert_eq!(processed.gaps(), []);
+        assert_eq!(processed.next_missing(0), Some(0));
+
+        processed.insert_range(range(10, 19));
+        processed.insert_range(range(30, 39));
+        processed.insert(25);
+        assert_eq!(processed.gaps(), [range(20, 24), range(26, 29)]);
+        assert_eq!(
+            processed.missing(range(0, 45)),
+            [range(0, 9), range(20, 24), range(26, 29), range(40, 45)]
+        );
+        assert!(processed.contains(25));
+        assert!(!processed.contains(26));
+        assert_eq!(processed.next_missing(12), Some(20));
+        assert_eq!(processed.next_missing(22), Some(22));
+
+        // Filling the gaps merges the ranges
+        processed.insert_range(range(20, 24));
+        processed.insert_range(range(26, 30));
+        assert_eq!(processed.ranges().collect::<Vec<_>>(), [range(10, 39)]);
+        assert_eq!(processed.gaps(), []);
+
+        processed.insert(u64::MAX);
+        assert_eq!(processed.next_missing(u64::MAX), None);
+        assert_eq!(processed.span(), Some(range(10, u64::MAX)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn persistence() {
+        let processed: ProcessedCheckpoints = [range(10, 19), range(30, 39), range(20, 25)]
+            .into_iter()
+            .collect();
+        let json = serde_json::to_value(&processed).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "start": "10", "end": "25" },
+                { "start": "30", "end": "39" },
+            ])
+        );
+        assert_eq!(
+            serde_json::from_value::<ProcessedCheckpoints>(json).unwrap(),
+            processed
+        );
+
+        serde_json::from_value::<CheckpointRange>(serde_json::json!({ "start": "2", "end": "1" }))
+            .unwrap_err();
+    }
+}
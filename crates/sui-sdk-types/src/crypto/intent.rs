@@ -17,6 +17,7 @@
 /// intent = intent-scope intent-version intent-app-id
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
 pub struct Intent {
     pub scope: IntentScope,
     pub version: IntentVersion,
@@ -32,10 +33,68 @@ impl Intent {
         }
     }
 
+    /// The intent of a user signature over a [`Transaction`](crate::Transaction).
+    pub const fn sui_transaction() -> Self {
+        Self {
+            scope: IntentScope::TransactionData,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        }
+    }
+
+    /// The intent of a user signature over a [`PersonalMessage`](crate::PersonalMessage).
+    pub const fn personal_message() -> Self {
+        Self {
+            scope: IntentScope::PersonalMessage,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        }
+    }
+
+    /// The intent of a validator signature over a
+    /// [`CheckpointSummary`](crate::CheckpointSummary).
+    pub const fn checkpoint_summary() -> Self {
+        Self {
+            scope: IntentScope::CheckpointSummary,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        }
+    }
+
+    /// The intent of a validator signature over
+    /// [`TransactionEffects`](crate::TransactionEffects).
+    pub const fn transaction_effects() -> Self {
+        Self {
+            scope: IntentScope::TransactionEffects,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        }
+    }
+
+    /// The intent of a validator's proof of possession of its protocol key.
+    pub const fn proof_of_possession() -> Self {
+        Self {
+            scope: IntentScope::ProofOfPossession,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        }
+    }
+
     pub fn to_bytes(self) -> [u8; 3] {
         [self.scope as u8, self.version as u8, self.app_id as u8]
     }
 
+    /// Parse an intent from its 3-byte serialized form, returning `None` if any of the bytes
+    /// isn't a known value.
+    pub fn from_bytes(bytes: [u8; 3]) -> Option<Self> {
+        let [scope, version, app_id] = bytes;
+        Some(Self {
+            scope: IntentScope::from_u8(scope)?,
+            version: IntentVersion::from_u8(version)?,
+            app_id: IntentAppId::from_u8(app_id)?,
+        })
+    }
+
     pub fn scope(self) -> IntentScope {
         self.scope
     }
@@ -60,6 +119,7 @@ impl Intent {
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
 #[non_exhaustive]
 pub enum IntentScope {
     TransactionData = 0,         // Used for a user signature on a transaction data.
@@ -73,6 +133,24 @@ pub enum IntentScope {
     ConsensusBlock = 8,    // Used for consensus authority signature on block's digest
 }
 
+impl IntentScope {
+    pub fn from_u8(byte: u8) -> Option<Self> {
+        let scope = match byte {
+            0 => Self::TransactionData,
+            1 => Self::TransactionEffects,
+            2 => Self::CheckpointSummary,
+            3 => Self::PersonalMessage,
+            4 => Self::SenderSignedTransaction,
+            5 => Self::ProofOfPossession,
+            6 => Self::HeaderDigest,
+            7 => Self::BridgeEventUnused,
+            8 => Self::ConsensusBlock,
+            _ => return None,
+        };
+        Some(scope)
+    }
+}
+
 /// Byte signifying the version of an [`Intent`]
 ///
 /// # BCS
@@ -84,11 +162,21 @@ pub enum IntentScope {
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
 #[non_exhaustive]
 pub enum IntentVersion {
     V0 = 0,
 }
 
+impl IntentVersion {
+    pub fn from_u8(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::V0),
+            _ => None,
+        }
+    }
+}
+
 /// Byte signifying the application id of an [`Intent`]
 ///
 /// # BCS
@@ -100,9 +188,122 @@ pub enum IntentVersion {
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
 #[non_exhaustive]
 pub enum IntentAppId {
     Sui = 0,
     Narwhal = 1,
     Consensus = 2,
 }
+
+impl IntentAppId {
+    pub fn from_u8(byte: u8) -> Option<Self> {
+        let app_id = match byte {
+            0 => Self::Sui,
+            1 => Self::Narwhal,
+            2 => Self::Consensus,
+            _ => return None,
+        };
+        Some(app_id)
+    }
+}
+
+/// A message along with the [`Intent`] it is signed with.
+///
+/// The digest that a signature commits to is the hash of the BCS serialized `IntentMessage`, see
+/// [`IntentMessage::signing_digest`].
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// intent-message = intent bcs-value
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct IntentMessage<T> {
+    pub intent: Intent,
+    pub value: T,
+}
+
+impl<T> IntentMessage<T> {
+    pub fn new(intent: Intent, value: T) -> Self {
+        Self { intent, value }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+mod serialization {
+    use super::*;
+
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    impl Serialize for Intent {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.to_bytes().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Intent {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let bytes = <[u8; 3]>::deserialize(deserializer)?;
+            Self::from_bytes(bytes)
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid intent {bytes:?}")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn intent_bytes() {
+        assert_eq!(Intent::sui_transaction().to_bytes(), [0, 0, 0]);
+        assert_eq!(Intent::personal_message().to_bytes(), [3, 0, 0]);
+        assert_eq!(
+            Intent::from_bytes([3, 0, 0]),
+            Some(Intent::personal_message())
+        );
+        assert_eq!(Intent::from_bytes([9, 0, 0]), None);
+        assert_eq!(Intent::from_bytes([0, 1, 0]), None);
+        assert_eq!(Intent::from_bytes([0, 0, 3]), None);
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test_strategy::proptest]
+    fn roundtrip_intent_bytes(intent: Intent) {
+        assert_eq!(Intent::from_bytes(intent.to_bytes()), Some(intent));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn intent_message_bcs() {
+        let message = IntentMessage::new(Intent::personal_message(), vec![1u8, 2, 3]);
+        let bytes = bcs::to_bytes(&message).unwrap();
+        assert_eq!(bytes, [3, 0, 0, 3, 1, 2, 3]);
+        assert_eq!(
+            bcs::from_bytes::<IntentMessage<Vec<u8>>>(&bytes).unwrap(),
+            message
+        );
+
+        bcs::from_bytes::<IntentMessage<Vec<u8>>>(&[3, 1, 0, 0]).unwrap_err();
+    }
+}
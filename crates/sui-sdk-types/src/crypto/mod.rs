@@ -15,8 +15,17 @@ pub use ed25519::Ed25519PublicKey;
 pub use ed25519::Ed25519Signature;
 pub use intent::Intent;
 pub use intent::IntentAppId;
+pub use intent::IntentMessage;
 pub use intent::IntentScope;
 pub use intent::IntentVersion;
+pub use multisig::InvalidMultisigCommittee;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use multisig::KeytoolMultisig;
+pub use multisig::KeytoolMultisigError;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use multisig::KeytoolMultisigMember;
 pub use multisig::MultisigAggregatedSignature;
 pub use multisig::MultisigCommittee;
 pub use multisig::MultisigMember;
@@ -28,14 +37,18 @@ pub use secp256k1::Secp256k1PublicKey;
 pub use secp256k1::Secp256k1Signature;
 pub use secp256r1::Secp256r1PublicKey;
 pub use secp256r1::Secp256r1Signature;
+pub use signature::LenientUserSignature;
+pub use signature::SignatureFlag;
 pub use signature::SignatureScheme;
 pub use signature::SimpleSignature;
 pub use signature::UserSignature;
 pub use validator::ValidatorAggregatedSignature;
 pub use validator::ValidatorCommittee;
 pub use validator::ValidatorCommitteeMember;
+pub use validator::ValidatorMetadata;
 pub use validator::ValidatorSignature;
 pub use zklogin::Bn254FieldElement;
+pub use zklogin::Bn254FieldElementParseError;
 pub use zklogin::CircomG1;
 pub use zklogin::CircomG2;
 pub use zklogin::Jwk;
@@ -7,6 +7,7 @@ use super::Secp256k1Signature;
 use super::Secp256r1PublicKey;
 use super::Secp256r1Signature;
 use super::SignatureScheme;
+use crate::Address;
 
 pub type WeightUnit = u8;
 pub type ThresholdUnit = u16;
@@ -160,35 +161,120 @@ impl MultisigCommittee {
         SignatureScheme::Multisig
     }
 
-    /// Checks if the Committee is valid.
+    /// Checks if the Committee is valid, see [`MultisigCommittee::validate`].
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Check that the committee satisfies the rules enforced on chain.
     ///
     /// A valid committee is one that:
     ///  - Has a nonzero threshold
     ///  - Has at least one member
     ///  - Has at most ten members
     ///  - No member has weight 0
-    ///  - the sum of the weights of all members must be larger than the threshold
     ///  - contains no duplicate members
-    pub fn is_valid(&self) -> bool {
-        self.threshold != 0
-            && !self.members.is_empty()
-            && self.members.len() <= MAX_COMMITTEE_SIZE
-            && !self.members.iter().any(|member| member.weight == 0)
-            && self
-                .members
+    ///  - the sum of the weights of all members must be larger than the threshold
+    ///
+    /// Signatures from an invalid committee are rejected when the transaction is executed, so it
+    /// is worth checking a committee before gathering signatures from its members.
+    pub fn validate(&self) -> Result<(), InvalidMultisigCommittee> {
+        if self.threshold == 0 {
+            return Err(InvalidMultisigCommittee::ZeroThreshold);
+        }
+
+        if self.members.is_empty() || self.members.len() > MAX_COMMITTEE_SIZE {
+            return Err(InvalidMultisigCommittee::Size(self.members.len()));
+        }
+
+        for (index, member) in self.members.iter().enumerate() {
+            if member.weight == 0 {
+                return Err(InvalidMultisigCommittee::ZeroWeight { index });
+            }
+
+            if self.members[..index]
                 .iter()
-                .map(|member| member.weight as ThresholdUnit)
-                .sum::<ThresholdUnit>()
-                >= self.threshold
-            && !self.members.iter().enumerate().any(|(i, member)| {
-                self.members
-                    .iter()
-                    .skip(i + 1)
-                    .any(|m| member.public_key == m.public_key)
-            })
+                .any(|m| m.public_key == member.public_key)
+            {
+                return Err(InvalidMultisigCommittee::DuplicateMember { index });
+            }
+        }
+
+        let total_weight = self
+            .members
+            .iter()
+            .map(|member| member.weight as ThresholdUnit)
+            .sum::<ThresholdUnit>();
+        if total_weight < self.threshold {
+            return Err(InvalidMultisigCommittee::UnreachableThreshold {
+                total_weight,
+                threshold: self.threshold,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when a [`MultisigCommittee`] breaks the rules enforced on chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidMultisigCommittee {
+    /// The threshold is zero.
+    ZeroThreshold,
+
+    /// The committee doesn't have between one and ten members.
+    Size(usize),
+
+    /// The member at `index` has a weight of zero.
+    ZeroWeight { index: usize },
+
+    /// The member at `index` has the same public key as a previous member.
+    DuplicateMember { index: usize },
+
+    /// The total weight of the members is lower than the threshold.
+    UnreachableThreshold {
+        total_weight: ThresholdUnit,
+        threshold: ThresholdUnit,
+    },
+}
+
+impl std::fmt::Display for InvalidMultisigCommittee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ZeroThreshold => f.write_str("multisig threshold is zero"),
+            Self::Size(size) => write!(
+                f,
+                "multisig committee has {size} members, expected between 1 and {MAX_COMMITTEE_SIZE}"
+            ),
+            Self::ZeroWeight { index } => write!(f, "multisig member {index} has a weight of zero"),
+            Self::DuplicateMember { index } => {
+                write!(f, "multisig member {index} is a duplicate of a previous member")
+            }
+            Self::UnreachableThreshold {
+                total_weight,
+                threshold,
+            } => write!(
+                f,
+                "multisig threshold {threshold} is larger than the total weight {total_weight} of the members"
+            ),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for InvalidMultisigCommittee {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ZeroThreshold => "multisig_zero_threshold",
+            Self::Size(_) => "multisig_committee_size",
+            Self::ZeroWeight { .. } => "multisig_zero_weight",
+            Self::DuplicateMember { .. } => "multisig_duplicate_member",
+            Self::UnreachableThreshold { .. } => "multisig_unreachable_threshold",
+        }
     }
 }
 
+impl std::error::Error for InvalidMultisigCommittee {}
+
 /// Aggregated signature from members of a multisig committee.
 ///
 /// # BCS
@@ -304,6 +390,136 @@ fn roaring_bitmap_to_u16(roaring: &roaring::RoaringBitmap) -> Result<BitmapUnit,
     Ok(val)
 }
 
+/// A multisig committee in the JSON format output by `sui keytool multi-sig-address`.
+///
+/// ```text
+/// {
+///   "multisigAddress": "0x...",
+///   "multisig": [
+///     { "address": "0x...", "publicBase64Key": "AN2e...", "weight": 1 },
+///     ...
+///   ]
+/// }
+/// ```
+///
+/// The keytool doesn't include the threshold of the committee in its output, so it needs to be
+/// provided separately when converting to a [`MultisigCommittee`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeytoolMultisig {
+    pub multisig_address: Address,
+    pub multisig: Vec<KeytoolMultisigMember>,
+}
+
+/// A member of a [`KeytoolMultisig`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeytoolMultisigMember {
+    /// The address of the member's own public key.
+    pub address: Address,
+    /// The member's public key, formatted as the base64 encoding of its flag-prefixed bytes.
+    #[serde(with = "::serde_with::As::<::serde_with::DisplayFromStr>")]
+    pub public_base64_key: MultisigMemberPublicKey,
+    pub weight: WeightUnit,
+}
+
+/// Error returned when a [`KeytoolMultisig`] doesn't describe the committee it claims to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeytoolMultisigError {
+    /// The address of the member at `index` doesn't match its public key.
+    MemberAddress { index: usize },
+    /// The multisig address doesn't match the committee formed by the members and the threshold.
+    CommitteeAddress { expected: Address, actual: Address },
+}
+
+impl std::fmt::Display for KeytoolMultisigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MemberAddress { index } => {
+                write!(
+                    f,
+                    "address of multisig member {index} doesn't match its public key"
+                )
+            }
+            Self::CommitteeAddress { expected, actual } => {
+                write!(
+                    f,
+                    "multisig address {actual} doesn't match the committee's address {expected}"
+                )
+            }
+        }
+    }
+}
+
+impl crate::error::ErrorCode for KeytoolMultisigError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::MemberAddress { .. } => "multisig_member_address_mismatch",
+            Self::CommitteeAddress { .. } => "multisig_address_mismatch",
+        }
+    }
+}
+
+impl std::error::Error for KeytoolMultisigError {}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+impl MultisigCommittee {
+    /// Convert this committee to the format output by `sui keytool multi-sig-address`.
+    pub fn to_keytool(&self) -> KeytoolMultisig {
+        KeytoolMultisig {
+            multisig_address: self.derive_address(),
+            multisig: self
+                .members()
+                .iter()
+                .map(|member| KeytoolMultisigMember {
+                    address: member.public_key().derive_address(),
+                    public_base64_key: member.public_key().clone(),
+                    weight: member.weight(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Load a committee from the output of `sui keytool multi-sig-address` and the `threshold` it
+    /// was created with.
+    ///
+    /// The addresses included in the output are checked against the ones derived from the
+    /// committee, which also ensures that the right threshold was provided.
+    pub fn from_keytool(
+        keytool: &KeytoolMultisig,
+        threshold: ThresholdUnit,
+    ) -> Result<Self, KeytoolMultisigError> {
+        let mut members = Vec::with_capacity(keytool.multisig.len());
+        for (index, member) in keytool.multisig.iter().enumerate() {
+            if member.public_base64_key.derive_address() != member.address {
+                return Err(KeytoolMultisigError::MemberAddress { index });
+            }
+            members.push(MultisigMember::new(
+                member.public_base64_key.clone(),
+                member.weight,
+            ));
+        }
+
+        let committee = Self::new(members, threshold);
+        let expected = committee.derive_address();
+        if expected != keytool.multisig_address {
+            return Err(KeytoolMultisigError::CommitteeAddress {
+                expected,
+                actual: keytool.multisig_address,
+            });
+        }
+
+        Ok(committee)
+    }
+}
+
 /// A signature from a member of a multisig committee.
 ///
 /// # BCS
@@ -427,6 +643,91 @@ mod serialization {
         }
     }
 
+    impl MultisigMemberPublicKey {
+        /// Serialize this public key as its signature scheme flag followed by the key's bytes,
+        /// the format used by the keytool.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut buf = Vec::new();
+            match self {
+                MultisigMemberPublicKey::Ed25519(public_key) => {
+                    buf.push(SignatureScheme::Ed25519 as u8);
+                    buf.extend_from_slice(public_key.as_ref());
+                }
+                MultisigMemberPublicKey::Secp256k1(public_key) => {
+                    buf.push(SignatureScheme::Secp256k1 as u8);
+                    buf.extend_from_slice(public_key.as_ref());
+                }
+                MultisigMemberPublicKey::Secp256r1(public_key) => {
+                    buf.push(SignatureScheme::Secp256r1 as u8);
+                    buf.extend_from_slice(public_key.as_ref());
+                }
+                MultisigMemberPublicKey::ZkLogin(public_id) => {
+                    buf.push(SignatureScheme::ZkLogin as u8);
+                    buf.extend(public_id.to_bytes());
+                }
+            }
+            buf
+        }
+
+        pub fn to_base64(&self) -> String {
+            Base64::encode_string(&self.to_bytes())
+        }
+
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+            use serde::de::Error;
+
+            let flag = SignatureScheme::from_byte(
+                *bytes
+                    .first()
+                    .ok_or_else(|| bcs::Error::custom("missing signature scheme flag"))?,
+            )
+            .map_err(bcs::Error::custom)?;
+            let public_key_bytes = &bytes[1..];
+            match flag {
+                SignatureScheme::Ed25519 => Ed25519PublicKey::from_bytes(public_key_bytes)
+                    .map(Self::Ed25519)
+                    .map_err(bcs::Error::custom),
+                SignatureScheme::Secp256k1 => Secp256k1PublicKey::from_bytes(public_key_bytes)
+                    .map(Self::Secp256k1)
+                    .map_err(bcs::Error::custom),
+                SignatureScheme::Secp256r1 => Secp256r1PublicKey::from_bytes(public_key_bytes)
+                    .map(Self::Secp256r1)
+                    .map_err(bcs::Error::custom),
+                SignatureScheme::ZkLogin => ZkLoginPublicIdentifier::from_bytes(public_key_bytes)
+                    .map(Self::ZkLogin)
+                    .map_err(bcs::Error::custom),
+                SignatureScheme::Multisig
+                | SignatureScheme::Bls12381
+                | SignatureScheme::Passkey => Err(bcs::Error::custom(
+                    "invalid multisig member public key type",
+                )),
+            }
+        }
+
+        pub fn from_base64(s: &str) -> Result<Self, bcs::Error> {
+            use serde::de::Error;
+
+            let bytes = Base64::decode_vec(s).map_err(bcs::Error::custom)?;
+            Self::from_bytes(&bytes)
+        }
+    }
+
+    /// Formats the public key as the base64 encoding of its flag-prefixed bytes, the format
+    /// accepted and output by the keytool.
+    impl std::fmt::Display for MultisigMemberPublicKey {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.to_base64())
+        }
+    }
+
+    impl std::str::FromStr for MultisigMemberPublicKey {
+        type Err = bcs::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::from_base64(s)
+        }
+    }
+
     pub struct LegacyMultisigMember;
 
     impl SerializeAs<MultisigMember> for LegacyMultisigMember {
@@ -829,4 +1130,124 @@ mod serialization {
             }
         }
     }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use test_strategy::proptest;
+
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::wasm_bindgen_test as test;
+
+        #[proptest]
+        fn roundtrip_public_key_string(public_key: MultisigMemberPublicKey) {
+            let s = public_key.to_string();
+            assert_eq!(s.parse::<MultisigMemberPublicKey>().unwrap(), public_key);
+        }
+
+        #[test]
+        fn public_key_string() {
+            let ed25519: MultisigMemberPublicKey = "AA19qzWMja2qTvoASadbB0NlVbEKNoIZu2gPcFcTSdd1"
+                .parse()
+                .unwrap();
+            assert!(matches!(ed25519, MultisigMemberPublicKey::Ed25519(_)));
+            assert_eq!(
+                ed25519.to_string(),
+                "AA19qzWMja2qTvoASadbB0NlVbEKNoIZu2gPcFcTSdd1"
+            );
+
+            // BLS keys can't be members of a committee
+            "BA19qzWMja2qTvoASadbB0NlVbEKNoIZu2gPcFcTSdd1"
+                .parse::<MultisigMemberPublicKey>()
+                .unwrap_err();
+        }
+
+        #[test]
+        fn committee_validation() {
+            let key = |byte: u8| {
+                MultisigMemberPublicKey::Ed25519(crate::Ed25519PublicKey::new([byte; 32]))
+            };
+            let member = |byte: u8, weight| MultisigMember::new(key(byte), weight);
+
+            let committee = MultisigCommittee::new(vec![member(1, 1), member(2, 2)], 3);
+            assert_eq!(committee.validate(), Ok(()));
+
+            let invalid = [
+                (
+                    MultisigCommittee::new(vec![member(1, 1)], 0),
+                    InvalidMultisigCommittee::ZeroThreshold,
+                ),
+                (
+                    MultisigCommittee::new(vec![], 1),
+                    InvalidMultisigCommittee::Size(0),
+                ),
+                (
+                    MultisigCommittee::new((0..11).map(|i| member(i, 1)).collect(), 1),
+                    InvalidMultisigCommittee::Size(11),
+                ),
+                (
+                    MultisigCommittee::new(vec![member(1, 1), member(2, 0)], 1),
+                    InvalidMultisigCommittee::ZeroWeight { index: 1 },
+                ),
+                (
+                    MultisigCommittee::new(vec![member(1, 1), member(1, 2)], 1),
+                    InvalidMultisigCommittee::DuplicateMember { index: 1 },
+                ),
+                (
+                    MultisigCommittee::new(vec![member(1, 1), member(2, 2)], 4),
+                    InvalidMultisigCommittee::UnreachableThreshold {
+                        total_weight: 3,
+                        threshold: 4,
+                    },
+                ),
+            ];
+            for (committee, error) in invalid {
+                assert_eq!(committee.validate(), Err(error));
+                assert!(!committee.is_valid());
+            }
+        }
+
+        #[cfg(feature = "hash")]
+        #[proptest]
+        fn roundtrip_keytool(committee: MultisigCommittee) {
+            let keytool = committee.to_keytool();
+            let json = serde_json::to_value(&keytool).unwrap();
+            assert_eq!(
+                json["multisigAddress"],
+                committee.derive_address().to_string()
+            );
+            assert_eq!(
+                json["multisig"].as_array().unwrap().len(),
+                committee.members().len()
+            );
+
+            let keytool: KeytoolMultisig = serde_json::from_value(json).unwrap();
+            assert_eq!(
+                MultisigCommittee::from_keytool(&keytool, committee.threshold()).unwrap(),
+                committee
+            );
+            assert!(matches!(
+                MultisigCommittee::from_keytool(&keytool, committee.threshold().wrapping_add(1)),
+                Err(KeytoolMultisigError::CommitteeAddress { .. })
+            ));
+        }
+
+        #[cfg(feature = "hash")]
+        #[test]
+        fn keytool_member_address() {
+            let json = serde_json::json!({
+                "multisigAddress": "0x0",
+                "multisig": [{
+                    "address": "0x0",
+                    "publicBase64Key": "AA19qzWMja2qTvoASadbB0NlVbEKNoIZu2gPcFcTSdd1",
+                    "weight": 1
+                }]
+            });
+            let keytool: KeytoolMultisig = serde_json::from_value(json).unwrap();
+            assert_eq!(
+                MultisigCommittee::from_keytool(&keytool, 1),
+                Err(KeytoolMultisigError::MemberAddress { index: 0 })
+            );
+        }
+    }
 }
@@ -57,9 +57,10 @@ impl SimpleSignature {
 }
 
 impl SimpleSignature {
+    /// Serialize this signature as the scheme flag followed by the signature and public key.
     #[cfg(feature = "serde")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
-    fn to_bytes(&self) -> Vec<u8> {
+    pub fn to_bytes(&self) -> Vec<u8> {
         let mut buf = Vec::new();
         match self {
             SimpleSignature::Ed25519 {
@@ -459,6 +460,99 @@ impl UserSignature {
     }
 }
 
+/// The flag of a signature, which may be a scheme unknown to this version of the library.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SignatureFlag {
+    Known(SignatureScheme),
+    Unknown(u8),
+}
+
+impl SignatureFlag {
+    /// Construct from a byte flag, passing through flags of schemes unknown to this library
+    /// instead of failing like [`SignatureScheme::from_byte`].
+    pub fn from_byte(flag: u8) -> Self {
+        SignatureScheme::from_byte(flag).map_or(Self::Unknown(flag), Self::Known)
+    }
+
+    /// Convert to a byte flag
+    pub fn to_u8(self) -> u8 {
+        match self {
+            Self::Known(scheme) => scheme.to_u8(),
+            Self::Unknown(flag) => flag,
+        }
+    }
+
+    /// Return the signature scheme, if it is known to this library
+    pub fn scheme(self) -> Option<SignatureScheme> {
+        match self {
+            Self::Known(scheme) => Some(scheme),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<SignatureScheme> for SignatureFlag {
+    fn from(scheme: SignatureScheme) -> Self {
+        Self::Known(scheme)
+    }
+}
+
+/// A user signature, or the raw bytes of a signature whose scheme is unknown to this library
+///
+/// Signature schemes are added by new protocol versions, which an indexer may observe before being
+/// upgraded. Unlike [`UserSignature`], failing to deserialize a signature with an unknown flag,
+/// and with it the whole transaction, a `LenientUserSignature` records such signatures as is, so
+/// they can be stored and re-serialized unchanged. Signatures of known schemes which are
+/// malformed are still rejected.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is the same as the one of [`UserSignature`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub enum LenientUserSignature {
+    Known(UserSignature),
+    Unknown {
+        /// The flag of the unknown signature scheme
+        #[cfg_attr(feature = "proptest", strategy(0x07u8..))]
+        flag: u8,
+        /// The bytes of the signature following its flag
+        signature: Vec<u8>,
+    },
+}
+
+impl LenientUserSignature {
+    /// Return the flag for this signature
+    pub fn flag(&self) -> SignatureFlag {
+        match self {
+            Self::Known(signature) => SignatureFlag::Known(signature.scheme()),
+            Self::Unknown { flag, .. } => SignatureFlag::Unknown(*flag),
+        }
+    }
+
+    /// Return the signature, if its scheme is known to this library
+    pub fn known(&self) -> Option<&UserSignature> {
+        match self {
+            Self::Known(signature) => Some(signature),
+            Self::Unknown { .. } => None,
+        }
+    }
+
+    /// Convert into the signature, if its scheme is known to this library
+    pub fn into_known(self) -> Option<UserSignature> {
+        match self {
+            Self::Known(signature) => Some(signature),
+            Self::Unknown { .. } => None,
+        }
+    }
+}
+
+impl From<UserSignature> for LenientUserSignature {
+    fn from(signature: UserSignature) -> Self {
+        Self::Known(signature)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod serialization {
@@ -514,6 +608,47 @@ mod serialization {
             }
         }
 
+        /// Parse a signature from its scheme flag followed by the signature and public key, as
+        /// returned by [`SimpleSignature::to_bytes`].
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+            Self::from_serialized_bytes(bytes)
+        }
+
+        /// Parse a signature from the standard base64 encoding of its bytes, as returned by
+        /// [`SimpleSignature::to_base64`].
+        pub fn from_base64(s: &str) -> Result<Self, bcs::Error> {
+            use base64ct::Encoding;
+            use serde::de::Error;
+
+            let bytes = base64ct::Base64::decode_vec(s).map_err(bcs::Error::custom)?;
+            Self::from_bytes(&bytes)
+        }
+    }
+
+    /// Formats the signature as the standard base64 encoding of its flag-prefixed bytes, the
+    /// format used by wallets, the RPC, and the CLI.
+    impl std::fmt::Display for UserSignature {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.to_base64())
+        }
+    }
+
+    impl std::str::FromStr for UserSignature {
+        type Err = bcs::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::from_base64(s)
+        }
+    }
+
+    impl SimpleSignature {
+        /// Encode the bytes returned by [`SimpleSignature::to_bytes`] as standard base64.
+        pub fn to_base64(&self) -> String {
+            use base64ct::Encoding;
+
+            base64ct::Base64::encode_string(&self.to_bytes())
+        }
+
         pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
             Self::from_serialized_bytes(bytes)
         }
@@ -527,6 +662,22 @@ mod serialization {
         }
     }
 
+    /// Formats the signature as the standard base64 encoding of its flag-prefixed bytes, the
+    /// same format as [`UserSignature`].
+    impl std::fmt::Display for SimpleSignature {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.to_base64())
+        }
+    }
+
+    impl std::str::FromStr for SimpleSignature {
+        type Err = bcs::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::from_base64(s)
+        }
+    }
+
     #[derive(serde_derive::Serialize)]
     #[serde(tag = "scheme", rename_all = "lowercase")]
     enum ReadableUserSignatureRef<'a> {
@@ -657,6 +808,133 @@ mod serialization {
         }
     }
 
+    impl LenientUserSignature {
+        /// The serialized bytes of this signature: its flag followed by the bytes of the
+        /// signature, the same as [`UserSignature::to_bytes`] for known schemes.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            match self {
+                Self::Known(signature) => signature.to_bytes(),
+                Self::Unknown { flag, signature } => {
+                    let mut buf = Vec::with_capacity(1 + signature.len());
+                    buf.push(*flag);
+                    buf.extend_from_slice(signature);
+                    buf
+                }
+            }
+        }
+
+        /// The base64 encoding of the [`to_bytes`](Self::to_bytes) of this signature.
+        pub fn to_base64(&self) -> String {
+            use base64ct::Encoding;
+
+            base64ct::Base64::encode_string(&self.to_bytes())
+        }
+
+        fn from_serialized_bytes<T: AsRef<[u8]>, E: serde::de::Error>(bytes: T) -> Result<Self, E> {
+            let bytes = bytes.as_ref();
+
+            let flag = *bytes
+                .first()
+                .ok_or_else(|| serde::de::Error::custom("missing signature scheme flag"))?;
+            match SignatureFlag::from_byte(flag) {
+                SignatureFlag::Known(_) => {
+                    UserSignature::from_serialized_bytes(bytes).map(Self::Known)
+                }
+                SignatureFlag::Unknown(flag) => Ok(Self::Unknown {
+                    flag,
+                    signature: bytes[1..].to_vec(),
+                }),
+            }
+        }
+
+        /// Decode a signature from its serialized bytes, a flag followed by the bytes of the
+        /// signature.
+        ///
+        /// Signatures with an unknown flag are kept as is, while signatures of a known scheme are
+        /// decoded as a [`UserSignature`] and rejected if they are malformed.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+            Self::from_serialized_bytes(bytes)
+        }
+
+        /// Decode a signature from the base64 encoding of its serialized bytes, see
+        /// [`LenientUserSignature::from_bytes`].
+        pub fn from_base64(s: &str) -> Result<Self, bcs::Error> {
+            use base64ct::Encoding;
+            use serde::de::Error;
+
+            let bytes = base64ct::Base64::decode_vec(s).map_err(bcs::Error::custom)?;
+            Self::from_bytes(&bytes)
+        }
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+    #[serde(tag = "scheme", rename_all = "lowercase")]
+    enum ReadableUnknownSignature {
+        Unknown {
+            flag: u8,
+            #[serde(with = "crate::_serde::ReadableBase64Encoded")]
+            signature: Vec<u8>,
+        },
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(untagged)]
+    enum ReadableLenientUserSignature {
+        Unknown(ReadableUnknownSignature),
+        Known(UserSignature),
+    }
+
+    impl serde::Serialize for LenientUserSignature {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match self {
+                Self::Known(signature) => signature.serialize(serializer),
+                Self::Unknown { flag, signature } => {
+                    if serializer.is_human_readable() {
+                        ReadableUnknownSignature::Unknown {
+                            flag: *flag,
+                            signature: signature.clone(),
+                        }
+                        .serialize(serializer)
+                    } else {
+                        serializer.serialize_bytes(&self.to_bytes())
+                    }
+                }
+            }
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for LenientUserSignature {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                match ReadableLenientUserSignature::deserialize(deserializer)? {
+                    ReadableLenientUserSignature::Known(signature) => Ok(Self::Known(signature)),
+                    ReadableLenientUserSignature::Unknown(ReadableUnknownSignature::Unknown {
+                        flag,
+                        signature,
+                    }) => match SignatureFlag::from_byte(flag) {
+                        SignatureFlag::Known(scheme) => Err(serde::de::Error::custom(format!(
+                            "signature flag {flag:02x} is the known scheme {}",
+                            scheme.name()
+                        ))),
+                        SignatureFlag::Unknown(flag) => Ok(Self::Unknown { flag, signature }),
+                    },
+                }
+            } else {
+                use serde_with::DeserializeAs;
+
+                let bytes: std::borrow::Cow<'de, [u8]> =
+                    serde_with::Bytes::deserialize_as(deserializer)?;
+                Self::from_serialized_bytes(bytes)
+            }
+        }
+    }
+
     #[cfg(test)]
     mod test {
         use super::*;
@@ -694,6 +972,74 @@ mod serialization {
             }
         }
 
+        #[test]
+        fn base64_flag_format() {
+            // The simple fixtures above without their BCS length prefix
+            const FIXTURES: &[&str] = &[
+                "ANp47jDZQszLl6ocHM/ShqWvViENf/24lL0ye0qdbd3nmrhqplhiegSWhoGZcpzoWgPltPA413BL0LB0xAaEhw8Nfas1jI2tqk76AEmnWwdDZVWxCjaCGbtoD3BXE0nXdQ==",
+                "AStxPpZRJBcYPUNojCWzmtarrjxqQMyW8vjW6BRSHIg9aepRGX51pAsb4gs16YBEodhU06KQoknyOq74fOVFrmsCDhfNWTnkaxlmQZaM11mRC6JXfif6c/3jh225vsW86ys=",
+                "AunWngr11JBO3YB5HTnIrUyRea0ubpKJ5v3J+SlbWA4GKqc71kpmJZSsslBq/YzHDrjMzfuDchV2wH9Ojqp+XwgDR/uvI/A4q8TDCKJxEXoqTP+u3bxf+Bx1F7xsdKfttDA=",
+            ];
+
+            for fixture in FIXTURES {
+                let sig: UserSignature = fixture.parse().unwrap();
+                assert_eq!(&sig.to_string(), fixture);
+
+                let UserSignature::Simple(simple) = &sig else {
+                    panic!("expected a simple signature");
+                };
+                assert_eq!(&simple.to_string(), fixture);
+                assert_eq!(&fixture.parse::<SimpleSignature>().unwrap(), simple);
+            }
+
+            const MULTISIG: &str = "AwMAky4FZ4IwuMnrr6QBilZIHLyClRVCZy9a+qBWUnEFROQzSM+pH8QBg0XFVHMxPQlG+/7DfxLhSfKLfOknW/KQBQEqEPdbLUR13VuXS8tAUOr/u11qbWsMtiLTMj5OdPKfjx3bWXJtmipd5WRiL13ujD2O9xPtR2pL/8SW02LMOUjzAuXcd4AcMXbNhlddnO1VEqpMl2ppYkhdVv0ATLn6q5ZnSDmdaMfYG7LyD7V2GoX7ypvKMwO67GVsEb/GrbJ8n2IHAAMADX2rNYyNrapO+gBJp1sHQ2VVsQo2ghm7aA9wVxNJ13UBAQIOF81ZOeRrGWZBlozXWZELold+J/pz/eOHbbm+xbzrKwECA0f7ryPwOKvEwwiicRF6Kkz/rt28X/gcdRe8bHSn7bQwAQIA";
+            let multisig: UserSignature = MULTISIG.parse().unwrap();
+            assert_eq!(multisig.scheme(), SignatureScheme::Multisig);
+            assert_eq!(multisig.to_string(), MULTISIG);
+            MULTISIG.parse::<SimpleSignature>().unwrap_err();
+        }
+
+        #[test]
+        fn unknown_signature_flag() {
+            assert_eq!(
+                SignatureFlag::from_byte(0x01).scheme(),
+                Some(SignatureScheme::Secp256k1)
+            );
+            assert_eq!(SignatureFlag::from_byte(0x2a), SignatureFlag::Unknown(0x2a));
+            assert_eq!(SignatureFlag::from_byte(0x2a).to_u8(), 0x2a);
+
+            let unknown = [0x2a, 1, 2, 3];
+            UserSignature::from_bytes(&unknown).unwrap_err();
+            let sig = LenientUserSignature::from_bytes(&unknown).unwrap();
+            assert_eq!(sig.flag(), SignatureFlag::Unknown(0x2a));
+            assert_eq!(sig.known(), None);
+            assert_eq!(sig.to_bytes(), unknown);
+
+            let bcs = bcs::to_bytes(&sig).unwrap();
+            assert_eq!(bcs::from_bytes::<LenientUserSignature>(&bcs).unwrap(), sig);
+            let json = serde_json::to_string(&sig).unwrap();
+            assert_eq!(json, r#"{"scheme":"unknown","flag":42,"signature":"AQID"}"#);
+            assert_eq!(
+                serde_json::from_str::<LenientUserSignature>(&json).unwrap(),
+                sig
+            );
+            serde_json::from_str::<LenientUserSignature>(
+                r#"{"scheme":"unknown","flag":0,"signature":"AQID"}"#,
+            )
+            .unwrap_err();
+
+            // Known schemes are still decoded, and rejected when malformed
+            let known = "ANp47jDZQszLl6ocHM/ShqWvViENf/24lL0ye0qdbd3nmrhqplhiegSWhoGZcpzoWgPltPA413BL0LB0xAaEhw8Nfas1jI2tqk76AEmnWwdDZVWxCjaCGbtoD3BXE0nXdQ==";
+            let sig = LenientUserSignature::from_base64(known).unwrap();
+            assert_eq!(sig.known(), Some(&known.parse::<UserSignature>().unwrap()));
+            let json = serde_json::to_string(&sig).unwrap();
+            assert_eq!(
+                serde_json::from_str::<LenientUserSignature>(&json).unwrap(),
+                sig
+            );
+            LenientUserSignature::from_bytes(&[0x00, 1, 2, 3]).unwrap_err();
+        }
+
         #[test]
         fn legacy_multisig_fixtures() {
             const FIXTURE1: &str = "rgIDAgAnwUSyrALP8m0eEPZE6aPggBELk72n1u3LU+i4nx5kqzhahcICbskEYzHJrbarvFr/RQITgDMoorqpDhN8dgsKATyrN3CD8g37D60dYiGW6sOBqIcf3E1mdMsKvX2pbOZsYQv8VNL+2Jz3vnMXcwEZF32PplKjcnmyUGRhV11M7n4UOjAAAAEAAAAAAAEAEAAAAAAAAQADLEFBMTlxeldNamEycVR2b0FTYWRiQjBObFZiRUtOb0ladTJnUGNGY1RTZGQxATBBUUlPRjgxWk9lUnJHV1pCbG96WFdaRUxvbGQrSi9wei9lT0hiYm0reGJ6ckt3PT0BMEFnTkgrNjhqOERpcnhNTUlvbkVSZWlwTS82N2R2Ri80SEhVWHZHeDBwKzIwTUE9PQECAA==";
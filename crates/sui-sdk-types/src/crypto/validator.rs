@@ -2,6 +2,7 @@ use super::Bls12381PublicKey;
 use super::Bls12381Signature;
 use crate::checkpoint::EpochId;
 use crate::checkpoint::StakeUnit;
+use crate::Address;
 
 /// The Validator Set for a particular epoch.
 ///
@@ -47,6 +48,123 @@ pub struct ValidatorCommitteeMember {
     pub stake: StakeUnit,
 }
 
+impl ValidatorCommittee {
+    /// The sum of the stake of all members of the committee.
+    pub fn total_stake(&self) -> StakeUnit {
+        self.members.iter().map(|member| member.stake).sum()
+    }
+
+    /// Return the member with the given public key.
+    pub fn member(&self, public_key: &Bls12381PublicKey) -> Option<&ValidatorCommitteeMember> {
+        self.members
+            .iter()
+            .find(|member| member.public_key == *public_key)
+    }
+
+    /// Pick a member at random, with a probability proportional to its stake.
+    ///
+    /// Returns `None` if no member has any stake.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rand")))]
+    pub fn sample_by_stake<R>(&self, mut rng: R) -> Option<&ValidatorCommitteeMember>
+    where
+        R: rand_core::RngCore,
+    {
+        let members: Vec<_> = self.members.iter().collect();
+        sample_index_by_stake(&members, &mut rng).map(|index| members[index])
+    }
+
+    /// Pick `count` distinct members at random, each draw having a probability proportional to
+    /// the stake of the members not picked yet.
+    ///
+    /// Members without any stake are never picked, so fewer than `count` members are returned if
+    /// not enough of them have stake.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rand")))]
+    pub fn sample_many_by_stake<R>(
+        &self,
+        mut rng: R,
+        count: usize,
+    ) -> Vec<&ValidatorCommitteeMember>
+    where
+        R: rand_core::RngCore,
+    {
+        let mut remaining: Vec<_> = self.members.iter().collect();
+        let mut sample = Vec::with_capacity(count.min(remaining.len()));
+        while sample.len() < count {
+            let Some(index) = sample_index_by_stake(&remaining, &mut rng) else {
+                break;
+            };
+            sample.push(remaining.swap_remove(index));
+        }
+        sample
+    }
+}
+
+#[cfg(feature = "rand")]
+fn sample_index_by_stake<R: rand_core::RngCore>(
+    members: &[&ValidatorCommitteeMember],
+    rng: &mut R,
+) -> Option<usize> {
+    let total: u128 = members.iter().map(|member| member.stake as u128).sum();
+    if total == 0 {
+        return None;
+    }
+
+    // Rejection sampling to avoid the bias of reducing a random number modulo the total
+    let zone = u128::MAX - (u128::MAX % total + 1) % total;
+    let mut target = loop {
+        let value = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+        if value <= zone {
+            break value % total;
+        }
+    };
+
+    members.iter().position(|member| {
+        let stake = member.stake as u128;
+        if target < stake {
+            true
+        } else {
+            target -= stake;
+            false
+        }
+    })
+}
+
+/// Descriptive and network information about a validator, as published in the system state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct ValidatorMetadata {
+    /// The Sui address of the validator.
+    pub address: Address,
+    /// The public key the validator signs with as a member of the committee.
+    pub public_key: Bls12381PublicKey,
+    pub name: String,
+    pub description: String,
+    pub image_url: Option<String>,
+    pub project_url: Option<String>,
+    /// The multiaddr the validator serves transaction and certificate requests on.
+    pub network_address: String,
+    /// The multiaddr the validator uses for state sync and other peer to peer traffic.
+    pub p2p_address: String,
+    /// The multiaddr of the validator's consensus primary.
+    pub primary_address: String,
+    /// The commission charged by the validator on staking rewards, in basis points.
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub commission_rate: u64,
+}
+
+impl ValidatorMetadata {
+    /// The commission charged by the validator as a fraction, e.g. `0.02` for 2%.
+    pub fn commission(&self) -> f64 {
+        self.commission_rate as f64 / 10_000.0
+    }
+}
+
 /// An aggregated signature from multiple Validators.
 ///
 /// # BCS
@@ -121,6 +239,85 @@ mod test {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
+    fn committee_with_stakes(stakes: &[StakeUnit]) -> ValidatorCommittee {
+        ValidatorCommittee {
+            epoch: 0,
+            members: stakes
+                .iter()
+                .enumerate()
+                .map(|(index, stake)| {
+                    let mut public_key = [0; Bls12381PublicKey::LENGTH];
+                    public_key[0] = index as u8;
+                    ValidatorCommitteeMember {
+                        public_key: Bls12381PublicKey::new(public_key),
+                        stake: *stake,
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// A xorshift generator, good enough to exercise sampling deterministically.
+    #[cfg(feature = "rand")]
+    struct TestRng(u64);
+
+    #[cfg(feature = "rand")]
+    impl rand_core::RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn total_stake() {
+        let committee = committee_with_stakes(&[1, 2, 3]);
+        assert_eq!(committee.total_stake(), 6);
+        let member = &committee.members[1];
+        assert_eq!(committee.member(&member.public_key), Some(member));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_by_stake() {
+        let committee = committee_with_stakes(&[0, 1, 9]);
+        let mut rng = TestRng(42);
+
+        let mut counts = [0; 3];
+        for _ in 0..1000 {
+            let member = committee.sample_by_stake(&mut rng).unwrap();
+            counts[member.public_key.inner()[0] as usize] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        assert!((50..150).contains(&counts[1]), "{counts:?}");
+        assert_eq!(counts[1] + counts[2], 1000);
+
+        let sample = committee.sample_many_by_stake(&mut rng, 3);
+        assert_eq!(sample.len(), 2);
+        assert_ne!(sample[0], sample[1]);
+        assert!(sample.iter().all(|member| member.stake > 0));
+
+        assert_eq!(
+            committee_with_stakes(&[0, 0]).sample_by_stake(&mut rng),
+            None
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn aggregated_signature_fixture() {
@@ -328,6 +328,12 @@ impl std::fmt::Display for Bn254FieldElementParseError {
     }
 }
 
+impl crate::error::ErrorCode for Bn254FieldElementParseError {
+    fn code(&self) -> &'static str {
+        "invalid_bn254_field_element"
+    }
+}
+
 impl std::error::Error for Bn254FieldElementParseError {}
 
 impl std::str::FromStr for Bn254FieldElement {
@@ -420,14 +426,7 @@ mod serialization {
                 };
                 readable.serialize(serializer)
             } else {
-                let mut buf = Vec::new();
-                let iss_bytes = self.iss.as_bytes();
-                buf.push(iss_bytes.len() as u8);
-                buf.extend(iss_bytes);
-
-                buf.extend(&self.address_seed.0);
-
-                serializer.serialize_bytes(&buf)
+                serializer.serialize_bytes(&self.to_bytes())
             }
         }
     }
@@ -449,27 +448,39 @@ mod serialization {
                     .ok_or_else(|| serde::de::Error::custom("invalid zklogin public identifier"))
             } else {
                 let bytes: Cow<'de, [u8]> = Bytes::deserialize_as(deserializer)?;
-                let iss_len = *bytes
-                    .first()
-                    .ok_or_else(|| serde::de::Error::custom("invalid zklogin public identifier"))?;
-                let iss_bytes = bytes
-                    .get(1..(1 + iss_len as usize))
-                    .ok_or_else(|| serde::de::Error::custom("invalid zklogin public identifier"))?;
-                let iss = std::str::from_utf8(iss_bytes).map_err(serde::de::Error::custom)?;
-                let address_seed_bytes = bytes
-                    .get((1 + iss_len as usize)..)
-                    .ok_or_else(|| serde::de::Error::custom("invalid zklogin public identifier"))?;
-
-                let address_seed = <[u8; 32]>::try_from(address_seed_bytes)
-                    .map_err(serde::de::Error::custom)
-                    .map(Bn254FieldElement)?;
-
-                Self::new(iss.into(), address_seed)
-                    .ok_or_else(|| serde::de::Error::custom("invalid zklogin public identifier"))
+                Self::from_bytes(&bytes).map_err(serde::de::Error::custom)
             }
         }
     }
 
+    impl ZkLoginPublicIdentifier {
+        pub(crate) fn to_bytes(&self) -> Vec<u8> {
+            let mut buf = Vec::new();
+            let iss_bytes = self.iss.as_bytes();
+            buf.push(iss_bytes.len() as u8);
+            buf.extend(iss_bytes);
+
+            buf.extend(&self.address_seed.0);
+
+            buf
+        }
+
+        pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+            const INVALID: &str = "invalid zklogin public identifier";
+
+            let iss_len = *bytes.first().ok_or(INVALID)?;
+            let iss_bytes = bytes.get(1..(1 + iss_len as usize)).ok_or(INVALID)?;
+            let iss = std::str::from_utf8(iss_bytes).map_err(|_| INVALID)?;
+            let address_seed_bytes = bytes.get((1 + iss_len as usize)..).ok_or(INVALID)?;
+
+            let address_seed = <[u8; 32]>::try_from(address_seed_bytes)
+                .map_err(|_| INVALID)
+                .map(Bn254FieldElement)?;
+
+            Self::new(iss.into(), address_seed).ok_or(INVALID)
+        }
+    }
+
     #[derive(serde_derive::Serialize)]
     struct AuthenticatorRef<'a> {
         inputs: &'a ZkLoginInputs,
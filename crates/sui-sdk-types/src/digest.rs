@@ -60,14 +60,20 @@ impl Digest {
     }
 
     /// Decodes a digest from a Base58 encoded string.
+    ///
+    /// The string must decode to exactly [`Digest::LENGTH`] bytes.
     pub fn from_base58<T: AsRef<[u8]>>(base58: T) -> Result<Self, DigestParseError> {
         let mut buf = [0; Self::LENGTH];
 
-        bs58::decode(base58)
+        let len = bs58::decode(base58)
             .onto(&mut buf)
             //TODO fix error to contain bs58 parse error
             .map_err(|_| DigestParseError)?;
 
+        if len != Self::LENGTH {
+            return Err(DigestParseError);
+        }
+
         Ok(Self(buf))
     }
 
@@ -199,6 +205,12 @@ impl std::fmt::Display for DigestParseError {
     }
 }
 
+impl crate::error::ErrorCode for DigestParseError {
+    fn code(&self) -> &'static str {
+        "invalid_digest"
+    }
+}
+
 impl std::error::Error for DigestParseError {}
 
 //
@@ -350,4 +362,63 @@ mod test {
         let d = s.parse::<Digest>().unwrap();
         assert_eq!(digest, d);
     }
+
+    #[proptest]
+    fn roundtrip_wrapper_display_fromstr(digest: TransactionDigest) {
+        let d = digest.to_string().parse::<TransactionDigest>().unwrap();
+        assert_eq!(digest, d);
+        assert_eq!(
+            TransactionDigest::from_base58(digest.to_base58()),
+            Ok(digest)
+        );
+    }
+
+    #[test]
+    fn base58() {
+        let s = "7CuBm1AnLgkBMB6GiEn5d3RizznF5LbawjJTs8A5dcXF";
+        let digest: TransactionDigest = s.parse().unwrap();
+        assert_eq!(digest.to_string(), s);
+        assert_eq!(ObjectDigest::from(Digest::from(digest)).to_string(), s);
+
+        // Leading zero bytes are encoded as leading '1's
+        assert_eq!(
+            CheckpointDigest::ZERO.to_string(),
+            "11111111111111111111111111111111"
+        );
+        assert_eq!(
+            "11111111111111111111111111111111".parse(),
+            Ok(CheckpointDigest::ZERO)
+        );
+
+        // Too short, too long, and not Base58
+        for invalid in [
+            "",
+            "1",
+            "7CuBm1AnLgkBMB6GiEn5d3RizznF5LbawjJTs8A5dc",
+            "7CuBm1AnLgkBMB6GiEn5d3RizznF5LbawjJTs8A5dcXF1",
+            "0CuBm1AnLgkBMB6GiEn5d3RizznF5LbawjJTs8A5dcXF",
+        ] {
+            assert_eq!(invalid.parse::<ObjectDigest>(), Err(DigestParseError));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let s = "7CuBm1AnLgkBMB6GiEn5d3RizznF5LbawjJTs8A5dcXF";
+        let digest: TransactionDigest = s.parse().unwrap();
+
+        let json = serde_json::to_string(&digest).unwrap();
+        assert_eq!(json, format!("\"{s}\""));
+        assert_eq!(
+            serde_json::from_str::<TransactionDigest>(&json).unwrap(),
+            digest
+        );
+        assert!(serde_json::from_str::<TransactionDigest>("\"1\"").is_err());
+
+        // The binary form is length prefixed
+        let bcs = bcs::to_bytes(&digest).unwrap();
+        assert_eq!(bcs.len(), Digest::LENGTH + 1);
+        assert_eq!(bcs::from_bytes::<TransactionDigest>(&bcs).unwrap(), digest);
+    }
 }
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use super::ObjectChange;
+use super::TransactionEffects;
+use crate::execution_status::ExecutionStatus;
+use crate::GasCostSummary;
+use crate::ObjectId;
+
+/// The differences between two executions of a transaction, as returned by
+/// [`TransactionEffects::diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectsDiff {
+    /// The statuses of both executions, if they differ.
+    pub status: Option<(ExecutionStatus, ExecutionStatus)>,
+
+    /// The gas costs of both executions, if they differ.
+    pub gas: Option<(GasCostSummary, GasCostSummary)>,
+
+    /// The objects changed differently by both executions, ordered by id.
+    pub objects: Vec<ObjectDiff>,
+}
+
+impl EffectsDiff {
+    /// Whether both executions had the same outcome.
+    pub fn is_empty(&self) -> bool {
+        self.status.is_none() && self.gas.is_none() && self.objects.is_empty()
+    }
+}
+
+/// An object changed differently by two executions of a transaction, with its change in each of
+/// them, if it was changed at all.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectDiff {
+    pub object_id: ObjectId,
+    pub left: Option<ObjectChange>,
+    pub right: Option<ObjectChange>,
+}
+
+impl TransactionEffects {
+    /// Compare these effects, on the left, with the effects of another execution of the same
+    /// transaction, on the right, e.g. a dry run with the actual execution, or replays with
+    /// different versions of a package.
+    ///
+    /// Objects are compared by the kind of their change and their owners. Versions, and so
+    /// digests, depend on the state each execution started from, so digests are only compared
+    /// when both executions wrote an object at the same version. The owners of objects before the
+    /// transaction are only compared when both effects record them, which V1 effects don't.
+    pub fn diff(&self, other: &TransactionEffects) -> EffectsDiff {
+        let status = (self.status() != other.status())
+            .then(|| (self.status().clone(), other.status().clone()));
+        let gas = (self.gas_summary() != other.gas_summary())
+            .then(|| (self.gas_summary().clone(), other.gas_summary().clone()));
+
+        let mut changes: BTreeMap<ObjectId, (Option<_>, Option<_>)> = BTreeMap::new();
+        for change in self.object_changes() {
+            let object_id = change.object_id;
+            changes.entry(object_id).or_default().0 = Some(change);
+        }
+        for change in other.object_changes() {
+            let object_id = change.object_id;
+            changes.entry(object_id).or_default().1 = Some(change);
+        }
+        let objects = changes
+            .into_iter()
+            .filter(|(_, (left, right))| match (left, right) {
+                (Some(left), Some(right)) => !same_change(left, right),
+                _ => true,
+            })
+            .map(|(object_id, (left, right))| ObjectDiff {
+                object_id,
+                left,
+                right,
+            })
+            .collect();
+
+        EffectsDiff {
+            status,
+            gas,
+            objects,
+        }
+    }
+}
+
+fn same_change(left: &ObjectChange, right: &ObjectChange) -> bool {
+    let same_output = match (&left.output, &right.output) {
+        (Some(left), Some(right)) => {
+            left.owner == right.owner
+                && (left.reference.version() != right.reference.version()
+                    || left.reference.digest() == right.reference.digest())
+        }
+        (None, None) => true,
+        _ => false,
+    };
+    let same_input_owner = match (&left.input_owner, &right.input_owner) {
+        (Some(left), Some(right)) => left == right,
+        _ => true,
+    };
+    left.kind == right.kind && same_input_owner && same_output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::effects;
+    use crate::ChangedObject;
+    use crate::IdOperation;
+    use crate::ObjectDigest;
+    use crate::ObjectIn;
+    use crate::ObjectOut;
+    use crate::ObjectReference;
+    use crate::ObjectReferenceWithOwner;
+    use crate::Owner;
+    use crate::TransactionDigest;
+    use crate::TransactionEffectsV1;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn written(byte: u8, digest: ObjectDigest, owner: Owner) -> ChangedObject {
+        ChangedObject {
+            object_id: ObjectId::new([byte; 32]),
+            input_state: ObjectIn::Exist {
+                version: 1,
+                digest: ObjectDigest::ZERO,
+                owner,
+            },
+            output_state: ObjectOut::ObjectWrite { digest, owner },
+            id_operation: IdOperation::None,
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let owner = Owner::Address(crate::Address::TWO);
+        let digest = ObjectDigest::new([1; 32]);
+        let left = effects(
+            5,
+            vec![
+                written(0, digest, owner),
+                written(1, digest, owner),
+                written(2, digest, owner),
+            ],
+        );
+
+        // Digests of objects written at different versions aren't compared
+        let dry_run = effects(
+            6,
+            vec![
+                written(0, ObjectDigest::ZERO, owner),
+                written(1, digest, owner),
+                written(2, digest, owner),
+            ],
+        );
+        assert!(left.diff(&dry_run).is_empty());
+
+        let mut replay = effects(
+            5,
+            vec![
+                written(0, ObjectDigest::ZERO, owner),
+                written(1, digest, Owner::Immutable),
+            ],
+        );
+        let TransactionEffects::V2(effects) = &mut replay else {
+            unreachable!()
+        };
+        effects.gas_used.computation_cost = 1;
+
+        let diff = left.diff(&replay);
+        assert_eq!(diff.status, None);
+        assert!(diff.gas.is_some());
+        assert_eq!(
+            diff.objects
+                .iter()
+                .map(|object| (object.object_id, object.right.is_some()))
+                .collect::<Vec<_>>(),
+            vec![
+                (ObjectId::new([0; 32]), true),
+                (ObjectId::new([1; 32]), true),
+                (ObjectId::new([2; 32]), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_across_versions() {
+        let owner = Owner::Address(crate::Address::TWO);
+        let digest = ObjectDigest::new([1; 32]);
+        let gas = ObjectReferenceWithOwner {
+            reference: ObjectReference::new(ObjectId::new([0; 32]), 5, digest),
+            owner,
+        };
+        let v1 = TransactionEffects::V1(Box::new(TransactionEffectsV1 {
+            status: ExecutionStatus::Success,
+            epoch: 0,
+            gas_used: GasCostSummary::default(),
+            modified_at_versions: vec![],
+            shared_objects: vec![],
+            transaction_digest: TransactionDigest::ZERO,
+            created: vec![],
+            mutated: vec![gas.clone()],
+            unwrapped: vec![],
+            deleted: vec![],
+            unwrapped_then_deleted: vec![],
+            wrapped: vec![],
+            gas_object: gas,
+            events_digest: None,
+            dependencies: vec![],
+        }));
+        let v2 = effects(5, vec![written(0, digest, owner)]);
+
+        // V1 effects don't record the owner of objects before the transaction
+        assert!(v1.object_changes()[0].input_owner.is_none());
+        assert!(v1.diff(&v2).is_empty());
+        assert!(v2.diff(&v1).is_empty());
+
+        let moved = effects(5, vec![written(0, digest, Owner::Immutable)]);
+        assert_eq!(v1.diff(&moved).objects.len(), 1);
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt::Write;
+
+use super::ObjectChange;
+use super::ObjectChangeKind;
+use super::TransactionEffects;
+use crate::execution_status::ExecutionStatus;
+use crate::object::Owner;
+
+const KINDS: [(ObjectChangeKind, &str); 5] = [
+    (ObjectChangeKind::Created, "created"),
+    (ObjectChangeKind::Mutated, "mutated"),
+    (ObjectChangeKind::Deleted, "deleted"),
+    (ObjectChangeKind::Wrapped, "wrapped"),
+    (ObjectChangeKind::Unwrapped, "unwrapped"),
+];
+
+impl TransactionEffects {
+    /// Format these effects over several lines, listing the objects changed by the transaction,
+    /// like the CLI does.
+    ///
+    /// This is the alternate form of the `Display` implementation, i.e. `format!("{effects:#}")`.
+    pub fn to_pretty_string(&self) -> String {
+        format!("{self:#}")
+    }
+}
+
+/// Formats a one line summary of the effects, with the status of the transaction, the gas it
+/// used, and the number of objects it changed of each kind, e.g.
+///
+/// ```text
+/// 7c6V...: success, gas used 2000 (net 1000), 1 created, 1 mutated
+/// ```
+///
+/// The alternate form, `{:#}`, lists every object changed by the transaction over several lines,
+/// see [`TransactionEffects::to_pretty_string`].
+impl std::fmt::Display for TransactionEffects {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let changes = self.object_changes();
+        let gas = self.gas_summary();
+
+        if !f.alternate() {
+            write!(f, "{}: ", self.transaction_digest())?;
+            match self.status() {
+                ExecutionStatus::Success => f.write_str("success")?,
+                ExecutionStatus::Failure { error, command } => {
+                    write!(f, "failed with {error:?}")?;
+                    if let Some(command) = command {
+                        write!(f, " in command {command}")?;
+                    }
+                }
+            }
+            write!(
+                f,
+                ", gas used {} (net {})",
+                gas.gas_used(),
+                gas.net_gas_usage()
+            )?;
+
+            let mut any = false;
+            for (kind, name) in KINDS {
+                let count = changes.iter().filter(|change| change.kind == kind).count();
+                if count > 0 {
+                    write!(f, ", {count} {name}")?;
+                    any = true;
+                }
+            }
+            if !any {
+                f.write_str(", no object changes")?;
+            }
+            return Ok(());
+        }
+
+        writeln!(f, "Transaction: {}", self.transaction_digest())?;
+        writeln!(f, "Epoch: {}", self.epoch())?;
+        match self.status() {
+            ExecutionStatus::Success => writeln!(f, "Status: success")?,
+            ExecutionStatus::Failure { error, command } => {
+                write!(f, "Status: failed with {error:?}")?;
+                if let Some(command) = command {
+                    write!(f, " in command {command}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        writeln!(f, "Gas used: {gas} (net {})", gas.net_gas_usage())?;
+
+        let gas_object = self.gas_object();
+        if let Some(gas_object) = &gas_object {
+            writeln!(
+                f,
+                "Gas object: {} (version {}, {})",
+                gas_object.reference.object_id(),
+                gas_object.reference.version(),
+                owner(&gas_object.owner),
+            )?;
+        }
+
+        for (kind, name) in KINDS {
+            // switch to is_none_or when the API is stabilized as now it would fail in wasm tests
+            #[allow(clippy::unnecessary_map_or)]
+            let mut objects = changes
+                .iter()
+                .filter(|change| change.kind == kind)
+                .filter(|change| {
+                    gas_object
+                        .as_ref()
+                        .map_or(true, |gas| *gas.reference.object_id() != change.object_id)
+                })
+                .peekable();
+            if objects.peek().is_none() {
+                continue;
+            }
+
+            writeln!(f, "{}{}:", name[..1].to_uppercase(), &name[1..])?;
+            for change in objects {
+                writeln!(f, "  {}", object(change))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn object(change: &ObjectChange) -> String {
+    let mut line = change.object_id.to_string();
+    match &change.output {
+        Some(output) => write!(
+            line,
+            " (version {}, {})",
+            output.reference.version(),
+            owner(&output.owner)
+        ),
+        None => match change.input_version {
+            Some(version) => write!(line, " (from version {version})"),
+            None => Ok(()),
+        },
+    }
+    .expect("writing to a string cannot fail");
+    line
+}
+
+fn owner(owner: &Owner) -> String {
+    match owner {
+        Owner::Address(address) => format!("owned by {address}"),
+        Owner::Object(object_id) => format!("owned by object {object_id}"),
+        Owner::Shared(version) => format!("shared since version {version}"),
+        Owner::Immutable => "immutable".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::created;
+    use crate::test_util::effects_v2;
+    use crate::test_util::mutated;
+    use crate::GasCostSummary;
+    use crate::ObjectId;
+    use crate::TransactionDigest;
+    use crate::TransactionEffectsV2;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn display() {
+        let owner = Owner::Address(crate::Address::TWO);
+        let effects = TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            epoch: 7,
+            gas_used: GasCostSummary::new(1000, 1000, 1000, 10),
+            gas_object_index: Some(0),
+            ..effects_v2(
+                5,
+                vec![
+                    mutated(ObjectId::new([1; 32]), 1, owner),
+                    created(ObjectId::new([2; 32]), Owner::Immutable),
+                ],
+            )
+        }));
+
+        assert_eq!(
+            effects.to_string(),
+            format!(
+                "{}: success, gas used 2000 (net 1000), 1 created, 1 mutated",
+                TransactionDigest::ZERO
+            )
+        );
+
+        let pretty = effects.to_pretty_string();
+        assert!(pretty.starts_with(&format!(
+            "Transaction: {}\nEpoch: 7\nStatus: success\n",
+            TransactionDigest::ZERO
+        )));
+        assert!(pretty.contains(&format!(
+            "Gas object: {} (version 5, owned by {})\n",
+            ObjectId::new([1; 32]),
+            crate::Address::TWO
+        )));
+        assert!(pretty.contains(&format!(
+            "Created:\n  {} (version 5, immutable)\n",
+            ObjectId::new([2; 32])
+        )));
+        // The gas object isn't listed again among the mutated objects
+        assert!(!pretty.contains("Mutated:"));
+    }
+}
@@ -1,6 +1,13 @@
+mod diff;
+mod display;
+mod object_change;
 mod v1;
 mod v2;
 
+pub use diff::EffectsDiff;
+pub use diff::ObjectDiff;
+pub use object_change::ObjectChange;
+pub use object_change::ObjectChangeKind;
 pub use v1::ModifiedAtVersion;
 pub use v1::ObjectReferenceWithOwner;
 pub use v1::TransactionEffectsV1;
@@ -13,6 +20,12 @@ pub use v2::UnchangedSharedKind;
 pub use v2::UnchangedSharedObject;
 
 use crate::execution_status::ExecutionStatus;
+use crate::object::Owner;
+use crate::ObjectId;
+use crate::ObjectReference;
+use crate::TransactionDigest;
+use crate::ValidatorAggregatedSignature;
+use crate::ValidatorSignature;
 
 /// The output or effects of executing a transaction
 ///
@@ -55,6 +68,302 @@ impl TransactionEffects {
             TransactionEffects::V2(e) => e.gas_summary(),
         }
     }
+
+    /// Return the digest of the transaction these effects are for.
+    pub fn transaction_digest(&self) -> &TransactionDigest {
+        match self {
+            TransactionEffects::V1(e) => &e.transaction_digest,
+            TransactionEffects::V2(e) => &e.transaction_digest,
+        }
+    }
+
+    /// Return the digest of the events emitted by the transaction, if it emitted any.
+    pub fn events_digest(&self) -> Option<&crate::TransactionEventsDigest> {
+        match self {
+            TransactionEffects::V1(e) => e.events_digest.as_ref(),
+            TransactionEffects::V2(e) => e.events_digest.as_ref(),
+        }
+    }
+
+    /// Return the digest of the auxiliary data of the transaction, if it has any.
+    ///
+    /// V1 effects have no auxiliary data.
+    pub fn auxiliary_data_digest(&self) -> Option<&crate::EffectsAuxiliaryDataDigest> {
+        match self {
+            TransactionEffects::V1(_) => None,
+            TransactionEffects::V2(e) => e.auxiliary_data_digest.as_ref(),
+        }
+    }
+
+    /// Return the digests of the transactions this transaction depends on.
+    pub fn dependencies(&self) -> &[TransactionDigest] {
+        match self {
+            TransactionEffects::V1(e) => &e.dependencies,
+            TransactionEffects::V2(e) => &e.dependencies,
+        }
+    }
+
+    /// Return the lamport version of the transaction, i.e. the version of every object it wrote.
+    ///
+    /// V1 effects don't record it, so it's the highest version of the objects they wrote, the
+    /// gas object included.
+    pub fn lamport_version(&self) -> crate::Version {
+        match self {
+            TransactionEffects::V1(e) => e
+                .created
+                .iter()
+                .chain(&e.mutated)
+                .chain(&e.unwrapped)
+                .chain(std::iter::once(&e.gas_object))
+                .map(|object| object.reference.version())
+                .max()
+                .unwrap_or_default(),
+            TransactionEffects::V2(e) => e.lamport_version,
+        }
+    }
+
+    /// Return the shared objects the transaction took as input but didn't change.
+    ///
+    /// V1 effects don't record them, so it's always empty for them.
+    pub fn unchanged_shared_objects(&self) -> &[UnchangedSharedObject] {
+        match self {
+            TransactionEffects::V1(_) => &[],
+            TransactionEffects::V2(e) => &e.unchanged_shared_objects,
+        }
+    }
+
+    /// Return the references and owners of the objects created by the transaction.
+    pub fn created_objects(&self) -> Vec<ObjectReferenceWithOwner> {
+        match self {
+            TransactionEffects::V1(e) => e.created.clone(),
+            TransactionEffects::V2(e) => e
+                .changed_objects
+                .iter()
+                .filter(|change| change.id_operation == IdOperation::Created)
+                .filter_map(|change| written_object(e.lamport_version, change))
+                .collect(),
+        }
+    }
+
+    /// Return the reference and owner of the gas object after the transaction, if it paid for gas.
+    ///
+    /// System transactions don't use a gas object: V2 effects record none, and V1 effects record a
+    /// placeholder with the zero object id, for which `None` is returned as well.
+    pub fn gas_object(&self) -> Option<ObjectReferenceWithOwner> {
+        match self {
+            TransactionEffects::V1(e) => Some(e.gas_object.clone())
+                .filter(|gas| *gas.reference.object_id() != ObjectId::ZERO),
+            TransactionEffects::V2(e) => e
+                .gas_object_index
+                .and_then(|index| e.changed_objects.get(index as usize))
+                .and_then(|change| written_object(e.lamport_version, change)),
+        }
+    }
+
+    /// Return the references and owners of the objects mutated by the transaction, at their new
+    /// versions, the gas object included.
+    pub fn mutated_objects(&self) -> Vec<ObjectReferenceWithOwner> {
+        match self {
+            TransactionEffects::V1(e) => e.mutated.clone(),
+            TransactionEffects::V2(e) => e
+                .changed_objects
+                .iter()
+                .filter(|change| matches!(change.input_state, ObjectIn::Exist { .. }))
+                .filter_map(|change| written_object(e.lamport_version, change))
+                .collect(),
+        }
+    }
+
+    /// Return the references and owners of the objects mutated by the transaction, like
+    /// [`TransactionEffects::mutated_objects`], without the gas object.
+    pub fn mutated_objects_excluding_gas(&self) -> Vec<ObjectReferenceWithOwner> {
+        let gas = self.gas_object();
+        let mut mutated = self.mutated_objects();
+        mutated.retain(|object| Some(object) != gas.as_ref());
+        mutated
+    }
+
+    /// Return the references and owners of the objects written by the transaction, i.e. the
+    /// objects it created, mutated or unwrapped, at their new versions.
+    pub fn written_objects(&self) -> Vec<ObjectReferenceWithOwner> {
+        match self {
+            TransactionEffects::V1(e) => e
+                .created
+                .iter()
+                .chain(&e.mutated)
+                .chain(&e.unwrapped)
+                .cloned()
+                .collect(),
+            TransactionEffects::V2(e) => e
+                .changed_objects
+                .iter()
+                .filter_map(|change| written_object(e.lamport_version, change))
+                .collect(),
+        }
+    }
+}
+
+/// The reference and owner of an object written by a transaction, at its new version.
+fn written_object(
+    lamport_version: crate::Version,
+    change: &ChangedObject,
+) -> Option<ObjectReferenceWithOwner> {
+    let (version, digest, owner) = match &change.output_state {
+        ObjectOut::NotExist => return None,
+        ObjectOut::ObjectWrite { digest, owner } => (lamport_version, *digest, *owner),
+        ObjectOut::PackageWrite { version, digest } => (*version, *digest, Owner::Immutable),
+    };
+
+    Some(ObjectReferenceWithOwner {
+        reference: ObjectReference::new(change.object_id, version, digest),
+        owner,
+    })
+}
+
+/// Data generated along with the effects of a transaction which isn't critical to the protocol,
+/// and is stored separately from them to keep effects small. Effects commit to it with their
+/// `auxiliary_data_digest`, its [`digest`](EffectsAuxiliaryData::digest).
+///
+/// No version of the protocol produces auxiliary data yet, so its contents are kept as opaque
+/// bytes, to be archived as they are.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// effects-auxiliary-data = %x00 bytes ; v1
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub enum EffectsAuxiliaryData {
+    V1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::_serde::ReadableBase64Encoded")
+        )]
+        contents: Vec<u8>,
+    },
+}
+
+/// Transaction effects signed by a single validator.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// signed-transaction-effects = transaction-effects validator-signature
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct SignedTransactionEffects {
+    pub effects: TransactionEffects,
+    pub signature: ValidatorSignature,
+}
+
+/// Transaction effects certified by a quorum of the validator committee.
+///
+/// An effects certificate proves the finality of a transaction as soon as it is formed, without
+/// waiting for the transaction to be included in a checkpoint.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// certified-transaction-effects = transaction-effects validator-aggregated-signature
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct CertifiedTransactionEffects {
+    pub effects: TransactionEffects,
+    pub signature: ValidatorAggregatedSignature,
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+impl TransactionEffects {
+    /// Decode BCS encoded effects reported by an untrusted party, e.g. the `rawEffects` returned
+    /// by a wallet after executing a transaction, checking that they belong to `transaction`.
+    ///
+    /// This only guarantees that the effects claim to be for `transaction`; it does not check
+    /// that they were certified by the validator committee.
+    pub fn from_reported_bytes(
+        bytes: &[u8],
+        transaction: &crate::Transaction,
+    ) -> Result<Self, ReportedEffectsError> {
+        let effects: Self = bcs::from_bytes(bytes).map_err(ReportedEffectsError::InvalidBcs)?;
+
+        let expected = transaction.digest();
+        let actual = *effects.transaction_digest();
+        if expected != actual {
+            return Err(ReportedEffectsError::TransactionDigestMismatch { expected, actual });
+        }
+
+        Ok(effects)
+    }
+}
+
+/// Error returned when reported effects can't be trusted to belong to a transaction.
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+#[derive(Debug)]
+pub enum ReportedEffectsError {
+    /// The reported bytes aren't valid BCS encoded `TransactionEffects`.
+    InvalidBcs(bcs::Error),
+
+    /// The effects are for a different transaction.
+    TransactionDigestMismatch {
+        expected: TransactionDigest,
+        actual: TransactionDigest,
+    },
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::fmt::Display for ReportedEffectsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBcs(e) => write!(f, "invalid effects bcs: {e}"),
+            Self::TransactionDigestMismatch { expected, actual } => write!(
+                f,
+                "effects are for transaction {actual}, expected transaction {expected}"
+            ),
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl crate::error::ErrorCode for ReportedEffectsError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidBcs(_) => "invalid_effects_bcs",
+            Self::TransactionDigestMismatch { .. } => "effects_transaction_digest_mismatch",
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::error::Error for ReportedEffectsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidBcs(e) => Some(e),
+            Self::TransactionDigestMismatch { .. } => None,
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -142,6 +451,7 @@ mod serialization {
     #[cfg(test)]
     mod tests {
         use super::TransactionEffects;
+        use crate::ObjectChangeKind;
 
         use base64ct::Base64;
         use base64ct::Encoding;
@@ -149,11 +459,15 @@ mod serialization {
         #[cfg(target_arch = "wasm32")]
         use wasm_bindgen_test::wasm_bindgen_test as test;
 
+        const GENESIS_EFFECTS: &str = include_str!("fixtures/genesis-transaction-effects");
+        const PYTH_WORMHOLE_V2: &str = include_str!("fixtures/pyth-wormhole-v2");
+
+        fn fixture(fixture: &str) -> TransactionEffects {
+            bcs::from_bytes(&Base64::decode_vec(fixture.trim()).unwrap()).unwrap()
+        }
+
         #[test]
         fn effects_fixtures() {
-            const GENESIS_EFFECTS: &str = include_str!("fixtures/genesis-transaction-effects");
-            const PYTH_WORMHOLE_V2: &str = include_str!("fixtures/pyth-wormhole-v2");
-
             for fixture in [GENESIS_EFFECTS, PYTH_WORMHOLE_V2] {
                 let fixture = Base64::decode_vec(fixture.trim()).unwrap();
                 let fx: TransactionEffects = bcs::from_bytes(&fixture).unwrap();
@@ -164,5 +478,99 @@ mod serialization {
                 assert_eq!(fx, serde_json::from_str(&json).unwrap());
             }
         }
+
+        #[test]
+        fn dependencies_and_lamport_version() {
+            for (fx, dependencies, lamport_version) in [
+                (fixture(GENESIS_EFFECTS), 0, 1),
+                (fixture(PYTH_WORMHOLE_V2), 6, 92030322),
+            ] {
+                for object in fx.written_objects() {
+                    assert_eq!(object.reference.version(), fx.lamport_version());
+                }
+                assert_eq!(fx.dependencies().len(), dependencies);
+                assert_eq!(fx.lamport_version(), lamport_version);
+            }
+        }
+
+        #[test]
+        fn fixture_object_changes() {
+            for (fx, kind) in [
+                (fixture(GENESIS_EFFECTS), ObjectChangeKind::Created),
+                (fixture(PYTH_WORMHOLE_V2), ObjectChangeKind::Mutated),
+            ] {
+                let changes = fx.object_changes();
+                assert_eq!(changes.len(), fx.written_objects().len());
+                assert!(changes.iter().all(|change| change.kind == kind));
+            }
+        }
+
+        #[test]
+        fn gas_and_mutated_objects() {
+            // Genesis doesn't pay for gas
+            let fx = fixture(GENESIS_EFFECTS);
+            assert_eq!(fx.gas_object(), None);
+            assert_eq!(fx.mutated_objects(), fx.mutated_objects_excluding_gas());
+
+            let fx = fixture(PYTH_WORMHOLE_V2);
+            let gas = fx.gas_object().unwrap();
+            let mutated = fx.mutated_objects();
+            assert!(mutated.contains(&gas));
+            let excluding_gas = fx.mutated_objects_excluding_gas();
+            assert!(!excluding_gas.contains(&gas));
+            assert_eq!(excluding_gas.len() + 1, mutated.len());
+        }
+
+        #[test]
+        fn auxiliary_data() {
+            use super::super::EffectsAuxiliaryData;
+
+            let data = EffectsAuxiliaryData::V1 {
+                contents: vec![1, 2, 3],
+            };
+            let bytes = bcs::to_bytes(&data).unwrap();
+            assert_eq!(bytes, [0, 3, 1, 2, 3]);
+            assert_eq!(
+                bcs::from_bytes::<EffectsAuxiliaryData>(&bytes).unwrap(),
+                data
+            );
+
+            let json = serde_json::to_string(&data).unwrap();
+            assert_eq!(json, r#"{"v1":{"contents":"AQID"}}"#);
+            assert_eq!(
+                serde_json::from_str::<EffectsAuxiliaryData>(&json).unwrap(),
+                data
+            );
+        }
+
+        #[cfg(feature = "hash")]
+        #[test]
+        fn reported_effects() {
+            use crate::Transaction;
+
+            const GENESIS_TRANSACTION: &str =
+                include_str!("../transaction/fixtures/genesis-transaction");
+
+            let decode = |s: &str| Base64::decode_vec(s.trim()).unwrap();
+            let genesis: Transaction = bcs::from_bytes(&decode(GENESIS_TRANSACTION)).unwrap();
+
+            let fx = TransactionEffects::from_reported_bytes(&decode(GENESIS_EFFECTS), &genesis)
+                .unwrap();
+            assert_eq!(fx.transaction_digest(), &genesis.digest());
+            assert!(!fx.created_objects().is_empty());
+
+            // Effects for a different transaction are rejected
+            let err = TransactionEffects::from_reported_bytes(&decode(PYTH_WORMHOLE_V2), &genesis)
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                super::super::ReportedEffectsError::TransactionDigestMismatch { .. }
+            ));
+
+            // As are bytes that aren't effects
+            let mut bytes = decode(GENESIS_EFFECTS);
+            bytes.push(0);
+            TransactionEffects::from_reported_bytes(&bytes, &genesis).unwrap_err();
+        }
     }
 }
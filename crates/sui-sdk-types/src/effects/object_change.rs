@@ -0,0 +1,234 @@
+use super::IdOperation;
+use super::ObjectIn;
+use super::ObjectReferenceWithOwner;
+use super::TransactionEffects;
+use super::TransactionEffectsV1;
+use super::TransactionEffectsV2;
+use crate::object::Owner;
+use crate::ObjectId;
+use crate::ObjectReference;
+use crate::Version;
+
+/// What happened to an object during the execution of a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ObjectChangeKind {
+    /// The object was created.
+    Created,
+
+    /// The object existed before the transaction and was written to.
+    Mutated,
+
+    /// The object was deleted, either from the root level or after being unwrapped.
+    Deleted,
+
+    /// The object was wrapped into another object, and so no longer exists at the root level.
+    Wrapped,
+
+    /// The object was extracted from the object it was wrapped in.
+    Unwrapped,
+}
+
+/// A change to an object made by a transaction, as returned by
+/// [`TransactionEffects::object_changes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct ObjectChange {
+    pub object_id: ObjectId,
+
+    pub kind: ObjectChangeKind,
+
+    /// The version of the object before the transaction, if it existed at the root level.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::_serde::OptionReadableDisplay")
+    )]
+    pub input_version: Option<Version>,
+
+    /// The owner of the object before the transaction, if it existed at the root level.
+    ///
+    /// V1 effects don't record it, so it's always `None` for them.
+    pub input_owner: Option<Owner>,
+
+    /// The reference and owner of the object after the transaction, if it still exists at the
+    /// root level.
+    pub output: Option<ObjectReferenceWithOwner>,
+}
+
+impl TransactionEffects {
+    /// Return the changes the transaction made to objects, the gas object included.
+    ///
+    /// Objects created and wrapped by the same transaction never existed at the root level and
+    /// aren't included.
+    pub fn object_changes(&self) -> Vec<ObjectChange> {
+        match self {
+            TransactionEffects::V1(e) => v1_object_changes(e),
+            TransactionEffects::V2(e) => v2_object_changes(e),
+        }
+    }
+}
+
+fn v1_object_changes(effects: &TransactionEffectsV1) -> Vec<ObjectChange> {
+    let input_version = |object_id: &ObjectId| {
+        effects
+            .modified_at_versions
+            .iter()
+            .find(|modified| &modified.object_id == object_id)
+            .map(|modified| modified.version)
+    };
+    let written = |kind, objects: &[ObjectReferenceWithOwner]| {
+        objects
+            .iter()
+            .map(move |object| ObjectChange {
+                object_id: *object.reference.object_id(),
+                kind,
+                input_version: match kind {
+                    ObjectChangeKind::Mutated => input_version(object.reference.object_id()),
+                    _ => None,
+                },
+                input_owner: None,
+                output: Some(object.clone()),
+            })
+            .collect::<Vec<_>>()
+    };
+    let removed = |kind, objects: &[ObjectReference], existed: bool| {
+        objects
+            .iter()
+            .map(move |object| ObjectChange {
+                object_id: *object.object_id(),
+                kind,
+                input_version: existed.then(|| input_version(object.object_id())).flatten(),
+                input_owner: None,
+                output: None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut changes = written(ObjectChangeKind::Created, &effects.created);
+    changes.extend(written(ObjectChangeKind::Mutated, &effects.mutated));
+    changes.extend(written(ObjectChangeKind::Unwrapped, &effects.unwrapped));
+    changes.extend(removed(ObjectChangeKind::Deleted, &effects.deleted, true));
+    changes.extend(removed(
+        ObjectChangeKind::Deleted,
+        &effects.unwrapped_then_deleted,
+        false,
+    ));
+    changes.extend(removed(ObjectChangeKind::Wrapped, &effects.wrapped, true));
+    changes
+}
+
+fn v2_object_changes(effects: &TransactionEffectsV2) -> Vec<ObjectChange> {
+    effects
+        .changed_objects
+        .iter()
+        .filter_map(|change| {
+            let (input_version, input_owner) = match &change.input_state {
+                ObjectIn::NotExist => (None, None),
+                ObjectIn::Exist { version, owner, .. } => (Some(*version), Some(*owner)),
+            };
+            let output = super::written_object(effects.lamport_version, change);
+
+            let kind = match (
+                input_version.is_some(),
+                output.is_some(),
+                change.id_operation,
+            ) {
+                (_, _, IdOperation::Deleted) => ObjectChangeKind::Deleted,
+                (false, true, IdOperation::Created) => ObjectChangeKind::Created,
+                (false, true, IdOperation::None) => ObjectChangeKind::Unwrapped,
+                (true, true, _) => ObjectChangeKind::Mutated,
+                (true, false, _) => ObjectChangeKind::Wrapped,
+                // Created then wrapped, never existing at the root level
+                (false, false, _) => return None,
+            };
+
+            Some(ObjectChange {
+                object_id: change.object_id,
+                kind,
+                input_version,
+                input_owner,
+                output,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::effects;
+    use crate::ChangedObject;
+    use crate::ObjectDigest;
+    use crate::ObjectOut;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn change(byte: u8, existed: bool, exists: bool, id_operation: IdOperation) -> ChangedObject {
+        ChangedObject {
+            object_id: ObjectId::new([byte; 32]),
+            input_state: if existed {
+                ObjectIn::Exist {
+                    version: 3,
+                    digest: ObjectDigest::ZERO,
+                    owner: Owner::Immutable,
+                }
+            } else {
+                ObjectIn::NotExist
+            },
+            output_state: if exists {
+                ObjectOut::ObjectWrite {
+                    digest: ObjectDigest::ZERO,
+                    owner: Owner::Shared(1),
+                }
+            } else {
+                ObjectOut::NotExist
+            },
+            id_operation,
+        }
+    }
+
+    #[test]
+    fn v2_object_changes() {
+        let effects = effects(
+            7,
+            vec![
+                change(0, false, true, IdOperation::Created),
+                change(1, true, true, IdOperation::None),
+                change(2, true, false, IdOperation::Deleted),
+                change(3, true, false, IdOperation::None),
+                change(4, false, true, IdOperation::None),
+                change(5, false, false, IdOperation::Deleted),
+                change(6, false, false, IdOperation::Created),
+            ],
+        );
+
+        let changes = effects.object_changes();
+        assert_eq!(
+            changes.iter().map(|change| change.kind).collect::<Vec<_>>(),
+            [
+                ObjectChangeKind::Created,
+                ObjectChangeKind::Mutated,
+                ObjectChangeKind::Deleted,
+                ObjectChangeKind::Wrapped,
+                ObjectChangeKind::Unwrapped,
+                ObjectChangeKind::Deleted,
+            ]
+        );
+
+        let mutated = &changes[1];
+        assert_eq!(mutated.input_version, Some(3));
+        assert_eq!(mutated.input_owner, Some(Owner::Immutable));
+        let output = mutated.output.as_ref().unwrap();
+        assert_eq!(output.reference.version(), 7);
+        assert_eq!(output.owner, Owner::Shared(1));
+        assert_eq!(changes[3].output, None);
+    }
+}
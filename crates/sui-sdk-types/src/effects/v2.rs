@@ -169,6 +169,48 @@ pub enum UnchangedSharedKind {
     PerEpochConfig,
 }
 
+impl UnchangedSharedKind {
+    /// Return the version of the object the transaction was executed against, if any.
+    ///
+    /// For deleted objects this is the version at which they were deleted, and for canceled
+    /// transactions a sentinel version recording the reason of the cancellation.
+    pub fn version(&self) -> Option<Version> {
+        match self {
+            Self::ReadOnlyRoot { version, .. }
+            | Self::MutateDeleted { version }
+            | Self::ReadDeleted { version }
+            | Self::Canceled { version } => Some(*version),
+            Self::PerEpochConfig => None,
+        }
+    }
+
+    /// Return the digest of the object read by the transaction, if it was read at the root level.
+    pub fn digest(&self) -> Option<&ObjectDigest> {
+        match self {
+            Self::ReadOnlyRoot { digest, .. } => Some(digest),
+            _ => None,
+        }
+    }
+
+    /// Whether the object had already been deleted when the transaction was executed.
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, Self::MutateDeleted { .. } | Self::ReadDeleted { .. })
+    }
+
+    /// Whether the transaction was canceled, e.g. because of shared object congestion.
+    pub fn is_canceled(&self) -> bool {
+        matches!(self, Self::Canceled { .. })
+    }
+
+    /// The reason the transaction was canceled, if it was.
+    pub fn cancellation_reason(&self) -> Option<crate::CancellationReason> {
+        match self {
+            Self::Canceled { version } => crate::CancellationReason::from_version(*version),
+            _ => None,
+        }
+    }
+}
+
 /// State of an object prior to execution
 ///
 /// If an object exists (at root-level) in the store prior to this transaction,
@@ -274,6 +316,11 @@ impl TransactionEffectsV2 {
     pub fn gas_summary(&self) -> &GasCostSummary {
         &self.gas_used
     }
+
+    /// The shared objects which were read, but not changed, by this transaction.
+    pub fn unchanged_shared_objects(&self) -> &[UnchangedSharedObject] {
+        &self.unchanged_shared_objects
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -779,3 +826,42 @@ mod serialization {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn unchanged_shared_kind() {
+        let read = UnchangedSharedKind::ReadOnlyRoot {
+            version: 3,
+            digest: ObjectDigest::ZERO,
+        };
+        assert_eq!(read.version(), Some(3));
+        assert_eq!(read.digest(), Some(&ObjectDigest::ZERO));
+        assert!(!read.is_deleted());
+
+        let deleted = UnchangedSharedKind::ReadDeleted { version: 4 };
+        assert_eq!(deleted.version(), Some(4));
+        assert_eq!(deleted.digest(), None);
+        assert!(deleted.is_deleted());
+        assert!(UnchangedSharedKind::MutateDeleted { version: 4 }.is_deleted());
+
+        let canceled = UnchangedSharedKind::Canceled { version: u64::MAX };
+        assert!(canceled.is_canceled());
+        assert!(!canceled.is_deleted());
+        assert_eq!(canceled.cancellation_reason(), None);
+        let congested = UnchangedSharedKind::Canceled {
+            version: crate::CancellationReason::Congested.version(),
+        };
+        assert_eq!(
+            congested.cancellation_reason(),
+            Some(crate::CancellationReason::Congested)
+        );
+
+        assert_eq!(UnchangedSharedKind::PerEpochConfig.version(), None);
+    }
+}
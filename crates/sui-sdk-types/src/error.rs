@@ -0,0 +1,123 @@
+//! Machine-readable codes for the errors of the SDK.
+//!
+//! Every error type of the SDK implements [`ErrorCode`], which identifies the kind of error with a
+//! stable, `snake_case` code that doesn't change with the wording of its message. With the `serde`
+//! feature enabled, errors also implement `serde::Serialize` as a struct with `code` and
+//! `message` fields, e.g. for services returning them as JSON:
+//!
+//! ```json
+//! { "code": "invalid_address", "message": "Unable to parse Address ..." }
+//! ```
+//!
+//! The one exception is the `SignatureError` of `sui-crypto`, which is the error type of the
+//! `signature` crate and so can't implement traits of the SDK.
+
+/// An error identified by a stable, machine-readable code.
+pub trait ErrorCode: std::fmt::Display {
+    /// The code identifying the kind of this error, e.g. `invalid_address`.
+    ///
+    /// Codes are `snake_case` and stable across releases, unlike the messages of errors.
+    fn code(&self) -> &'static str;
+}
+
+/// Serialize `error` as a struct with its `code` and its `message`.
+///
+/// This is how the errors of the SDK implement `serde::Serialize`, and can be used to do the same
+/// for other errors implementing [`ErrorCode`].
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub fn serialize<E, S>(error: &E, serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: ErrorCode + ?Sized,
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeStruct;
+
+    let mut state = serializer.serialize_struct("Error", 2)?;
+    state.serialize_field("code", error.code())?;
+    state.serialize_field("message", &error.to_string())?;
+    state.end()
+}
+
+#[cfg(feature = "serde")]
+macro_rules! impl_serialize {
+    ($($error:ty),* $(,)?) => {
+        $(
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+            impl serde::Serialize for $error {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize(self, serializer)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_serialize!(
+    crate::AddressParseError,
+    crate::Bn254FieldElementParseError,
+    crate::token::AmountParseError,
+    crate::DigestParseError,
+    crate::EventDecodeError,
+    crate::InvalidGasPayment,
+    crate::InvalidMultisigCommittee,
+    crate::InvalidSenderSignedData,
+    crate::InvalidSystemTransaction,
+    crate::InvalidTransaction,
+    crate::KeytoolMultisigError,
+    crate::move_value::MoveValueError,
+    crate::TypeParseError,
+    crate::postcondition::PostconditionViolation,
+    crate::PublishIssue,
+    crate::serialization_profile::SerializationError,
+);
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl_serialize!(
+    crate::CheckpointChainError,
+    crate::EventsDigestMismatch,
+    crate::genesis::GenesisError,
+    crate::ObjectProofError,
+    crate::ReportedEffectsError,
+    crate::qr::TransactionPartError,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn codes() {
+        assert_eq!(crate::AddressParseError.code(), "invalid_address");
+        assert_eq!(
+            crate::token::AmountParseError::TooManyDecimals { decimals: 9 }.code(),
+            "too_many_decimals"
+        );
+        assert_eq!(
+            "not a type".parse::<crate::TypeTag>().unwrap_err().code(),
+            "invalid_type_tag"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_as_json() {
+        let json = serde_json::to_value(crate::DigestParseError).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "invalid_digest",
+                "message": crate::DigestParseError.to_string(),
+            })
+        );
+
+        let violation = crate::postcondition::PostconditionViolation::Deleted(vec![]);
+        let json = serde_json::to_value(&violation).unwrap();
+        assert_eq!(json["code"], "objects_deleted");
+        assert_eq!(json["message"], "0 objects were deleted");
+    }
+}
@@ -0,0 +1,182 @@
+//! Ordering and deduplication of events received from unreliable sources.
+//!
+//! Subscriptions which reconnect, or queries retried from an earlier cursor, can deliver the same
+//! event more than once and deliver events out of order. An [`EventSequencer`] buffers such
+//! events and releases each of them exactly once, checkpoint by checkpoint, once the caller knows
+//! that every event of a checkpoint has been received, e.g. because the source moved past it.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::CheckpointSequenceNumber;
+use crate::TransactionDigest;
+
+/// The identifier of an event: the transaction emitting it and its index among the events of
+/// the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct EventId {
+    #[cfg_attr(feature = "serde", serde(rename = "txDigest"))]
+    pub transaction_digest: TransactionDigest,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub event_seq: u64,
+}
+
+/// A buffer releasing events in order and without duplicates.
+///
+/// Events are released by checkpoint, in increasing order. Within a checkpoint, transactions are
+/// released by their position in the checkpoint, and the events of a transaction by index.
+#[derive(Clone, Debug)]
+pub struct EventSequencer<T> {
+    pending: BTreeMap<CheckpointSequenceNumber, PendingCheckpoint<T>>,
+    pending_ids: BTreeSet<EventId>,
+    released_through: Option<CheckpointSequenceNumber>,
+}
+
+/// The events of a checkpoint, by position and digest of their transaction, then by index.
+type PendingCheckpoint<T> = BTreeMap<(u64, TransactionDigest), BTreeMap<u64, T>>;
+
+impl<T> Default for EventSequencer<T> {
+    fn default() -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            pending_ids: BTreeSet::new(),
+            released_through: None,
+        }
+    }
+}
+
+impl<T> EventSequencer<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffer `event`, emitted in `checkpoint` by the transaction at `transaction_index` among the
+    /// transactions of the checkpoint, until the checkpoint is released.
+    ///
+    /// Return `false`, dropping the event, if it is already buffered or if its checkpoint was
+    /// already released.
+    pub fn insert(
+        &mut self,
+        checkpoint: CheckpointSequenceNumber,
+        transaction_index: u64,
+        id: EventId,
+        event: T,
+    ) -> bool {
+        if self
+            .released_through
+            .is_some_and(|released| checkpoint <= released)
+            || !self.pending_ids.insert(id)
+        {
+            return false;
+        }
+
+        self.pending
+            .entry(checkpoint)
+            .or_default()
+            .entry((transaction_index, id.transaction_digest))
+            .or_default()
+            .insert(id.event_seq, event);
+        true
+    }
+
+    /// Release, in order, the events of every checkpoint up to `checkpoint` included, which the
+    /// caller guarantees were all received.
+    ///
+    /// Events of these checkpoints inserted later are dropped.
+    pub fn release(&mut self, checkpoint: CheckpointSequenceNumber) -> Vec<(EventId, T)> {
+        if self
+            .released_through
+            .is_some_and(|released| checkpoint <= released)
+        {
+            return Vec::new();
+        }
+        self.released_through = Some(checkpoint);
+
+        let later = match checkpoint.checked_add(1) {
+            Some(next) => self.pending.split_off(&next),
+            None => BTreeMap::new(),
+        };
+        let released = std::mem::replace(&mut self.pending, later);
+
+        let mut events = Vec::new();
+        for (_, pending) in released {
+            for ((_, transaction_digest), transaction) in pending {
+                for (event_seq, event) in transaction {
+                    let id = EventId {
+                        transaction_digest,
+                        event_seq,
+                    };
+                    self.pending_ids.remove(&id);
+                    events.push((id, event));
+                }
+            }
+        }
+        events
+    }
+
+    /// The number of events buffered, waiting for their checkpoint to be released.
+    pub fn pending(&self) -> usize {
+        self.pending_ids.len()
+    }
+
+    /// The last checkpoint released, if any.
+    pub fn released_through(&self) -> Option<CheckpointSequenceNumber> {
+        self.released_through
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn id(byte: u8, event_seq: u64) -> EventId {
+        EventId {
+            transaction_digest: TransactionDigest::new([byte; 32]),
+            event_seq,
+        }
+    }
+
+    #[test]
+    fn event_sequencer() {
+        let mut sequencer = EventSequencer::new();
+
+        // Delivered out of order, with a reconnection replaying some events
+        assert!(sequencer.insert(2, 0, id(9, 0), "c"));
+        assert!(sequencer.insert(1, 1, id(0, 0), "a2"));
+        assert!(sequencer.insert(1, 0, id(5, 1), "b"));
+        assert!(sequencer.insert(1, 0, id(5, 0), "a"));
+        assert!(!sequencer.insert(1, 0, id(5, 1), "b"));
+        assert!(sequencer.insert(3, 0, id(1, 0), "d"));
+        assert_eq!(sequencer.pending(), 5);
+
+        assert_eq!(
+            sequencer.release(2),
+            vec![
+                (id(5, 0), "a"),
+                (id(5, 1), "b"),
+                (id(0, 0), "a2"),
+                (id(9, 0), "c"),
+            ]
+        );
+        assert_eq!(sequencer.released_through(), Some(2));
+        assert_eq!(sequencer.pending(), 1);
+
+        // Events of released checkpoints are dropped
+        assert!(!sequencer.insert(2, 0, id(9, 0), "c"));
+        assert!(!sequencer.insert(1, 2, id(7, 0), "late"));
+        assert!(sequencer.release(1).is_empty());
+
+        assert_eq!(sequencer.release(3), vec![(id(1, 0), "d")]);
+        assert_eq!(sequencer.pending(), 0);
+    }
+}
@@ -60,6 +60,212 @@ pub struct Event {
     pub contents: Vec<u8>,
 }
 
+/// A Rust type mirroring a Move struct emitted as an event, which events can be decoded into
+/// with [`Event::decode_as`].
+///
+/// ```
+/// use sui_sdk_types::MoveEvent;
+/// use sui_sdk_types::StructTag;
+///
+/// #[derive(serde_derive::Deserialize)]
+/// struct PriceUpdated {
+///     price: u64,
+/// }
+///
+/// impl MoveEvent for PriceUpdated {
+///     fn struct_tag() -> StructTag {
+///         "0x42::oracle::PriceUpdated".parse().unwrap()
+///     }
+/// }
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub trait MoveEvent: serde::de::DeserializeOwned {
+    /// The type of the Move struct.
+    fn struct_tag() -> StructTag;
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Event {
+    /// Check that this event has the type `expected` and decode its BCS contents as a `T`.
+    pub fn decode<T: serde::de::DeserializeOwned>(
+        &self,
+        expected: &StructTag,
+    ) -> Result<T, EventDecodeError> {
+        if self.type_ != *expected {
+            return Err(EventDecodeError::TypeMismatch {
+                expected: Box::new(expected.clone()),
+                actual: Box::new(self.type_.clone()),
+            });
+        }
+        bcs::from_bytes(&self.contents)
+            .map_err(|e| EventDecodeError::Deserialization(e.to_string()))
+    }
+
+    /// Decode this event as the Move event `T` mirrors, checking that it has its type.
+    pub fn decode_as<T: MoveEvent>(&self) -> Result<T, EventDecodeError> {
+        self.decode(&T::struct_tag())
+    }
+}
+
+/// Error returned when an [`Event`] can't be decoded as a Rust type.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventDecodeError {
+    /// The event doesn't have the expected type.
+    TypeMismatch {
+        expected: Box<StructTag>,
+        actual: Box<StructTag>,
+    },
+    /// The contents of the event couldn't be deserialized.
+    Deserialization(String),
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for EventDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeMismatch { expected, actual } => {
+                write!(f, "event type mismatch: expected {expected}, got {actual}")
+            }
+            Self::Deserialization(e) => write!(f, "invalid event contents: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::error::ErrorCode for EventDecodeError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::TypeMismatch { .. } => "event_type_mismatch",
+            Self::Deserialization(_) => "invalid_event_contents",
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for EventDecodeError {}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+impl TransactionEvents {
+    /// Verify that these are the events committed to by `effects`, e.g. when ingesting events
+    /// from an untrusted source, once the effects themselves have been verified.
+    ///
+    /// Effects of transactions which didn't emit any event don't record an events digest, so the
+    /// events are then expected to be empty.
+    pub fn verify(&self, effects: &crate::TransactionEffects) -> Result<(), EventsDigestMismatch> {
+        let expected = effects.events_digest().copied();
+        let actual = (!self.0.is_empty()).then(|| self.digest());
+        if expected != actual {
+            return Err(EventsDigestMismatch { expected, actual });
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when events aren't the ones committed to by the effects of their transaction.
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventsDigestMismatch {
+    /// The events digest recorded in the effects, if any.
+    pub expected: Option<crate::TransactionEventsDigest>,
+    /// The digest of the events, if there are any.
+    pub actual: Option<crate::TransactionEventsDigest>,
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::fmt::Display for EventsDigestMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => {
+                write!(f, "events digest is {actual}, expected {expected}")
+            }
+            (Some(expected), None) => write!(f, "missing events, expected digest {expected}"),
+            (None, _) => write!(f, "unexpected events, the effects don't record any"),
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl crate::error::ErrorCode for EventsDigestMismatch {
+    fn code(&self) -> &'static str {
+        "events_digest_mismatch"
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+impl std::error::Error for EventsDigestMismatch {}
+
+/// A filter selecting events, e.g. to query or subscribe to them.
+///
+/// Its JSON form is the one of the filters of the JSON-RPC API, e.g.
+/// `{"MoveModule":{"package":"0x2","module":"coin"}}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub enum EventFilter {
+    /// Events emitted by transactions sent by the given address.
+    Sender(Address),
+
+    /// Events emitted by functions of the given package, i.e. whose [`Event::package_id`] is
+    /// the package.
+    Package(ObjectId),
+
+    /// Events emitted by functions of the given module.
+    MoveModule {
+        package: ObjectId,
+        module: Identifier,
+    },
+
+    /// Events of the given type.
+    ///
+    /// A type without type parameters matches every instantiation of a generic type, e.g.
+    /// `0x2::coin::CoinEvent` matches `0x2::coin::CoinEvent<0x2::sui::SUI>`, while a type with
+    /// type parameters only matches that instantiation.
+    MoveEventType(StructTag),
+
+    /// Events emitted by transactions executed from `start_time`, included, to `end_time`,
+    /// excluded, both in milliseconds since the unix epoch.
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+    TimeRange {
+        #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+        start_time: u64,
+        #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+        end_time: u64,
+    },
+}
+
+impl EventFilter {
+    /// Whether `event`, emitted by a transaction executed at `timestamp_ms`, is selected by this
+    /// filter.
+    pub fn matches(&self, event: &Event, timestamp_ms: u64) -> bool {
+        match self {
+            Self::Sender(sender) => event.sender == *sender,
+            Self::Package(package) => event.package_id == *package,
+            Self::MoveModule { package, module } => {
+                event.package_id == *package && event.module == *module
+            }
+            Self::MoveEventType(type_) if type_.type_params.is_empty() => {
+                event.type_.address == type_.address
+                    && event.type_.module == type_.module
+                    && event.type_.name == type_.name
+            }
+            Self::MoveEventType(type_) => event.type_ == *type_,
+            Self::TimeRange {
+                start_time,
+                end_time,
+            } => (*start_time..*end_time).contains(&timestamp_ms),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(
     feature = "serde",
@@ -79,3 +285,157 @@ pub struct BalanceChange {
     #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
     pub amount: i128,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn event_filter() {
+        let event = Event {
+            package_id: ObjectId::from(Address::TWO),
+            module: "pay".parse().unwrap(),
+            sender: Address::THREE,
+            type_: "0x2::coin::CoinEvent<0x2::sui::SUI>".parse().unwrap(),
+            contents: vec![],
+        };
+
+        let selected = [
+            EventFilter::Sender(Address::THREE),
+            EventFilter::Package(ObjectId::from(Address::TWO)),
+            EventFilter::MoveModule {
+                package: ObjectId::from(Address::TWO),
+                module: "pay".parse().unwrap(),
+            },
+            EventFilter::MoveEventType("0x2::coin::CoinEvent".parse().unwrap()),
+            EventFilter::MoveEventType("0x2::coin::CoinEvent<0x2::sui::SUI>".parse().unwrap()),
+            EventFilter::TimeRange {
+                start_time: 10,
+                end_time: 11,
+            },
+        ];
+        for filter in selected {
+            assert!(filter.matches(&event, 10), "{filter:?}");
+        }
+
+        let rejected = [
+            EventFilter::Sender(Address::TWO),
+            EventFilter::MoveModule {
+                package: ObjectId::from(Address::TWO),
+                module: "coin".parse().unwrap(),
+            },
+            EventFilter::MoveEventType("0x2::coin::Other".parse().unwrap()),
+            EventFilter::MoveEventType("0x2::coin::CoinEvent<u64>".parse().unwrap()),
+            EventFilter::TimeRange {
+                start_time: 0,
+                end_time: 10,
+            },
+        ];
+        for filter in rejected {
+            assert!(!filter.matches(&event, 10), "{filter:?}");
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "hash"))]
+    #[test]
+    fn verify_events() {
+        let events = TransactionEvents(vec![Event {
+            package_id: ObjectId::from(Address::TWO),
+            module: "pay".parse().unwrap(),
+            sender: Address::THREE,
+            type_: "0x2::coin::CoinEvent<0x2::sui::SUI>".parse().unwrap(),
+            contents: vec![1, 2, 3],
+        }]);
+        let effects = |events_digest| {
+            crate::TransactionEffects::V2(Box::new(crate::TransactionEffectsV2 {
+                events_digest,
+                ..crate::test_util::effects_v2(1, vec![])
+            }))
+        };
+
+        events.verify(&effects(Some(events.digest()))).unwrap();
+        TransactionEvents(vec![]).verify(&effects(None)).unwrap();
+
+        let mut tampered = events.clone();
+        tampered.0[0].contents[0] = 0;
+        assert_eq!(
+            tampered.verify(&effects(Some(events.digest()))),
+            Err(EventsDigestMismatch {
+                expected: Some(events.digest()),
+                actual: Some(tampered.digest()),
+            })
+        );
+        assert!(TransactionEvents(vec![])
+            .verify(&effects(Some(events.digest())))
+            .is_err());
+        assert!(events.verify(&effects(None)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn decode() {
+        #[derive(Debug, PartialEq, serde_derive::Deserialize)]
+        struct PriceUpdated {
+            feed: Address,
+            price: u64,
+        }
+
+        impl MoveEvent for PriceUpdated {
+            fn struct_tag() -> StructTag {
+                "0x42::oracle::PriceUpdated".parse().unwrap()
+            }
+        }
+
+        let mut event = Event {
+            package_id: ObjectId::from(Address::TWO),
+            module: "oracle".parse().unwrap(),
+            sender: Address::THREE,
+            type_: PriceUpdated::struct_tag(),
+            contents: bcs::to_bytes(&(Address::TWO, 1_000u64)).unwrap(),
+        };
+        assert_eq!(
+            event.decode_as::<PriceUpdated>(),
+            Ok(PriceUpdated {
+                feed: Address::TWO,
+                price: 1_000
+            })
+        );
+
+        event.contents.push(0);
+        assert!(matches!(
+            event.decode_as::<PriceUpdated>(),
+            Err(EventDecodeError::Deserialization(_))
+        ));
+
+        event.type_ = "0x42::oracle::Other".parse().unwrap();
+        assert!(matches!(
+            event.decode_as::<PriceUpdated>(),
+            Err(EventDecodeError::TypeMismatch { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn event_filter_json() {
+        let filter: EventFilter =
+            serde_json::from_str(r#"{"MoveModule":{"package":"0x2","module":"coin"}}"#).unwrap();
+        assert_eq!(
+            filter,
+            EventFilter::MoveModule {
+                package: ObjectId::from(Address::TWO),
+                module: "coin".parse().unwrap(),
+            }
+        );
+
+        let filter = EventFilter::TimeRange {
+            start_time: 1,
+            end_time: 2,
+        };
+        let json = serde_json::to_string(&filter).unwrap();
+        assert_eq!(json, r#"{"TimeRange":{"startTime":"1","endTime":"2"}}"#);
+        assert_eq!(serde_json::from_str::<EventFilter>(&json).unwrap(), filter);
+    }
+}
@@ -34,6 +34,29 @@ pub enum ExecutionStatus {
     },
 }
 
+impl ExecutionStatus {
+    /// Whether the transaction executed successfully.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    /// The error the transaction failed with, if it failed.
+    pub fn error(&self) -> Option<&ExecutionError> {
+        match self {
+            Self::Success => None,
+            Self::Failure { error, .. } => Some(error),
+        }
+    }
+
+    /// The index of the command the transaction failed in, if it failed during a command.
+    pub fn command(&self) -> Option<u64> {
+        match self {
+            Self::Success => None,
+            Self::Failure { command, .. } => *command,
+        }
+    }
+}
+
 /// An error that can occur during the execution of a transaction
 ///
 /// # BCS
@@ -258,6 +281,113 @@ pub enum ExecutionError {
     ExecutionCanceledDueToRandomnessUnavailable,
 }
 
+impl ExecutionError {
+    /// The location and abort code of a Move abort, if this error is one.
+    ///
+    /// The location identifies the module which aborted, and the function within it when known,
+    /// which together with the code is what a program needs to map the abort to one of the error
+    /// constants of the module.
+    pub fn move_abort(&self) -> Option<(&MoveLocation, u64)> {
+        match self {
+            Self::MoveAbort { location, code } => Some((location, *code)),
+            _ => None,
+        }
+    }
+
+    /// The coarse category of this error, e.g. to decide whether to resubmit the transaction.
+    pub fn category(&self) -> ExecutionErrorCategory {
+        use ExecutionErrorCategory as Category;
+
+        match self {
+            Self::InsufficientGas => Category::OutOfGas,
+            Self::MoveAbort { .. } => Category::MoveAbort,
+            Self::MovePrimitiveRuntimeError { .. } => Category::MoveRuntimeError,
+            Self::InvalidGasObject
+            | Self::CircularObjectOwnership { .. }
+            | Self::InsufficientCoinBalance
+            | Self::CoinBalanceOverflow
+            | Self::FunctionNotFound
+            | Self::ArityMismatch
+            | Self::TypeArityMismatch
+            | Self::NonEntryFunctionInvoked
+            | Self::CommandArgumentError { .. }
+            | Self::TypeArgumentError { .. }
+            | Self::UnusedValueWithoutDrop { .. }
+            | Self::InvalidPublicFunctionReturnType { .. }
+            | Self::InvalidTransferObject
+            | Self::SharedObjectOperationNotAllowed
+            | Self::InputObjectDeleted => Category::InvalidInput,
+            Self::ObjectTooBig { .. }
+            | Self::EffectsTooLarge { .. }
+            | Self::WrittenObjectsTooLarge { .. } => Category::LimitExceeded,
+            Self::PackageTooBig { .. }
+            | Self::PublishErrorNonZeroAddress
+            | Self::SuiMoveVerificationError
+            | Self::SuiMoveVerificationTimedout => Category::PublishError,
+            Self::PublishUpgradeMissingDependency
+            | Self::PublishUpgradeDependencyDowngrade
+            | Self::PackageUpgradeError { .. } => Category::PackageUpgradeError,
+            Self::CertificateDenied
+            | Self::AddressDeniedForCoin { .. }
+            | Self::CoinTypeGlobalPause { .. } => Category::Denied,
+            Self::ExecutionCanceledDueToSharedObjectCongestion { .. } => {
+                Category::SharedObjectCongestion
+            }
+            Self::ExecutionCanceledDueToRandomnessUnavailable => Category::RandomnessUnavailable,
+            Self::InvariantViolation
+            | Self::FeatureNotYetSupported
+            | Self::VmVerificationOrDeserializationError
+            | Self::VmInvariantViolation => Category::Internal,
+        }
+    }
+}
+
+/// A coarse classification of [`ExecutionError`]s, as returned by [`ExecutionError::category`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionErrorCategory {
+    /// The gas budget was exhausted; the transaction may succeed with a larger budget.
+    OutOfGas,
+    /// A Move function aborted, see [`ExecutionError::move_abort`].
+    MoveAbort,
+    /// A Move runtime error, like an arithmetic overflow or an out of bounds vector access.
+    MoveRuntimeError,
+    /// The transaction is invalid, e.g. a command got an argument of the wrong type, or an input
+    /// object can't be used like it is.
+    InvalidInput,
+    /// The transaction exceeded a limit on the size of the objects or effects it produced.
+    LimitExceeded,
+    /// A package couldn't be published.
+    PublishError,
+    /// A package couldn't be upgraded.
+    PackageUpgradeError,
+    /// The transaction was denied, e.g. by the deny list of a regulated coin.
+    Denied,
+    /// The transaction was canceled before execution because of congestion on its shared
+    /// objects.
+    SharedObjectCongestion,
+    /// The transaction was canceled before execution because randomness couldn't be generated.
+    RandomnessUnavailable,
+    /// An internal error of the execution layer.
+    Internal,
+}
+
+impl ExecutionErrorCategory {
+    /// Whether retrying the transaction later may succeed without changing what it does.
+    ///
+    /// This is only the case for transactions canceled before execution because of the state of
+    /// the network. A canceled transaction is still final, and its gas objects are still charged
+    /// and bumped to a new version, so resubmitting the same signed transaction only returns the
+    /// same effects: the retry must be rebuilt against the new versions of its inputs and signed
+    /// again. Transactions running out of gas may succeed with a larger budget, but aren't
+    /// considered transient.
+    pub fn is_transient(self) -> bool {
+        matches!(
+            self,
+            Self::SharedObjectCongestion | Self::RandomnessUnavailable
+        )
+    }
+}
+
 /// Location in move bytecode where an error occurred
 ///
 /// # BCS
@@ -1513,3 +1643,38 @@ mod serialization {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn error_category() {
+        let congestion = ExecutionError::ExecutionCanceledDueToSharedObjectCongestion {
+            congested_objects: vec![ObjectId::ZERO],
+        };
+        assert_eq!(
+            congestion.category(),
+            ExecutionErrorCategory::SharedObjectCongestion
+        );
+        assert!(congestion.category().is_transient());
+
+        assert_eq!(
+            ExecutionError::InsufficientGas.category(),
+            ExecutionErrorCategory::OutOfGas
+        );
+        assert!(!ExecutionError::InsufficientGas.category().is_transient());
+
+        let argument_error = ExecutionError::CommandArgumentError {
+            argument: 0,
+            kind: CommandArgumentError::TypeMismatch,
+        };
+        assert_eq!(
+            argument_error.category(),
+            ExecutionErrorCategory::InvalidInput
+        );
+    }
+}
@@ -3,12 +3,14 @@
 use super::Object;
 use super::ObjectId;
 use super::TypeTag;
+use super::Version;
 use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct Coin<'a> {
     coin_type: Cow<'a, TypeTag>,
     id: ObjectId,
+    version: Version,
     balance: u64,
 }
 
@@ -21,10 +23,27 @@ impl<'a> Coin<'a> {
         &self.id
     }
 
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
     pub fn balance(&self) -> u64 {
         self.balance
     }
 
+    /// Whether this is a coin of SUI, the gas coin.
+    pub fn is_sui_coin(&self) -> bool {
+        match self.coin_type.as_ref() {
+            TypeTag::Struct(tag) => {
+                tag.address == super::Address::TWO
+                    && tag.module.as_str() == "sui"
+                    && tag.name.as_str() == "SUI"
+                    && tag.type_params.is_empty()
+            }
+            _ => false,
+        }
+    }
+
     pub fn try_from_object(object: &'a Object) -> Option<Self> {
         match &object.data {
             super::ObjectData::Struct(move_struct) => {
@@ -42,6 +61,7 @@ impl<'a> Coin<'a> {
                 Some(Self {
                     coin_type: Cow::Borrowed(coin_type),
                     id,
+                    version: move_struct.version(),
                     balance,
                 })
             }
@@ -53,7 +73,55 @@ impl<'a> Coin<'a> {
         Coin {
             coin_type: Cow::Owned(self.coin_type.into_owned()),
             id: self.id,
+            version: self.version,
             balance: self.balance,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MoveStruct;
+    use crate::ObjectData;
+    use crate::Owner;
+    use crate::StructTag;
+    use crate::TransactionDigest;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn object(type_: StructTag, contents: Vec<u8>) -> Object {
+        Object::new(
+            ObjectData::Struct(MoveStruct::new(type_, true, 3, contents).unwrap()),
+            Owner::Immutable,
+            TransactionDigest::ZERO,
+            0,
+        )
+    }
+
+    #[test]
+    fn coin() {
+        let mut contents = vec![7; ObjectId::LENGTH];
+        contents.extend(1_000u64.to_le_bytes());
+
+        let gas = object(StructTag::gas_coin(), contents.clone());
+        let coin = Coin::try_from_object(&gas).unwrap();
+        assert_eq!(coin.id(), &ObjectId::new([7; 32]));
+        assert_eq!(coin.version(), 3);
+        assert_eq!(coin.balance(), 1_000);
+        assert!(coin.is_sui_coin());
+
+        let usdc = object(
+            StructTag::coin("0xa::usdc::USDC".parse().unwrap()),
+            contents.clone(),
+        );
+        let coin = Coin::try_from_object(&usdc).unwrap().into_owned();
+        assert_eq!(coin.coin_type(), &"0xa::usdc::USDC".parse().unwrap());
+        assert!(!coin.is_sui_coin());
+
+        assert!(Coin::try_from_object(&object(StructTag::staked_sui(), contents)).is_none());
+        let truncated = object(StructTag::gas_coin(), vec![7; ObjectId::LENGTH]);
+        assert!(Coin::try_from_object(&truncated).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+//! Fuzzing harnesses for the parsers of untrusted input exposed by this crate.
+//!
+//! Each function takes arbitrary bytes, feeds them to a public parsing entry point and, when
+//! parsing succeeds, checks that the parsed value survives a round trip through its serialized
+//! form. Invalid input is expected and ignored; a panic means a bug was found. The harnesses are
+//! meant to be called from [cargo-fuzz] targets:
+//!
+//! ```ignore
+//! #![no_main]
+//!
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| sui_sdk_types::fuzz::transaction(data));
+//! ```
+//!
+//! [cargo-fuzz]: https://github.com/rust-fuzz/cargo-fuzz
+
+use crate::SignatureScheme;
+use crate::Transaction;
+use crate::TypeTag;
+use crate::UserSignature;
+
+/// Decode `data` as a BCS serialized [`Transaction`].
+pub fn transaction(data: &[u8]) {
+    let Ok(transaction) = bcs::from_bytes::<Transaction>(data) else {
+        return;
+    };
+    let bytes = bcs::to_bytes(&transaction).expect("serializing a decoded transaction failed");
+    assert_eq!(bcs::from_bytes::<Transaction>(&bytes).unwrap(), transaction);
+}
+
+/// Parse `data` as the flag-prefixed bytes of a [`UserSignature`].
+pub fn user_signature(data: &[u8]) {
+    let Ok(signature) = UserSignature::from_bytes(data) else {
+        return;
+    };
+    assert_eq!(
+        UserSignature::from_bytes(&signature.to_bytes()).unwrap(),
+        signature
+    );
+}
+
+/// Parse `data` as the serialized form of a
+/// [`ZkLoginAuthenticator`](crate::ZkLoginAuthenticator), without its signature scheme flag.
+pub fn zklogin_authenticator(data: &[u8]) {
+    let mut bytes = Vec::with_capacity(data.len() + 1);
+    bytes.push(SignatureScheme::ZkLogin.to_u8());
+    bytes.extend_from_slice(data);
+
+    let Ok(signature) = UserSignature::from_bytes(&bytes) else {
+        return;
+    };
+    assert!(matches!(signature, UserSignature::ZkLogin(_)));
+    assert_eq!(
+        UserSignature::from_bytes(&signature.to_bytes()).unwrap(),
+        signature
+    );
+}
+
+/// Parse `data`, if it is valid UTF-8, as the string form of a [`TypeTag`].
+pub fn type_tag(data: &[u8]) {
+    let Some(type_tag) = std::str::from_utf8(data)
+        .ok()
+        .and_then(|s| s.parse::<TypeTag>().ok())
+    else {
+        return;
+    };
+    assert_eq!(type_tag.to_string().parse::<TypeTag>().unwrap(), type_tag);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn harnesses_accept_arbitrary_input() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            &[0xff; 64],
+            &[0x00; 300],
+            b"0x2::coin::Coin<0x2::sui::SUI>",
+            b"vector<vector<u8>",
+        ];
+        for input in inputs {
+            transaction(input);
+            user_signature(input);
+            zklogin_authenticator(input);
+            type_tag(input);
+        }
+    }
+}
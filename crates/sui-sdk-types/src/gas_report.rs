@@ -0,0 +1,315 @@
+//! Aggregation of gas charges across many transactions, e.g. for accounting exports.
+//!
+//! A [`GasReport`] sums the [`GasCostSummary`] of each transaction added to it, grouped by the
+//! address paying for gas and by UTC day, and can be rolled up per address, per day or overall.
+//! Totals are kept as `u128` so that summing the charges of any number of transactions can't
+//! overflow.
+
+use std::collections::BTreeMap;
+
+use crate::Address;
+use crate::CheckpointData;
+use crate::CheckpointTimestamp;
+use crate::GasCostSummary;
+use crate::Transaction;
+use crate::TransactionEffects;
+
+/// The number of milliseconds in a day.
+const MILLISECONDS_PER_DAY: u64 = 86_400_000;
+
+/// A UTC day, counted from the unix epoch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct Day(#[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))] u64);
+
+impl Day {
+    pub fn new(days_since_epoch: u64) -> Self {
+        Self(days_since_epoch)
+    }
+
+    /// The day a checkpoint with the given timestamp was created on.
+    pub fn from_timestamp_ms(timestamp_ms: CheckpointTimestamp) -> Self {
+        Self(timestamp_ms / MILLISECONDS_PER_DAY)
+    }
+
+    /// The number of days since the unix epoch.
+    pub fn days_since_epoch(&self) -> u64 {
+        self.0
+    }
+
+    /// The timestamp, in milliseconds, of the start of the day.
+    pub fn start_timestamp_ms(&self) -> u128 {
+        u128::from(self.0) * u128::from(MILLISECONDS_PER_DAY)
+    }
+}
+
+impl std::fmt::Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The gas charges of a number of transactions, summed up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct GasTotals {
+    /// The number of transactions the charges were summed over.
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub transactions: u64,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub computation_cost: u128,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub storage_cost: u128,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub storage_rebate: u128,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub non_refundable_storage_fee: u128,
+}
+
+impl GasTotals {
+    /// Add the charges of a single transaction.
+    pub fn add(&mut self, gas: &GasCostSummary) {
+        self.transactions += 1;
+        self.computation_cost += u128::from(gas.computation_cost);
+        self.storage_cost += u128::from(gas.storage_cost);
+        self.storage_rebate += u128::from(gas.storage_rebate);
+        self.non_refundable_storage_fee += u128::from(gas.non_refundable_storage_fee);
+    }
+
+    /// Add up the charges of two sets of transactions.
+    pub fn merge(&mut self, other: &GasTotals) {
+        self.transactions += other.transactions;
+        self.computation_cost += other.computation_cost;
+        self.storage_cost += other.storage_cost;
+        self.storage_rebate += other.storage_rebate;
+        self.non_refundable_storage_fee += other.non_refundable_storage_fee;
+    }
+
+    /// The total gas used by the transactions, excluding storage rebates:
+    /// `computation_cost + storage_cost`.
+    pub fn gas_used(&self) -> u128 {
+        self.computation_cost + self.storage_cost
+    }
+
+    /// The amount deducted from the gas coins of the transactions:
+    /// `computation_cost + storage_cost - storage_rebate`, which is negative when more storage was
+    /// refunded than charged.
+    pub fn net_gas_usage(&self) -> i128 {
+        // Totals are sums of u64s and so fit in an i128
+        self.gas_used() as i128 - self.storage_rebate as i128
+    }
+}
+
+impl std::fmt::Display for GasTotals {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "transactions: {}, computation_cost: {}, storage_cost: {}, storage_rebate: {}, non_refundable_storage_fee: {}",
+            self.transactions,
+            self.computation_cost,
+            self.storage_cost,
+            self.storage_rebate,
+            self.non_refundable_storage_fee,
+        )
+    }
+}
+
+/// The gas charges of the transactions paid for by `address` on `day`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct GasReportEntry {
+    pub address: Address,
+    pub day: Day,
+    pub totals: GasTotals,
+}
+
+/// Gas charges summed per paying address and per day.
+///
+/// Transactions are attributed to the owner of their gas payment, which is their sender unless
+/// they are sponsored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GasReport {
+    totals: BTreeMap<(Address, Day), GasTotals>,
+}
+
+impl GasReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no transaction has been added to the report.
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Add the charges of a transaction paid for by `address`, in a checkpoint created at
+    /// `timestamp_ms`.
+    pub fn add(
+        &mut self,
+        address: Address,
+        timestamp_ms: CheckpointTimestamp,
+        gas: &GasCostSummary,
+    ) {
+        self.totals
+            .entry((address, Day::from_timestamp_ms(timestamp_ms)))
+            .or_default()
+            .add(gas);
+    }
+
+    /// Add the charges of a transaction from its effects, in a checkpoint created at
+    /// `timestamp_ms`.
+    pub fn add_transaction(
+        &mut self,
+        transaction: &Transaction,
+        effects: &TransactionEffects,
+        timestamp_ms: CheckpointTimestamp,
+    ) {
+        self.add(
+            transaction.gas_payment.owner,
+            timestamp_ms,
+            effects.gas_summary(),
+        );
+    }
+
+    /// Add the charges of every transaction of a checkpoint.
+    pub fn add_checkpoint(&mut self, checkpoint: &CheckpointData) {
+        let timestamp_ms = checkpoint.checkpoint_summary.checkpoint.timestamp_ms;
+        for transaction in &checkpoint.transactions {
+            self.add_transaction(
+                &transaction.transaction.transaction,
+                &transaction.effects,
+                timestamp_ms,
+            );
+        }
+    }
+
+    /// The charges of each address on each day, ordered by address then day.
+    pub fn entries(&self) -> impl Iterator<Item = GasReportEntry> + '_ {
+        self.totals
+            .iter()
+            .map(|(&(address, day), &totals)| GasReportEntry {
+                address,
+                day,
+                totals,
+            })
+    }
+
+    /// The charges of each address, over all days.
+    pub fn by_address(&self) -> BTreeMap<Address, GasTotals> {
+        let mut by_address = BTreeMap::<_, GasTotals>::new();
+        for (&(address, _), totals) in &self.totals {
+            by_address.entry(address).or_default().merge(totals);
+        }
+        by_address
+    }
+
+    /// The charges of each day, over all addresses.
+    pub fn by_day(&self) -> BTreeMap<Day, GasTotals> {
+        let mut by_day = BTreeMap::<_, GasTotals>::new();
+        for (&(_, day), totals) in &self.totals {
+            by_day.entry(day).or_default().merge(totals);
+        }
+        by_day
+    }
+
+    /// The charges of every transaction in the report.
+    pub fn total(&self) -> GasTotals {
+        let mut total = GasTotals::default();
+        for totals in self.totals.values() {
+            total.merge(totals);
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn gas_report() {
+        let alice = Address::TWO;
+        let bob = Address::THREE;
+        let day = MILLISECONDS_PER_DAY;
+
+        let mut report = GasReport::new();
+        assert!(report.is_empty());
+        report.add(alice, 10, &GasCostSummary::new(100, 50, 20, 1));
+        report.add(alice, day - 1, &GasCostSummary::new(100, 0, 40, 2));
+        report.add(alice, day, &GasCostSummary::new(u64::MAX, 0, 0, 0));
+        report.add(bob, day + 10, &GasCostSummary::new(300, 10, 0, 0));
+
+        let entries: Vec<_> = report.entries().collect();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            GasReportEntry {
+                address: alice,
+                day: Day::new(0),
+                totals: GasTotals {
+                    transactions: 2,
+                    computation_cost: 200,
+                    storage_cost: 50,
+                    storage_rebate: 60,
+                    non_refundable_storage_fee: 3,
+                },
+            }
+        );
+        assert_eq!(entries[0].totals.net_gas_usage(), 190);
+
+        let by_address = report.by_address();
+        assert_eq!(by_address[&alice].transactions, 3);
+        assert_eq!(
+            by_address[&alice].computation_cost,
+            u128::from(u64::MAX) + 200
+        );
+        assert_eq!(by_address[&bob].gas_used(), 310);
+
+        let by_day = report.by_day();
+        assert_eq!(by_day[&Day::new(1)].transactions, 2);
+        assert_eq!(Day::new(1).start_timestamp_ms(), u128::from(day));
+
+        let total = report.total();
+        assert_eq!(total.transactions, 4);
+        assert_eq!(total.storage_rebate, 60);
+        assert_eq!(total.non_refundable_storage_fee, 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn export() {
+        let mut report = GasReport::new();
+        report.add(Address::TWO, 0, &GasCostSummary::new(1, 2, 3, 4));
+        let entry = report.entries().next().unwrap();
+        assert_eq!(
+            serde_json::to_value(entry).unwrap(),
+            serde_json::json!({
+                "address": Address::TWO.to_string(),
+                "day": "0",
+                "totals": {
+                    "transactions": "1",
+                    "computation_cost": "1",
+                    "storage_cost": "2",
+                    "storage_rebate": "3",
+                    "non_refundable_storage_fee": "4",
+                },
+            })
+        );
+    }
+}
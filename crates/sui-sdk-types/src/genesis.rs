@@ -0,0 +1,593 @@
+//! Parsing and verification of the genesis of a network.
+//!
+//! The genesis blob distributed with the configuration of a network holds the first checkpoint,
+//! the transaction initializing the chain state and its outputs. Light clients use it as their
+//! root of trust: once the genesis checkpoint is known to be the one of the network, its
+//! committee can be used to verify the checkpoints of the first epoch, and so on.
+//!
+//! ```no_run
+//! use sui_sdk_types::genesis::Genesis;
+//! use sui_sdk_types::token::Network;
+//!
+//! let bytes = std::fs::read("genesis.blob").unwrap();
+//! let genesis = Genesis::from_bytes(&bytes).unwrap();
+//! genesis.verify_for_network(Network::Mainnet).unwrap();
+//! let committee = genesis.committee().unwrap();
+//! ```
+
+use crate::network::NetworkMetadata;
+use crate::token::Network;
+use crate::Bls12381PublicKey;
+use crate::CheckpointChainError;
+use crate::CheckpointContents;
+use crate::CheckpointDigest;
+use crate::CheckpointSummary;
+use crate::GenesisObject;
+use crate::GenesisTransaction;
+use crate::Object;
+use crate::SenderSignedData;
+use crate::SignedCheckpointSummary;
+use crate::TransactionEffects;
+use crate::TransactionEvents;
+use crate::TransactionKind;
+use crate::ValidatorCommittee;
+use crate::ValidatorCommitteeMember;
+
+/// The digest of the genesis checkpoint of `network`, if it is a long-lived network.
+///
+/// Devnet and localnets are regularly wiped and don't have a stable genesis.
+pub fn genesis_checkpoint_digest(network: Network) -> Option<CheckpointDigest> {
+    NetworkMetadata::for_network(network).genesis_checkpoint_digest()
+}
+
+/// The genesis of a network, as found in its genesis blob.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// genesis = signed-checkpoint-summary checkpoint-contents sender-signed-data
+///           transaction-effects transaction-events (vector object)
+/// ```
+#[derive(Clone, Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct Genesis {
+    /// The genesis checkpoint, certified by the genesis committee.
+    pub checkpoint: SignedCheckpointSummary,
+    pub checkpoint_contents: CheckpointContents,
+    /// The genesis transaction.
+    pub transaction: SenderSignedData,
+    pub effects: TransactionEffects,
+    pub events: TransactionEvents,
+    /// The objects written by the genesis transaction.
+    pub objects: Vec<Object>,
+}
+
+impl Genesis {
+    /// Deserialize the contents of a genesis blob.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, GenesisError> {
+        bcs::from_bytes(bytes).map_err(|e| GenesisError::Deserialization(e.to_string()))
+    }
+
+    /// The genesis checkpoint.
+    pub fn checkpoint(&self) -> &CheckpointSummary {
+        &self.checkpoint.checkpoint
+    }
+
+    /// The digest of the genesis checkpoint, which identifies the network.
+    pub fn checkpoint_digest(&self) -> CheckpointDigest {
+        self.checkpoint().digest()
+    }
+
+    /// The chain identifier of the network, i.e. the hex encoding of the first 4 bytes of the
+    /// digest of its genesis checkpoint, e.g. `35834a8a` for mainnet.
+    pub fn chain_id(&self) -> String {
+        hex::encode(&self.checkpoint_digest().inner()[..4])
+    }
+
+    /// The genesis transaction, initializing the chain state.
+    pub fn genesis_transaction(&self) -> Result<&GenesisTransaction, GenesisError> {
+        let transaction = self
+            .transaction
+            .validate()
+            .map_err(|_| GenesisError::NotGenesisTransaction)?;
+        match &transaction.transaction.kind {
+            TransactionKind::Genesis(genesis) => Ok(genesis),
+            _ => Err(GenesisError::NotGenesisTransaction),
+        }
+    }
+
+    /// The objects initializing the chain state.
+    pub fn genesis_objects(&self) -> Result<&[GenesisObject], GenesisError> {
+        self.genesis_transaction()
+            .map(|genesis| genesis.objects.as_slice())
+    }
+
+    /// Check that the parts of this genesis are consistent: the checkpoint is the first of the
+    /// chain and commits to the genesis transaction, along with its effects and events.
+    ///
+    /// This doesn't check that this is the genesis of any particular network, see
+    /// [`Genesis::verify_for_network`].
+    pub fn verify(&self) -> Result<(), GenesisError> {
+        let checkpoint = self.checkpoint();
+        if checkpoint.sequence_number != 0
+            || checkpoint.epoch != 0
+            || checkpoint.previous_digest.is_some()
+            || self.checkpoint.signature.epoch != 0
+        {
+            return Err(GenesisError::NotGenesisCheckpoint);
+        }
+
+        self.genesis_transaction()?;
+        let transaction = self.transaction.transactions()[0].transaction.digest();
+        if *self.effects.transaction_digest() != transaction {
+            return Err(GenesisError::TransactionMismatch);
+        }
+        let info = checkpoint
+            .prove_inclusion(&self.checkpoint_contents, &transaction)
+            .map_err(GenesisError::Contents)?
+            .ok_or(GenesisError::TransactionMismatch)?;
+        if info.effects != self.effects.digest() {
+            return Err(GenesisError::TransactionMismatch);
+        }
+
+        if self.effects.events_digest() != Some(&self.events.digest()) {
+            return Err(GenesisError::EventsMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// [`Genesis::verify`] this genesis, and check that it is the one of `network`.
+    pub fn verify_for_network(&self, network: Network) -> Result<(), GenesisError> {
+        let expected =
+            genesis_checkpoint_digest(network).ok_or(GenesisError::UnknownNetwork(network))?;
+        let actual = self.checkpoint_digest();
+        if actual != expected {
+            return Err(GenesisError::DigestMismatch { expected, actual });
+        }
+        self.verify()
+    }
+
+    /// The committee of the first epoch, read from the system state initialized by the genesis
+    /// transaction.
+    pub fn committee(&self) -> Result<ValidatorCommittee, GenesisError> {
+        let system_state = self
+            .objects
+            .iter()
+            .filter_map(Object::as_struct)
+            .find(|object| {
+                let type_ = object.object_type();
+                type_.address == crate::Address::TWO
+                    && type_.module.as_str() == "dynamic_field"
+                    && type_.type_params.iter().any(|param| {
+                        matches!(
+                            param,
+                            crate::TypeTag::Struct(inner)
+                                if inner.address == crate::Address::THREE
+                                    && inner.module.as_str() == "sui_system_state_inner"
+                        )
+                    })
+            })
+            .ok_or(GenesisError::MissingSystemState)?;
+
+        system_state::committee(system_state.contents()).ok_or(GenesisError::InvalidSystemState)
+    }
+}
+
+/// Decoding of the committee from the BCS serialized `Field<u64, SuiSystemStateInner>` storing
+/// the system state, following the layouts of the Sui framework.
+mod system_state {
+    use super::*;
+
+    struct Reader<'a>(&'a [u8]);
+
+    impl<'a> Reader<'a> {
+        fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+            if self.0.len() < len {
+                return None;
+            }
+            let (bytes, rest) = self.0.split_at(len);
+            self.0 = rest;
+            Some(bytes)
+        }
+
+        fn u8(&mut self) -> Option<u8> {
+            self.bytes(1).map(|bytes| bytes[0])
+        }
+
+        fn u64(&mut self) -> Option<u64> {
+            self.bytes(8)
+                .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        }
+
+        fn length(&mut self) -> Option<usize> {
+            let mut length = 0usize;
+            for shift in (0..32).step_by(7) {
+                let byte = self.u8()?;
+                length |= usize::from(byte & 0x7f) << shift;
+                if byte & 0x80 == 0 {
+                    return Some(length);
+                }
+            }
+            None
+        }
+
+        /// A `vector<u8>`, or a `String` or `Url` which are represented the same way.
+        fn vector(&mut self) -> Option<&'a [u8]> {
+            let length = self.length()?;
+            self.bytes(length)
+        }
+
+        fn option(&mut self, read: impl FnOnce(&mut Self) -> Option<()>) -> Option<()> {
+            match self.u8()? {
+                0 => Some(()),
+                1 => read(self),
+                _ => None,
+            }
+        }
+
+        /// An `ID`, `UID` or `address`.
+        fn id(&mut self) -> Option<()> {
+            self.bytes(32).map(|_| ())
+        }
+
+        /// A `Bag` or a `Table`: their `UID` and their size.
+        fn collection(&mut self) -> Option<()> {
+            self.id()?;
+            self.u64().map(|_| ())
+        }
+
+        fn skip_u64s(&mut self, count: usize) -> Option<()> {
+            self.bytes(8 * count).map(|_| ())
+        }
+    }
+
+    pub(super) fn committee(contents: &[u8]) -> Option<ValidatorCommittee> {
+        let mut reader = Reader(contents);
+
+        // Field { id, name: u64, value: SuiSystemStateInner { epoch, protocol_version,
+        // system_state_version, validators: ValidatorSet { total_stake, active_validators, .. },
+        // .. } }
+        reader.id()?;
+        reader.u64()?;
+        let epoch = reader.u64()?;
+        reader.skip_u64s(3)?;
+
+        let count = reader.length()?;
+        let members = (0..count)
+            .map(|_| validator(&mut reader))
+            .collect::<Option<_>>()?;
+        Some(ValidatorCommittee { epoch, members })
+    }
+
+    fn validator(reader: &mut Reader<'_>) -> Option<ValidatorCommitteeMember> {
+        // ValidatorMetadata { sui_address, protocol_pubkey_bytes, network_pubkey_bytes,
+        // worker_pubkey_bytes, proof_of_possession, name, description, image_url, project_url,
+        // net_address, p2p_address, primary_address, worker_address, 8 next epoch options,
+        // extra_fields }
+        reader.id()?;
+        let public_key = Bls12381PublicKey::from_bytes(reader.vector()?).ok()?;
+        for _ in 0..11 {
+            reader.vector()?;
+        }
+        for _ in 0..8 {
+            reader.option(|reader| reader.vector().map(|_| ()))?;
+        }
+        reader.collection()?;
+
+        // Validator { metadata, voting_power, operation_cap_id, gas_price, staking_pool,
+        // commission_rate, next_epoch_stake, next_epoch_gas_price, next_epoch_commission_rate,
+        // extra_fields }
+        let stake = reader.u64()?;
+        reader.id()?;
+        reader.u64()?;
+
+        // StakingPool { id, activation_epoch, deactivation_epoch, sui_balance, rewards_pool,
+        // pool_token_balance, exchange_rates, pending_stake, pending_total_sui_withdraw,
+        // pending_pool_token_withdraw, extra_fields }
+        reader.id()?;
+        reader.option(|reader| reader.u64().map(|_| ()))?;
+        reader.option(|reader| reader.u64().map(|_| ()))?;
+        reader.skip_u64s(3)?;
+        reader.collection()?;
+        reader.skip_u64s(3)?;
+        reader.collection()?;
+
+        reader.skip_u64s(4)?;
+        reader.collection()?;
+
+        Some(ValidatorCommitteeMember { public_key, stake })
+    }
+}
+
+/// Error returned when a genesis is malformed, or isn't the one expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GenesisError {
+    /// The genesis blob couldn't be deserialized.
+    Deserialization(String),
+    /// The checkpoint isn't the first checkpoint of a chain.
+    NotGenesisCheckpoint,
+    /// The transaction isn't a single genesis transaction.
+    NotGenesisTransaction,
+    /// The contents don't match the checkpoint.
+    Contents(CheckpointChainError),
+    /// The checkpoint doesn't commit to the transaction and its effects.
+    TransactionMismatch,
+    /// The effects don't commit to the events.
+    EventsMismatch,
+    /// No long-lived genesis is known for the network.
+    UnknownNetwork(Network),
+    /// The genesis isn't the one of the network.
+    DigestMismatch {
+        expected: CheckpointDigest,
+        actual: CheckpointDigest,
+    },
+    /// The genesis objects don't include the system state.
+    MissingSystemState,
+    /// The system state couldn't be decoded.
+    InvalidSystemState,
+}
+
+impl std::fmt::Display for GenesisError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deserialization(e) => write!(f, "invalid genesis blob: {e}"),
+            Self::NotGenesisCheckpoint => write!(f, "not the first checkpoint of a chain"),
+            Self::NotGenesisTransaction => write!(f, "not a single genesis transaction"),
+            Self::Contents(e) => write!(f, "invalid genesis checkpoint contents: {e}"),
+            Self::TransactionMismatch => write!(
+                f,
+                "genesis checkpoint doesn't commit to the genesis transaction and its effects"
+            ),
+            Self::EventsMismatch => write!(f, "genesis effects don't commit to the events"),
+            Self::UnknownNetwork(network) => {
+                write!(f, "no long-lived genesis is known for {network:?}")
+            }
+            Self::DigestMismatch { expected, actual } => write!(
+                f,
+                "genesis checkpoint digest mismatch: expected {expected}, got {actual}"
+            ),
+            Self::MissingSystemState => write!(f, "genesis objects don't include a system state"),
+            Self::InvalidSystemState => write!(f, "unable to decode the genesis system state"),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for GenesisError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Deserialization(_) => "invalid_genesis_blob",
+            Self::NotGenesisCheckpoint => "not_genesis_checkpoint",
+            Self::NotGenesisTransaction => "not_genesis_transaction",
+            Self::Contents(_) => "invalid_genesis_contents",
+            Self::TransactionMismatch => "genesis_transaction_mismatch",
+            Self::EventsMismatch => "genesis_events_mismatch",
+            Self::UnknownNetwork(_) => "unknown_network",
+            Self::DigestMismatch { .. } => "genesis_digest_mismatch",
+            Self::MissingSystemState => "missing_system_state",
+            Self::InvalidSystemState => "invalid_system_state",
+        }
+    }
+}
+
+impl std::error::Error for GenesisError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Contents(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::effects_v2;
+    use crate::Bls12381Signature;
+    use crate::CheckpointTransactionInfo;
+    use crate::GasCostSummary;
+    use crate::MoveStruct;
+    use crate::ObjectId;
+    use crate::Owner;
+    use crate::SignedTransaction;
+    use crate::StructTag;
+    use crate::Transaction;
+    use crate::TransactionDigest;
+    use crate::TransactionEffectsV2;
+    use crate::TypeTag;
+    use crate::ValidatorAggregatedSignature;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    /// The BCS serialized system state, with one active validator per public key.
+    fn system_state(validators: &[(Bls12381PublicKey, u64)]) -> Vec<u8> {
+        let id = |out: &mut Vec<u8>| out.extend([0x5a; 32]);
+        let vector = |out: &mut Vec<u8>, bytes: &[u8]| {
+            out.push(bytes.len() as u8);
+            out.extend(bytes);
+        };
+        let u64s = |out: &mut Vec<u8>, count: usize| out.extend(vec![0; 8 * count]);
+
+        let mut out = Vec::new();
+        id(&mut out);
+        out.extend(1u64.to_le_bytes()); // version key
+        out.extend(0u64.to_le_bytes()); // epoch
+        u64s(&mut out, 3);
+        out.push(validators.len() as u8);
+        for (public_key, stake) in validators {
+            id(&mut out);
+            vector(&mut out, public_key.inner());
+            for _ in 0..11 {
+                vector(&mut out, b"metadata");
+            }
+            out.extend([1, 3, b'k', b'e', b'y']); // Some next epoch key
+            out.extend([0; 7]);
+            id(&mut out);
+            u64s(&mut out, 1);
+
+            out.extend(stake.to_le_bytes());
+            id(&mut out);
+            u64s(&mut out, 1);
+            id(&mut out);
+            out.extend([1, 0, 0, 0, 0, 0, 0, 0, 0]); // Some activation epoch
+            out.push(0);
+            u64s(&mut out, 3);
+            id(&mut out);
+            u64s(&mut out, 4);
+            id(&mut out);
+            u64s(&mut out, 5);
+            id(&mut out);
+            u64s(&mut out, 1);
+        }
+        // The rest of the system state isn't read
+        out.extend([0; 64]);
+        out
+    }
+
+    fn genesis(validators: &[(Bls12381PublicKey, u64)]) -> Genesis {
+        let type_ = StructTag {
+            address: crate::Address::TWO,
+            module: "dynamic_field".parse().unwrap(),
+            name: "Field".parse().unwrap(),
+            type_params: vec![
+                TypeTag::U64,
+                TypeTag::Struct(Box::new(StructTag {
+                    address: crate::Address::THREE,
+                    module: "sui_system_state_inner".parse().unwrap(),
+                    name: "SuiSystemStateInner".parse().unwrap(),
+                    type_params: vec![],
+                })),
+            ],
+        };
+        let object = Object::new(
+            crate::ObjectData::Struct(
+                MoveStruct::new(type_, false, 1, system_state(validators)).unwrap(),
+            ),
+            Owner::Object(ObjectId::new([5; 32])),
+            TransactionDigest::ZERO,
+            0,
+        );
+
+        let transaction = SenderSignedData::new(vec![SignedTransaction {
+            transaction: Transaction::new_system(TransactionKind::Genesis(GenesisTransaction {
+                objects: vec![],
+            })),
+            signatures: vec![],
+        }]);
+        let digest = transaction.transactions()[0].transaction.digest();
+        let events = TransactionEvents(vec![]);
+        let effects = TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            transaction_digest: digest,
+            events_digest: Some(events.digest()),
+            ..effects_v2(1, vec![])
+        }));
+        let checkpoint_contents = CheckpointContents::new(vec![CheckpointTransactionInfo {
+            transaction: digest,
+            effects: effects.digest(),
+            signatures: vec![],
+        }]);
+        let checkpoint = CheckpointSummary {
+            epoch: 0,
+            sequence_number: 0,
+            network_total_transactions: 1,
+            content_digest: checkpoint_contents.digest(),
+            previous_digest: None,
+            epoch_rolling_gas_cost_summary: GasCostSummary::default(),
+            timestamp_ms: 0,
+            checkpoint_commitments: vec![],
+            end_of_epoch_data: None,
+            version_specific_data: vec![],
+        };
+
+        Genesis {
+            checkpoint: SignedCheckpointSummary {
+                checkpoint,
+                signature: ValidatorAggregatedSignature {
+                    epoch: 0,
+                    signature: Bls12381Signature::new([0; Bls12381Signature::LENGTH]),
+                    bitmap: Default::default(),
+                },
+            },
+            checkpoint_contents,
+            transaction,
+            effects,
+            events,
+            objects: vec![object],
+        }
+    }
+
+    #[test]
+    fn known_networks() {
+        let mainnet = genesis_checkpoint_digest(Network::Mainnet).unwrap();
+        assert!(hex::encode(mainnet.inner()).starts_with("35834a8a"));
+        let testnet = genesis_checkpoint_digest(Network::Testnet).unwrap();
+        assert!(hex::encode(testnet.inner()).starts_with("4c78adac"));
+        assert_eq!(genesis_checkpoint_digest(Network::Devnet), None);
+    }
+
+    #[test]
+    fn parse_and_verify() {
+        let keys = [
+            Bls12381PublicKey::new([1; Bls12381PublicKey::LENGTH]),
+            Bls12381PublicKey::new([2; Bls12381PublicKey::LENGTH]),
+        ];
+        let genesis = genesis(&[(keys[0], 3_000), (keys[1], 7_000)]);
+        let bytes = bcs::to_bytes(&genesis).unwrap();
+        let parsed = Genesis::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, genesis);
+
+        parsed.verify().unwrap();
+        assert_eq!(parsed.genesis_objects().unwrap(), []);
+        assert_eq!(
+            parsed.chain_id(),
+            hex::encode(&parsed.checkpoint_digest().inner()[..4])
+        );
+        assert_eq!(
+            parsed.committee().unwrap(),
+            ValidatorCommittee {
+                epoch: 0,
+                members: vec![
+                    ValidatorCommitteeMember {
+                        public_key: keys[0],
+                        stake: 3_000
+                    },
+                    ValidatorCommitteeMember {
+                        public_key: keys[1],
+                        stake: 7_000
+                    },
+                ],
+            }
+        );
+
+        assert!(matches!(
+            parsed.verify_for_network(Network::Mainnet),
+            Err(GenesisError::DigestMismatch { .. })
+        ));
+        assert_eq!(
+            parsed.verify_for_network(Network::Localnet),
+            Err(GenesisError::UnknownNetwork(Network::Localnet))
+        );
+
+        let mut tampered = genesis.clone();
+        tampered.events = TransactionEvents(vec![crate::Event {
+            package_id: ObjectId::ZERO,
+            module: "genesis".parse().unwrap(),
+            sender: crate::Address::ZERO,
+            type_: StructTag::gas_coin(),
+            contents: vec![],
+        }]);
+        assert_eq!(tampered.verify(), Err(GenesisError::EventsMismatch));
+
+        let mut tampered = genesis.clone();
+        tampered.checkpoint.checkpoint.sequence_number = 1;
+        assert_eq!(tampered.verify(), Err(GenesisError::NotGenesisCheckpoint));
+
+        let mut tampered = genesis;
+        tampered.objects.clear();
+        assert_eq!(tampered.committee(), Err(GenesisError::MissingSystemState));
+
+        Genesis::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+    }
+}
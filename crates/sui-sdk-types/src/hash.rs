@@ -5,48 +5,184 @@ use blake2::Digest as DigestTrait;
 
 type Blake2b256 = blake2::Blake2b<blake2::digest::consts::U32>;
 
+/// An implementation of the Blake2b256 hash function.
+///
+/// All digests in Sui are computed with Blake2b256. This trait allows swapping the implementation
+/// used by a [`Hasher`], e.g. for one which takes advantage of the SIMD instructions available on
+/// the target platform.
+pub trait HashBackend: Default {
+    /// Process the provided data, updating internal state.
+    fn update(&mut self, data: &[u8]);
+
+    /// Finalize hashing, returning the 32-byte hash.
+    fn finalize(self) -> [u8; Digest::LENGTH];
+}
+
+/// The portable, pure-Rust, implementation of Blake2b256 provided by the `blake2` crate.
+#[derive(Clone, Debug, Default)]
+pub struct PortableBackend(Blake2b256);
+
+impl HashBackend for PortableBackend {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data)
+    }
+
+    fn finalize(self) -> [u8; Digest::LENGTH] {
+        self.0.finalize().into()
+    }
+}
+
+/// An implementation of Blake2b256 provided by the `blake2b_simd` crate, which detects at runtime
+/// whether vectorized instructions, like AVX2, can be used.
+#[cfg(feature = "hash-simd")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "hash-simd")))]
+#[derive(Clone, Debug)]
+pub struct SimdBackend(blake2b_simd::State);
+
+#[cfg(feature = "hash-simd")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "hash-simd")))]
+impl Default for SimdBackend {
+    fn default() -> Self {
+        Self(
+            blake2b_simd::Params::new()
+                .hash_length(Digest::LENGTH)
+                .to_state(),
+        )
+    }
+}
+
+#[cfg(feature = "hash-simd")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "hash-simd")))]
+impl HashBackend for SimdBackend {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> [u8; Digest::LENGTH] {
+        let mut buf = [0; Digest::LENGTH];
+        buf.copy_from_slice(self.0.finalize().as_bytes());
+        buf
+    }
+}
+
+/// The backend used by default by [`Hasher`] and all of the digest calculations in this crate.
+///
+/// This is [`SimdBackend`] when the `hash-simd` feature is enabled and [`PortableBackend`]
+/// otherwise.
+#[cfg(feature = "hash-simd")]
+pub type DefaultBackend = SimdBackend;
+
+/// The backend used by default by [`Hasher`] and all of the digest calculations in this crate.
+///
+/// This is `SimdBackend` when the `hash-simd` feature is enabled and [`PortableBackend`]
+/// otherwise.
+#[cfg(not(feature = "hash-simd"))]
+pub type DefaultBackend = PortableBackend;
+
 /// A Blake2b256 Hasher
 #[derive(Debug, Default)]
-pub struct Hasher(Blake2b256);
+pub struct Hasher<B = DefaultBackend>(B);
 
 impl Hasher {
     /// Initialize a new Blake2b256 Hasher instance.
     pub fn new() -> Self {
-        Self(Blake2b256::new())
+        Self(DefaultBackend::default())
+    }
+
+    /// Convenience function for creating a new Hasher instance, hashing the provided data, and
+    /// returning the resultant `Digest`
+    pub fn digest<T: AsRef<[u8]>>(data: T) -> Digest {
+        Self::digest_with_backend(data)
+    }
+
+    /// Initialize a new Blake2b256 Hasher instance which has already processed `domain`, one of
+    /// the separators found in [`domain`].
+    pub fn with_domain(domain: &str) -> Self {
+        let mut hasher = Self::new();
+        hasher.update(domain);
+        hasher
+    }
+}
+
+impl<B: HashBackend> Hasher<B> {
+    /// Initialize a new Blake2b256 Hasher instance using the provided backend.
+    pub fn with_backend(backend: B) -> Self {
+        Self(backend)
     }
 
     /// Process the provided data, updating internal state.
     pub fn update<T: AsRef<[u8]>>(&mut self, data: T) {
-        self.0.update(data)
+        self.0.update(data.as_ref())
     }
 
     /// Finalize hashing, consuming the Hasher instance and returning the resultant hash or
     /// `Digest`.
     pub fn finalize(self) -> Digest {
-        let mut buf = [0; Digest::LENGTH];
-        let result = self.0.finalize();
-
-        buf.copy_from_slice(result.as_slice());
-
-        Digest::new(buf)
+        Digest::new(self.0.finalize())
     }
 
-    /// Convenience function for creating a new Hasher instance, hashing the provided data, and
-    /// returning the resultant `Digest`
-    pub fn digest<T: AsRef<[u8]>>(data: T) -> Digest {
-        let mut hasher = Self::new();
+    /// Like [`Hasher::digest`], but using the backend `B`.
+    pub fn digest_with_backend<T: AsRef<[u8]>>(data: T) -> Digest {
+        let mut hasher = Self::with_backend(B::default());
         hasher.update(data);
         hasher.finalize()
     }
 }
 
-impl std::io::Write for Hasher {
+impl Digest {
+    /// Calculate the Blake2b256 hash of `data`.
+    pub fn new_from_bytes<T: AsRef<[u8]>>(data: T) -> Self {
+        Hasher::digest(data)
+    }
+}
+
+/// The domain separators prefixed to the BCS bytes of values when calculating their digest.
+///
+/// Each type hashed by Sui has its own prefix, so that values of different types never have the
+/// same digest even if their BCS bytes are equal. Digests of custom payloads consistent with the
+/// ones calculated by the node can be obtained with [`Hasher::with_domain`] or [`bcs_digest`].
+pub mod domain {
+    /// The domain of the digest of an [`Object`](crate::Object).
+    pub const OBJECT: &str = "Object::";
+
+    /// The domain of the digest of a [`Transaction`](crate::Transaction).
+    pub const TRANSACTION_DATA: &str = "TransactionData::";
+
+    /// The domain of the digest of [`TransactionEffects`](crate::TransactionEffects).
+    pub const TRANSACTION_EFFECTS: &str = "TransactionEffects::";
+
+    /// The domain of the digest of
+    /// [`EffectsAuxiliaryData`](crate::EffectsAuxiliaryData).
+    pub const EFFECTS_AUXILIARY_DATA: &str = "EffectsAuxiliaryData::";
+
+    /// The domain of the digest of [`TransactionEvents`](crate::TransactionEvents).
+    pub const TRANSACTION_EVENTS: &str = "TransactionEvents::";
+
+    /// The domain of the digest of a [`CheckpointSummary`](crate::CheckpointSummary).
+    pub const CHECKPOINT_SUMMARY: &str = "CheckpointSummary::";
+
+    /// The domain of the digest of [`CheckpointContents`](crate::CheckpointContents).
+    pub const CHECKPOINT_CONTENTS: &str = "CheckpointContents::";
+}
+
+/// Calculate the digest of `value` in `domain`, i.e. the Blake2b256 hash of its BCS bytes
+/// prefixed with `domain`.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub fn bcs_digest<T: serde::Serialize + ?Sized>(domain: &str, value: &T) -> Digest {
+    let mut hasher = Hasher::with_domain(domain);
+    bcs::serialize_into(&mut hasher, value).unwrap();
+    hasher.finalize()
+}
+
+impl<B: HashBackend> std::io::Write for Hasher<B> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.0.write(buf)
+        self.0.update(buf);
+        Ok(buf.len())
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
-        self.0.flush()
+        Ok(())
     }
 }
 
@@ -234,6 +370,26 @@ impl crate::PasskeyPublicKey {
     }
 }
 
+impl crate::MultisigMemberPublicKey {
+    /// Derive the `Address` of this public key on its own, as if it were used as a single signer.
+    ///
+    /// For a [`ZkLoginPublicIdentifier`] this is the address derived using the full 32-byte
+    /// `address_seed` value, see [`ZkLoginPublicIdentifier::derive_address_padded`].
+    ///
+    /// [`ZkLoginPublicIdentifier`]: crate::ZkLoginPublicIdentifier
+    /// [`ZkLoginPublicIdentifier::derive_address_padded`]: crate::ZkLoginPublicIdentifier::derive_address_padded
+    pub fn derive_address(&self) -> Address {
+        use crate::MultisigMemberPublicKey::*;
+
+        match self {
+            Ed25519(p) => p.derive_address(),
+            Secp256k1(p) => p.derive_address(),
+            Secp256r1(p) => p.derive_address(),
+            ZkLogin(p) => p.derive_address_padded(),
+        }
+    }
+}
+
 impl crate::MultisigCommittee {
     /// Derive an `Address` from this MultisigCommittee.
     ///
@@ -278,14 +434,17 @@ impl crate::MultisigCommittee {
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod type_digest {
-    use super::Hasher;
+    use super::bcs_digest;
+    use super::domain;
     use crate::CheckpointContents;
     use crate::CheckpointContentsDigest;
     use crate::CheckpointDigest;
     use crate::CheckpointSummary;
-    use crate::Digest;
+    use crate::EffectsAuxiliaryData;
+    use crate::EffectsAuxiliaryDataDigest;
     use crate::Object;
     use crate::ObjectDigest;
+    use crate::SignedTransaction;
     use crate::Transaction;
     use crate::TransactionDigest;
     use crate::TransactionEffects;
@@ -298,105 +457,205 @@ mod type_digest {
         ///
         /// This is done by hashing the BCS bytes of this `Object` prefixed
         pub fn digest(&self) -> ObjectDigest {
-            const SALT: &str = "Object::";
-            let digest = type_digest(SALT, self);
+            let digest = bcs_digest(domain::OBJECT, self);
             ObjectDigest::new(digest.into_inner())
         }
     }
 
     impl CheckpointSummary {
+        /// Calculate the digest of this `CheckpointSummary`
+        ///
+        /// This is the digest the next checkpoint records as its `previous_digest`, and the one
+        /// checkpoints are identified by.
         pub fn digest(&self) -> CheckpointDigest {
-            const SALT: &str = "CheckpointSummary::";
-            let digest = type_digest(SALT, self);
+            let digest = bcs_digest(domain::CHECKPOINT_SUMMARY, self);
             CheckpointDigest::new(digest.into_inner())
         }
     }
 
     impl CheckpointContents {
+        /// Calculate the digest of this `CheckpointContents`
+        ///
+        /// This is the digest the checkpoint summary commits to as its `content_digest`.
         pub fn digest(&self) -> CheckpointContentsDigest {
-            const SALT: &str = "CheckpointContents::";
-            let digest = type_digest(SALT, self);
+            let digest = bcs_digest(domain::CHECKPOINT_CONTENTS, self);
             CheckpointContentsDigest::new(digest.into_inner())
         }
     }
 
     impl Transaction {
+        /// Calculate the digest of this `Transaction`
+        ///
+        /// This is the Blake2b256 hash of the BCS bytes of the transaction, prefixed with
+        /// `TransactionData::`, and is the same digest validators and fullnodes use to identify
+        /// the transaction. It can be computed before submitting the transaction to correlate it
+        /// with the responses of the network.
         pub fn digest(&self) -> TransactionDigest {
-            const SALT: &str = "TransactionData::";
-            let digest = type_digest(SALT, self);
+            let digest = bcs_digest(domain::TRANSACTION_DATA, self);
             TransactionDigest::new(digest.into_inner())
         }
     }
 
+    impl SignedTransaction {
+        /// Calculate the digest of the signed transaction.
+        ///
+        /// Signatures aren't part of the digest, which is the same as the one of the inner
+        /// [`Transaction`].
+        pub fn digest(&self) -> TransactionDigest {
+            self.transaction.digest()
+        }
+    }
+
     impl TransactionEffects {
+        /// Calculate the digest of these `TransactionEffects`
+        ///
+        /// This is the Blake2b256 hash of the BCS bytes of the effects, prefixed with
+        /// `TransactionEffects::`, and is the digest certified for the transaction in the
+        /// [`CheckpointContents`] of the checkpoint which included it. Comparing the two ensures
+        /// that effects returned by an RPC node are the ones the network agreed on.
         pub fn digest(&self) -> TransactionEffectsDigest {
-            const SALT: &str = "TransactionEffects::";
-            let digest = type_digest(SALT, self);
+            let digest = bcs_digest(domain::TRANSACTION_EFFECTS, self);
             TransactionEffectsDigest::new(digest.into_inner())
         }
     }
 
+    impl EffectsAuxiliaryData {
+        /// Calculate the digest of this `EffectsAuxiliaryData`
+        ///
+        /// This is the digest the effects of the transaction record as their
+        /// `auxiliary_data_digest`.
+        pub fn digest(&self) -> EffectsAuxiliaryDataDigest {
+            let digest = bcs_digest(domain::EFFECTS_AUXILIARY_DATA, self);
+            EffectsAuxiliaryDataDigest::new(digest.into_inner())
+        }
+    }
+
     impl TransactionEvents {
+        /// Calculate the digest of these `TransactionEvents`
+        ///
+        /// This is the digest the effects of the transaction emitting the events record as their
+        /// `events_digest`, see [`TransactionEvents::verify`](crate::TransactionEvents::verify).
         pub fn digest(&self) -> TransactionEventsDigest {
-            const SALT: &str = "TransactionEvents::";
-            let digest = type_digest(SALT, self);
+            let digest = bcs_digest(domain::TRANSACTION_EVENTS, self);
             TransactionEventsDigest::new(digest.into_inner())
         }
     }
-
-    fn type_digest<T: serde::Serialize>(salt: &str, ty: &T) -> Digest {
-        let mut hasher = Hasher::new();
-        hasher.update(salt);
-        bcs::serialize_into(&mut hasher, ty).unwrap();
-        hasher.finalize()
-    }
 }
 
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod signing_message {
     use crate::hash::Hasher;
-    use crate::Digest;
     use crate::Intent;
-    use crate::IntentAppId;
-    use crate::IntentScope;
-    use crate::IntentVersion;
+    use crate::IntentMessage;
     use crate::PersonalMessage;
     use crate::SigningDigest;
     use crate::Transaction;
 
-    impl Transaction {
+    impl<T: serde::Serialize> IntentMessage<T> {
+        /// The digest that a signature over this message commits to: the hash of the BCS
+        /// serialized intent message.
         pub fn signing_digest(&self) -> SigningDigest {
-            const INTENT: Intent = Intent {
-                scope: IntentScope::TransactionData,
-                version: IntentVersion::V0,
-                app_id: IntentAppId::Sui,
-            };
-            let digest = signing_digest(INTENT, self);
-            digest.into_inner()
+            let mut hasher = Hasher::new();
+            bcs::serialize_into(&mut hasher, self).unwrap();
+            hasher.finalize().into_inner()
+        }
+
+        /// The BCS serialized form of this message: its intent followed by its BCS serialized
+        /// value.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            bcs::to_bytes(self).unwrap()
         }
     }
 
-    fn signing_digest<T: serde::Serialize + ?Sized>(intent: Intent, ty: &T) -> Digest {
-        let mut hasher = Hasher::new();
-        hasher.update(intent.to_bytes());
-        bcs::serialize_into(&mut hasher, ty).unwrap();
-        hasher.finalize()
+    /// A message signed by users under an [`Intent`], so that external signers can be given the
+    /// exact bytes to sign.
+    ///
+    /// Users sign the [`signing_digest`](Signable::signing_digest) of transactions and personal
+    /// messages. This isn't implemented for the messages signed by validators, e.g.
+    /// [`CheckpointSummary`](crate::CheckpointSummary), as those sign their intent message
+    /// followed by the epoch of the signature rather than a digest, see `sui_crypto::intent`.
+    pub trait Signable {
+        /// The intent a signature over this message is made with.
+        fn intent(&self) -> Intent;
+
+        /// The BCS serialized [`IntentMessage`] of this message: its intent followed by its BCS
+        /// serialized form.
+        fn intent_message_bytes(&self) -> Vec<u8>;
+
+        /// The 32-byte, domain separated digest of this message: the hash of its intent message.
+        fn signing_digest(&self) -> SigningDigest {
+            let mut hasher = Hasher::new();
+            hasher.update(self.intent_message_bytes());
+            hasher.finalize().into_inner()
+        }
+    }
+
+    impl Signable for Transaction {
+        fn intent(&self) -> Intent {
+            Intent::sui_transaction()
+        }
+
+        fn intent_message_bytes(&self) -> Vec<u8> {
+            bcs::to_bytes(&IntentMessage::new(Intent::sui_transaction(), self)).unwrap()
+        }
+    }
+
+    impl Signable for PersonalMessage<'_> {
+        fn intent(&self) -> Intent {
+            Intent::personal_message()
+        }
+
+        fn intent_message_bytes(&self) -> Vec<u8> {
+            bcs::to_bytes(&IntentMessage::new(Intent::personal_message(), &self.0)).unwrap()
+        }
+    }
+
+    impl Transaction {
+        pub fn signing_digest(&self) -> SigningDigest {
+            IntentMessage::new(Intent::sui_transaction(), self).signing_digest()
+        }
     }
 
     impl PersonalMessage<'_> {
         pub fn signing_digest(&self) -> SigningDigest {
-            const INTENT: Intent = Intent {
-                scope: IntentScope::PersonalMessage,
-                version: IntentVersion::V0,
-                app_id: IntentAppId::Sui,
-            };
-            let digest = signing_digest(INTENT, &self.0);
-            digest.into_inner()
+            IntentMessage::new(Intent::personal_message(), &self.0).signing_digest()
         }
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use signing_message::Signable;
+
+macro_rules! impl_address_from {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<&$ty> for Address {
+                fn from(public_key: &$ty) -> Self {
+                    public_key.derive_address()
+                }
+            }
+
+            impl From<$ty> for Address {
+                fn from(public_key: $ty) -> Self {
+                    public_key.derive_address()
+                }
+            }
+        )*
+    };
+}
+
+// zklogin isn't included since a `ZkLoginPublicIdentifier` may correspond to more than one
+// address, see `ZkLoginPublicIdentifier::derive_address`.
+impl_address_from!(
+    crate::Ed25519PublicKey,
+    crate::Secp256k1PublicKey,
+    crate::Secp256r1PublicKey,
+    crate::PasskeyPublicKey,
+    crate::MultisigCommittee,
+);
+
 /// A 1-byte domain separator for deriving `ObjectId`s in Sui. It is starting from `0xf0` to ensure
 /// no hashing collision for any ObjectId vs Address which is derived as the hash of `flag ||
 /// pubkey`.
@@ -447,7 +706,9 @@ impl crate::ObjectId {
 
 #[cfg(test)]
 mod test {
+    use super::Hasher;
     use super::HashingIntent;
+    use super::PortableBackend;
     use crate::SignatureScheme;
     use test_strategy::proptest;
 
@@ -478,4 +739,151 @@ mod test {
     fn roundtrip_hashing_intent(intent: HashingIntent) {
         assert_eq!(Ok(intent), HashingIntent::from_byte(intent as u8));
     }
+
+    #[proptest]
+    fn address_from_public_key(
+        ed25519: crate::Ed25519PublicKey,
+        secp256k1: crate::Secp256k1PublicKey,
+        secp256r1: crate::Secp256r1PublicKey,
+        multisig: crate::MultisigCommittee,
+    ) {
+        use crate::Address;
+
+        assert_eq!(Address::from(&ed25519), ed25519.derive_address());
+        assert_eq!(Address::from(&secp256k1), secp256k1.derive_address());
+        assert_eq!(Address::from(&secp256r1), secp256r1.derive_address());
+        assert_eq!(Address::from(&multisig), multisig.derive_address());
+
+        // Keys of different schemes with the same bytes control different addresses
+        let passkey = crate::PasskeyPublicKey::new(secp256r1);
+        assert_ne!(Address::from(passkey), Address::from(secp256r1));
+    }
+
+    #[test]
+    fn blake2b256_test_vector() {
+        let digest = Hasher::<PortableBackend>::digest_with_backend(b"abc");
+        assert_eq!(
+            hex::encode(digest.inner()),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+        assert_eq!(crate::Digest::new_from_bytes(b"abc"), digest);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn transaction_digest() {
+        use base64ct::Base64;
+        use base64ct::Encoding;
+
+        const FIXTURE: &str = include_str!("transaction/fixtures/genesis-transaction");
+        let transaction: crate::Transaction =
+            bcs::from_bytes(&Base64::decode_vec(FIXTURE.trim()).unwrap()).unwrap();
+
+        // The digest recorded for this transaction in its effects
+        assert_eq!(
+            transaction.digest().to_string(),
+            "7CuBm1AnLgkBMB6GiEn5d3RizznF5LbawjJTs8A5dcXF"
+        );
+
+        assert_eq!(
+            super::bcs_digest(super::domain::TRANSACTION_DATA, &transaction).into_inner(),
+            transaction.digest().into_inner()
+        );
+
+        // Signatures don't contribute to the digest
+        let digest = transaction.digest();
+        let signed = crate::SignedTransaction::new(transaction, vec![]);
+        assert_eq!(signed.digest(), digest);
+    }
+
+    #[test]
+    fn transaction_effects_digest() {
+        use base64ct::Base64;
+        use base64ct::Encoding;
+
+        const FIXTURE: &str = include_str!("effects/fixtures/genesis-transaction-effects");
+        let bytes = Base64::decode_vec(FIXTURE.trim()).unwrap();
+        let effects: crate::TransactionEffects = bcs::from_bytes(&bytes).unwrap();
+
+        let mut hasher = Hasher::with_domain(super::domain::TRANSACTION_EFFECTS);
+        hasher.update(&bytes);
+        assert_eq!(
+            effects.digest().into_inner(),
+            hasher.finalize().into_inner()
+        );
+        assert_eq!(
+            effects.digest().to_string(),
+            "7jB9doth4a49EwuxLYcQ1RpuQCKNH7V7HdZbSfhTbH8N"
+        );
+
+        // Any change to the effects changes the digest
+        let mut modified = effects.clone();
+        let crate::TransactionEffects::V1(inner) = &mut modified else {
+            panic!("genesis effects are v1");
+        };
+        inner.epoch += 1;
+        assert_ne!(modified.digest(), effects.digest());
+    }
+
+    #[cfg(feature = "hash-simd")]
+    #[proptest]
+    fn backends_agree(data: Vec<u8>, split: usize) {
+        use super::SimdBackend;
+        use std::io::Write;
+
+        let split = split % (data.len() + 1);
+        let mut portable = Hasher::with_backend(PortableBackend::default());
+        portable.update(&data[..split]);
+        portable.write_all(&data[split..]).unwrap();
+        let mut simd = Hasher::with_backend(SimdBackend::default());
+        simd.update(&data[..split]);
+        simd.write_all(&data[split..]).unwrap();
+
+        assert_eq!(portable.finalize(), simd.finalize());
+        assert_eq!(
+            Hasher::<PortableBackend>::digest_with_backend(&data),
+            Hasher::<SimdBackend>::digest_with_backend(&data)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn effects_auxiliary_data_digest() {
+        use crate::EffectsAuxiliaryData;
+
+        let data = EffectsAuxiliaryData::V1 {
+            contents: vec![1, 2, 3],
+        };
+        assert_eq!(
+            data.digest().into_inner(),
+            super::bcs_digest("EffectsAuxiliaryData::", &data).into_inner()
+        );
+        assert_ne!(
+            data.digest(),
+            EffectsAuxiliaryData::V1 { contents: vec![] }.digest()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn signable() {
+        use super::Signable;
+        use crate::Intent;
+        use crate::PersonalMessage;
+        use crate::Transaction;
+        use crate::TransactionKind;
+
+        let transaction = Transaction::new_system(TransactionKind::EndOfEpoch(vec![]));
+        let bytes = transaction.intent_message_bytes();
+        assert_eq!(&bytes[..3], Intent::sui_transaction().to_bytes());
+        assert_eq!(&bytes[3..], bcs::to_bytes(&transaction).unwrap());
+        assert_eq!(
+            Signable::signing_digest(&transaction),
+            transaction.signing_digest()
+        );
+
+        let message = PersonalMessage(b"hello".into());
+        assert_eq!(message.intent(), Intent::personal_message());
+        assert_eq!(Signable::signing_digest(&message), message.signing_digest());
+    }
 }
@@ -13,13 +13,15 @@
 //! specifically for their use case. Below is a list of the available feature flags.
 //!
 //! - `serde`: Enables support for serializing and deserializing types to/from BCS utilizing
-//!            [serde] library.
+//!   [serde] library.
 //! - `rand`: Enables support for generating random instances of a number of types via the [rand]
-//!           library.
+//!   library.
 //! - `hash`: Enables support for hashing, which is required for deriving addresses and calculating
-//!           digests for various types.
+//!   digests for various types.
+//! - `hash-simd`: Enables the `hash` feature and makes hashing use a SIMD accelerated Blake2b256
+//!   implementation, when supported by the platform.
 //! - `proptest`: Enables support for the [proptest] library by providing implementations of
-//!               [proptest::arbitrary::Arbitrary] for many types.
+//!   [proptest::arbitrary::Arbitrary] for many types.
 //!
 //! [feature flags]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-features-section
 //! [serde]: https://docs.rs/serde
@@ -95,26 +97,55 @@
 // TODO finish documenting all public items
 // #![warn(missing_docs)]
 
+pub mod activity;
 mod address;
 mod checkpoint;
+mod checkpoint_range;
 mod crypto;
 mod digest;
 mod effects;
+pub mod error;
+pub mod event_stream;
 mod events;
 mod execution_status;
 pub mod framework;
 mod gas;
+pub mod gas_report;
+pub mod move_value;
+pub mod network;
 mod object;
 mod object_id;
+pub mod ownership;
+pub mod postcondition;
+mod protocol_config;
+mod signed_message;
+pub mod token;
 mod transaction;
 mod type_tag;
 mod u256;
 
+#[cfg(feature = "fuzz")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fuzz")))]
+pub mod fuzz;
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+pub mod genesis;
+
 #[cfg(feature = "hash")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "hash")))]
 pub mod hash;
 
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+pub mod qr;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub mod serialization_profile;
+
 pub use address::Address;
+pub use address::AddressForm;
 pub use address::AddressParseError;
 pub use checkpoint::CheckpointCommitment;
 pub use checkpoint::CheckpointContents;
@@ -126,22 +157,37 @@ pub use checkpoint::CheckpointTransaction;
 pub use checkpoint::CheckpointTransactionInfo;
 pub use checkpoint::EndOfEpochData;
 pub use checkpoint::EpochId;
+pub use checkpoint::LenientCheckpointData;
+pub use checkpoint::LenientCheckpointTransaction;
 pub use checkpoint::ProtocolVersion;
 pub use checkpoint::SignedCheckpointSummary;
 pub use checkpoint::StakeUnit;
+pub use checkpoint_range::CheckpointRange;
+pub use checkpoint_range::ProcessedCheckpoints;
 pub use crypto::Bls12381PublicKey;
 pub use crypto::Bls12381Signature;
 pub use crypto::Bn254FieldElement;
+pub use crypto::Bn254FieldElementParseError;
 pub use crypto::CircomG1;
 pub use crypto::CircomG2;
 pub use crypto::Ed25519PublicKey;
 pub use crypto::Ed25519Signature;
 pub use crypto::Intent;
 pub use crypto::IntentAppId;
+pub use crypto::IntentMessage;
 pub use crypto::IntentScope;
 pub use crypto::IntentVersion;
+pub use crypto::InvalidMultisigCommittee;
 pub use crypto::Jwk;
 pub use crypto::JwkId;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use crypto::KeytoolMultisig;
+pub use crypto::KeytoolMultisigError;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use crypto::KeytoolMultisigMember;
+pub use crypto::LenientUserSignature;
 pub use crypto::MultisigAggregatedSignature;
 pub use crypto::MultisigCommittee;
 pub use crypto::MultisigMember;
@@ -153,12 +199,14 @@ pub use crypto::Secp256k1PublicKey;
 pub use crypto::Secp256k1Signature;
 pub use crypto::Secp256r1PublicKey;
 pub use crypto::Secp256r1Signature;
+pub use crypto::SignatureFlag;
 pub use crypto::SignatureScheme;
 pub use crypto::SimpleSignature;
 pub use crypto::UserSignature;
 pub use crypto::ValidatorAggregatedSignature;
 pub use crypto::ValidatorCommittee;
 pub use crypto::ValidatorCommitteeMember;
+pub use crypto::ValidatorMetadata;
 pub use crypto::ValidatorSignature;
 pub use crypto::ZkLoginAuthenticator;
 pub use crypto::ZkLoginClaim;
@@ -176,22 +224,41 @@ pub use digest::SigningDigest;
 pub use digest::TransactionDigest;
 pub use digest::TransactionEffectsDigest;
 pub use digest::TransactionEventsDigest;
+pub use effects::CertifiedTransactionEffects;
 pub use effects::ChangedObject;
+pub use effects::EffectsAuxiliaryData;
+pub use effects::EffectsDiff;
 pub use effects::IdOperation;
 pub use effects::ModifiedAtVersion;
+pub use effects::ObjectChange;
+pub use effects::ObjectChangeKind;
+pub use effects::ObjectDiff;
 pub use effects::ObjectIn;
 pub use effects::ObjectOut;
 pub use effects::ObjectReferenceWithOwner;
+pub use effects::SignedTransactionEffects;
 pub use effects::TransactionEffects;
 pub use effects::TransactionEffectsV1;
 pub use effects::TransactionEffectsV2;
 pub use effects::UnchangedSharedKind;
 pub use effects::UnchangedSharedObject;
+pub use error::ErrorCode;
 pub use events::BalanceChange;
 pub use events::Event;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use events::EventDecodeError;
+pub use events::EventFilter;
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+pub use events::EventsDigestMismatch;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use events::MoveEvent;
 pub use events::TransactionEvents;
 pub use execution_status::CommandArgumentError;
 pub use execution_status::ExecutionError;
+pub use execution_status::ExecutionErrorCategory;
 pub use execution_status::ExecutionStatus;
 pub use execution_status::MoveLocation;
 pub use execution_status::PackageUpgradeError;
@@ -209,12 +276,32 @@ pub use object::TypeOrigin;
 pub use object::UpgradeInfo;
 pub use object::Version;
 pub use object_id::ObjectId;
+pub use protocol_config::ProtocolConfig;
+pub use protocol_config::ProtocolConfigTable;
+pub use protocol_config::ProtocolFeatures;
+pub use protocol_config::TransactionLimits;
+pub use signed_message::AnySignedMessage;
+pub use signed_message::SignedPersonalMessage;
 pub use transaction::ActiveJwk;
 pub use transaction::Argument;
 pub use transaction::AuthenticatorStateExpire;
 pub use transaction::AuthenticatorStateUpdate;
 pub use transaction::CanceledTransaction;
+pub use transaction::CanceledTransactionV2;
+pub use transaction::CancellationReason;
 pub use transaction::ChangeEpoch;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::ClearSigningFields;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::ClearSigningPreimage;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::CoinSource;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::CoinSplit;
 pub use transaction::Command;
 pub use transaction::ConsensusCommitPrologue;
 pub use transaction::ConsensusCommitPrologueV2;
@@ -225,26 +312,63 @@ pub use transaction::EndOfEpochTransactionKind;
 pub use transaction::GasPayment;
 pub use transaction::GenesisTransaction;
 pub use transaction::Input;
+pub use transaction::InvalidGasPayment;
+pub use transaction::InvalidSenderSignedData;
+pub use transaction::InvalidSystemTransaction;
+pub use transaction::InvalidTransaction;
+pub use transaction::LenientSignedTransaction;
 pub use transaction::MakeMoveVector;
 pub use transaction::MergeCoins;
 pub use transaction::MoveCall;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::MoveCallTarget;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::ObjectTransfer;
 pub use transaction::ProgrammableTransaction;
 pub use transaction::Publish;
+pub use transaction::PublishIssue;
 pub use transaction::RandomnessStateUpdate;
+pub use transaction::SenderSignedData;
 pub use transaction::SignedTransaction;
 pub use transaction::SplitCoins;
 pub use transaction::SystemPackage;
 pub use transaction::Transaction;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::TransactionBlockBytes;
 pub use transaction::TransactionExpiration;
 pub use transaction::TransactionKind;
 pub use transaction::TransferObjects;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use transaction::TransferredObject;
 pub use transaction::Upgrade;
 pub use transaction::VersionAssignment;
+pub use transaction::VersionAssignmentV2;
+pub use type_tag::Ability;
+pub use type_tag::AbilityResolver;
+pub use type_tag::AbilitySet;
+pub use type_tag::DatatypeAbilities;
 pub use type_tag::Identifier;
 pub use type_tag::StructTag;
 pub use type_tag::TypeParseError;
 pub use type_tag::TypeTag;
 
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+pub use checkpoint::CheckpointChainError;
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+pub use checkpoint::ObjectProofError;
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+pub use effects::ReportedEffectsError;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub(crate) use transaction::LenientSignedTransactionWithIntentMessage;
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 pub(crate) use transaction::SignedTransactionWithIntentMessage;
@@ -252,6 +376,9 @@ pub(crate) use transaction::SignedTransactionWithIntentMessage;
 #[cfg(test)]
 mod serialization_proptests;
 
+#[cfg(test)]
+mod test_util;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PersonalMessage<'a>(pub std::borrow::Cow<'a, [u8]>);
 
@@ -368,5 +495,6 @@ mod _serde {
         }
     }
 
+    pub(crate) use super::LenientSignedTransactionWithIntentMessage;
     pub(crate) use super::SignedTransactionWithIntentMessage;
 }
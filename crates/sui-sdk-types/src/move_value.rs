@@ -0,0 +1,724 @@
+//! Decoding of Move values from their BCS bytes.
+//!
+//! BCS isn't self-describing: the contents of an object, or a pure argument, can only be
+//! interpreted given the layout of their type, i.e. the names and types of the fields of every
+//! struct involved, which come from the packages defining them. Given a [`MoveTypeLayout`],
+//! [`MoveValue::from_bcs`] decodes bytes into an annotated tree of values.
+//!
+//! With the `serde` feature, decoded values can be converted to the JSON the JSON-RPC API returns
+//! for them, see `MoveValue::to_json` and `ObjectData::to_json`.
+//!
+//! ```
+//! use sui_sdk_types::move_value::MoveFieldLayout;
+//! use sui_sdk_types::move_value::MoveStructLayout;
+//! use sui_sdk_types::move_value::MoveTypeLayout;
+//! use sui_sdk_types::move_value::MoveValue;
+//!
+//! let layout = MoveTypeLayout::Struct(Box::new(MoveStructLayout {
+//!     type_: "0x2::balance::Balance<0x2::sui::SUI>".parse().unwrap(),
+//!     fields: vec![MoveFieldLayout::new("value", MoveTypeLayout::U64)],
+//! }));
+//!
+//! let value = MoveValue::from_bcs(&layout, &42u64.to_le_bytes()).unwrap();
+//! assert_eq!(value.field("value"), Some(&MoveValue::U64(42)));
+//! ```
+
+use crate::Address;
+use crate::Identifier;
+use crate::StructTag;
+
+/// The layout of a Move type, describing how its values are serialized.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MoveTypeLayout {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<MoveTypeLayout>),
+    Struct(Box<MoveStructLayout>),
+    Enum(Box<MoveEnumLayout>),
+}
+
+/// The layout of a Move struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveStructLayout {
+    /// The type of the struct, with its type parameters instantiated.
+    pub type_: StructTag,
+
+    /// The fields of the struct, in declaration order.
+    pub fields: Vec<MoveFieldLayout>,
+}
+
+/// The layout of a Move enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveEnumLayout {
+    /// The type of the enum, with its type parameters instantiated.
+    pub type_: StructTag,
+
+    /// The variants of the enum, in declaration order, which is the order of their tags.
+    pub variants: Vec<MoveVariantLayout>,
+}
+
+/// The layout of a variant of a Move enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveVariantLayout {
+    pub name: Identifier,
+
+    /// The fields of the variant, in declaration order.
+    pub fields: Vec<MoveFieldLayout>,
+}
+
+/// The layout of a field of a Move struct or enum variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveFieldLayout {
+    pub name: Identifier,
+    pub layout: MoveTypeLayout,
+}
+
+impl MoveFieldLayout {
+    /// Construct the layout of a field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` isn't a valid identifier.
+    pub fn new(name: &str, layout: MoveTypeLayout) -> Self {
+        Self {
+            name: Identifier::new(name).expect("invalid field name"),
+            layout,
+        }
+    }
+}
+
+/// A Move value, annotated with the names of its fields and the types of its structs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MoveValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    /// A `u256`, as its little-endian bytes.
+    U256([u8; 32]),
+    Address(Address),
+    Signer(Address),
+    Vector(Vec<MoveValue>),
+    Struct(MoveStructValue),
+    Variant(MoveVariantValue),
+}
+
+/// The value of a Move struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveStructValue {
+    pub type_: StructTag,
+
+    /// The fields of the struct, in declaration order, with their names.
+    pub fields: Vec<(Identifier, MoveValue)>,
+}
+
+/// The value of a Move enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveVariantValue {
+    pub type_: StructTag,
+
+    /// The name of the variant.
+    pub name: Identifier,
+
+    /// The tag of the variant, i.e. its index among the variants of the enum.
+    pub tag: u16,
+
+    /// The fields of the variant, in declaration order, with their names.
+    pub fields: Vec<(Identifier, MoveValue)>,
+}
+
+impl MoveValue {
+    /// Decode the BCS `bytes` of a value with the given layout.
+    pub fn from_bcs(layout: &MoveTypeLayout, bytes: &[u8]) -> Result<Self, MoveValueError> {
+        let mut reader = Reader { bytes, offset: 0 };
+        let value = reader.value(layout)?;
+        if reader.offset != bytes.len() {
+            return Err(MoveValueError::TrailingBytes {
+                offset: reader.offset,
+            });
+        }
+        Ok(value)
+    }
+
+    /// Return the field `name` of a struct or enum variant, if this is one and it has the field.
+    pub fn field(&self, name: &str) -> Option<&MoveValue> {
+        let fields = match self {
+            Self::Struct(value) => &value.fields,
+            Self::Variant(value) => &value.fields,
+            _ => return None,
+        };
+        fields
+            .iter()
+            .find(|(field, _)| field.as_str() == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl crate::MoveStruct {
+    /// Decode the contents of this object given the layout of its type.
+    pub fn decode(&self, layout: &MoveStructLayout) -> Result<MoveStructValue, MoveValueError> {
+        if layout.type_ != *self.object_type() {
+            return Err(MoveValueError::TypeMismatch {
+                expected: Box::new(self.object_type().clone()),
+                actual: Box::new(layout.type_.clone()),
+            });
+        }
+
+        let mut reader = Reader {
+            bytes: self.contents(),
+            offset: 0,
+        };
+        let value = reader.struct_(layout)?;
+        if reader.offset != reader.bytes.len() {
+            return Err(MoveValueError::TrailingBytes {
+                offset: reader.offset,
+            });
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl MoveValue {
+    /// Convert to JSON, in the shape the JSON-RPC API gives Move values.
+    ///
+    /// `u8`, `u16` and `u32` are numbers while larger integers are decimal strings, and a few
+    /// framework types are collapsed into the value they wrap: strings and URLs into strings,
+    /// `ID`s into addresses, `UID`s into `{"id": ...}`, balances into their value, and options
+    /// into their value or `null`. Other structs are `{"type": ..., "fields": {...}}`, and enum
+    /// variants `{"type": ..., "variant": ..., "fields": {...}}`.
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value;
+
+        match self {
+            Self::Bool(value) => Value::Bool(*value),
+            Self::U8(value) => Value::from(*value),
+            Self::U16(value) => Value::from(*value),
+            Self::U32(value) => Value::from(*value),
+            Self::U64(value) => Value::String(value.to_string()),
+            Self::U128(value) => Value::String(value.to_string()),
+            Self::U256(bytes) => {
+                let value = crate::u256::U256::from_le(crate::u256::U256::from_digits(*bytes));
+                Value::String(value.to_string())
+            }
+            Self::Address(address) | Self::Signer(address) => Value::String(address.to_string()),
+            Self::Vector(elements) => Value::Array(elements.iter().map(Self::to_json).collect()),
+            Self::Struct(value) => framework_json(value).unwrap_or_else(|| {
+                serde_json::json!({
+                    "type": rpc_struct_tag(&value.type_),
+                    "fields": fields_json(&value.fields),
+                })
+            }),
+            Self::Variant(value) => serde_json::json!({
+                "type": rpc_struct_tag(&value.type_),
+                "variant": value.name.as_str(),
+                "fields": fields_json(&value.fields),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl crate::ObjectData {
+    /// Decode the contents of this object given the layout of its type, and convert them to the
+    /// JSON the JSON-RPC API returns for objects with the `showContent` option.
+    ///
+    /// Packages are rejected, as their JSON-RPC content is their disassembled modules.
+    pub fn to_json(&self, layout: &MoveStructLayout) -> Result<serde_json::Value, MoveValueError> {
+        let object = match self {
+            Self::Struct(object) => object,
+            Self::Package(_) => return Err(MoveValueError::Package),
+        };
+        let value = object.decode(layout)?;
+        Ok(serde_json::json!({
+            "dataType": "moveObject",
+            "type": rpc_struct_tag(&value.type_),
+            "hasPublicTransfer": object.has_public_transfer(),
+            "fields": fields_json(&value.fields),
+        }))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn fields_json(fields: &[(Identifier, MoveValue)]) -> serde_json::Value {
+    fields
+        .iter()
+        .map(|(name, value)| (name.as_str().to_owned(), value.to_json()))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// The JSON of the framework structs the JSON-RPC API collapses into the value they wrap, if `value`
+/// is one of them.
+#[cfg(feature = "serde")]
+fn framework_json(value: &MoveStructValue) -> Option<serde_json::Value> {
+    use serde_json::Value;
+
+    let type_ = &value.type_;
+    let field = |name| {
+        value
+            .fields
+            .iter()
+            .find(|(field, _)| field.as_str() == name)
+            .map(|(_, value)| value)
+    };
+    let framework = match type_.address {
+        Address::ONE => 1,
+        Address::TWO => 2,
+        _ => return None,
+    };
+
+    match (framework, type_.module.as_str(), type_.name.as_str()) {
+        (1, "string" | "ascii", "String") => {
+            let MoveValue::Vector(bytes) = field("bytes")? else {
+                return None;
+            };
+            let bytes = bytes
+                .iter()
+                .map(|byte| match byte {
+                    MoveValue::U8(byte) => Some(*byte),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            String::from_utf8(bytes).ok().map(Value::String)
+        }
+        (1, "option", "Option") => {
+            let MoveValue::Vector(elements) = field("vec")? else {
+                return None;
+            };
+            Some(elements.first().map_or(Value::Null, MoveValue::to_json))
+        }
+        (2, "url", "Url") => field("url").map(MoveValue::to_json),
+        (2, "object", "ID") => field("bytes").map(MoveValue::to_json),
+        (2, "object", "UID") => {
+            let id = field("id")?.to_json();
+            id.is_string().then(|| serde_json::json!({ "id": id }))
+        }
+        (2, "balance", "Balance") => field("value").map(MoveValue::to_json),
+        _ => None,
+    }
+}
+
+/// Format `tag` like the JSON-RPC API does, with the leading zeros of addresses trimmed.
+#[cfg(feature = "serde")]
+fn rpc_struct_tag(tag: &StructTag) -> String {
+    use std::fmt::Write;
+
+    fn address(address: &Address, out: &mut String) {
+        let hex = address.to_hex();
+        let trimmed = hex.trim_start_matches("0x").trim_start_matches('0');
+        out.push_str("0x");
+        out.push_str(if trimmed.is_empty() { "0" } else { trimmed });
+    }
+
+    fn type_tag(tag: &crate::TypeTag, out: &mut String) {
+        match tag {
+            crate::TypeTag::Vector(element) => {
+                out.push_str("vector<");
+                type_tag(element, out);
+                out.push('>');
+            }
+            crate::TypeTag::Struct(tag) => struct_tag(tag, out),
+            primitive => write!(out, "{primitive}").expect("writing to a string cannot fail"),
+        }
+    }
+
+    fn struct_tag(tag: &StructTag, out: &mut String) {
+        address(&tag.address, out);
+        write!(out, "::{}::{}", tag.module, tag.name).expect("writing to a string cannot fail");
+        if !tag.type_params.is_empty() {
+            out.push('<');
+            for (i, param) in tag.type_params.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                type_tag(param, out);
+            }
+            out.push('>');
+        }
+    }
+
+    let mut out = String::new();
+    struct_tag(tag, &mut out);
+    out
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], MoveValueError> {
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + N)
+            .ok_or(MoveValueError::UnexpectedEnd)?;
+        self.offset += N;
+        Ok(bytes.try_into().expect("slice has length N"))
+    }
+
+    fn uleb128(&mut self) -> Result<u32, MoveValueError> {
+        let offset = self.offset;
+        let mut value = 0u64;
+        for shift in (0..32).step_by(7) {
+            let [byte] = self.take()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                // Encodings must be canonical, i.e. without trailing zero bytes
+                if shift > 0 && byte == 0 {
+                    break;
+                }
+                return u32::try_from(value).map_err(|_| MoveValueError::InvalidLength { offset });
+            }
+        }
+        Err(MoveValueError::InvalidLength { offset })
+    }
+
+    fn value(&mut self, layout: &MoveTypeLayout) -> Result<MoveValue, MoveValueError> {
+        Ok(match layout {
+            MoveTypeLayout::Bool => match self.take()? {
+                [0] => MoveValue::Bool(false),
+                [1] => MoveValue::Bool(true),
+                [byte] => {
+                    return Err(MoveValueError::InvalidBool {
+                        offset: self.offset - 1,
+                        byte,
+                    })
+                }
+            },
+            MoveTypeLayout::U8 => MoveValue::U8(u8::from_le_bytes(self.take()?)),
+            MoveTypeLayout::U16 => MoveValue::U16(u16::from_le_bytes(self.take()?)),
+            MoveTypeLayout::U32 => MoveValue::U32(u32::from_le_bytes(self.take()?)),
+            MoveTypeLayout::U64 => MoveValue::U64(u64::from_le_bytes(self.take()?)),
+            MoveTypeLayout::U128 => MoveValue::U128(u128::from_le_bytes(self.take()?)),
+            MoveTypeLayout::U256 => MoveValue::U256(self.take()?),
+            MoveTypeLayout::Address => MoveValue::Address(Address::new(self.take()?)),
+            MoveTypeLayout::Signer => MoveValue::Signer(Address::new(self.take()?)),
+            MoveTypeLayout::Vector(element) => {
+                let len = self.uleb128()? as usize;
+                // Don't trust the length for the allocation, every element takes at least a byte
+                let mut elements = Vec::with_capacity(len.min(self.bytes.len() - self.offset));
+                for _ in 0..len {
+                    elements.push(self.value(element)?);
+                }
+                MoveValue::Vector(elements)
+            }
+            MoveTypeLayout::Struct(layout) => MoveValue::Struct(self.struct_(layout)?),
+            MoveTypeLayout::Enum(layout) => {
+                let offset = self.offset;
+                let tag = self.uleb128()?;
+                let variant = layout
+                    .variants
+                    .get(tag as usize)
+                    .ok_or(MoveValueError::UnknownVariant { offset, tag })?;
+                MoveValue::Variant(MoveVariantValue {
+                    type_: layout.type_.clone(),
+                    name: variant.name.clone(),
+                    tag: tag as u16,
+                    fields: self.fields(&variant.fields)?,
+                })
+            }
+        })
+    }
+
+    fn struct_(&mut self, layout: &MoveStructLayout) -> Result<MoveStructValue, MoveValueError> {
+        Ok(MoveStructValue {
+            type_: layout.type_.clone(),
+            fields: self.fields(&layout.fields)?,
+        })
+    }
+
+    fn fields(
+        &mut self,
+        fields: &[MoveFieldLayout],
+    ) -> Result<Vec<(Identifier, MoveValue)>, MoveValueError> {
+        fields
+            .iter()
+            .map(|field| Ok((field.name.clone(), self.value(&field.layout)?)))
+            .collect()
+    }
+}
+
+/// Error returned when BCS bytes can't be decoded as a Move value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MoveValueError {
+    /// The bytes ended before the value.
+    UnexpectedEnd,
+    /// Bytes remain after the value, from the given offset.
+    TrailingBytes { offset: usize },
+    /// The byte at the given offset isn't a boolean.
+    InvalidBool { offset: usize, byte: u8 },
+    /// The length of a vector, or the tag of a variant, at the given offset isn't a canonical
+    /// ULEB128 encoded `u32`.
+    InvalidLength { offset: usize },
+    /// The variant tag at the given offset isn't a variant of the enum.
+    UnknownVariant { offset: usize, tag: u32 },
+    /// The layout doesn't have the type of the object.
+    TypeMismatch {
+        expected: Box<StructTag>,
+        actual: Box<StructTag>,
+    },
+    /// The object is a package, which has no Move value.
+    Package,
+}
+
+impl std::fmt::Display for MoveValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of move value"),
+            Self::TrailingBytes { offset } => {
+                write!(f, "trailing bytes after move value at offset {offset}")
+            }
+            Self::InvalidBool { offset, byte } => {
+                write!(f, "invalid bool {byte:#04x} at offset {offset}")
+            }
+            Self::InvalidLength { offset } => write!(f, "invalid length at offset {offset}"),
+            Self::UnknownVariant { offset, tag } => {
+                write!(f, "unknown variant {tag} at offset {offset}")
+            }
+            Self::TypeMismatch { expected, actual } => {
+                write!(f, "layout type mismatch: expected {expected}, got {actual}")
+            }
+            Self::Package => write!(f, "packages have no move value"),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for MoveValueError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::UnexpectedEnd => "move_value_unexpected_end",
+            Self::TrailingBytes { .. } => "move_value_trailing_bytes",
+            Self::InvalidBool { .. } => "move_value_invalid_bool",
+            Self::InvalidLength { .. } => "move_value_invalid_length",
+            Self::UnknownVariant { .. } => "move_value_unknown_variant",
+            Self::TypeMismatch { .. } => "move_value_layout_type_mismatch",
+            Self::Package => "move_value_of_package",
+        }
+    }
+}
+
+impl std::error::Error for MoveValueError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn coin_layout() -> MoveStructLayout {
+        MoveStructLayout {
+            type_: "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap(),
+            fields: vec![
+                MoveFieldLayout::new(
+                    "id",
+                    MoveTypeLayout::Struct(Box::new(MoveStructLayout {
+                        type_: "0x2::object::UID".parse().unwrap(),
+                        fields: vec![MoveFieldLayout::new(
+                            "id",
+                            MoveTypeLayout::Struct(Box::new(MoveStructLayout {
+                                type_: "0x2::object::ID".parse().unwrap(),
+                                fields: vec![MoveFieldLayout::new(
+                                    "bytes",
+                                    MoveTypeLayout::Address,
+                                )],
+                            })),
+                        )],
+                    })),
+                ),
+                MoveFieldLayout::new(
+                    "balance",
+                    MoveTypeLayout::Struct(Box::new(MoveStructLayout {
+                        type_: "0x2::balance::Balance<0x2::sui::SUI>".parse().unwrap(),
+                        fields: vec![MoveFieldLayout::new("value", MoveTypeLayout::U64)],
+                    })),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn decode_object() {
+        let mut contents = vec![7; 32];
+        contents.extend(1_000u64.to_le_bytes());
+        let object = crate::MoveStruct::new(
+            "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap(),
+            true,
+            1,
+            contents,
+        )
+        .unwrap();
+
+        let coin = MoveValue::Struct(object.decode(&coin_layout()).unwrap());
+        assert_eq!(
+            coin.field("id")
+                .and_then(|uid| uid.field("id"))
+                .and_then(|id| id.field("bytes")),
+            Some(&MoveValue::Address(Address::new([7; 32])))
+        );
+        assert_eq!(
+            coin.field("balance")
+                .and_then(|balance| balance.field("value")),
+            Some(&MoveValue::U64(1_000))
+        );
+
+        let mut layout = coin_layout();
+        layout.type_ = "0x2::coin::Coin<u8>".parse().unwrap();
+        assert!(matches!(
+            object.decode(&layout),
+            Err(MoveValueError::TypeMismatch { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn object_json() {
+        let mut contents = vec![7; 32];
+        contents.extend(1_000u64.to_le_bytes());
+        let object = crate::ObjectData::Struct(
+            crate::MoveStruct::new(
+                "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap(),
+                true,
+                1,
+                contents,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            object.to_json(&coin_layout()).unwrap(),
+            serde_json::json!({
+                "dataType": "moveObject",
+                "type": "0x2::coin::Coin<0x2::sui::SUI>",
+                "hasPublicTransfer": true,
+                "fields": {
+                    "id": { "id": Address::new([7; 32]).to_string() },
+                    "balance": "1000",
+                },
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn value_json() {
+        let string = |value: &str| MoveStructValue {
+            type_: "0x1::string::String".parse().unwrap(),
+            fields: vec![(
+                Identifier::new("bytes").unwrap(),
+                MoveValue::Vector(value.bytes().map(MoveValue::U8).collect()),
+            )],
+        };
+        let option = |value: Option<MoveValue>| {
+            MoveValue::Struct(MoveStructValue {
+                type_: "0x1::option::Option<0x1::string::String>".parse().unwrap(),
+                fields: vec![(
+                    Identifier::new("vec").unwrap(),
+                    MoveValue::Vector(value.into_iter().collect()),
+                )],
+            })
+        };
+        let value = MoveValue::Struct(MoveStructValue {
+            type_: "0x00ab::m::S<vector<0x2::sui::SUI>>".parse().unwrap(),
+            fields: vec![
+                (
+                    Identifier::new("name").unwrap(),
+                    option(Some(MoveValue::Struct(string("sui")))),
+                ),
+                (Identifier::new("none").unwrap(), option(None)),
+                (Identifier::new("small").unwrap(), MoveValue::U8(1)),
+                (Identifier::new("large").unwrap(), MoveValue::U256([1; 32])),
+            ],
+        });
+
+        assert_eq!(
+            value.to_json(),
+            serde_json::json!({
+                "type": "0xab::m::S<vector<0x2::sui::SUI>>",
+                "fields": {
+                    "name": "sui",
+                    "none": null,
+                    "small": 1,
+                    "large": "454086624460063511464984254936031011189294057512315937409637584344757371137",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn decode_values() {
+        let option = MoveTypeLayout::Enum(Box::new(MoveEnumLayout {
+            type_: "0x0::m::Maybe<u16>".parse().unwrap(),
+            variants: vec![
+                MoveVariantLayout {
+                    name: Identifier::new("None").unwrap(),
+                    fields: vec![],
+                },
+                MoveVariantLayout {
+                    name: Identifier::new("Some").unwrap(),
+                    fields: vec![MoveFieldLayout::new("value", MoveTypeLayout::U16)],
+                },
+            ],
+        }));
+        let layout = MoveTypeLayout::Vector(Box::new(option));
+
+        let value = MoveValue::from_bcs(&layout, &[2, 1, 0x34, 0x12, 0]).unwrap();
+        let MoveValue::Vector(elements) = &value else {
+            panic!("expected a vector");
+        };
+        assert_eq!(elements[0].field("value"), Some(&MoveValue::U16(0x1234)));
+        let MoveValue::Variant(none) = &elements[1] else {
+            panic!("expected a variant");
+        };
+        assert_eq!((none.name.as_str(), none.tag), ("None", 0));
+
+        assert_eq!(
+            MoveValue::from_bcs(&layout, &[1, 2]),
+            Err(MoveValueError::UnknownVariant { offset: 1, tag: 2 })
+        );
+        assert_eq!(
+            MoveValue::from_bcs(&layout, &[1, 1, 0]),
+            Err(MoveValueError::UnexpectedEnd)
+        );
+        assert_eq!(
+            MoveValue::from_bcs(&layout, &[0, 0]),
+            Err(MoveValueError::TrailingBytes { offset: 1 })
+        );
+        // Non-canonical and oversized lengths
+        assert_eq!(
+            MoveValue::from_bcs(&layout, &[0x80, 0]),
+            Err(MoveValueError::InvalidLength { offset: 0 })
+        );
+        assert_eq!(
+            MoveValue::from_bcs(&layout, &[0xff, 0xff, 0xff, 0xff, 0x7f]),
+            Err(MoveValueError::InvalidLength { offset: 0 })
+        );
+
+        assert_eq!(
+            MoveValue::from_bcs(&MoveTypeLayout::Bool, &[2]),
+            Err(MoveValueError::InvalidBool { offset: 0, byte: 2 })
+        );
+        let max = [0xff; 32];
+        assert_eq!(
+            MoveValue::from_bcs(&MoveTypeLayout::U256, &max),
+            Ok(MoveValue::U256(max))
+        );
+        assert_eq!(
+            MoveValue::from_bcs(&MoveTypeLayout::U256, &max[1..]),
+            Err(MoveValueError::UnexpectedEnd)
+        );
+    }
+}
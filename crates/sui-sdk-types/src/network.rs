@@ -0,0 +1,215 @@
+//! Static metadata of the public networks.
+//!
+//! Some identifiers differ from one network to another, like the digest of the genesis
+//! checkpoint, while others are the same on every network but easy to get wrong, like the
+//! `initial_shared_version` of system objects. A [`NetworkMetadata`] gathers them for a network,
+//! which can be selected by its chain identifier, e.g. as returned by an RPC node.
+//!
+//! ```
+//! use sui_sdk_types::network::NetworkMetadata;
+//! use sui_sdk_types::token::Network;
+//!
+//! let metadata = NetworkMetadata::from_chain_id("35834a8a").unwrap();
+//! assert_eq!(metadata.network(), Network::Mainnet);
+//! ```
+//!
+//! Only the values known to be the same on every network are recorded besides the genesis
+//! checkpoint: the addresses of the framework packages, which are upgraded in place, and the
+//! system objects created at genesis. In particular:
+//!
+//! - system objects created after genesis, like the `Random` or `DenyList` objects, have an
+//!   `initial_shared_version` specific to each network which isn't recorded here, and needs to be
+//!   looked up on chain;
+//! - the versions of the framework packages, which change with each upgrade of the network,
+//!   aren't recorded either.
+
+use crate::token::Network;
+use crate::CheckpointDigest;
+use crate::ObjectId;
+use crate::Version;
+
+/// The digest of the genesis checkpoint of mainnet.
+const MAINNET_GENESIS_DIGEST: &str = "4btiuiMPvEENsttpZC7CZ53DruC3MAgfznDbASZ7DR6S";
+
+/// The digest of the genesis checkpoint of testnet.
+const TESTNET_GENESIS_DIGEST: &str = "69WiPg3DAQiwdxfncX6wYQ2siKwAe6L9BZthQea3JNMD";
+
+const fn object_id(last: u8) -> ObjectId {
+    let mut bytes = [0; ObjectId::LENGTH];
+    bytes[ObjectId::LENGTH - 1] = last;
+    ObjectId::new(bytes)
+}
+
+/// The packages of the framework, at the same address on every network.
+///
+/// All of them are published at genesis but `Bridge`, which was published by a later upgrade on
+/// mainnet and testnet, and doesn't exist on networks where the bridge isn't enabled.
+const FRAMEWORK_PACKAGES: &[FrameworkPackage] = &[
+    FrameworkPackage {
+        name: "MoveStdlib",
+        package_id: object_id(1),
+    },
+    FrameworkPackage {
+        name: "Sui",
+        package_id: object_id(2),
+    },
+    FrameworkPackage {
+        name: "SuiSystem",
+        package_id: object_id(3),
+    },
+    FrameworkPackage {
+        name: "Bridge",
+        package_id: object_id(0xb),
+    },
+];
+
+/// The system shared objects created at genesis, with the same `initial_shared_version` on every
+/// network.
+const GENESIS_SHARED_OBJECTS: &[SystemObject] = &[
+    SystemObject {
+        name: "SuiSystemState",
+        object_id: object_id(5),
+        initial_shared_version: 1,
+    },
+    SystemObject {
+        name: "Clock",
+        object_id: object_id(6),
+        initial_shared_version: 1,
+    },
+];
+
+/// A package of the Sui framework.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameworkPackage {
+    /// The name of the package, e.g. `Sui` for `0x2`.
+    pub name: &'static str,
+    pub package_id: ObjectId,
+}
+
+/// A shared object of the system, e.g. the `Clock`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SystemObject {
+    /// The name of the type of the object, e.g. `Clock` for `0x6`.
+    pub name: &'static str,
+    pub object_id: ObjectId,
+    pub initial_shared_version: Version,
+}
+
+/// The static metadata of a network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkMetadata {
+    network: Network,
+    genesis_checkpoint_digest: Option<&'static str>,
+}
+
+impl NetworkMetadata {
+    /// The metadata of `network`.
+    pub const fn for_network(network: Network) -> Self {
+        let genesis_checkpoint_digest = match network {
+            Network::Mainnet => Some(MAINNET_GENESIS_DIGEST),
+            Network::Testnet => Some(TESTNET_GENESIS_DIGEST),
+            Network::Devnet | Network::Localnet => None,
+        };
+        Self {
+            network,
+            genesis_checkpoint_digest,
+        }
+    }
+
+    /// The metadata of the long-lived network with the given chain identifier, i.e. the hex
+    /// encoding of the first 4 bytes of the digest of its genesis checkpoint.
+    ///
+    /// Devnet and localnets are regularly wiped, and get a new chain identifier each time, so
+    /// they can't be selected this way.
+    pub fn from_chain_id(chain_id: &str) -> Option<Self> {
+        [Network::Mainnet, Network::Testnet]
+            .into_iter()
+            .map(Self::for_network)
+            .find(|metadata| {
+                metadata
+                    .chain_id()
+                    .is_some_and(|id| id.eq_ignore_ascii_case(chain_id))
+            })
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// The digest of the genesis checkpoint of the network, if it is a long-lived network.
+    pub fn genesis_checkpoint_digest(&self) -> Option<CheckpointDigest> {
+        self.genesis_checkpoint_digest
+            .map(|digest| digest.parse().expect("well-known digests are valid"))
+    }
+
+    /// The chain identifier of the network, if it is a long-lived network, e.g. `35834a8a` for
+    /// mainnet.
+    pub fn chain_id(&self) -> Option<String> {
+        self.genesis_checkpoint_digest()
+            .map(|digest| hex::encode(&digest.inner()[..4]))
+    }
+
+    /// The packages of the framework, which are the same on every network.
+    ///
+    /// Their addresses don't change when they are upgraded, but the `Bridge` package is only
+    /// published on networks where the bridge is enabled.
+    pub fn framework_packages(&self) -> &'static [FrameworkPackage] {
+        FRAMEWORK_PACKAGES
+    }
+
+    /// The system shared objects whose `initial_shared_version` is known statically, i.e. the
+    /// ones created at genesis, which are the same on every network.
+    pub fn system_objects(&self) -> &'static [SystemObject] {
+        GENESIS_SHARED_OBJECTS
+    }
+
+    /// The `initial_shared_version` of `object_id`, if it is a system shared object whose
+    /// version is known statically.
+    pub fn system_initial_shared_version(&self, object_id: &ObjectId) -> Option<Version> {
+        self.system_objects()
+            .iter()
+            .find(|object| object.object_id == *object_id)
+            .map(|object| object.initial_shared_version)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn chain_ids() {
+        let mainnet = NetworkMetadata::for_network(Network::Mainnet);
+        assert_eq!(mainnet.chain_id().as_deref(), Some("35834a8a"));
+        let testnet = NetworkMetadata::for_network(Network::Testnet);
+        assert_eq!(testnet.chain_id().as_deref(), Some("4c78adac"));
+
+        assert_eq!(NetworkMetadata::from_chain_id("35834A8A"), Some(mainnet));
+        assert_eq!(NetworkMetadata::from_chain_id("4c78adac"), Some(testnet));
+        assert_eq!(NetworkMetadata::from_chain_id("00000000"), None);
+
+        let devnet = NetworkMetadata::for_network(Network::Devnet);
+        assert_eq!(devnet.genesis_checkpoint_digest(), None);
+        assert_eq!(devnet.chain_id(), None);
+    }
+
+    #[test]
+    fn system_objects() {
+        let metadata = NetworkMetadata::for_network(Network::Testnet);
+        assert_eq!(
+            metadata.system_initial_shared_version(&"0x6".parse().unwrap()),
+            Some(1)
+        );
+        assert_eq!(
+            metadata.system_initial_shared_version(&ObjectId::RANDOM),
+            None
+        );
+        assert!(metadata
+            .framework_packages()
+            .iter()
+            .any(|package| package.package_id == "0x2".parse().unwrap()));
+    }
+}
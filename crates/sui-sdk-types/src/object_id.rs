@@ -29,6 +29,13 @@ impl ObjectId {
     pub const LENGTH: usize = Address::LENGTH;
     pub const ZERO: Self = Self(Address::ZERO);
 
+    /// The id of the `0x8::random::Random` shared object, the source of on-chain randomness.
+    pub const RANDOM: Self = {
+        let mut bytes = [0; Self::LENGTH];
+        bytes[31] = 8;
+        Self::new(bytes)
+    };
+
     /// Generates a new ObjectId from the provided byte array.
     pub const fn new(bytes: [u8; Self::LENGTH]) -> Self {
         Self(Address::new(bytes))
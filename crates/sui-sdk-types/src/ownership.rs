@@ -0,0 +1,310 @@
+//! Tracking of the history of the owners of objects.
+//!
+//! An [`OwnershipIndex`] is fed the effects and output objects of executed transactions, e.g.
+//! from a stream of [`CheckpointData`], and records every change of ownership in an
+//! [`ObjectStore`]. It can then be queried for the owner an object had at a given version, or at a
+//! given point in time.
+
+use std::collections::BTreeMap;
+
+use crate::CheckpointData;
+use crate::IdOperation;
+use crate::Object;
+use crate::ObjectId;
+use crate::ObjectIn;
+use crate::ObjectOut;
+use crate::Owner;
+use crate::TransactionDigest;
+use crate::TransactionEffects;
+use crate::Version;
+
+/// The owner of an object from a version onwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnershipRecord {
+    pub object_id: ObjectId,
+
+    /// The version from which this record applies.
+    pub version: Version,
+
+    /// The owner of the object, or `None` if the object was deleted or wrapped at this version.
+    pub owner: Option<Owner>,
+
+    /// The transaction which produced this version.
+    pub transaction: TransactionDigest,
+
+    /// The timestamp of the checkpoint which included the transaction, if known.
+    pub timestamp_ms: Option<u64>,
+}
+
+/// Persistent storage for the records of an [`OwnershipIndex`].
+pub trait ObjectStore {
+    type Error;
+
+    /// Persist `record`, replacing any record for the same object and version.
+    ///
+    /// Records of the same object can be inserted in any order.
+    fn insert(&mut self, record: OwnershipRecord) -> Result<(), Self::Error>;
+
+    /// Return the records of `object_id`, sorted by version.
+    fn records(&self, object_id: &ObjectId) -> Result<Vec<OwnershipRecord>, Self::Error>;
+}
+
+/// An [`ObjectStore`] keeping its records in memory.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryObjectStore {
+    records: BTreeMap<ObjectId, BTreeMap<Version, OwnershipRecord>>,
+}
+
+impl ObjectStore for MemoryObjectStore {
+    type Error = std::convert::Infallible;
+
+    fn insert(&mut self, record: OwnershipRecord) -> Result<(), Self::Error> {
+        self.records
+            .entry(record.object_id)
+            .or_default()
+            .insert(record.version, record);
+        Ok(())
+    }
+
+    fn records(&self, object_id: &ObjectId) -> Result<Vec<OwnershipRecord>, Self::Error> {
+        Ok(self
+            .records
+            .get(object_id)
+            .map(|records| records.values().cloned().collect())
+            .unwrap_or_default())
+    }
+}
+
+/// An index of the owners of objects over time.
+#[derive(Clone, Debug, Default)]
+pub struct OwnershipIndex<S> {
+    store: S,
+}
+
+impl<S: ObjectStore> OwnershipIndex<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Record the ownership changes made by a transaction.
+    ///
+    /// The owners of the objects written by the transaction are taken from `output_objects`,
+    /// while the objects it deleted or wrapped are taken from `effects`.
+    pub fn index_transaction(
+        &mut self,
+        effects: &TransactionEffects,
+        output_objects: &[Object],
+        timestamp_ms: Option<u64>,
+    ) -> Result<(), S::Error> {
+        let transaction = *effects.transaction_digest();
+        let record = |object_id, version, owner| OwnershipRecord {
+            object_id,
+            version,
+            owner,
+            transaction,
+            timestamp_ms,
+        };
+
+        for object in output_objects {
+            self.store.insert(record(
+                object.object_id(),
+                object.version(),
+                Some(*object.owner()),
+            ))?;
+        }
+
+        for (object_id, version) in removed_objects(effects) {
+            self.store.insert(record(object_id, version, None))?;
+        }
+
+        Ok(())
+    }
+
+    /// Record the ownership changes made by all the transactions of a checkpoint.
+    pub fn index_checkpoint(&mut self, checkpoint: &CheckpointData) -> Result<(), S::Error> {
+        let timestamp_ms = checkpoint.checkpoint_summary.checkpoint.timestamp_ms;
+        for transaction in &checkpoint.transactions {
+            self.index_transaction(
+                &transaction.effects,
+                &transaction.output_objects,
+                Some(timestamp_ms),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Return the record in effect for `object_id` at `version`, i.e. the record of the latest
+    /// version not greater than `version`.
+    pub fn owner_at_version(
+        &self,
+        object_id: &ObjectId,
+        version: Version,
+    ) -> Result<Option<OwnershipRecord>, S::Error> {
+        Ok(self
+            .store
+            .records(object_id)?
+            .into_iter()
+            .rev()
+            .find(|record| record.version <= version))
+    }
+
+    /// Return the record in effect for `object_id` at `timestamp_ms`, i.e. the record of the latest
+    /// version produced by a checkpoint no later than `timestamp_ms`.
+    ///
+    /// Records without a timestamp are ignored.
+    pub fn owner_at_time(
+        &self,
+        object_id: &ObjectId,
+        timestamp_ms: u64,
+    ) -> Result<Option<OwnershipRecord>, S::Error> {
+        Ok(self
+            .store
+            .records(object_id)?
+            .into_iter()
+            .rev()
+            .find(|record| record.timestamp_ms.is_some_and(|t| t <= timestamp_ms)))
+    }
+}
+
+/// The objects which existed before a transaction and were deleted or wrapped by it, along with
+/// the version they were deleted or wrapped at.
+///
+/// This includes objects which were wrapped before the transaction, and which it unwrapped and
+/// then deleted.
+fn removed_objects(effects: &TransactionEffects) -> Vec<(ObjectId, Version)> {
+    match effects {
+        TransactionEffects::V1(e) => e
+            .deleted
+            .iter()
+            .chain(&e.wrapped)
+            .chain(&e.unwrapped_then_deleted)
+            .map(|reference| (*reference.object_id(), reference.version()))
+            .collect(),
+        TransactionEffects::V2(e) => e
+            .changed_objects
+            .iter()
+            .filter(|change| {
+                change.output_state == ObjectOut::NotExist
+                    && (matches!(change.input_state, ObjectIn::Exist { .. })
+                        || change.id_operation == IdOperation::Deleted)
+            })
+            .map(|change| (change.object_id, e.lamport_version))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::address;
+    use crate::test_util::coin;
+    use crate::test_util::deleted;
+    use crate::test_util::effects_v2;
+    use crate::ChangedObject;
+    use crate::StructTag;
+    use crate::TransactionEffectsV2;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn object(id: ObjectId, version: Version, owner: Owner) -> Object {
+        coin(id, StructTag::gas_coin(), version, owner, 0)
+    }
+
+    fn effects(
+        transaction: TransactionDigest,
+        lamport_version: Version,
+        changed_objects: Vec<ChangedObject>,
+    ) -> TransactionEffects {
+        TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            transaction_digest: transaction,
+            ..effects_v2(lamport_version, changed_objects)
+        }))
+    }
+
+    #[test]
+    fn ownership_history() {
+        let id = ObjectId::from(address(9));
+        let alice = Owner::Address(address(0xa));
+        let bob = Owner::Address(address(0xb));
+        let (create, transfer, delete) = (
+            TransactionDigest::new([1; 32]),
+            TransactionDigest::new([2; 32]),
+            TransactionDigest::new([3; 32]),
+        );
+
+        let mut index = OwnershipIndex::new(MemoryObjectStore::default());
+        // Index out of order, as can happen when processing checkpoints concurrently
+        index
+            .index_transaction(
+                &effects(transfer, 5, vec![]),
+                &[object(id, 5, bob)],
+                Some(2000),
+            )
+            .unwrap();
+        index
+            .index_transaction(
+                &effects(create, 2, vec![]),
+                &[object(id, 2, alice)],
+                Some(1000),
+            )
+            .unwrap();
+        index
+            .index_transaction(&effects(delete, 8, vec![deleted(id, 5, bob)]), &[], None)
+            .unwrap();
+
+        let owner_at_version = |version| {
+            index
+                .owner_at_version(&id, version)
+                .unwrap()
+                .map(|record| (record.owner, record.transaction))
+        };
+        assert_eq!(owner_at_version(1), None);
+        assert_eq!(owner_at_version(2), Some((Some(alice), create)));
+        assert_eq!(owner_at_version(4), Some((Some(alice), create)));
+        assert_eq!(owner_at_version(5), Some((Some(bob), transfer)));
+        assert_eq!(owner_at_version(100), Some((None, delete)));
+
+        let owner_at_time = |timestamp_ms| {
+            index
+                .owner_at_time(&id, timestamp_ms)
+                .unwrap()
+                .and_then(|record| record.owner)
+        };
+        assert_eq!(owner_at_time(999), None);
+        assert_eq!(owner_at_time(1500), Some(alice));
+        // The deletion has no timestamp and so isn't taken into account
+        assert_eq!(owner_at_time(10_000), Some(bob));
+    }
+
+    #[test]
+    fn unwrapped_then_deleted() {
+        let id = ObjectId::from(address(9));
+        let unwrapped_then_deleted = ChangedObject {
+            object_id: id,
+            input_state: ObjectIn::NotExist,
+            output_state: ObjectOut::NotExist,
+            id_operation: IdOperation::Deleted,
+        };
+        // Objects created and wrapped by the same transaction never existed on their own
+        let created_then_wrapped = ChangedObject {
+            id_operation: IdOperation::Created,
+            ..unwrapped_then_deleted.clone()
+        };
+
+        let removed = removed_objects(&effects(
+            TransactionDigest::ZERO,
+            7,
+            vec![unwrapped_then_deleted, created_then_wrapped],
+        ));
+        assert_eq!(removed, vec![(id, 7)]);
+    }
+}
@@ -0,0 +1,443 @@
+//! Assertions over the outcome of executing a transaction.
+//!
+//! Bots and other automated senders usually expect a transaction to have a precise outcome: a
+//! trade should leave them with more of a coin, a mint should create an object of a given type.
+//! A list of [`Postcondition`]s can be checked in a single call with
+//! [`ExecutionOutcome::check`], against the outcome of a dry run before submitting the
+//! transaction, and against the outcome of its actual execution afterwards.
+//!
+//! An [`ExecutionOutcome`] also computes the per-address, per-coin-type
+//! [`balance_changes`](ExecutionOutcome::balance_changes) of a transaction, gas fees included.
+
+use std::collections::BTreeMap;
+
+use crate::framework::Coin;
+use crate::Address;
+use crate::BalanceChange;
+use crate::CheckpointTransaction;
+use crate::ExecutionStatus;
+use crate::IdOperation;
+use crate::Object;
+use crate::ObjectId;
+use crate::Owner;
+use crate::StructTag;
+use crate::TransactionEffects;
+use crate::TypeTag;
+
+/// The outcome of executing a transaction, or of a dry run of it.
+#[derive(Clone, Copy, Debug)]
+pub struct ExecutionOutcome<'a> {
+    pub effects: &'a TransactionEffects,
+
+    /// The state of the objects mutated, wrapped or deleted by the transaction, before it
+    /// executed.
+    pub input_objects: &'a [Object],
+
+    /// The state of the objects written by the transaction, after it executed.
+    pub output_objects: &'a [Object],
+}
+
+impl<'a> From<&'a CheckpointTransaction> for ExecutionOutcome<'a> {
+    fn from(transaction: &'a CheckpointTransaction) -> Self {
+        Self {
+            effects: &transaction.effects,
+            input_objects: &transaction.input_objects,
+            output_objects: &transaction.output_objects,
+        }
+    }
+}
+
+impl ExecutionOutcome<'_> {
+    /// Check all of `postconditions`, returning the ones which don't hold.
+    pub fn check(
+        &self,
+        postconditions: &[Postcondition],
+    ) -> Result<(), Vec<PostconditionViolation>> {
+        let violations: Vec<_> = postconditions
+            .iter()
+            .filter_map(|postcondition| postcondition.check(self).err())
+            .collect();
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// The change of the total balance of the coins of type `coin_type` owned by `owner`.
+    ///
+    /// This includes the gas fees paid by the owner, if the coin type is SUI.
+    pub fn balance_change(&self, owner: &Address, coin_type: &TypeTag) -> i128 {
+        let balance = |objects: &[Object]| -> i128 {
+            objects
+                .iter()
+                .filter(|object| object.owner() == &Owner::Address(*owner))
+                .filter_map(Coin::try_from_object)
+                .filter(|coin| coin.coin_type() == coin_type)
+                .map(|coin| coin.balance() as i128)
+                .sum()
+        };
+        balance(self.output_objects) - balance(self.input_objects)
+    }
+
+    /// The change of the total balance of every coin type owned by every address, ordered by
+    /// address then coin type. Balances which didn't change aren't included.
+    ///
+    /// Gas is accounted for through the gas coins, which are part of the input and output
+    /// objects of every transaction: the change of the SUI balance of the gas owner includes the
+    /// gas fees it paid, net of storage rebates, even if the transaction failed. Gas coins
+    /// merged into the first one are deleted, and so only count towards its input balance.
+    pub fn balance_changes(&self) -> Vec<BalanceChange> {
+        let mut changes = BTreeMap::<(Address, TypeTag), i128>::new();
+        let mut add = |objects: &[Object], sign: i128| {
+            for object in objects {
+                let Owner::Address(owner) = object.owner() else {
+                    continue;
+                };
+                let Some(coin) = Coin::try_from_object(object) else {
+                    continue;
+                };
+                *changes
+                    .entry((*owner, coin.coin_type().clone()))
+                    .or_default() += sign * coin.balance() as i128;
+            }
+        };
+        add(self.input_objects, -1);
+        add(self.output_objects, 1);
+
+        changes
+            .into_iter()
+            .filter(|(_, amount)| *amount != 0)
+            .map(|((address, coin_type), amount)| BalanceChange {
+                address,
+                coin_type,
+                amount,
+            })
+            .collect()
+    }
+
+    /// The objects created by the transaction, in its output objects.
+    pub fn created_objects(&self) -> impl Iterator<Item = &Object> {
+        let created = self.effects.created_objects();
+        self.output_objects.iter().filter(move |object| {
+            created
+                .iter()
+                .any(|created| *created.reference.object_id() == object.object_id())
+        })
+    }
+
+    /// The IDs of the objects deleted by the transaction.
+    pub fn deleted_objects(&self) -> Vec<ObjectId> {
+        match self.effects {
+            TransactionEffects::V1(e) => e
+                .deleted
+                .iter()
+                .chain(&e.unwrapped_then_deleted)
+                .map(|reference| *reference.object_id())
+                .collect(),
+            TransactionEffects::V2(e) => e
+                .changed_objects
+                .iter()
+                .filter(|change| change.id_operation == IdOperation::Deleted)
+                .map(|change| change.object_id)
+                .collect(),
+        }
+    }
+}
+
+/// An expectation about the outcome of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Postcondition {
+    /// The transaction executed successfully.
+    Success,
+
+    /// The transaction created at least one object of this type.
+    ObjectCreated(StructTag),
+
+    /// The balance of `coin_type` owned by `owner` changed by at least `min_change`.
+    ///
+    /// A negative `min_change` bounds how much `owner` can lose, e.g. to gas fees.
+    BalanceChange {
+        owner: Address,
+        coin_type: TypeTag,
+        min_change: i128,
+    },
+
+    /// The transaction didn't delete any object.
+    NoDeletions,
+}
+
+impl Postcondition {
+    pub fn check(&self, outcome: &ExecutionOutcome<'_>) -> Result<(), PostconditionViolation> {
+        match self {
+            Self::Success => match outcome.effects.status() {
+                ExecutionStatus::Success => Ok(()),
+                failure => Err(PostconditionViolation::Failed(failure.clone())),
+            },
+            Self::ObjectCreated(object_type) => {
+                let created = outcome
+                    .created_objects()
+                    .any(|object| object.as_struct().map(|s| s.object_type()) == Some(object_type));
+                if created {
+                    Ok(())
+                } else {
+                    Err(PostconditionViolation::NoObjectCreated(object_type.clone()))
+                }
+            }
+            Self::BalanceChange {
+                owner,
+                coin_type,
+                min_change,
+            } => {
+                let change = outcome.balance_change(owner, coin_type);
+                if change >= *min_change {
+                    Ok(())
+                } else {
+                    Err(PostconditionViolation::BalanceChange {
+                        owner: *owner,
+                        coin_type: coin_type.clone(),
+                        min_change: *min_change,
+                        change,
+                    })
+                }
+            }
+            Self::NoDeletions => {
+                let deleted = outcome.deleted_objects();
+                if deleted.is_empty() {
+                    Ok(())
+                } else {
+                    Err(PostconditionViolation::Deleted(deleted))
+                }
+            }
+        }
+    }
+}
+
+/// A [`Postcondition`] which doesn't hold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PostconditionViolation {
+    /// The transaction failed.
+    Failed(ExecutionStatus),
+
+    /// No object of this type was created.
+    NoObjectCreated(StructTag),
+
+    /// The balance changed by less than expected.
+    BalanceChange {
+        owner: Address,
+        coin_type: TypeTag,
+        min_change: i128,
+        change: i128,
+    },
+
+    /// These objects were deleted.
+    Deleted(Vec<ObjectId>),
+}
+
+impl std::fmt::Display for PostconditionViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Failed(status) => write!(f, "transaction failed: {status:?}"),
+            Self::NoObjectCreated(object_type) => {
+                write!(f, "no object of type {object_type} was created")
+            }
+            Self::BalanceChange {
+                owner,
+                coin_type,
+                min_change,
+                change,
+            } => write!(
+                f,
+                "balance of {coin_type} owned by {owner} changed by {change}, expected at least {min_change}"
+            ),
+            Self::Deleted(objects) => write!(f, "{} objects were deleted", objects.len()),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for PostconditionViolation {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Failed(_) => "transaction_failed",
+            Self::NoObjectCreated(_) => "no_object_created",
+            Self::BalanceChange { .. } => "balance_change_too_small",
+            Self::Deleted(_) => "objects_deleted",
+        }
+    }
+}
+
+impl std::error::Error for PostconditionViolation {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::address;
+    use crate::test_util::coin;
+    use crate::test_util::created;
+    use crate::test_util::deleted;
+    use crate::test_util::effects_v2;
+    use crate::test_util::mutated;
+    use crate::ChangedObject;
+    use crate::ExecutionError;
+    use crate::TransactionEffectsV2;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn object(id: u8, object_type: StructTag, owner: Address, balance: u64) -> Object {
+        coin(
+            address(id).into(),
+            object_type,
+            1,
+            Owner::Address(owner),
+            balance,
+        )
+    }
+
+    fn effects(status: ExecutionStatus, changed_objects: Vec<ChangedObject>) -> TransactionEffects {
+        TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            status,
+            ..effects_v2(2, changed_objects)
+        }))
+    }
+
+    #[test]
+    fn postconditions() {
+        let alice = address(0xa);
+        let sui: TypeTag = "0x2::sui::SUI".parse().unwrap();
+        let nft: StructTag = "0x1234::nft::Nft".parse().unwrap();
+
+        // Alice pays 100 MIST from her gas coin to mint an NFT
+        let effects = effects(
+            ExecutionStatus::Success,
+            vec![
+                mutated(address(1).into(), 1, Owner::Address(alice)),
+                created(address(2).into(), Owner::Address(alice)),
+            ],
+        );
+        let input_objects = [object(1, StructTag::gas_coin(), alice, 1_000)];
+        let output_objects = [
+            object(1, StructTag::gas_coin(), alice, 900),
+            object(2, nft.clone(), alice, 0),
+        ];
+        let outcome = ExecutionOutcome {
+            effects: &effects,
+            input_objects: &input_objects,
+            output_objects: &output_objects,
+        };
+
+        assert_eq!(outcome.balance_change(&alice, &sui), -100);
+        assert_eq!(outcome.balance_change(&address(0xb), &sui), 0);
+        outcome
+            .check(&[
+                Postcondition::Success,
+                Postcondition::ObjectCreated(nft),
+                Postcondition::BalanceChange {
+                    owner: alice,
+                    coin_type: sui.clone(),
+                    min_change: -100,
+                },
+                Postcondition::NoDeletions,
+            ])
+            .unwrap();
+
+        let violations = outcome
+            .check(&[
+                Postcondition::ObjectCreated(StructTag::staked_sui()),
+                Postcondition::BalanceChange {
+                    owner: alice,
+                    coin_type: sui.clone(),
+                    min_change: 0,
+                },
+            ])
+            .unwrap_err();
+        assert_eq!(
+            violations,
+            [
+                PostconditionViolation::NoObjectCreated(StructTag::staked_sui()),
+                PostconditionViolation::BalanceChange {
+                    owner: alice,
+                    coin_type: sui,
+                    min_change: 0,
+                    change: -100,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn balance_changes() {
+        let alice = address(0xa);
+        let bob = address(0xb);
+        let sui: TypeTag = "0x2::sui::SUI".parse().unwrap();
+        let usdc: TypeTag = "0x1234::usdc::USDC".parse().unwrap();
+        let usdc_coin = StructTag::coin(usdc.clone());
+
+        // Alice merges a second gas coin into her first one, pays 100 MIST of gas and sends one
+        // of her two USDC coins to Bob
+        let effects = effects(ExecutionStatus::Success, vec![]);
+        let input_objects = [
+            object(1, StructTag::gas_coin(), alice, 1_000),
+            object(2, StructTag::gas_coin(), alice, 10),
+            object(3, usdc_coin.clone(), alice, 50),
+            object(4, usdc_coin.clone(), alice, 7),
+        ];
+        let output_objects = [
+            object(1, StructTag::gas_coin(), alice, 910),
+            object(3, usdc_coin.clone(), bob, 50),
+            object(4, usdc_coin, alice, 7),
+        ];
+        let outcome = ExecutionOutcome {
+            effects: &effects,
+            input_objects: &input_objects,
+            output_objects: &output_objects,
+        };
+
+        assert_eq!(
+            outcome.balance_changes(),
+            [
+                BalanceChange {
+                    address: alice,
+                    coin_type: sui,
+                    amount: -100,
+                },
+                BalanceChange {
+                    address: alice,
+                    coin_type: usdc.clone(),
+                    amount: -50,
+                },
+                BalanceChange {
+                    address: bob,
+                    coin_type: usdc,
+                    amount: 50,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn failures_and_deletions() {
+        let status = ExecutionStatus::Failure {
+            error: ExecutionError::InsufficientGas,
+            command: None,
+        };
+        let effects = effects(
+            status.clone(),
+            vec![deleted(address(1).into(), 1, Owner::Address(address(0xa)))],
+        );
+        let outcome = ExecutionOutcome {
+            effects: &effects,
+            input_objects: &[],
+            output_objects: &[],
+        };
+
+        assert_eq!(
+            outcome.check(&[Postcondition::Success, Postcondition::NoDeletions]),
+            Err(vec![
+                PostconditionViolation::Failed(status),
+                PostconditionViolation::Deleted(vec![address(1).into()]),
+            ])
+        );
+    }
+}
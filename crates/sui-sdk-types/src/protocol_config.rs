@@ -0,0 +1,338 @@
+//! The parts of the protocol config of the Sui network which clients need to check transactions.
+//!
+//! Validators reject transactions according to the [`ProtocolConfig`] of the current protocol
+//! version, e.g. when they use a feature which isn't enabled yet or exceed a limit. A
+//! [`ProtocolConfigTable`] gathers the configs of successive protocol versions, so that historical
+//! transactions can be checked against the config they were executed with.
+//! [`ProtocolConfigTable::for_network`] builds the table of mainnet or testnet from the versions
+//! at which the modelled features changed on that network.
+
+use std::collections::BTreeMap;
+
+use super::GasPayment;
+use super::ProgrammableTransaction;
+use super::ProtocolVersion;
+use crate::token::Network;
+
+/// A subset of the on-chain protocol configuration which governs how transactions are validated
+/// and charged.
+///
+/// The full protocol config lives in the Sui node and is versioned by [`ProtocolVersion`]. Only
+/// the parameters which are needed to perform client-side checks on transactions are included
+/// here.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProtocolConfig {
+    /// The protocol version these parameters apply to.
+    pub protocol_version: ProtocolVersion,
+
+    /// The fixed base cost, in gas units, which every user transaction is charged regardless of
+    /// what it does. The minimum budget of a transaction is this value multiplied by its gas
+    /// price.
+    pub base_tx_cost_fixed: u64,
+
+    /// The maximum budget, in MIST, that a transaction is allowed to specify.
+    pub max_tx_gas: u64,
+
+    /// The kinds of transactions and commands which are enabled.
+    pub features: ProtocolFeatures,
+
+    /// The size limits of programmable transactions.
+    pub limits: TransactionLimits,
+}
+
+/// The features of the protocol which gate kinds of transactions and commands.
+///
+/// The default has every feature enabled, as in the latest protocol versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolFeatures {
+    /// Whether packages can be upgraded, with the `Upgrade` command.
+    pub package_upgrades: bool,
+
+    /// Whether the JWKs used by zklogin are tracked on-chain, with `AuthenticatorStateUpdate`
+    /// transactions.
+    pub authenticator_state: bool,
+
+    /// Whether epochs are ended by `EndOfEpochTransaction`s rather than `ChangeEpoch` ones.
+    pub end_of_epoch_transaction: bool,
+
+    /// Whether on-chain randomness is enabled, with `RandomnessStateUpdate` transactions.
+    pub random_beacon: bool,
+
+    /// Whether coins can be regulated with a deny list.
+    pub coin_deny_list: bool,
+
+    /// Whether the native bridge is enabled.
+    pub bridge: bool,
+
+    /// The latest version of the consensus commit prologue transaction, from 1 to 4.
+    pub consensus_commit_prologue_version: u8,
+}
+
+impl Default for ProtocolFeatures {
+    fn default() -> Self {
+        Self {
+            package_upgrades: true,
+            authenticator_state: true,
+            end_of_epoch_transaction: true,
+            random_beacon: true,
+            coin_deny_list: true,
+            bridge: true,
+            consensus_commit_prologue_version: 4,
+        }
+    }
+}
+
+/// The size limits a programmable transaction must stay within.
+///
+/// The default is the limits of the latest protocol versions, which are also the ones checked
+/// by [`Transaction::validity_check`](crate::Transaction::validity_check).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransactionLimits {
+    /// The maximum number of commands of a transaction.
+    pub max_commands: usize,
+
+    /// The maximum number of object inputs of a transaction.
+    pub max_input_objects: usize,
+
+    /// The maximum size of a pure input, in bytes.
+    pub max_pure_argument_size: usize,
+
+    /// The maximum number of arguments of a command.
+    pub max_arguments: usize,
+
+    /// The maximum number of type arguments of a move call.
+    pub max_type_arguments: usize,
+
+    /// The maximum number of modules in a published or upgraded package.
+    pub max_modules_in_publish: usize,
+
+    /// The maximum number of publish and upgrade commands of a transaction.
+    pub max_publish_or_upgrade: usize,
+
+    /// The maximum total size of the modules of a published or upgraded package, in bytes.
+    pub max_package_size: usize,
+
+    /// The maximum number of objects used to pay for gas.
+    pub max_gas_objects: usize,
+}
+
+impl Default for TransactionLimits {
+    fn default() -> Self {
+        Self {
+            max_commands: ProgrammableTransaction::MAX_COMMANDS,
+            max_input_objects: ProgrammableTransaction::MAX_INPUT_OBJECTS,
+            max_pure_argument_size: ProgrammableTransaction::MAX_PURE_ARGUMENT_SIZE,
+            max_arguments: ProgrammableTransaction::MAX_ARGUMENTS,
+            max_type_arguments: ProgrammableTransaction::MAX_TYPE_ARGUMENTS,
+            max_modules_in_publish: ProgrammableTransaction::MAX_MODULES_IN_PUBLISH,
+            max_publish_or_upgrade: ProgrammableTransaction::MAX_PUBLISH_OR_UPGRADE,
+            max_package_size: ProgrammableTransaction::MAX_PACKAGE_SIZE,
+            max_gas_objects: GasPayment::MAX_GAS_OBJECTS,
+        }
+    }
+}
+
+/// Protocol configs keyed by the protocol version they apply from, e.g. to replay historical
+/// checkpoints under the rules which applied when they were created.
+///
+/// A config only needs to be inserted for the versions which changed it: looking up a version
+/// returns the config of the latest version at or before it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProtocolConfigTable {
+    configs: BTreeMap<ProtocolVersion, ProtocolConfig>,
+}
+
+impl ProtocolConfigTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert `config` for its protocol version, returning the config it replaces, if any.
+    pub fn insert(&mut self, config: ProtocolConfig) -> Option<ProtocolConfig> {
+        self.configs.insert(config.protocol_version, config)
+    }
+
+    /// The config which applies at `protocol_version`, or `None` if it's older than every config
+    /// in the table.
+    pub fn get(&self, protocol_version: ProtocolVersion) -> Option<&ProtocolConfig> {
+        self.configs
+            .range(..=protocol_version)
+            .next_back()
+            .map(|(_, config)| config)
+    }
+
+    /// The configs of the table, ordered by protocol version.
+    pub fn iter(&self) -> impl Iterator<Item = &ProtocolConfig> {
+        self.configs.values()
+    }
+}
+
+/// A change of the modelled features of the protocol, with the versions it took effect from on
+/// mainnet and on testnet.
+struct FeatureChange {
+    mainnet: ProtocolVersion,
+    testnet: ProtocolVersion,
+    apply: fn(&mut ProtocolFeatures),
+}
+
+/// The versions the modelled features were enabled at on mainnet and testnet, as set by the
+/// `sui-protocol-config` crate of the Sui node.
+const FEATURE_CHANGES: &[FeatureChange] = &[
+    FeatureChange {
+        mainnet: 25,
+        testnet: 24,
+        apply: |features| {
+            features.end_of_epoch_transaction = true;
+            features.authenticator_state = true;
+        },
+    },
+    FeatureChange {
+        mainnet: 35,
+        testnet: 35,
+        apply: |features| features.coin_deny_list = true,
+    },
+    FeatureChange {
+        mainnet: 36,
+        testnet: 36,
+        apply: |features| features.consensus_commit_prologue_version = 2,
+    },
+    FeatureChange {
+        mainnet: 54,
+        testnet: 48,
+        apply: |features| features.random_beacon = true,
+    },
+    FeatureChange {
+        mainnet: 52,
+        testnet: 50,
+        apply: |features| features.consensus_commit_prologue_version = 3,
+    },
+    FeatureChange {
+        mainnet: 56,
+        testnet: 46,
+        apply: |features| features.bridge = true,
+    },
+    FeatureChange {
+        mainnet: 75,
+        testnet: 75,
+        apply: |features| features.consensus_commit_prologue_version = 4,
+    },
+];
+
+impl ProtocolConfigTable {
+    /// The table of the protocol configs of `network`, from its first protocol version, or
+    /// `None` for devnet and localnet, which are regularly reset to the latest protocol version
+    /// and use the [`Default`] features.
+    ///
+    /// Only the versions at which the [`ProtocolFeatures`] changed are recorded. The gas
+    /// parameters and [`TransactionLimits`] of every config are the ones of the latest protocol
+    /// versions.
+    pub fn for_network(network: Network) -> Option<Self> {
+        let version = match network {
+            Network::Mainnet => |change: &FeatureChange| change.mainnet,
+            Network::Testnet => |change: &FeatureChange| change.testnet,
+            Network::Devnet | Network::Localnet => return None,
+        };
+
+        let mut config = ProtocolConfig {
+            protocol_version: 1,
+            base_tx_cost_fixed: 1_000,
+            max_tx_gas: 50_000_000_000,
+            features: ProtocolFeatures {
+                package_upgrades: true,
+                authenticator_state: false,
+                end_of_epoch_transaction: false,
+                random_beacon: false,
+                coin_deny_list: false,
+                bridge: false,
+                consensus_commit_prologue_version: 1,
+            },
+            limits: TransactionLimits::default(),
+        };
+        let mut changes = FEATURE_CHANGES.iter().collect::<Vec<_>>();
+        changes.sort_by_key(|change| version(change));
+
+        let mut table = Self::new();
+        table.insert(config.clone());
+        for change in changes {
+            config.protocol_version = version(change);
+            (change.apply)(&mut config.features);
+            table.insert(config.clone());
+        }
+        Some(table)
+    }
+}
+
+impl FromIterator<ProtocolConfig> for ProtocolConfigTable {
+    fn from_iter<I: IntoIterator<Item = ProtocolConfig>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for config in iter {
+            table.insert(config);
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn config(protocol_version: ProtocolVersion, package_upgrades: bool) -> ProtocolConfig {
+        ProtocolConfig {
+            protocol_version,
+            features: ProtocolFeatures {
+                package_upgrades,
+                ..Default::default()
+            },
+            ..crate::test_util::protocol_config()
+        }
+    }
+
+    #[test]
+    fn protocol_config_table() {
+        let table: ProtocolConfigTable = [config(10, true), config(5, false)].into_iter().collect();
+        assert_eq!(table.get(4), None);
+        assert_eq!(table.get(5), Some(&config(5, false)));
+        assert_eq!(table.get(9), Some(&config(5, false)));
+        assert_eq!(table.get(42), Some(&config(10, true)));
+        assert_eq!(
+            table.iter().map(|c| c.protocol_version).collect::<Vec<_>>(),
+            [5, 10]
+        );
+    }
+
+    #[test]
+    fn network_tables() {
+        assert!(ProtocolConfigTable::for_network(Network::Devnet).is_none());
+
+        for network in [Network::Mainnet, Network::Testnet] {
+            let table = ProtocolConfigTable::for_network(network).unwrap();
+            let first = table.get(1).unwrap();
+            assert!(first.features.package_upgrades);
+            assert!(!first.features.end_of_epoch_transaction);
+            assert_eq!(first.features.consensus_commit_prologue_version, 1);
+
+            // Every feature is enabled in the latest versions
+            let latest = table.iter().last().unwrap();
+            assert_eq!(latest.features, ProtocolFeatures::default());
+
+            // Features are never disabled once enabled
+            for (previous, next) in table.iter().zip(table.iter().skip(1)) {
+                assert!(previous.protocol_version < next.protocol_version);
+                assert!(
+                    next.features.consensus_commit_prologue_version
+                        >= previous.features.consensus_commit_prologue_version
+                );
+                assert!(next.features.random_beacon || !previous.features.random_beacon);
+                assert!(next.features.bridge || !previous.features.bridge);
+            }
+        }
+
+        let mainnet = ProtocolConfigTable::for_network(Network::Mainnet).unwrap();
+        let testnet = ProtocolConfigTable::for_network(Network::Testnet).unwrap();
+        assert!(!mainnet.get(50).unwrap().features.bridge);
+        assert!(testnet.get(50).unwrap().features.bridge);
+    }
+}
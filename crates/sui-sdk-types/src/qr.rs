@@ -0,0 +1,373 @@
+//! Transfer of transactions as a sequence of QR codes, e.g. to an air-gapped signer.
+//!
+//! A transaction rarely fits in a single QR code, so [`encode_transaction`] splits its BCS bytes
+//! into [`TransactionPart`]s small enough to be displayed one after the other, as an animated QR
+//! code. Each part is rendered as a line of text:
+//!
+//! ```text
+//! sui-tx/<index>-<total>/<transaction digest>/<crc32 of the data, in hex>/<data, in unpadded base64url>
+//! ```
+//!
+//! with `index` starting at 1. A [`TransactionAssembler`], on the receiving side, accepts the
+//! parts in any order and with repetitions, as they are scanned, and once it has all of them
+//! returns the transaction after checking that it has the digest announced by every part.
+//!
+//! This format is specific to this crate: it isn't the Uniform Resources (UR) encoding used by
+//! other air-gapped wallets, which can't read these parts.
+
+use std::collections::BTreeMap;
+
+use base64ct::Base64UrlUnpadded;
+use base64ct::Encoding;
+
+use crate::Transaction;
+use crate::TransactionDigest;
+
+const PREFIX: &str = "sui-tx";
+
+/// A part of the BCS bytes of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionPart {
+    /// The index of this part, starting at 0.
+    pub index: u16,
+
+    /// The number of parts of the transaction.
+    pub total: u16,
+
+    /// The digest of the whole transaction.
+    pub digest: TransactionDigest,
+
+    /// The bytes of the transaction carried by this part.
+    pub data: Vec<u8>,
+}
+
+/// Split `transaction` into parts carrying at most `max_data_len` bytes each.
+///
+/// Fails if `max_data_len` is 0, or so small that the transaction needs more than `u16::MAX`
+/// parts.
+pub fn encode_transaction(
+    transaction: &Transaction,
+    max_data_len: usize,
+) -> Result<Vec<TransactionPart>, TransactionPartError> {
+    let bytes = bcs::to_bytes(transaction).expect("serialization cannot fail");
+    let total = (max_data_len > 0)
+        .then(|| bytes.len().div_ceil(max_data_len))
+        .and_then(|total| u16::try_from(total).ok())
+        .ok_or(TransactionPartError::TooManyParts { max_data_len })?;
+    let digest = transaction.digest();
+
+    Ok(bytes
+        .chunks(max_data_len)
+        .enumerate()
+        .map(|(index, data)| TransactionPart {
+            index: index as u16,
+            total,
+            digest,
+            data: data.to_vec(),
+        })
+        .collect())
+}
+
+impl std::fmt::Display for TransactionPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{PREFIX}/{}-{}/{}/{:08x}/{}",
+            u32::from(self.index) + 1,
+            self.total,
+            self.digest,
+            crc32(&self.data),
+            Base64UrlUnpadded::encode_string(&self.data),
+        )
+    }
+}
+
+impl std::str::FromStr for TransactionPart {
+    type Err = TransactionPartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split('/');
+        let mut field = || fields.next().ok_or(TransactionPartError::Malformed);
+
+        if !field()?.eq_ignore_ascii_case(PREFIX) {
+            return Err(TransactionPartError::Malformed);
+        }
+        let (index, total) = field()?
+            .split_once('-')
+            .ok_or(TransactionPartError::Malformed)?;
+        let index = index
+            .parse::<u16>()
+            .map_err(|_| TransactionPartError::Malformed)?;
+        let total = total
+            .parse::<u16>()
+            .map_err(|_| TransactionPartError::Malformed)?;
+        if index == 0 || index > total {
+            return Err(TransactionPartError::Malformed);
+        }
+        let digest = field()?
+            .parse()
+            .map_err(|_| TransactionPartError::Malformed)?;
+        let checksum =
+            u32::from_str_radix(field()?, 16).map_err(|_| TransactionPartError::Malformed)?;
+        let data =
+            Base64UrlUnpadded::decode_vec(field()?).map_err(|_| TransactionPartError::Malformed)?;
+        if fields.next().is_some() {
+            return Err(TransactionPartError::Malformed);
+        }
+
+        if crc32(&data) != checksum {
+            return Err(TransactionPartError::ChecksumMismatch { index: index - 1 });
+        }
+
+        Ok(Self {
+            index: index - 1,
+            total,
+            digest,
+            data,
+        })
+    }
+}
+
+/// Reassembles a transaction from its parts, received in any order.
+#[derive(Clone, Debug, Default)]
+pub struct TransactionAssembler {
+    expected: Option<(TransactionDigest, u16)>,
+    parts: BTreeMap<u16, Vec<u8>>,
+}
+
+impl TransactionAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `part`, returning the transaction once all its parts were received.
+    ///
+    /// Parts already received are ignored. A part of another transaction than the one being
+    /// assembled, e.g. because the sender started displaying a new transaction, discards the
+    /// parts received so far and starts assembling the new transaction.
+    ///
+    /// If the reassembled parts aren't the announced transaction, the parts received are
+    /// discarded as well, so that the transaction can be scanned again.
+    pub fn insert(
+        &mut self,
+        part: TransactionPart,
+    ) -> Result<Option<Transaction>, TransactionPartError> {
+        if part.index >= part.total {
+            return Err(TransactionPartError::UnexpectedPart { index: part.index });
+        }
+        if self.expected != Some((part.digest, part.total)) {
+            self.reset();
+            self.expected = Some((part.digest, part.total));
+        }
+        self.parts.entry(part.index).or_insert(part.data);
+
+        if self.parts.len() < usize::from(part.total) {
+            return Ok(None);
+        }
+
+        let bytes = self.parts.values().flatten().copied().collect::<Vec<_>>();
+        let result = match bcs::from_bytes::<Transaction>(&bytes) {
+            Err(_) => Err(TransactionPartError::InvalidTransaction),
+            Ok(transaction) if transaction.digest() != part.digest => {
+                Err(TransactionPartError::DigestMismatch {
+                    expected: part.digest,
+                    actual: transaction.digest(),
+                })
+            }
+            Ok(transaction) => return Ok(Some(transaction)),
+        };
+        self.parts.clear();
+        result
+    }
+
+    /// Discard the parts received so far, to start assembling another transaction.
+    pub fn reset(&mut self) {
+        self.expected = None;
+        self.parts.clear();
+    }
+
+    /// The number of distinct parts received so far, and the number of parts of the transaction,
+    /// once a first part was received.
+    pub fn progress(&self) -> Option<(usize, u16)> {
+        self.expected.map(|(_, total)| (self.parts.len(), total))
+    }
+}
+
+/// The CRC-32 (IEEE) checksum of `data`.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Error returned when decoding or reassembling the parts of a transaction fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionPartError {
+    /// The text isn't a transaction part.
+    Malformed,
+    /// The data of the part with the given index doesn't match its checksum.
+    ChecksumMismatch { index: u16 },
+    /// The part with the given index isn't one of the parts of its transaction.
+    UnexpectedPart { index: u16 },
+    /// The transaction can't be split into at most `u16::MAX` parts of `max_data_len` bytes.
+    TooManyParts { max_data_len: usize },
+    /// The reassembled bytes aren't a transaction.
+    InvalidTransaction,
+    /// The reassembled transaction doesn't have the digest announced by its parts.
+    DigestMismatch {
+        expected: TransactionDigest,
+        actual: TransactionDigest,
+    },
+}
+
+impl std::fmt::Display for TransactionPartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed => write!(f, "malformed transaction part"),
+            Self::ChecksumMismatch { index } => {
+                write!(f, "checksum mismatch in transaction part {index}")
+            }
+            Self::UnexpectedPart { index } => {
+                write!(f, "transaction part {index} is past the last part")
+            }
+            Self::TooManyParts { max_data_len } => write!(
+                f,
+                "transaction doesn't fit in {} parts of {max_data_len} bytes",
+                u16::MAX
+            ),
+            Self::InvalidTransaction => write!(f, "reassembled parts aren't a transaction"),
+            Self::DigestMismatch { expected, actual } => write!(
+                f,
+                "reassembled transaction digest mismatch: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for TransactionPartError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Malformed => "malformed_transaction_part",
+            Self::ChecksumMismatch { .. } => "transaction_part_checksum_mismatch",
+            Self::UnexpectedPart { .. } => "unexpected_transaction_part",
+            Self::TooManyParts { .. } => "too_many_transaction_parts",
+            Self::InvalidTransaction => "invalid_reassembled_transaction",
+            Self::DigestMismatch { .. } => "reassembled_transaction_digest_mismatch",
+        }
+    }
+}
+
+impl std::error::Error for TransactionPartError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn genesis() -> Transaction {
+        const GENESIS_TRANSACTION: &str = include_str!("transaction/fixtures/genesis-transaction");
+        let bytes = base64ct::Base64::decode_vec(GENESIS_TRANSACTION.trim()).unwrap();
+        bcs::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn transfer_transaction() {
+        let transaction = genesis();
+        let len = bcs::to_bytes(&transaction).unwrap().len();
+        let parts = encode_transaction(&transaction, 512).unwrap();
+        assert_eq!(parts.len(), len.div_ceil(512));
+
+        let lines = parts.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert!(lines[0].starts_with(&format!("sui-tx/1-{}/", parts.len())));
+
+        // Parts are scanned in any order, some of them more than once
+        let mut assembler = TransactionAssembler::new();
+        let mut received = None;
+        for line in lines.iter().rev().chain(&lines) {
+            let part = line.parse::<TransactionPart>().unwrap();
+            if let Some(transaction) = assembler.insert(part).unwrap() {
+                received = Some(transaction);
+                break;
+            }
+        }
+        assert_eq!(received, Some(transaction));
+        assert_eq!(
+            assembler.progress(),
+            Some((parts.len(), parts.len() as u16))
+        );
+    }
+
+    #[test]
+    fn corrupted_parts() {
+        let transaction = genesis();
+        let parts = encode_transaction(&transaction, 512).unwrap();
+        assert_eq!(
+            encode_transaction(&transaction, 0),
+            Err(TransactionPartError::TooManyParts { max_data_len: 0 })
+        );
+
+        let line = parts[0].to_string();
+        let (rest, data) = line.rsplit_once('/').unwrap();
+        let mut bytes = Base64UrlUnpadded::decode_vec(data).unwrap();
+        bytes[0] ^= 1;
+        let corrupted = format!("{rest}/{}", Base64UrlUnpadded::encode_string(&bytes));
+        assert_eq!(
+            corrupted.parse::<TransactionPart>(),
+            Err(TransactionPartError::ChecksumMismatch { index: 0 })
+        );
+        assert_eq!(
+            "sui-tx/0-1/x/0/".parse::<TransactionPart>(),
+            Err(TransactionPartError::Malformed)
+        );
+
+        // A part whose data was altered consistently with its checksum is caught by the digest
+        let mut assembler = TransactionAssembler::new();
+        let mut altered = parts.clone();
+        altered.last_mut().unwrap().data = vec![0; 3];
+        let mut result = Ok(None);
+        for part in altered {
+            result = assembler.insert(part);
+        }
+        assert!(result.is_err());
+        assert_eq!(assembler.progress(), Some((0, parts.len() as u16)));
+
+        // The transaction can be scanned again after a failure
+        let mut result = Ok(None);
+        for part in parts.clone() {
+            result = assembler.insert(part);
+        }
+        assert_eq!(result, Ok(Some(transaction)));
+
+        // A part of another transaction restarts the assembly
+        let mut assembler = TransactionAssembler::new();
+        let mut other = parts[1].clone();
+        other.digest = TransactionDigest::ZERO;
+        assert_eq!(assembler.insert(other), Ok(None));
+        assert_eq!(assembler.insert(parts[0].clone()), Ok(None));
+        assert_eq!(assembler.progress(), Some((1, parts.len() as u16)));
+
+        let mut out_of_range = parts[0].clone();
+        out_of_range.index = out_of_range.total;
+        assert_eq!(
+            assembler.insert(out_of_range),
+            Err(TransactionPartError::UnexpectedPart {
+                index: parts.len() as u16
+            })
+        );
+
+        assembler.reset();
+        assert_eq!(assembler.progress(), None);
+    }
+}
@@ -0,0 +1,851 @@
+//! Explicit selection of the serialized representation of types.
+//!
+//! Types in this crate have two representations: a binary one, which is the canonical BCS form
+//! used on-chain, and a human readable one, which matches the JSON returned by the RPC. Which one
+//! is used is normally decided by the serialization format itself, through
+//! [`serde::Serializer::is_human_readable`], e.g. `bcs` always uses the binary representation
+//! and `serde_json` always uses the readable one.
+//!
+//! This module makes that choice explicit:
+//!
+//! - [`SerializationProfile`] serializes and deserializes values to and from bytes using one of
+//!   the supported combinations of format and representation.
+//! - [`Compact`] wraps a value so that it uses its binary representation in any format, which
+//!   allows embedding the compact form of a value in a larger JSON document.
+//!
+//! ```
+//! use sui_sdk_types::serialization_profile::SerializationProfile;
+//! use sui_sdk_types::Address;
+//!
+//! let address = Address::TWO;
+//! for profile in [
+//!     SerializationProfile::Bcs,
+//!     SerializationProfile::Json,
+//!     SerializationProfile::CompactJson,
+//! ] {
+//!     let bytes = profile.to_vec(&address).unwrap();
+//!     assert_eq!(profile.from_slice::<Address>(&bytes).unwrap(), address);
+//! }
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::de::DeserializeSeed;
+use serde::de::EnumAccess;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::VariantAccess;
+use serde::de::Visitor;
+use serde::ser::SerializeMap;
+use serde::ser::SerializeSeq;
+use serde::ser::SerializeStruct;
+use serde::ser::SerializeStructVariant;
+use serde::ser::SerializeTuple;
+use serde::ser::SerializeTupleStruct;
+use serde::ser::SerializeTupleVariant;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+/// A combination of serialization format and representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerializationProfile {
+    /// The canonical BCS form, as stored on-chain and used for hashing and signing.
+    Bcs,
+    /// The human readable JSON form, compatible with the RPC.
+    Json,
+    /// JSON of the binary representation.
+    ///
+    /// Values keep the exact structure of their BCS form, e.g. enums are externally tagged by
+    /// variant name, digests are arrays of bytes and maps are sequences of keys and values, which
+    /// makes this form cheaper to produce and parse than [`SerializationProfile::Json`] at the
+    /// expense of readability. It is meant for internal storage and is not compatible with the
+    /// RPC.
+    CompactJson,
+}
+
+impl SerializationProfile {
+    /// Serialize `value` according to this profile.
+    pub fn to_vec<T: ?Sized + Serialize>(self, value: &T) -> Result<Vec<u8>, SerializationError> {
+        match self {
+            Self::Bcs => bcs::to_bytes(value).map_err(SerializationError::Bcs),
+            Self::Json => serde_json::to_vec(value).map_err(SerializationError::Json),
+            Self::CompactJson => {
+                serde_json::to_vec(&Compact(value)).map_err(SerializationError::Json)
+            }
+        }
+    }
+
+    /// Deserialize a value serialized according to this profile.
+    pub fn from_slice<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, SerializationError> {
+        match self {
+            Self::Bcs => bcs::from_bytes(bytes).map_err(SerializationError::Bcs),
+            Self::Json => serde_json::from_slice(bytes).map_err(SerializationError::Json),
+            Self::CompactJson => serde_json::from_slice::<Compact<T>>(bytes)
+                .map(Compact::into_inner)
+                .map_err(SerializationError::Json),
+        }
+    }
+
+    /// Returns true if this profile uses the human readable representation of values.
+    pub fn is_human_readable(self) -> bool {
+        matches!(self, Self::Json)
+    }
+}
+
+/// Error returned when serializing or deserializing with a [`SerializationProfile`].
+#[derive(Debug)]
+pub enum SerializationError {
+    Bcs(bcs::Error),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bcs(e) => write!(f, "bcs error: {e}"),
+            Self::Json(e) => write!(f, "json error: {e}"),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for SerializationError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Bcs(_) => "bcs_error",
+            Self::Json(_) => "json_error",
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Bcs(e) => Some(e),
+            Self::Json(e) => Some(e),
+        }
+    }
+}
+
+/// Wrapper which makes a value use its binary representation regardless of the format it is
+/// serialized with.
+///
+/// In binary formats, like BCS, this is the same as serializing the value directly.
+///
+/// ```
+/// use sui_sdk_types::serialization_profile::Compact;
+/// use sui_sdk_types::TypeTag;
+///
+/// let vector = TypeTag::Vector(Box::new(TypeTag::U8));
+/// let json = serde_json::to_string(&Compact(&vector)).unwrap();
+/// assert_eq!(json, r#"{"vector":"u8"}"#);
+/// assert_eq!(serde_json::to_string(&vector).unwrap(), r#""vector<u8>""#);
+///
+/// let type_tag: Compact<TypeTag> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(type_tag.into_inner(), vector);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Compact<T>(pub T);
+
+impl<T> Compact<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize> Serialize for Compact<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(CompactSerializer(serializer))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Compact<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(CompactDeserializer(deserializer)).map(Compact)
+    }
+}
+
+/// Reference to a value which is serialized with a [`CompactSerializer`].
+struct CompactRef<'a, T: ?Sized>(&'a T);
+
+impl<T: ?Sized + Serialize> Serialize for CompactRef<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(CompactSerializer(serializer))
+    }
+}
+
+/// A serializer which delegates to `S` but reports not being human readable, for itself as well
+/// as for all of the serializers of the nested values.
+struct CompactSerializer<S>(S);
+
+impl<S: Serializer> Serializer for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = CompactSerializer<S::SerializeSeq>;
+    type SerializeTuple = CompactSerializer<S::SerializeTuple>;
+    type SerializeTupleStruct = CompactSerializer<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = CompactSerializer<S::SerializeTupleVariant>;
+    type SerializeMap = CompactMapSerializer<S::SerializeSeq>;
+    type SerializeStruct = CompactSerializer<S::SerializeStruct>;
+    type SerializeStructVariant = CompactSerializer<S::SerializeStructVariant>;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_i128(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_u64(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_u128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_some(&CompactRef(value))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_newtype_struct(name, &CompactRef(value))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0
+            .serialize_newtype_variant(name, variant_index, variant, &CompactRef(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.0.serialize_seq(len).map(CompactSerializer)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.0.serialize_tuple(len).map(CompactSerializer)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.0
+            .serialize_tuple_struct(name, len)
+            .map(CompactSerializer)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.0
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(CompactSerializer)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.0
+            .serialize_seq(len.map(|len| len * 2))
+            .map(CompactMapSerializer)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.0.serialize_struct(name, len).map(CompactSerializer)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.0
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(CompactSerializer)
+    }
+}
+
+impl<S: SerializeSeq> SerializeSeq for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&CompactRef(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeTuple> SerializeTuple for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&CompactRef(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeTupleStruct> SerializeTupleStruct for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(&CompactRef(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeTupleVariant> SerializeTupleVariant for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(&CompactRef(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+/// Maps are serialized as a flat sequence of alternating keys and values, like in BCS, since the
+/// binary representation of keys usually isn't a string, which JSON requires of object keys.
+struct CompactMapSerializer<S>(S);
+
+impl<S: SerializeSeq> SerializeMap for CompactMapSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&CompactRef(key))
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&CompactRef(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeStruct> SerializeStruct for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, &CompactRef(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.0.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<S: SerializeStructVariant> SerializeStructVariant for CompactSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, &CompactRef(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.0.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+/// A deserializer which delegates to `D` but reports not being human readable, for itself as well
+/// as for all of the deserializers of the nested values.
+///
+/// The same wrapper is used around the visitors, seeds, and accessors handed out by `D`, so that
+/// nested values are reached through a wrapped deserializer as well.
+struct CompactDeserializer<D>(D);
+
+macro_rules! forward_deserialize {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                self.0.$method(CompactDeserializer(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for CompactDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_seq,
+        deserialize_identifier,
+        deserialize_ignored_any,
+    );
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_seq(CompactMapVisitor(visitor))
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_unit_struct(name, CompactDeserializer(visitor))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_newtype_struct(name, CompactDeserializer(visitor))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_tuple(len, CompactDeserializer(visitor))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_tuple_struct(name, len, CompactDeserializer(visitor))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_struct(name, fields, CompactDeserializer(visitor))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_enum(name, variants, CompactDeserializer(visitor))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CompactDeserializer<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    );
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.0.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.visit_some(CompactDeserializer(deserializer))
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.0.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.0
+            .visit_newtype_struct(CompactDeserializer(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_seq(CompactDeserializer(seq))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_map(CompactDeserializer(map))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_enum(CompactDeserializer(data))
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for CompactDeserializer<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize(CompactDeserializer(deserializer))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for CompactDeserializer<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.0.next_element_seed(CompactDeserializer(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for CompactDeserializer<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.0.next_key_seed(CompactDeserializer(seed))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.next_value_seed(CompactDeserializer(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+/// Reads the flat sequence written by [`CompactMapSerializer`] back as a map.
+struct CompactMapVisitor<V>(V);
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CompactMapVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.0.visit_map(CompactMapAccess(seq))
+    }
+}
+
+struct CompactMapAccess<A>(A);
+
+impl<'de, A: SeqAccess<'de>> MapAccess<'de> for CompactMapAccess<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.0.next_element_seed(CompactDeserializer(seed))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .next_element_seed(CompactDeserializer(seed))?
+            .ok_or_else(|| serde::de::Error::custom("map key without a value"))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint().map(|len| len / 2)
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for CompactDeserializer<A> {
+    type Error = A::Error;
+    type Variant = CompactDeserializer<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        self.0
+            .variant_seed(CompactDeserializer(seed))
+            .map(|(value, variant)| (value, CompactDeserializer(variant)))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for CompactDeserializer<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        self.0.newtype_variant_seed(CompactDeserializer(seed))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.tuple_variant(len, CompactDeserializer(visitor))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.struct_variant(fields, CompactDeserializer(visitor))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::PTB;
+    use crate::Address;
+    use crate::Transaction;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn profiles() {
+        let transaction = Transaction::from_base64(PTB).unwrap();
+
+        assert_eq!(
+            SerializationProfile::Bcs.to_vec(&transaction).unwrap(),
+            transaction.to_bytes()
+        );
+
+        let readable = SerializationProfile::Json.to_vec(&transaction).unwrap();
+        let compact = SerializationProfile::CompactJson
+            .to_vec(&transaction)
+            .unwrap();
+        assert_ne!(readable, compact);
+
+        // The compact form follows the structure of the BCS form, e.g. the transaction's version
+        // is an externally tagged variant instead of a `version` field
+        let compact_json: serde_json::Value = serde_json::from_slice(&compact).unwrap();
+        assert!(compact_json.get("1").is_some());
+        let readable_json: serde_json::Value = serde_json::from_slice(&readable).unwrap();
+        assert_eq!(readable_json["version"], "1");
+
+        for (profile, bytes) in [
+            (SerializationProfile::Json, &readable),
+            (SerializationProfile::CompactJson, &compact),
+        ] {
+            assert_eq!(
+                profile.from_slice::<Transaction>(bytes).unwrap(),
+                transaction
+            );
+        }
+
+        // Compact values can be embedded in readable documents
+        let document = serde_json::json!({
+            "sender": Address::TWO,
+            "transaction": Compact(&transaction),
+        });
+        let transaction_json = serde_json::to_vec(&document["transaction"]).unwrap();
+        assert_eq!(
+            SerializationProfile::CompactJson
+                .from_slice::<Transaction>(&transaction_json)
+                .unwrap(),
+            transaction
+        );
+
+        SerializationProfile::CompactJson
+            .from_slice::<Transaction>(&readable)
+            .unwrap_err();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::event_stream::EventId;
 use crate::*;
 use test_strategy::proptest;
 
@@ -38,6 +39,11 @@ where
     let json = serde_json::to_string(instance).unwrap();
     let deser_from_json = serde_json::from_str::<T>(&json).unwrap();
     assert_eq!(instance, &deser_from_json);
+
+    let profile = crate::serialization_profile::SerializationProfile::CompactJson;
+    let compact_json = profile.to_vec(instance).unwrap();
+    let deser_from_compact_json = profile.from_slice::<T>(&compact_json).unwrap();
+    assert_eq!(instance, &deser_from_compact_json);
 }
 
 serialization_test!(Address);
@@ -49,6 +55,8 @@ serialization_test!(CheckpointSummary);
 serialization_test!(CheckpointTimestamp);
 serialization_test!(CheckpointTransaction);
 serialization_test!(CheckpointTransactionInfo);
+serialization_test!(LenientCheckpointData);
+serialization_test!(LenientCheckpointTransaction);
 serialization_test!(EndOfEpochData);
 serialization_test!(SignedCheckpointSummary);
 serialization_test!(Bls12381PublicKey);
@@ -61,6 +69,7 @@ serialization_test!(Jwk);
 serialization_test!(JwkId);
 serialization_test!(MultisigAggregatedSignature);
 serialization_test!(MultisigCommittee);
+serialization_test!(LenientUserSignature);
 serialization_test!(MultisigMember);
 serialization_test!(MultisigMemberPublicKey);
 serialization_test!(MultisigMemberSignature);
@@ -72,6 +81,7 @@ serialization_test!(SimpleSignature);
 serialization_test!(UserSignature);
 serialization_test!(ValidatorAggregatedSignature);
 serialization_test!(ValidatorCommittee);
+serialization_test!(ValidatorMetadata);
 serialization_test!(ValidatorCommitteeMember);
 serialization_test!(ValidatorSignature);
 serialization_test!(ZkLoginAuthenticator);
@@ -99,11 +109,18 @@ serialization_test!(ObjectReferenceWithOwner);
 serialization_test!(TransactionEffects);
 serialization_test!(TransactionEffectsV1);
 serialization_test!(TransactionEffectsV2);
+serialization_test!(SignedTransactionEffects);
+serialization_test!(CertifiedTransactionEffects);
+serialization_test!(EffectsAuxiliaryData);
 serialization_test!(UnchangedSharedKind);
 serialization_test!(UnchangedSharedObject);
 serialization_test!(BalanceChange);
 serialization_test!(Event);
+serialization_test!(EventFilter);
+serialization_test!(EventId);
 serialization_test!(TransactionEvents);
+serialization_test!(SignedPersonalMessage);
+serialization_test!(AnySignedMessage);
 serialization_test!(CommandArgumentError);
 serialization_test!(ExecutionError);
 serialization_test!(ExecutionStatus);
@@ -129,8 +146,10 @@ serialization_test!(ConsensusCommitPrologueV2);
 serialization_test!(ConsensusCommitPrologueV3);
 serialization_test!(ConsensusCommitPrologueV4);
 serialization_test!(CanceledTransaction);
+serialization_test!(CanceledTransactionV2);
 serialization_test!(ConsensusDeterminedVersionAssignments);
 serialization_test!(VersionAssignment);
+serialization_test!(VersionAssignmentV2);
 serialization_test!(EndOfEpochTransactionKind);
 serialization_test!(GasPayment);
 serialization_test!(GenesisTransaction);
@@ -142,6 +161,8 @@ serialization_test!(ProgrammableTransaction);
 serialization_test!(Publish);
 serialization_test!(RandomnessStateUpdate);
 serialization_test!(SignedTransaction);
+serialization_test!(LenientSignedTransaction);
+serialization_test!(SenderSignedData);
 serialization_test!(SplitCoins);
 serialization_test!(SystemPackage);
 serialization_test!(Transaction);
@@ -0,0 +1,106 @@
+//! Signed messages of any kind.
+//!
+//! A [`SignedPersonalMessage`] pairs a personal message with the user signature over it, like
+//! [`SignedTransaction`] does for transactions. [`AnySignedMessage`] covers all of the signed
+//! artifacts of Sui, so that transactions, checkpoint summaries and personal messages can be
+//! stored or passed around as a single type and identified by their digest.
+
+use super::PersonalMessage;
+use super::SignedCheckpointSummary;
+use super::SignedTransaction;
+use super::UserSignature;
+
+/// A personal message along with a user signature over it.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// signed-personal-message = bytes user-signature
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct SignedPersonalMessage {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::_serde::ReadableBase64Encoded")
+    )]
+    pub message: Vec<u8>,
+    pub signature: UserSignature,
+}
+
+impl SignedPersonalMessage {
+    /// The message which was signed.
+    pub fn personal_message(&self) -> PersonalMessage<'_> {
+        PersonalMessage(self.message.as_slice().into())
+    }
+}
+
+/// Any of the signed artifacts of Sui, e.g. to archive them in a single store.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// any-signed-message =  %x00 signed-transaction
+///                    =/ %x01 signed-checkpoint-summary
+///                    =/ %x02 signed-personal-message
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub enum AnySignedMessage {
+    Transaction(SignedTransaction),
+    CheckpointSummary(SignedCheckpointSummary),
+    PersonalMessage(SignedPersonalMessage),
+}
+
+impl From<SignedTransaction> for AnySignedMessage {
+    fn from(transaction: SignedTransaction) -> Self {
+        Self::Transaction(transaction)
+    }
+}
+
+impl From<SignedCheckpointSummary> for AnySignedMessage {
+    fn from(checkpoint: SignedCheckpointSummary) -> Self {
+        Self::CheckpointSummary(checkpoint)
+    }
+}
+
+impl From<SignedPersonalMessage> for AnySignedMessage {
+    fn from(message: SignedPersonalMessage) -> Self {
+        Self::PersonalMessage(message)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "hash"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "serde", feature = "hash"))))]
+impl AnySignedMessage {
+    /// The digest identifying the signed content, independently of its signatures.
+    ///
+    /// This is the digest of the transaction or checkpoint summary, which doesn't commit to its
+    /// signatures. Personal messages have no digest of their own and use their signing digest.
+    pub fn digest(&self) -> crate::Digest {
+        match self {
+            Self::Transaction(transaction) => {
+                crate::Digest::new(transaction.transaction.digest().into_inner())
+            }
+            Self::CheckpointSummary(checkpoint) => {
+                crate::Digest::new(checkpoint.checkpoint.digest().into_inner())
+            }
+            Self::PersonalMessage(message) => {
+                crate::Digest::new(message.personal_message().signing_digest())
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Fixtures, and hand-built objects and effects, shared by the tests of this crate.
+
+use crate::Address;
+use crate::ChangedObject;
+use crate::ExecutionStatus;
+use crate::GasCostSummary;
+use crate::IdOperation;
+use crate::MoveStruct;
+use crate::Object;
+use crate::ObjectData;
+use crate::ObjectDigest;
+use crate::ObjectId;
+use crate::ObjectIn;
+use crate::ObjectOut;
+use crate::Owner;
+use crate::ProtocolConfig;
+use crate::StructTag;
+use crate::TransactionDigest;
+use crate::TransactionEffects;
+use crate::TransactionEffectsV2;
+use crate::Version;
+
+/// A programmable transaction calling `clob_v2::cancel_order`, as base64 encoded BCS.
+pub(crate) const PTB: &str = "AAADAQFEBbUNeR/TNGdU6Bcaqra8LtJsLEbv3QM8FLMK5QesMyx96QEAAAAAAQAIVsakAAAAAAABALyyokbZ/8ynfWQer6UyP1DpeCnPU1NC7AyFNJSaTztnQF40BQAAAAAgffPXh5XuG6TWjHk6qC5w9k2a+41oTWfm0sC1FOYRqsEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAN7pB2Nsb2JfdjIMY2FuY2VsX29yZGVyAgcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgNzdWkDU1VJAAddSzAlBmRcN/8TO5jEtQpa4UhBZZc41tcz1Z0NIXqTvwRjb2luBENPSU4AAwEAAAEBAAECAPgh00g/x3Jeuvqlo9Ejc9SZAb384UhPIZ2qcGajDfd9ASXQjpFOD6mfycbzwD1wc+IOkCXQ8rHQo/Vi5SDOGMR/Jl40BQAAAAAgV7P1E0IMKon5uI82R/0arWLt+dc1ng/4VwKDqpTCxHT4IdNIP8dyXrr6paPRI3PUmQG9/OFITyGdqnBmow33fe4CAAAAAAAAAMqaOwAAAAAA";
+
+/// An address which is zero except for its last byte.
+pub(crate) fn address(byte: u8) -> Address {
+    let mut bytes = [0; Address::LENGTH];
+    bytes[Address::LENGTH - 1] = byte;
+    Address::new(bytes)
+}
+
+/// A coin of `coin_type` holding `balance`.
+pub(crate) fn coin(
+    id: ObjectId,
+    coin_type: StructTag,
+    version: Version,
+    owner: Owner,
+    balance: u64,
+) -> Object {
+    let mut contents = id.as_bytes().to_vec();
+    contents.extend_from_slice(&balance.to_le_bytes());
+    let data = MoveStruct::new(coin_type, true, version, contents).unwrap();
+    Object::new(ObjectData::Struct(data), owner, TransactionDigest::ZERO, 0)
+}
+
+/// An object created with `owner`.
+pub(crate) fn created(object_id: ObjectId, owner: Owner) -> ChangedObject {
+    ChangedObject {
+        object_id,
+        input_state: ObjectIn::NotExist,
+        output_state: ObjectOut::ObjectWrite {
+            digest: ObjectDigest::ZERO,
+            owner,
+        },
+        id_operation: IdOperation::Created,
+    }
+}
+
+/// An object mutated from `version`, keeping its `owner`.
+pub(crate) fn mutated(object_id: ObjectId, version: Version, owner: Owner) -> ChangedObject {
+    ChangedObject {
+        object_id,
+        input_state: ObjectIn::Exist {
+            version,
+            digest: ObjectDigest::ZERO,
+            owner,
+        },
+        output_state: ObjectOut::ObjectWrite {
+            digest: ObjectDigest::ZERO,
+            owner,
+        },
+        id_operation: IdOperation::None,
+    }
+}
+
+/// An object deleted at `version`.
+pub(crate) fn deleted(object_id: ObjectId, version: Version, owner: Owner) -> ChangedObject {
+    ChangedObject {
+        object_id,
+        input_state: ObjectIn::Exist {
+            version,
+            digest: ObjectDigest::ZERO,
+            owner,
+        },
+        output_state: ObjectOut::NotExist,
+        id_operation: IdOperation::Deleted,
+    }
+}
+
+/// The effects of a successful transaction with the zero digest, which didn't pay for gas nor
+/// emit events.
+///
+/// Other fields can be set with the struct update syntax.
+pub(crate) fn effects_v2(
+    lamport_version: Version,
+    changed_objects: Vec<ChangedObject>,
+) -> TransactionEffectsV2 {
+    TransactionEffectsV2 {
+        status: ExecutionStatus::Success,
+        epoch: 0,
+        gas_used: GasCostSummary::default(),
+        transaction_digest: TransactionDigest::ZERO,
+        gas_object_index: None,
+        events_digest: None,
+        dependencies: vec![],
+        lamport_version,
+        changed_objects,
+        unchanged_shared_objects: vec![],
+        auxiliary_data_digest: None,
+    }
+}
+
+/// [`effects_v2`] as [`TransactionEffects`].
+pub(crate) fn effects(
+    lamport_version: Version,
+    changed_objects: Vec<ChangedObject>,
+) -> TransactionEffects {
+    TransactionEffects::V2(Box::new(effects_v2(lamport_version, changed_objects)))
+}
+
+/// The config of protocol version 1, with every feature enabled and the gas parameters and limits
+/// of the latest protocol versions.
+pub(crate) fn protocol_config() -> ProtocolConfig {
+    ProtocolConfig {
+        protocol_version: 1,
+        base_tx_cost_fixed: 1_000,
+        max_tx_gas: 50_000_000_000,
+        features: Default::default(),
+        limits: Default::default(),
+    }
+}
@@ -0,0 +1,303 @@
+//! A registry of coin types and their display metadata.
+//!
+//! Amounts of coins are stored on chain as integers in the smallest unit of the coin, and it is up
+//! to applications to know how many decimals a coin type has in order to display or parse amounts.
+//! A [`TokenRegistry`] maps coin types to a [`TokenInfo`] holding their symbol and decimals. The
+//! registry returned by [`TokenRegistry::for_network`] is preloaded with well-known coins, such as
+//! SUI, native USDC, and assets bridged with Wormhole or the Sui bridge, and more tokens can be
+//! added at runtime.
+
+use crate::StructTag;
+use crate::TypeTag;
+
+/// The networks with a preloaded set of well-known tokens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+    Devnet,
+    Localnet,
+}
+
+/// The display metadata of a coin type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenInfo {
+    /// The type `T` of `Coin<T>`.
+    pub coin_type: TypeTag,
+    pub symbol: String,
+    pub name: String,
+
+    /// The number of decimals of the coin, i.e. an amount of `10^decimals` in the smallest unit
+    /// of the coin is displayed as `1`.
+    pub decimals: u8,
+}
+
+impl TokenInfo {
+    pub fn new<S: Into<String>, N: Into<String>>(
+        coin_type: TypeTag,
+        symbol: S,
+        name: N,
+        decimals: u8,
+    ) -> Self {
+        Self {
+            coin_type,
+            symbol: symbol.into(),
+            name: name.into(),
+            decimals,
+        }
+    }
+
+    /// Format `amount`, in the smallest unit of the coin, as a decimal number without trailing
+    /// zeros, e.g. `1500000000` MIST is formatted as `1.5` SUI.
+    pub fn format_amount(&self, amount: u64) -> String {
+        let decimals = self.decimals as usize;
+        let digits = format!("{amount:0>width$}", width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            whole.to_owned()
+        } else {
+            format!("{whole}.{fraction}")
+        }
+    }
+
+    /// Parse a decimal amount, e.g. `1.5` SUI, into an amount in the smallest unit of the coin.
+    pub fn parse_amount(&self, amount: &str) -> Result<u64, AmountParseError> {
+        let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return Err(AmountParseError::Invalid);
+        }
+        if fraction.len() > self.decimals as usize {
+            return Err(AmountParseError::TooManyDecimals {
+                decimals: self.decimals,
+            });
+        }
+
+        // The amount in the smallest unit is the digits of the amount with the fraction padded to
+        // the decimals of the token, which is only checked for overflow once leading zeros are
+        // dropped so that tokens with many decimals can still hold small amounts
+        let digits = format!("{whole}{fraction:0<width$}", width = self.decimals as usize);
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            return Ok(0);
+        }
+        digits.parse().map_err(|_| AmountParseError::Overflow)
+    }
+}
+
+/// Error returned when parsing an amount of a token fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmountParseError {
+    /// The amount isn't a non-negative decimal number.
+    Invalid,
+
+    /// The amount has more decimals than the token.
+    TooManyDecimals { decimals: u8 },
+
+    /// The amount doesn't fit in a `u64` once converted to the smallest unit of the token.
+    Overflow,
+}
+
+impl std::fmt::Display for AmountParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid amount"),
+            Self::TooManyDecimals { decimals } => {
+                write!(f, "amount has more than the token's {decimals} decimals")
+            }
+            Self::Overflow => f.write_str("amount overflows"),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for AmountParseError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "invalid_amount",
+            Self::TooManyDecimals { .. } => "too_many_decimals",
+            Self::Overflow => "amount_overflow",
+        }
+    }
+}
+
+impl std::error::Error for AmountParseError {}
+
+/// The well-known tokens of every network: `(coin type, symbol, name, decimals)`.
+const COMMON_TOKENS: &[(&str, &str, &str, u8)] = &[("0x2::sui::SUI", "SUI", "Sui", 9)];
+
+const MAINNET_TOKENS: &[(&str, &str, &str, u8)] = &[
+    (
+        "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC",
+        "USDC",
+        "USD Coin",
+        6,
+    ),
+    (
+        "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN",
+        "wUSDC",
+        "USD Coin (Wormhole from Ethereum)",
+        6,
+    ),
+    (
+        "0xc060006111016b8a020ad5b33834984a437aaa7d3c74c18e09a95d48aceab08c::coin::COIN",
+        "wUSDT",
+        "Tether USD (Wormhole from Ethereum)",
+        6,
+    ),
+    (
+        "0xaf8cd5edc19c4512f4259f0bee101a40d41ebed738ade5874359610ef8eeced5::coin::COIN",
+        "WETH",
+        "Wrapped Ether (Wormhole)",
+        8,
+    ),
+    (
+        "0xd0e89b2af5e4910726fbcd8b8dd37bb79b29e5f83f7491bca830e94f7f226d29::eth::ETH",
+        "ETH",
+        "Ether (Sui Bridge)",
+        8,
+    ),
+];
+
+const TESTNET_TOKENS: &[(&str, &str, &str, u8)] = &[(
+    "0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC",
+    "USDC",
+    "USD Coin",
+    6,
+)];
+
+/// A registry of the display metadata of coin types.
+///
+/// Symbols aren't enforced to be unique on chain, and so aren't in the registry either: looking up
+/// a symbol returns the token registered last with it, which allows overriding the preloaded
+/// tokens.
+#[derive(Clone, Debug, Default)]
+pub struct TokenRegistry {
+    tokens: Vec<TokenInfo>,
+}
+
+impl TokenRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry preloaded with the well-known tokens of `network`.
+    pub fn for_network(network: Network) -> Self {
+        let network_tokens = match network {
+            Network::Mainnet => MAINNET_TOKENS,
+            Network::Testnet => TESTNET_TOKENS,
+            Network::Devnet | Network::Localnet => &[],
+        };
+
+        let mut registry = Self::new();
+        for (coin_type, symbol, name, decimals) in COMMON_TOKENS.iter().chain(network_tokens) {
+            let coin_type = coin_type.parse().expect("well-known coin types are valid");
+            registry.insert(TokenInfo::new(coin_type, *symbol, *name, *decimals));
+        }
+        registry
+    }
+
+    /// Add `token` to the registry, replacing any token with the same coin type.
+    pub fn insert(&mut self, token: TokenInfo) {
+        self.tokens.retain(|t| t.coin_type != token.coin_type);
+        self.tokens.push(token);
+    }
+
+    pub fn by_coin_type(&self, coin_type: &TypeTag) -> Option<&TokenInfo> {
+        self.tokens.iter().find(|t| t.coin_type == *coin_type)
+    }
+
+    /// Look up a token from the type of a `Coin<T>` object, e.g. `0x2::coin::Coin<0x2::sui::SUI>`.
+    pub fn by_object_type(&self, object_type: &StructTag) -> Option<&TokenInfo> {
+        self.by_coin_type(object_type.is_coin()?)
+    }
+
+    pub fn by_symbol(&self, symbol: &str) -> Option<&TokenInfo> {
+        self.tokens.iter().rev().find(|t| t.symbol == symbol)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TokenInfo> {
+        self.tokens.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn well_known_tokens() {
+        let registry = TokenRegistry::for_network(Network::Mainnet);
+        let sui = registry.by_symbol("SUI").unwrap();
+        assert_eq!(sui.decimals, 9);
+        assert_eq!(
+            registry.by_object_type(&StructTag::gas_coin()).unwrap(),
+            sui
+        );
+        assert_eq!(registry.by_symbol("USDC").unwrap().decimals, 6);
+
+        let testnet = TokenRegistry::for_network(Network::Testnet);
+        assert_ne!(
+            testnet.by_symbol("USDC").unwrap().coin_type,
+            registry.by_symbol("USDC").unwrap().coin_type
+        );
+        assert!(TokenRegistry::for_network(Network::Localnet)
+            .by_symbol("USDC")
+            .is_none());
+
+        // Tokens added at runtime override the preloaded ones
+        let mut registry = registry;
+        let coin_type: TypeTag = "0x1234::my_coin::MY_COIN".parse().unwrap();
+        registry.insert(TokenInfo::new(coin_type.clone(), "USDC", "Not USDC", 2));
+        assert_eq!(registry.by_symbol("USDC").unwrap().coin_type, coin_type);
+    }
+
+    #[test]
+    fn amounts() {
+        let sui = TokenInfo::new("0x2::sui::SUI".parse().unwrap(), "SUI", "Sui", 9);
+        assert_eq!(sui.format_amount(1_500_000_000), "1.5");
+        assert_eq!(sui.format_amount(1), "0.000000001");
+        assert_eq!(sui.format_amount(0), "0");
+        assert_eq!(sui.format_amount(u64::MAX), "18446744073.709551615");
+
+        assert_eq!(sui.parse_amount("1.5"), Ok(1_500_000_000));
+        assert_eq!(sui.parse_amount(".5"), Ok(500_000_000));
+        assert_eq!(sui.parse_amount("2"), Ok(2_000_000_000));
+        assert_eq!(sui.parse_amount("18446744073.709551615"), Ok(u64::MAX));
+        assert_eq!(
+            sui.parse_amount("18446744073.709551616"),
+            Err(AmountParseError::Overflow)
+        );
+        assert_eq!(
+            sui.parse_amount("0.0000000001"),
+            Err(AmountParseError::TooManyDecimals { decimals: 9 })
+        );
+        for invalid in ["", ".", "-1", "1.2.3", "1e9", " 1"] {
+            assert_eq!(sui.parse_amount(invalid), Err(AmountParseError::Invalid));
+        }
+
+        let no_decimals = TokenInfo::new(sui.coin_type.clone(), "X", "X", 0);
+        assert_eq!(no_decimals.format_amount(42), "42");
+        assert_eq!(no_decimals.parse_amount("42"), Ok(42));
+
+        // Amounts of tokens with more decimals than fit in a u64 can still be parsed
+        let many_decimals = TokenInfo::new(sui.coin_type.clone(), "X", "X", 24);
+        assert_eq!(many_decimals.parse_amount("0"), Ok(0));
+        assert_eq!(
+            many_decimals.parse_amount("0.000000000000000000000001"),
+            Ok(1)
+        );
+        assert_eq!(
+            many_decimals.parse_amount("0.000018446744073709551615"),
+            Ok(u64::MAX)
+        );
+        assert_eq!(
+            many_decimals.parse_amount("1"),
+            Err(AmountParseError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,394 @@
+use super::Argument;
+use super::Command;
+use super::Input;
+use super::ProgrammableTransaction;
+use super::Transaction;
+use super::TransactionKind;
+use crate::Address;
+use crate::Identifier;
+use crate::Intent;
+use crate::IntentMessage;
+use crate::ObjectId;
+use crate::StructTag;
+use crate::TypeTag;
+
+/// What a hardware wallet needs to clear sign a transaction: the bytes it signs, along with the
+/// fields it shows to the user for approval.
+///
+/// The wallet is expected to check that the `fields` match the transaction encoded in
+/// `intent_message` before displaying them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClearSigningPreimage {
+    /// The BCS serialized intent message the signature commits to, i.e. the 3-byte intent
+    /// followed by the BCS serialized transaction.
+    pub intent_message: Vec<u8>,
+    /// The fields of the transaction to display to the user.
+    pub fields: ClearSigningFields,
+}
+
+/// The fields of a transaction shown to a user when clear signing it.
+///
+/// Transfers and coin splits are decoded from their pure inputs, and the objects they use are
+/// traced back to the gas coin, to object inputs, or to the coins split off those. `complete` is
+/// `false` whenever the effect of a command can't be known before execution, e.g. for move calls,
+/// merges of coins, vectors of objects, or recipients and amounts which are the results of other
+/// commands, in which case the wallet should fall back to blind signing.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct ClearSigningFields {
+    pub sender: Address,
+    pub gas_owner: Address,
+    pub gas_price: u64,
+    pub gas_budget: u64,
+    /// The objects transferred to each recipient, in the order of the commands.
+    pub transfers: Vec<ObjectTransfer>,
+    /// The coins split off other coins, in the order of the commands.
+    pub splits: Vec<CoinSplit>,
+    /// The functions called, in the order of the commands.
+    pub move_calls: Vec<MoveCallTarget>,
+    /// Whether the transaction publishes or upgrades a package.
+    pub publishes: bool,
+    /// Whether every command, along with the objects, recipients and amounts it uses, is fully
+    /// understood.
+    pub complete: bool,
+}
+
+/// The objects sent to a recipient by a `TransferObjects` command.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct ObjectTransfer {
+    pub recipient: Address,
+    /// The objects transferred, leaving out those which can't be traced.
+    pub objects: Vec<TransferredObject>,
+}
+
+/// An object sent by a transfer.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub enum TransferredObject {
+    /// The gas coin, with all of the SUI left in it once gas is paid.
+    GasCoin,
+    /// An object input of the transaction.
+    Object(ObjectId),
+    /// A coin split off another coin earlier in the transaction.
+    Coin(CoinSplit),
+}
+
+/// An amount of a coin split off another one by a `SplitCoins` command.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct CoinSplit {
+    /// The coin the amount is taken from. Splitting a coin which was itself split off another
+    /// coin takes from the original coin.
+    pub source: CoinSource,
+    /// The type `T` of the `Coin<T>` split, which is only known from the transaction for SUI split
+    /// off the gas coin. The wallet needs to look up the type of other source objects.
+    pub coin_type: Option<TypeTag>,
+    pub amount: u64,
+}
+
+/// The coin a [`CoinSplit`] takes its amount from.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub enum CoinSource {
+    GasCoin,
+    Object(ObjectId),
+}
+
+/// A function called by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct MoveCallTarget {
+    pub package: ObjectId,
+    pub module: Identifier,
+    pub function: Identifier,
+}
+
+impl ClearSigningFields {
+    /// The compact, deterministic encoding of these fields sent to hardware wallets: their BCS
+    /// serialization.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("serialization cannot fail")
+    }
+
+    /// Decode fields from their [`ClearSigningFields::to_bytes`] encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+        bcs::from_bytes(bytes)
+    }
+}
+
+/// Decodes the fields of a programmable transaction, keeping track of the coins split by each
+/// command so that later commands using them can be traced back to their source.
+struct FieldsDecoder<'a> {
+    transaction: &'a ProgrammableTransaction,
+    fields: ClearSigningFields,
+    splits: Vec<Vec<CoinSplit>>,
+}
+
+impl FieldsDecoder<'_> {
+    fn add_command(&mut self, command: &Command) {
+        let mut splits = vec![];
+        match command {
+            Command::MoveCall(call) => {
+                self.fields.move_calls.push(MoveCallTarget {
+                    package: call.package,
+                    module: call.module.clone(),
+                    function: call.function.clone(),
+                });
+                self.fields.complete = false;
+            }
+            Command::TransferObjects(transfer) => {
+                let objects = transfer
+                    .objects
+                    .iter()
+                    .filter_map(|object| {
+                        let object = self.object(object);
+                        self.fields.complete &= object.is_some();
+                        object
+                    })
+                    .collect();
+                match self
+                    .transaction
+                    .pure(&transfer.address)
+                    .and_then(|value| Address::from_bytes(value).ok())
+                {
+                    Some(recipient) => self
+                        .fields
+                        .transfers
+                        .push(ObjectTransfer { recipient, objects }),
+                    None => self.fields.complete = false,
+                }
+            }
+            Command::SplitCoins(split) => {
+                let source = match self.object(&split.coin) {
+                    Some(TransferredObject::GasCoin) => Some((CoinSource::GasCoin, Some(sui()))),
+                    Some(TransferredObject::Object(id)) => Some((CoinSource::Object(id), None)),
+                    Some(TransferredObject::Coin(coin)) => Some((coin.source, coin.coin_type)),
+                    None => None,
+                };
+                for amount in &split.amounts {
+                    let amount = self
+                        .transaction
+                        .pure(amount)
+                        .and_then(|value| <[u8; 8]>::try_from(value).ok())
+                        .map(u64::from_le_bytes);
+                    match (&source, amount) {
+                        (Some((source, coin_type)), Some(amount)) => splits.push(CoinSplit {
+                            source: source.clone(),
+                            coin_type: coin_type.clone(),
+                            amount,
+                        }),
+                        _ => self.fields.complete = false,
+                    }
+                }
+                // Keep the results of the command aligned with the amounts
+                if splits.len() != split.amounts.len() {
+                    splits.clear();
+                }
+                self.fields.splits.extend(splits.iter().cloned());
+            }
+            Command::Publish(_) | Command::Upgrade(_) => self.fields.publishes = true,
+            Command::MergeCoins(_) | Command::MakeMoveVector(_) => self.fields.complete = false,
+        }
+        self.splits.push(splits);
+    }
+
+    /// Trace an object argument back to the gas coin, an object input, or a split coin.
+    fn object(&self, argument: &Argument) -> Option<TransferredObject> {
+        match argument {
+            Argument::Gas => Some(TransferredObject::GasCoin),
+            Argument::Input(i) => match self.transaction.inputs.get(*i as usize)? {
+                Input::ImmutableOrOwned(reference) | Input::Receiving(reference) => {
+                    Some(TransferredObject::Object(*reference.object_id()))
+                }
+                Input::Shared { object_id, .. } => Some(TransferredObject::Object(*object_id)),
+                Input::Pure { .. } => None,
+            },
+            Argument::Result(i) => match self.splits.get(*i as usize)?.as_slice() {
+                [coin] => Some(TransferredObject::Coin(coin.clone())),
+                _ => None,
+            },
+            Argument::NestedResult(i, j) => self
+                .splits
+                .get(*i as usize)?
+                .get(*j as usize)
+                .cloned()
+                .map(TransferredObject::Coin),
+        }
+    }
+}
+
+/// The type of SUI.
+fn sui() -> TypeTag {
+    StructTag::gas_coin()
+        .is_coin()
+        .cloned()
+        .expect("the gas coin is a coin")
+}
+
+impl Transaction {
+    /// Export what a hardware wallet needs to clear sign this transaction, or `None` if it isn't
+    /// a programmable transaction, as only those are signed by users.
+    pub fn clear_signing_preimage(&self) -> Option<ClearSigningPreimage> {
+        let TransactionKind::ProgrammableTransaction(transaction) = &self.kind else {
+            return None;
+        };
+
+        let mut decoder = FieldsDecoder {
+            transaction,
+            fields: ClearSigningFields {
+                sender: self.sender,
+                gas_owner: self.gas_payment.owner,
+                gas_price: self.gas_payment.price,
+                gas_budget: self.gas_payment.budget,
+                transfers: vec![],
+                splits: vec![],
+                move_calls: vec![],
+                publishes: false,
+                complete: true,
+            },
+            splits: vec![],
+        };
+        for command in &transaction.commands {
+            decoder.add_command(command);
+        }
+        let fields = decoder.fields;
+
+        let intent_message = bcs::to_bytes(&IntentMessage::new(Intent::sui_transaction(), self))
+            .expect("serialization cannot fail");
+        Some(ClearSigningPreimage {
+            intent_message,
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GasPayment;
+    use crate::MoveCall;
+    use crate::ObjectDigest;
+    use crate::ObjectReference;
+    use crate::SplitCoins;
+    use crate::TransactionExpiration;
+    use crate::TransferObjects;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn clear_signing_preimage() {
+        let recipient = Address::new([0xab; 32]);
+        let object_id = ObjectId::new([0xcd; 32]);
+        let mut transaction = Transaction {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs: vec![
+                    Input::Pure {
+                        value: 100u64.to_le_bytes().to_vec(),
+                    },
+                    Input::Pure {
+                        value: recipient.as_bytes().to_vec(),
+                    },
+                    Input::ImmutableOrOwned(ObjectReference::new(object_id, 1, ObjectDigest::ZERO)),
+                ],
+                commands: vec![
+                    Command::SplitCoins(SplitCoins {
+                        coin: Argument::Gas,
+                        amounts: vec![Argument::Input(0)],
+                    }),
+                    Command::SplitCoins(SplitCoins {
+                        coin: Argument::Input(2),
+                        amounts: vec![Argument::Input(0), Argument::Input(0)],
+                    }),
+                    Command::TransferObjects(TransferObjects {
+                        objects: vec![Argument::Result(0), Argument::NestedResult(1, 1)],
+                        address: Argument::Input(1),
+                    }),
+                ],
+            }),
+            sender: Address::TWO,
+            gas_payment: GasPayment {
+                objects: vec![],
+                owner: Address::THREE,
+                price: 1_000,
+                budget: 5_000_000,
+            },
+            expiration: TransactionExpiration::None,
+        };
+
+        let sui_split = CoinSplit {
+            source: CoinSource::GasCoin,
+            coin_type: Some("0x2::sui::SUI".parse().unwrap()),
+            amount: 100,
+        };
+        let object_split = CoinSplit {
+            source: CoinSource::Object(object_id),
+            coin_type: None,
+            amount: 100,
+        };
+        let preimage = transaction.clear_signing_preimage().unwrap();
+        assert_eq!(
+            preimage.fields,
+            ClearSigningFields {
+                sender: Address::TWO,
+                gas_owner: Address::THREE,
+                gas_price: 1_000,
+                gas_budget: 5_000_000,
+                transfers: vec![ObjectTransfer {
+                    recipient,
+                    objects: vec![
+                        TransferredObject::Coin(sui_split.clone()),
+                        TransferredObject::Coin(object_split.clone()),
+                    ],
+                }],
+                splits: vec![sui_split, object_split.clone(), object_split],
+                move_calls: vec![],
+                publishes: false,
+                complete: true,
+            }
+        );
+        assert_eq!(
+            ClearSigningFields::from_bytes(&preimage.fields.to_bytes()).unwrap(),
+            preimage.fields
+        );
+
+        assert_eq!(
+            &preimage.intent_message[..3],
+            Intent::sui_transaction().to_bytes()
+        );
+        assert_eq!(
+            bcs::from_bytes::<Transaction>(&preimage.intent_message[3..]).unwrap(),
+            transaction
+        );
+
+        // The effects of move calls aren't known before execution
+        let TransactionKind::ProgrammableTransaction(ptb) = &mut transaction.kind else {
+            unreachable!()
+        };
+        ptb.commands.push(Command::MoveCall(MoveCall {
+            package: ObjectId::from(Address::TWO),
+            module: "coin".parse().unwrap(),
+            function: "value".parse().unwrap(),
+            type_arguments: vec![],
+            arguments: vec![],
+        }));
+        let fields = transaction.clear_signing_preimage().unwrap().fields;
+        assert_eq!(
+            fields.move_calls,
+            [MoveCallTarget {
+                package: ObjectId::from(Address::TWO),
+                module: "coin".parse().unwrap(),
+                function: "value".parse().unwrap(),
+            }]
+        );
+        assert!(!fields.complete);
+
+        // Neither are recipients which are the result of a command
+        let TransactionKind::ProgrammableTransaction(ptb) = &mut transaction.kind else {
+            unreachable!()
+        };
+        ptb.commands.pop();
+        ptb.commands[2] = Command::TransferObjects(TransferObjects {
+            objects: vec![Argument::Gas],
+            address: Argument::Result(1),
+        });
+        let fields = transaction.clear_signing_preimage().unwrap().fields;
+        assert!(fields.transfers.is_empty());
+        assert!(!fields.complete);
+    }
+}
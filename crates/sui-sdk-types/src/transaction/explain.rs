@@ -0,0 +1,212 @@
+use super::Argument;
+use super::Command;
+use super::Input;
+use super::ProgrammableTransaction;
+use crate::Address;
+
+impl ProgrammableTransaction {
+    /// Describe each command of this transaction in plain English, e.g. for the prompt asking a
+    /// user to sign it.
+    ///
+    /// Object inputs are described by their ID, and pure inputs are decoded when the command
+    /// they are used by determines their type, i.e. for amounts of coins and recipients of
+    /// transfers. Other arguments are described by their position in the transaction.
+    pub fn explain(&self) -> Vec<String> {
+        self.commands
+            .iter()
+            .map(|command| self.explain_command(command))
+            .collect()
+    }
+
+    fn explain_command(&self, command: &Command) -> String {
+        match command {
+            Command::MoveCall(call) => {
+                let mut explanation =
+                    format!("Call {}::{}::{}", call.package, call.module, call.function);
+                match call.type_arguments.len() {
+                    0 => {}
+                    1 => explanation.push_str(" with 1 type arg"),
+                    n => explanation.push_str(&format!(" with {n} type args")),
+                }
+                explanation
+            }
+            Command::TransferObjects(transfer) => format!(
+                "Transfer {} to {}",
+                self.list(&transfer.objects, Self::argument),
+                self.recipient(&transfer.address)
+            ),
+            Command::SplitCoins(split) => format!(
+                "Split {} ({}) from {}",
+                plural(split.amounts.len(), "amount"),
+                self.list(&split.amounts, Self::amount),
+                self.argument(&split.coin)
+            ),
+            Command::MergeCoins(merge) => format!(
+                "Merge {} into {}",
+                self.list(&merge.coins_to_merge, Self::argument),
+                self.argument(&merge.coin)
+            ),
+            Command::Publish(publish) => format!(
+                "Publish a package of {} with {}",
+                plural(publish.modules.len(), "module"),
+                plural(publish.dependencies.len(), "dependency")
+            ),
+            Command::MakeMoveVector(vector) => {
+                let mut explanation = format!(
+                    "Make a vector of {}",
+                    plural(vector.elements.len(), "element")
+                );
+                if let Some(type_) = &vector.type_ {
+                    explanation.push_str(&format!(" of type {type_}"));
+                }
+                explanation
+            }
+            Command::Upgrade(upgrade) => format!(
+                "Upgrade package {} to a package of {}",
+                upgrade.package,
+                plural(upgrade.modules.len(), "module")
+            ),
+        }
+    }
+
+    fn argument(&self, argument: &Argument) -> String {
+        match argument {
+            Argument::Gas => "the gas coin".to_owned(),
+            Argument::Input(i) => match self.inputs.get(*i as usize) {
+                Some(Input::ImmutableOrOwned(reference) | Input::Receiving(reference)) => {
+                    format!("object {}", reference.object_id())
+                }
+                Some(Input::Shared { object_id, .. }) => format!("shared object {object_id}"),
+                Some(Input::Pure { .. }) | None => format!("input {i}"),
+            },
+            Argument::Result(i) => format!("the result of command {i}"),
+            Argument::NestedResult(i, j) => format!("result {j} of command {i}"),
+        }
+    }
+
+    pub(super) fn pure(&self, argument: &Argument) -> Option<&[u8]> {
+        match argument {
+            Argument::Input(i) => match self.inputs.get(*i as usize)? {
+                Input::Pure { value } => Some(value),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Describe an argument used as a `u64` amount.
+    fn amount(&self, argument: &Argument) -> String {
+        self.pure(argument)
+            .and_then(|value| <[u8; 8]>::try_from(value).ok())
+            .map(|value| u64::from_le_bytes(value).to_string())
+            .unwrap_or_else(|| self.argument(argument))
+    }
+
+    /// Describe an argument used as an address.
+    fn recipient(&self, argument: &Argument) -> String {
+        self.pure(argument)
+            .and_then(|value| Address::from_bytes(value).ok())
+            .map(|address| address.to_string())
+            .unwrap_or_else(|| self.argument(argument))
+    }
+
+    fn list(&self, arguments: &[Argument], describe: fn(&Self, &Argument) -> String) -> String {
+        let descriptions: Vec<_> = arguments
+            .iter()
+            .map(|argument| describe(self, argument))
+            .collect();
+        match descriptions.as_slice() {
+            [] => "nothing".to_owned(),
+            [description] => description.clone(),
+            [init @ .., last] => format!("{} and {last}", init.join(", ")),
+        }
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    match (count, noun.strip_suffix('y')) {
+        (1, _) => format!("1 {noun}"),
+        (_, Some(stem)) => format!("{count} {stem}ies"),
+        (_, None) => format!("{count} {noun}s"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MakeMoveVector;
+    use crate::MergeCoins;
+    use crate::MoveCall;
+    use crate::ObjectDigest;
+    use crate::ObjectId;
+    use crate::ObjectReference;
+    use crate::Publish;
+    use crate::SplitCoins;
+    use crate::TransferObjects;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn explain() {
+        let recipient = Address::new([0xab; 32]);
+        let coin = ObjectId::new([0xcd; 32]);
+        let transaction = ProgrammableTransaction {
+            inputs: vec![
+                Input::Pure {
+                    value: 100u64.to_le_bytes().to_vec(),
+                },
+                Input::Pure {
+                    value: 200u64.to_le_bytes().to_vec(),
+                },
+                Input::Pure {
+                    value: recipient.as_bytes().to_vec(),
+                },
+                Input::ImmutableOrOwned(ObjectReference::new(coin, 1, ObjectDigest::ZERO)),
+            ],
+            commands: vec![
+                Command::SplitCoins(SplitCoins {
+                    coin: Argument::Gas,
+                    amounts: vec![Argument::Input(0), Argument::Input(1)],
+                }),
+                Command::MergeCoins(MergeCoins {
+                    coin: Argument::Input(3),
+                    coins_to_merge: vec![Argument::NestedResult(0, 0)],
+                }),
+                Command::MoveCall(MoveCall {
+                    package: ObjectId::from(Address::TWO),
+                    module: "pay".parse().unwrap(),
+                    function: "keep".parse().unwrap(),
+                    type_arguments: vec!["0x2::sui::SUI".parse().unwrap()],
+                    arguments: vec![Argument::NestedResult(0, 1)],
+                }),
+                Command::TransferObjects(TransferObjects {
+                    objects: vec![Argument::Input(3), Argument::Gas, Argument::Result(2)],
+                    address: Argument::Input(2),
+                }),
+                Command::MakeMoveVector(MakeMoveVector {
+                    type_: Some(crate::TypeTag::U64),
+                    elements: vec![Argument::Input(0)],
+                }),
+                Command::Publish(Publish {
+                    modules: vec![vec![], vec![]],
+                    dependencies: vec![Address::ONE.into(), Address::TWO.into()],
+                }),
+            ],
+        };
+
+        assert_eq!(
+            transaction.explain(),
+            [
+                "Split 2 amounts (100 and 200) from the gas coin".to_owned(),
+                format!("Merge result 0 of command 0 into object {coin}"),
+                format!("Call {}::pay::keep with 1 type arg", ObjectId::from(Address::TWO)),
+                format!(
+                    "Transfer object {coin}, the gas coin and the result of command 2 to {recipient}"
+                ),
+                "Make a vector of 1 element of type u64".to_owned(),
+                "Publish a package of 2 modules with 2 dependencies".to_owned(),
+            ]
+        );
+    }
+}
@@ -8,19 +8,55 @@ use super::GenesisObject;
 use super::Identifier;
 use super::Jwk;
 use super::JwkId;
+use super::LenientUserSignature;
+use super::ObjectDigest;
 use super::ObjectId;
 use super::ObjectReference;
+use super::ProtocolConfig;
 use super::ProtocolVersion;
 use super::TransactionDigest;
 use super::TypeTag;
 use super::UserSignature;
 use super::Version;
 
+mod explain;
+mod publish_lint;
+mod validity;
+pub use publish_lint::PublishIssue;
+pub use validity::InvalidTransaction;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+mod clear_signing;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::ClearSigningFields;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::ClearSigningPreimage;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::CoinSource;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::CoinSplit;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::MoveCallTarget;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::ObjectTransfer;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub use clear_signing::TransferredObject;
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod serialization;
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub(crate) use serialization::LenientSignedTransactionWithIntentMessage;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 pub(crate) use serialization::SignedTransactionWithIntentMessage;
 
 /// A transaction
@@ -43,6 +79,90 @@ pub struct Transaction {
     pub expiration: TransactionExpiration,
 }
 
+/// The response of the JSON-RPC transaction building endpoints, e.g. `unsafe_transferObject`
+/// or `unsafe_moveCall`.
+///
+/// Only the built transaction is retained; the other fields of the response are derived from it
+/// and are ignored when deserializing. Use [`TransactionBlockBytes::transaction`] to decode it.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBlockBytes {
+    /// The Base64 encoding of the BCS serialized transaction.
+    pub tx_bytes: String,
+}
+
+impl Transaction {
+    /// Construct a system transaction of the provided kind.
+    ///
+    /// System transactions are sent from the zero address, carry the dummy gas payment returned
+    /// by [`GasPayment::new_system`] and have no expiration. Constructing them this way ensures
+    /// the resulting transaction is byte-for-byte identical to the one produced by validators.
+    pub fn new_system(kind: TransactionKind) -> Self {
+        Self {
+            kind,
+            sender: Address::ZERO,
+            gas_payment: GasPayment::new_system(),
+            expiration: TransactionExpiration::None,
+        }
+    }
+
+    /// Check that a transaction with a system `TransactionKind` has the sender, gas payment and
+    /// expiration that validators assign to system transactions.
+    ///
+    /// User transactions are not subject to this check and always pass.
+    pub fn validate_system_transaction(&self) -> Result<(), InvalidSystemTransaction> {
+        if !self.kind.is_system() {
+            return Ok(());
+        }
+
+        if self.sender != Address::ZERO {
+            return Err(InvalidSystemTransaction("sender must be the zero address"));
+        }
+
+        if self.gas_payment != GasPayment::new_system() {
+            return Err(InvalidSystemTransaction(
+                "gas payment must be the dummy system gas payment",
+            ));
+        }
+
+        if self.expiration != TransactionExpiration::None {
+            return Err(InvalidSystemTransaction("expiration must be None"));
+        }
+
+        Ok(())
+    }
+
+    /// The addresses which must sign this transaction: the sender and, for sponsored
+    /// transactions, the owner of the gas payment.
+    pub fn required_signers(&self) -> Vec<Address> {
+        if self.sender == self.gas_payment.owner {
+            vec![self.sender]
+        } else {
+            vec![self.sender, self.gas_payment.owner]
+        }
+    }
+}
+
+/// Error returned when a system transaction doesn't match the form validators produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidSystemTransaction(&'static str);
+
+impl std::fmt::Display for InvalidSystemTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid system transaction: {}", self.0)
+    }
+}
+
+impl crate::error::ErrorCode for InvalidSystemTransaction {
+    fn code(&self) -> &'static str {
+        "invalid_system_transaction"
+    }
+}
+
+impl std::error::Error for InvalidSystemTransaction {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
@@ -54,6 +174,161 @@ pub struct SignedTransaction {
     pub signatures: Vec<UserSignature>,
 }
 
+impl SignedTransaction {
+    pub fn new(transaction: Transaction, signatures: Vec<UserSignature>) -> Self {
+        Self {
+            transaction,
+            signatures,
+        }
+    }
+
+    pub fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    pub fn signatures(&self) -> &[UserSignature] {
+        &self.signatures
+    }
+
+    pub fn into_parts(self) -> (Transaction, Vec<UserSignature>) {
+        (self.transaction, self.signatures)
+    }
+}
+
+/// A signed transaction whose signatures may use schemes unknown to this version of the library.
+///
+/// Indexers decoding transactions from a newer protocol version can use this in place of a
+/// [`SignedTransaction`], with the same serialized form, so that a signature with an unknown flag
+/// doesn't fail the decoding of the whole transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct LenientSignedTransaction {
+    pub transaction: Transaction,
+    pub signatures: Vec<LenientUserSignature>,
+}
+
+impl LenientSignedTransaction {
+    /// Whether all the signatures use a scheme known to this library.
+    pub fn is_known(&self) -> bool {
+        self.signatures
+            .iter()
+            .all(|signature| signature.known().is_some())
+    }
+
+    /// Convert into a [`SignedTransaction`], if all the signatures use a scheme known to this
+    /// library.
+    pub fn into_known(self) -> Option<SignedTransaction> {
+        let signatures = self
+            .signatures
+            .into_iter()
+            .map(LenientUserSignature::into_known)
+            .collect::<Option<_>>()?;
+        Some(SignedTransaction::new(self.transaction, signatures))
+    }
+}
+
+impl From<SignedTransaction> for LenientSignedTransaction {
+    fn from(transaction: SignedTransaction) -> Self {
+        Self {
+            transaction: transaction.transaction,
+            signatures: transaction.signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The envelope in which user signed transactions are submitted and stored on chain.
+///
+/// The envelope holds a list of signed transactions, though the protocol currently requires it to
+/// contain exactly one. Decoding doesn't enforce this so that data produced by a future version of
+/// the protocol can still be read, [`SenderSignedData::validate`] should be used to check it.
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// sender-signed-data = (vector sender-signed-transaction)
+///
+/// sender-signed-transaction = intent-message-transaction (vector user-signature)
+/// intent-message-transaction = %x00 %x00 %x00 transaction
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct SenderSignedData(
+    #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
+    Vec<SignedTransaction>,
+);
+
+impl SenderSignedData {
+    pub fn new(transactions: Vec<SignedTransaction>) -> Self {
+        Self(transactions)
+    }
+
+    pub fn transactions(&self) -> &[SignedTransaction] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<SignedTransaction> {
+        self.0
+    }
+
+    /// Check that the envelope contains exactly one transaction, as required by the protocol,
+    /// and return it.
+    pub fn validate(&self) -> Result<&SignedTransaction, InvalidSenderSignedData> {
+        match self.0.as_slice() {
+            [transaction] => Ok(transaction),
+            transactions => Err(InvalidSenderSignedData(transactions.len())),
+        }
+    }
+
+    /// Unwrap the single transaction of the envelope, see [`SenderSignedData::validate`].
+    pub fn into_transaction(mut self) -> Result<SignedTransaction, InvalidSenderSignedData> {
+        if self.0.len() != 1 {
+            return Err(InvalidSenderSignedData(self.0.len()));
+        }
+        Ok(self.0.remove(0))
+    }
+}
+
+impl From<SignedTransaction> for SenderSignedData {
+    fn from(transaction: SignedTransaction) -> Self {
+        Self(vec![transaction])
+    }
+}
+
+/// Error returned when a [`SenderSignedData`] doesn't contain exactly one transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidSenderSignedData(usize);
+
+impl InvalidSenderSignedData {
+    /// The number of transactions the envelope contains.
+    pub fn count(&self) -> usize {
+        self.0
+    }
+}
+
+impl std::fmt::Display for InvalidSenderSignedData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "sender signed data must contain exactly one transaction, found {}",
+            self.0
+        )
+    }
+}
+
+impl crate::error::ErrorCode for InvalidSenderSignedData {
+    fn code(&self) -> &'static str {
+        "invalid_sender_signed_data"
+    }
+}
+
+impl std::error::Error for InvalidSenderSignedData {}
+
 /// A TTL for a transaction
 ///
 /// # BCS
@@ -111,6 +386,132 @@ pub struct GasPayment {
     pub budget: u64,
 }
 
+impl GasPayment {
+    /// The gas price used by system transactions.
+    pub const SYSTEM_GAS_PRICE: u64 = 1;
+
+    /// The dummy gas payment carried by system transactions.
+    ///
+    /// System transactions aren't charged for gas, but still carry a single placeholder gas
+    /// object reference with a zero id, version and digest, owned by the zero address.
+    pub fn new_system() -> Self {
+        Self {
+            objects: vec![ObjectReference::new(ObjectId::ZERO, 0, ObjectDigest::ZERO)],
+            owner: Address::ZERO,
+            price: Self::SYSTEM_GAS_PRICE,
+            budget: 0,
+        }
+    }
+
+    /// The maximum number of gas objects a transaction can be paid with.
+    pub const MAX_GAS_OBJECTS: usize = 256;
+
+    /// Check this payment against the rules validators enforce before executing a transaction,
+    /// given the current `reference_gas_price` and protocol `config` of the network.
+    ///
+    /// The budget must cover the [`ProtocolConfig::base_tx_cost_fixed`] at the payment's gas price
+    /// without exceeding [`ProtocolConfig::max_tx_gas`]. Passing these checks doesn't guarantee
+    /// that the gas objects exist or cover the budget, only that the transaction won't be rejected
+    /// outright because of its gas payment.
+    pub fn validate(
+        &self,
+        reference_gas_price: u64,
+        config: &ProtocolConfig,
+    ) -> Result<(), InvalidGasPayment> {
+        if self.price < reference_gas_price {
+            return Err(InvalidGasPayment::PriceTooLow {
+                price: self.price,
+                reference_gas_price,
+            });
+        }
+
+        let min = self.price.saturating_mul(config.base_tx_cost_fixed);
+        if self.budget < min {
+            return Err(InvalidGasPayment::BudgetTooLow {
+                budget: self.budget,
+                min,
+            });
+        }
+        if self.budget > config.max_tx_gas {
+            return Err(InvalidGasPayment::BudgetTooHigh {
+                budget: self.budget,
+                max: config.max_tx_gas,
+            });
+        }
+
+        if self.objects.len() > config.limits.max_gas_objects {
+            return Err(InvalidGasPayment::TooManyObjects {
+                count: self.objects.len(),
+                max: config.limits.max_gas_objects,
+            });
+        }
+        let mut seen = std::collections::BTreeSet::new();
+        for object in &self.objects {
+            if !seen.insert(object.object_id()) {
+                return Err(InvalidGasPayment::DuplicateObject(*object.object_id()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when a [`GasPayment`] breaks the rules validators enforce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidGasPayment {
+    /// The gas price is below the reference gas price of the network.
+    PriceTooLow {
+        price: u64,
+        reference_gas_price: u64,
+    },
+    /// The budget doesn't cover the base cost of a transaction.
+    BudgetTooLow { budget: u64, min: u64 },
+    /// The budget is above the maximum budget of a transaction.
+    BudgetTooHigh { budget: u64, max: u64 },
+    /// The payment uses more gas objects than the protocol allows.
+    TooManyObjects { count: usize, max: usize },
+    /// The same gas object is used more than once.
+    DuplicateObject(ObjectId),
+}
+
+impl std::fmt::Display for InvalidGasPayment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PriceTooLow {
+                price,
+                reference_gas_price,
+            } => write!(
+                f,
+                "gas price {price} is below the reference gas price {reference_gas_price}"
+            ),
+            Self::BudgetTooLow { budget, min } => {
+                write!(f, "gas budget {budget} is below the minimum budget {min}")
+            }
+            Self::BudgetTooHigh { budget, max } => {
+                write!(f, "gas budget {budget} is above the maximum budget {max}")
+            }
+            Self::TooManyObjects { count, max } => {
+                write!(f, "{count} gas objects exceed the maximum of {max}")
+            }
+            Self::DuplicateObject(id) => write!(f, "gas object {id} is used more than once"),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for InvalidGasPayment {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::PriceTooLow { .. } => "gas_price_too_low",
+            Self::BudgetTooLow { .. } => "gas_budget_too_low",
+            Self::BudgetTooHigh { .. } => "gas_budget_too_high",
+            Self::TooManyObjects { .. } => "too_many_gas_objects",
+            Self::DuplicateObject(_) => "duplicate_gas_object",
+        }
+    }
+}
+
+impl std::error::Error for InvalidGasPayment {}
+
 /// Randomness update
 ///
 /// # BCS
@@ -206,6 +607,60 @@ pub enum TransactionKind {
     ConsensusCommitPrologueV4(ConsensusCommitPrologueV4),
 }
 
+impl TransactionKind {
+    /// Returns the name of this kind of transaction.
+    ///
+    /// This is the discriminator used by the RPC, and by the human readable serialization of
+    /// `TransactionKind`, e.g. `"ProgrammableTransaction"` or `"ConsensusCommitPrologueV2"`.
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Self::ProgrammableTransaction(_) => "ProgrammableTransaction",
+            Self::ChangeEpoch(_) => "ChangeEpoch",
+            Self::Genesis(_) => "Genesis",
+            Self::ConsensusCommitPrologue(_) => "ConsensusCommitPrologue",
+            Self::AuthenticatorStateUpdate(_) => "AuthenticatorStateUpdate",
+            Self::EndOfEpoch(_) => "EndOfEpochTransaction",
+            Self::RandomnessStateUpdate(_) => "RandomnessStateUpdate",
+            Self::ConsensusCommitPrologueV2(_) => "ConsensusCommitPrologueV2",
+            Self::ConsensusCommitPrologueV3(_) => "ConsensusCommitPrologueV3",
+            Self::ConsensusCommitPrologueV4(_) => "ConsensusCommitPrologueV4",
+        }
+    }
+
+    /// The version assignments of consensus, for the consensus commit prologues recording them.
+    pub fn consensus_determined_version_assignments(
+        &self,
+    ) -> Option<&ConsensusDeterminedVersionAssignments> {
+        match self {
+            Self::ConsensusCommitPrologueV3(prologue) => {
+                Some(&prologue.consensus_determined_version_assignments)
+            }
+            Self::ConsensusCommitPrologueV4(prologue) => {
+                Some(&prologue.consensus_determined_version_assignments)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a kind of transaction that can only be created by the system and
+    /// not submitted by a user.
+    pub fn is_system(&self) -> bool {
+        !matches!(self, Self::ProgrammableTransaction(_))
+    }
+
+    /// The minimum gas budget, in gas units, a transaction of this kind must specify.
+    ///
+    /// To get the floor in MIST, multiply this by the gas price of the transaction. System
+    /// transactions aren't charged for gas and so have a floor of `0`.
+    pub fn required_gas_budget_floor(&self, config: &ProtocolConfig) -> u64 {
+        if self.is_system() {
+            0
+        } else {
+            config.base_tx_cost_fixed
+        }
+    }
+}
+
 /// Operation run at the end of an epoch
 ///
 /// # BCS
@@ -409,9 +864,10 @@ pub struct ConsensusCommitPrologueV2 {
 /// The BCS serialized form for this type is defined by the following ABNF:
 ///
 /// ```text
-/// consensus-determined-version-assignments = canceled-transactions
+/// consensus-determined-version-assignments = canceled-transactions / canceled-transactions-v2
 ///
 /// canceled-transactions = %x00 (vector canceled-transaction)
+/// canceled-transactions-v2 = %x01 (vector canceled-transaction-v2)
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
@@ -421,6 +877,12 @@ pub enum ConsensusDeterminedVersionAssignments {
         #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
         canceled_transactions: Vec<CanceledTransaction>,
     },
+    /// Canceled transaction version assignment, keyed by the id of the objects and the version
+    /// they started being shared at.
+    CanceledTransactionsV2 {
+        #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
+        canceled_transactions: Vec<CanceledTransactionV2>,
+    },
 }
 
 /// A transaction that was canceled
@@ -465,6 +927,194 @@ pub struct VersionAssignment {
     pub version: Version,
 }
 
+/// A transaction that was canceled, with version assignments keyed by the id and start version
+/// of its shared objects
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// canceled-transaction-v2 = digest (vector version-assignment-v2)
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct CanceledTransactionV2 {
+    pub digest: TransactionDigest,
+    #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
+    pub version_assignments: Vec<VersionAssignmentV2>,
+}
+
+/// Object version assignment from consensus, for an object identified by its id and the version
+/// it started being shared at
+///
+/// # BCS
+///
+/// The BCS serialized form for this type is defined by the following ABNF:
+///
+/// ```text
+/// version-assignment-v2 = object-id u64 u64 ; object id, start version, assigned version
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct VersionAssignmentV2 {
+    pub object_id: ObjectId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub start_version: Version,
+    #[cfg_attr(feature = "serde", serde(with = "crate::_serde::ReadableDisplay"))]
+    pub version: Version,
+}
+
+impl ConsensusDeterminedVersionAssignments {
+    /// The digests of the transactions canceled by consensus, with the reason of their
+    /// cancellation.
+    pub fn cancellations(&self) -> Vec<(TransactionDigest, Option<CancellationReason>)> {
+        match self {
+            Self::CanceledTransactions {
+                canceled_transactions,
+            } => canceled_transactions
+                .iter()
+                .map(|canceled| (canceled.digest, canceled.cancellation_reason()))
+                .collect(),
+            Self::CanceledTransactionsV2 {
+                canceled_transactions,
+            } => canceled_transactions
+                .iter()
+                .map(|canceled| (canceled.digest, canceled.cancellation_reason()))
+                .collect(),
+        }
+    }
+}
+
+/// The reason a transaction was canceled, given the reasons recorded in the versions assigned to
+/// its shared objects.
+///
+/// The objects which were only read by a canceled transaction are assigned
+/// [`CancellationReason::CanceledRead`], so any other reason is the actual cause.
+fn cancellation_reason(
+    reasons: impl Iterator<Item = CancellationReason>,
+) -> Option<CancellationReason> {
+    let mut reason = None;
+    for recorded in reasons {
+        if recorded != CancellationReason::CanceledRead {
+            return Some(recorded);
+        }
+        reason = Some(recorded);
+    }
+    reason
+}
+
+impl CanceledTransaction {
+    /// The reason the transaction was canceled, see
+    /// [`CanceledTransactionV2::cancellation_reason`].
+    pub fn cancellation_reason(&self) -> Option<CancellationReason> {
+        cancellation_reason(
+            self.version_assignments
+                .iter()
+                .filter_map(VersionAssignment::cancellation_reason),
+        )
+    }
+
+    /// The shared objects of the transaction which were congested, and caused its cancellation.
+    pub fn congested_objects(&self) -> impl Iterator<Item = &ObjectId> + '_ {
+        self.version_assignments
+            .iter()
+            .filter(|assignment| {
+                assignment.cancellation_reason() == Some(CancellationReason::Congested)
+            })
+            .map(|assignment| &assignment.object_id)
+    }
+}
+
+impl VersionAssignment {
+    /// The reason of the cancellation of the transaction recorded in the assigned version, if it
+    /// is a sentinel version rather than the actual version of the object.
+    pub fn cancellation_reason(&self) -> Option<CancellationReason> {
+        CancellationReason::from_version(self.version)
+    }
+}
+
+impl CanceledTransactionV2 {
+    /// The reason the transaction was canceled, i.e. the cause recorded in the versions assigned
+    /// to its shared objects, like congestion, rather than
+    /// [`CancellationReason::CanceledRead`], which is only recorded on its own if no other reason
+    /// is.
+    pub fn cancellation_reason(&self) -> Option<CancellationReason> {
+        cancellation_reason(
+            self.version_assignments
+                .iter()
+                .filter_map(VersionAssignmentV2::cancellation_reason),
+        )
+    }
+
+    /// The shared objects of the transaction which were congested, and caused its cancellation.
+    pub fn congested_objects(&self) -> impl Iterator<Item = &ObjectId> + '_ {
+        self.version_assignments
+            .iter()
+            .filter(|assignment| {
+                assignment.cancellation_reason() == Some(CancellationReason::Congested)
+            })
+            .map(|assignment| &assignment.object_id)
+    }
+}
+
+impl VersionAssignmentV2 {
+    /// The reason of the cancellation of the transaction recorded in the assigned version, see
+    /// [`VersionAssignment::cancellation_reason`].
+    pub fn cancellation_reason(&self) -> Option<CancellationReason> {
+        CancellationReason::from_version(self.version)
+    }
+}
+
+/// The reason a transaction was canceled by consensus.
+///
+/// Instead of the version of a shared object, consensus assigns the shared objects of a canceled
+/// transaction a sentinel version, above the highest valid version, recording the reason. These
+/// versions appear in the [`ConsensusDeterminedVersionAssignments`] of consensus commit
+/// prologues, and in the unchanged shared objects of the effects of the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CancellationReason {
+    /// The object was only read, and the transaction was canceled because of another object.
+    CanceledRead,
+    /// The object was congested, i.e. too many transactions were scheduled on it.
+    Congested,
+    /// Randomness couldn't be generated for the transaction.
+    RandomnessUnavailable,
+}
+
+impl CancellationReason {
+    /// The highest version an object can have.
+    pub const MAX_VERSION: Version = 0x7fff_ffff_ffff_ffff;
+
+    /// The sentinel version recording this reason.
+    pub const fn version(self) -> Version {
+        match self {
+            Self::CanceledRead => Self::MAX_VERSION + 1,
+            Self::Congested => Self::MAX_VERSION + 2,
+            Self::RandomnessUnavailable => Self::MAX_VERSION + 3,
+        }
+    }
+
+    /// The reason recorded by `version`, if it is a sentinel version.
+    pub fn from_version(version: Version) -> Option<Self> {
+        [
+            Self::CanceledRead,
+            Self::Congested,
+            Self::RandomnessUnavailable,
+        ]
+        .into_iter()
+        .find(|reason| reason.version() == version)
+    }
+}
+
 /// V3 of the consensus commit prologue system transaction
 ///
 /// # BCS
@@ -1057,3 +1707,186 @@ pub struct MoveCall {
     #[cfg_attr(feature = "proptest", any(proptest::collection::size_range(0..=2).lift()))]
     pub arguments: Vec<Argument>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn validate_gas_payment() {
+        let object = |byte| ObjectReference::new(ObjectId::new([byte; 32]), 1, ObjectDigest::ZERO);
+        let config = crate::test_util::protocol_config();
+        let payment = GasPayment {
+            objects: vec![object(1), object(2)],
+            owner: Address::TWO,
+            price: 1_000,
+            budget: 5_000_000,
+        };
+        payment.validate(1_000, &config).unwrap();
+
+        assert_eq!(
+            payment.validate(1_001, &config),
+            Err(InvalidGasPayment::PriceTooLow {
+                price: 1_000,
+                reference_gas_price: 1_001
+            })
+        );
+        assert_eq!(
+            GasPayment {
+                budget: 999_999,
+                ..payment.clone()
+            }
+            .validate(1_000, &config),
+            Err(InvalidGasPayment::BudgetTooLow {
+                budget: 999_999,
+                min: 1_000_000
+            })
+        );
+        assert_eq!(
+            GasPayment {
+                budget: config.max_tx_gas + 1,
+                ..payment.clone()
+            }
+            .validate(1_000, &config),
+            Err(InvalidGasPayment::BudgetTooHigh {
+                budget: config.max_tx_gas + 1,
+                max: config.max_tx_gas
+            })
+        );
+        assert_eq!(
+            GasPayment {
+                objects: (0..=255).chain([0]).map(object).collect(),
+                ..payment.clone()
+            }
+            .validate(1_000, &config),
+            Err(InvalidGasPayment::TooManyObjects {
+                count: 257,
+                max: 256
+            })
+        );
+        assert_eq!(
+            GasPayment {
+                objects: vec![object(1), object(2), object(1)],
+                ..payment.clone()
+            }
+            .validate(1_000, &config),
+            Err(InvalidGasPayment::DuplicateObject(ObjectId::new([1; 32])))
+        );
+
+        // The limits are the ones of the protocol config
+        let low_budget = ProtocolConfig {
+            base_tx_cost_fixed: 6_000,
+            ..config.clone()
+        };
+        assert_eq!(
+            payment.validate(1_000, &low_budget),
+            Err(InvalidGasPayment::BudgetTooLow {
+                budget: 5_000_000,
+                min: 6_000_000
+            })
+        );
+        let high_budget = ProtocolConfig {
+            max_tx_gas: 4_000_000,
+            ..config
+        };
+        assert_eq!(
+            payment.validate(1_000, &high_budget),
+            Err(InvalidGasPayment::BudgetTooHigh {
+                budget: 5_000_000,
+                max: 4_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn canceled_transactions() {
+        let assignment = |byte, version| VersionAssignment {
+            object_id: ObjectId::new([byte; 32]),
+            version,
+        };
+        let canceled = CanceledTransaction {
+            digest: TransactionDigest::ZERO,
+            version_assignments: vec![
+                assignment(1, CancellationReason::CanceledRead.version()),
+                assignment(2, CancellationReason::Congested.version()),
+                assignment(3, 7),
+            ],
+        };
+        // The cause of the cancellation is reported rather than the objects only read
+        assert_eq!(
+            canceled.cancellation_reason(),
+            Some(CancellationReason::Congested)
+        );
+        let read_only = CanceledTransaction {
+            digest: TransactionDigest::ZERO,
+            version_assignments: vec![assignment(1, CancellationReason::CanceledRead.version())],
+        };
+        assert_eq!(
+            read_only.cancellation_reason(),
+            Some(CancellationReason::CanceledRead)
+        );
+        assert_eq!(
+            canceled.congested_objects().collect::<Vec<_>>(),
+            vec![&ObjectId::new([2; 32])]
+        );
+        assert_eq!(
+            CancellationReason::from_version(CancellationReason::MAX_VERSION),
+            None
+        );
+
+        let prologue = TransactionKind::ConsensusCommitPrologueV3(ConsensusCommitPrologueV3 {
+            epoch: 1,
+            round: 2,
+            sub_dag_index: None,
+            commit_timestamp_ms: 3,
+            consensus_commit_digest: ConsensusCommitDigest::ZERO,
+            consensus_determined_version_assignments:
+                ConsensusDeterminedVersionAssignments::CanceledTransactions {
+                    canceled_transactions: vec![canceled.clone()],
+                },
+        });
+        assert_eq!(
+            prologue
+                .consensus_determined_version_assignments()
+                .map(ConsensusDeterminedVersionAssignments::cancellations),
+            Some(vec![(
+                TransactionDigest::ZERO,
+                Some(CancellationReason::Congested)
+            )])
+        );
+
+        let canceled = CanceledTransactionV2 {
+            digest: TransactionDigest::ZERO,
+            version_assignments: vec![
+                VersionAssignmentV2 {
+                    object_id: ObjectId::new([1; 32]),
+                    start_version: 3,
+                    version: CancellationReason::RandomnessUnavailable.version(),
+                },
+                VersionAssignmentV2 {
+                    object_id: ObjectId::new([2; 32]),
+                    start_version: 4,
+                    version: CancellationReason::CanceledRead.version(),
+                },
+            ],
+        };
+        assert_eq!(
+            canceled.cancellation_reason(),
+            Some(CancellationReason::RandomnessUnavailable)
+        );
+        assert_eq!(canceled.congested_objects().count(), 0);
+        let assignments = ConsensusDeterminedVersionAssignments::CanceledTransactionsV2 {
+            canceled_transactions: vec![canceled],
+        };
+        assert_eq!(
+            assignments.cancellations(),
+            vec![(
+                TransactionDigest::ZERO,
+                Some(CancellationReason::RandomnessUnavailable)
+            )]
+        );
+    }
+}
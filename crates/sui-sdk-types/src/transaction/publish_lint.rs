@@ -0,0 +1,390 @@
+use std::collections::BTreeSet;
+
+use super::ProgrammableTransaction;
+use super::Publish;
+use super::Upgrade;
+use crate::Address;
+use crate::Identifier;
+use crate::ObjectId;
+use crate::TransactionLimits;
+
+impl Publish {
+    /// Check the package published by this command for the mistakes which would make its
+    /// publication fail, before paying for a dry run.
+    ///
+    /// This checks that:
+    ///
+    /// - the package has at least one module, and no more than allowed by `limits`,
+    /// - the modules, added up, aren't larger than allowed by `limits`,
+    /// - every module is well formed enough for its module handles to be read,
+    /// - every package the modules refer to is declared as a dependency, which is required for
+    ///   the direct dependencies but not sufficient, as the transitive ones must be declared as
+    ///   well,
+    /// - no module refers to another package still at the `0x0` placeholder address, i.e. a
+    ///   dependency which hasn't been published and is neither part of the package.
+    ///
+    /// Issues are returned in the order above, see [`PublishIssue::is_error`] for which of them
+    /// are certain to make the publication fail.
+    pub fn lint(&self, limits: &TransactionLimits) -> Vec<PublishIssue> {
+        lint_package(&self.modules, &self.dependencies, limits)
+    }
+}
+
+impl Upgrade {
+    /// Check the package upgraded by this command for the mistakes which would make its upgrade
+    /// fail, before paying for a dry run.
+    ///
+    /// The checks performed are the same as [`Publish::lint`].
+    pub fn lint(&self, limits: &TransactionLimits) -> Vec<PublishIssue> {
+        lint_package(&self.modules, &self.dependencies, limits)
+    }
+}
+
+impl ProgrammableTransaction {
+    /// The maximum total size of the modules of a published or upgraded package, in bytes.
+    pub const MAX_PACKAGE_SIZE: usize = 100 * 1024;
+}
+
+fn lint_package(
+    modules: &[Vec<u8>],
+    dependencies: &[ObjectId],
+    limits: &TransactionLimits,
+) -> Vec<PublishIssue> {
+    let mut issues = Vec::new();
+
+    if modules.is_empty() {
+        issues.push(PublishIssue::NoModules);
+    }
+    if modules.len() > limits.max_modules_in_publish {
+        issues.push(PublishIssue::TooManyModules {
+            count: modules.len(),
+            max: limits.max_modules_in_publish,
+        });
+    }
+    let size = modules.iter().map(Vec::len).sum();
+    if size > limits.max_package_size {
+        issues.push(PublishIssue::PackageTooLarge {
+            size,
+            max: limits.max_package_size,
+        });
+    }
+
+    let mut handles = Vec::new();
+    for (index, module) in modules.iter().enumerate() {
+        match ModuleHandles::parse(module) {
+            Some(module) => handles.push(module),
+            None => issues.push(PublishIssue::MalformedModule { module: index }),
+        }
+    }
+
+    // The modules of the package itself, at the address they were compiled with.
+    let own: BTreeSet<_> = handles
+        .iter()
+        .map(|module| module.handles[module.self_handle].clone())
+        .collect();
+    let dependencies: BTreeSet<_> = dependencies.iter().map(|id| Address::from(*id)).collect();
+
+    let mut missing = BTreeSet::new();
+    let mut unpublished = BTreeSet::new();
+    for handle in handles.iter().flat_map(|module| &module.handles) {
+        if own.contains(handle) {
+            continue;
+        }
+        let (address, name) = handle;
+        if *address == Address::ZERO {
+            unpublished.insert(name.clone());
+        } else if !dependencies.contains(address) {
+            missing.insert(*address);
+        }
+    }
+    issues.extend(
+        missing
+            .into_iter()
+            .map(|address| PublishIssue::MissingDependency(address.into())),
+    );
+    issues.extend(
+        unpublished
+            .into_iter()
+            .map(|module| PublishIssue::UnpublishedDependency { module }),
+    );
+
+    issues
+}
+
+/// A mistake in a package about to be published or upgraded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublishIssue {
+    /// The package has no modules.
+    NoModules,
+    /// The package has more than `max` modules.
+    TooManyModules { count: usize, max: usize },
+    /// The modules of the package add up to more than `max` bytes.
+    PackageTooLarge { size: usize, max: usize },
+    /// The module at the given index can't be read as a Move module.
+    MalformedModule { module: usize },
+    /// A module refers to a package which isn't declared as a dependency.
+    MissingDependency(ObjectId),
+    /// A module refers to a module at the `0x0` placeholder address which isn't part of the
+    /// package, usually because it belongs to a dependency which hasn't been published yet.
+    UnpublishedDependency { module: Identifier },
+}
+
+impl PublishIssue {
+    /// Whether this issue is certain to make the publication fail.
+    ///
+    /// Unpublished dependencies are only reported as warnings, as they are usually the result of
+    /// a build configuration mistake which can't be told apart from a package which is meant to
+    /// be published along with its dependencies.
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::UnpublishedDependency { .. })
+    }
+}
+
+impl std::fmt::Display for PublishIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoModules => write!(f, "package has no modules"),
+            Self::TooManyModules { count, max } => {
+                write!(f, "{count} modules exceed the maximum of {max}")
+            }
+            Self::PackageTooLarge { size, max } => write!(
+                f,
+                "modules of {size} bytes exceed the maximum of {max} bytes"
+            ),
+            Self::MalformedModule { module } => write!(f, "module {module} is malformed"),
+            Self::MissingDependency(package) => write!(
+                f,
+                "package {package} is used but not declared as a dependency"
+            ),
+            Self::UnpublishedDependency { module } => write!(
+                f,
+                "module {module} is at address 0x0 but isn't part of the package, \
+                 is one of its dependencies unpublished?"
+            ),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for PublishIssue {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NoModules => "no_modules",
+            Self::TooManyModules { .. } => "too_many_modules",
+            Self::PackageTooLarge { .. } => "package_too_large",
+            Self::MalformedModule { .. } => "malformed_module",
+            Self::MissingDependency(_) => "missing_dependency",
+            Self::UnpublishedDependency { .. } => "unpublished_dependency",
+        }
+    }
+}
+
+impl std::error::Error for PublishIssue {}
+
+/// The Move binary format magic number which compiled modules start with.
+const MOVE_MAGIC: [u8; 4] = [0xa1, 0x1c, 0xeb, 0x0b];
+
+/// The kinds of the tables of a compiled module read by the lint.
+const MODULE_HANDLES: u8 = 0x1;
+const IDENTIFIERS: u8 = 0x7;
+const ADDRESS_IDENTIFIERS: u8 = 0x8;
+
+/// The modules a compiled module refers to, including itself.
+struct ModuleHandles {
+    handles: Vec<(Address, Identifier)>,
+    /// The index in `handles` of the module itself.
+    self_handle: usize,
+}
+
+impl ModuleHandles {
+    /// Read the module handles of a compiled module, or `None` if it's malformed.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader(bytes.strip_prefix(&MOVE_MAGIC)?);
+        let _version = reader.take(4)?;
+
+        let mut tables = Vec::new();
+        for _ in 0..reader.uleb128()? {
+            let kind = reader.take(1)?[0];
+            let offset = reader.uleb128()?;
+            let length = reader.uleb128()?;
+            tables.push((kind, offset, offset.checked_add(length)?));
+        }
+        let contents = reader.0;
+        let end = tables.iter().map(|(_, _, end)| *end).max().unwrap_or(0);
+        let table = |kind| {
+            tables
+                .iter()
+                .find(|table| table.0 == kind)
+                .map_or(Some(Reader(&[])), |&(_, start, end)| {
+                    contents.get(start..end).map(Reader)
+                })
+        };
+
+        let mut identifiers = Vec::new();
+        let mut reader = table(IDENTIFIERS)?;
+        while !reader.0.is_empty() {
+            let length = reader.uleb128()?;
+            let name = std::str::from_utf8(reader.take(length)?).ok()?;
+            identifiers.push(Identifier::new_unchecked(name));
+        }
+
+        let mut addresses = Vec::new();
+        let mut reader = table(ADDRESS_IDENTIFIERS)?;
+        while !reader.0.is_empty() {
+            addresses.push(Address::from_bytes(reader.take(Address::LENGTH)?).ok()?);
+        }
+
+        let mut handles = Vec::new();
+        let mut reader = table(MODULE_HANDLES)?;
+        while !reader.0.is_empty() {
+            let address = *addresses.get(reader.uleb128()?)?;
+            let name = identifiers.get(reader.uleb128()?)?.clone();
+            handles.push((address, name));
+        }
+
+        let self_handle = Reader(contents.get(end..)?).uleb128()?;
+        (self_handle < handles.len()).then_some(Self {
+            handles,
+            self_handle,
+        })
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.0.len() < length {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(length);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn uleb128(&mut self) -> Option<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f).checked_shl(shift)?;
+            if byte & 0x80 == 0 {
+                return usize::try_from(value).ok();
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    /// A minimal compiled module, with only the tables read by the lint, named `name` and
+    /// referring to the modules `uses`.
+    fn module(own: Address, name: &str, uses: &[(Address, &str)]) -> Vec<u8> {
+        let mut identifiers = vec![name];
+        let mut addresses = vec![own];
+        let mut handles = vec![(0u8, 0u8)];
+        for (address, name) in uses {
+            let address = addresses
+                .iter()
+                .position(|a| a == address)
+                .unwrap_or_else(|| {
+                    addresses.push(*address);
+                    addresses.len() - 1
+                });
+            identifiers.push(name);
+            handles.push((address as u8, identifiers.len() as u8 - 1));
+        }
+
+        let handles: Vec<u8> = handles.into_iter().flat_map(|(a, n)| [a, n]).collect();
+        let identifiers: Vec<u8> = identifiers
+            .iter()
+            .flat_map(|name| [&[name.len() as u8][..], name.as_bytes()].concat())
+            .collect();
+        let addresses: Vec<u8> = addresses.iter().flat_map(|a| a.into_inner()).collect();
+
+        let mut bytes = MOVE_MAGIC.to_vec();
+        bytes.extend(6u32.to_le_bytes());
+        bytes.push(3);
+        let mut offset = 0;
+        for (kind, table) in [
+            (MODULE_HANDLES, &handles),
+            (IDENTIFIERS, &identifiers),
+            (ADDRESS_IDENTIFIERS, &addresses),
+        ] {
+            bytes.extend([kind, offset as u8, table.len() as u8]);
+            offset += table.len();
+        }
+        bytes.extend(handles);
+        bytes.extend(identifiers);
+        bytes.extend(addresses);
+        // The index of the module's own handle
+        bytes.push(0);
+        bytes
+    }
+
+    #[test]
+    fn publish_lint() {
+        let limits = TransactionLimits::default();
+        let publish = Publish {
+            modules: vec![
+                module(
+                    Address::ZERO,
+                    "a",
+                    &[(Address::ZERO, "b"), (Address::TWO, "coin")],
+                ),
+                module(Address::ZERO, "b", &[(Address::TWO, "object")]),
+            ],
+            dependencies: vec![ObjectId::from(Address::ONE), ObjectId::from(Address::TWO)],
+        };
+        assert_eq!(publish.lint(&limits), []);
+
+        let mut missing = publish.clone();
+        missing.dependencies = vec![ObjectId::from(Address::ONE)];
+        assert_eq!(
+            missing.lint(&limits),
+            [PublishIssue::MissingDependency(ObjectId::from(
+                Address::TWO
+            ))]
+        );
+
+        let mut unpublished = publish.clone();
+        unpublished.modules[1] = module(Address::ZERO, "b", &[(Address::ZERO, "dependency")]);
+        let issues = unpublished.lint(&limits);
+        assert_eq!(
+            issues,
+            [PublishIssue::UnpublishedDependency {
+                module: "dependency".parse().unwrap()
+            }]
+        );
+        assert!(!issues[0].is_error());
+
+        let mut malformed = publish.clone();
+        malformed.modules[0].truncate(20);
+        assert_eq!(
+            malformed.lint(&limits),
+            [PublishIssue::MalformedModule { module: 0 }]
+        );
+
+        let small = TransactionLimits {
+            max_modules_in_publish: 1,
+            max_package_size: 10,
+            ..limits
+        };
+        let issues = publish.lint(&small);
+        assert_eq!(
+            issues[..2],
+            [
+                PublishIssue::TooManyModules { count: 2, max: 1 },
+                PublishIssue::PackageTooLarge {
+                    size: publish.modules.iter().map(Vec::len).sum(),
+                    max: 10,
+                },
+            ]
+        );
+        assert!(issues.iter().all(PublishIssue::is_error));
+    }
+}
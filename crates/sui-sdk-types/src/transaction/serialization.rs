@@ -14,6 +14,7 @@ mod transaction {
     use super::*;
     use crate::transaction::GasPayment;
     use crate::transaction::Transaction;
+    use crate::transaction::TransactionBlockBytes;
     use crate::transaction::TransactionExpiration;
     use crate::transaction::TransactionKind;
     use crate::Address;
@@ -113,6 +114,51 @@ mod transaction {
             })
         }
     }
+
+    impl Transaction {
+        /// Serialize this transaction to its BCS form.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            bcs::to_bytes(self).expect("serialization cannot fail")
+        }
+
+        /// Serialize this transaction to the Base64 encoding of its BCS form, i.e. the format of
+        /// the `txBytes` accepted by transaction execution RPCs.
+        pub fn to_base64(&self) -> String {
+            use base64ct::Encoding;
+
+            base64ct::Base64::encode_string(&self.to_bytes())
+        }
+
+        /// Deserialize a transaction from its BCS form.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+            bcs::from_bytes(bytes)
+        }
+
+        /// Deserialize a transaction from the Base64 encoding of its BCS form, e.g. the `txBytes`
+        /// returned by the `unsafe_*` transaction building RPCs.
+        pub fn from_base64(s: &str) -> Result<Self, bcs::Error> {
+            use base64ct::Encoding;
+            use serde::de::Error;
+
+            let bytes = base64ct::Base64::decode_vec(s).map_err(bcs::Error::custom)?;
+            Self::from_bytes(&bytes)
+        }
+    }
+
+    impl TransactionBlockBytes {
+        /// Decode the built transaction, so that it can be inspected before being signed.
+        pub fn transaction(&self) -> Result<Transaction, bcs::Error> {
+            Transaction::from_base64(&self.tx_bytes)
+        }
+    }
+
+    impl TryFrom<&TransactionBlockBytes> for Transaction {
+        type Error = bcs::Error;
+
+        fn try_from(value: &TransactionBlockBytes) -> Result<Self, Self::Error> {
+            value.transaction()
+        }
+    }
 }
 
 mod transaction_kind {
@@ -129,14 +175,17 @@ mod transaction_kind {
     use crate::transaction::RandomnessStateUpdate;
     use crate::transaction::TransactionKind;
 
+    /// The readable form of a `TransactionKind` is tagged with the same `kind` discriminators used
+    /// by the RPC, which are the ones returned by `TransactionKind::kind_name`.
     #[derive(serde_derive::Serialize)]
-    #[serde(tag = "kind", rename_all = "snake_case")]
+    #[serde(tag = "kind")]
     enum ReadableTransactionKindRef<'a> {
         ProgrammableTransaction(&'a ProgrammableTransaction),
         ChangeEpoch(&'a ChangeEpoch),
         Genesis(&'a GenesisTransaction),
         ConsensusCommitPrologue(&'a ConsensusCommitPrologue),
         AuthenticatorStateUpdate(&'a AuthenticatorStateUpdate),
+        #[serde(rename = "EndOfEpochTransaction")]
         EndOfEpoch {
             commands: &'a Vec<EndOfEpochTransactionKind>,
         },
@@ -146,21 +195,32 @@ mod transaction_kind {
         ConsensusCommitPrologueV4(&'a ConsensusCommitPrologueV4),
     }
 
+    /// Also accepts the snake_case discriminators which were previously used by this library.
     #[derive(serde_derive::Deserialize)]
-    #[serde(tag = "kind", rename_all = "snake_case")]
+    #[serde(tag = "kind")]
     #[serde(rename = "TransactionKind")]
     enum ReadableTransactionKind {
+        #[serde(alias = "programmable_transaction")]
         ProgrammableTransaction(ProgrammableTransaction),
+        #[serde(alias = "change_epoch")]
         ChangeEpoch(ChangeEpoch),
+        #[serde(alias = "genesis")]
         Genesis(GenesisTransaction),
+        #[serde(alias = "consensus_commit_prologue")]
         ConsensusCommitPrologue(ConsensusCommitPrologue),
+        #[serde(alias = "authenticator_state_update")]
         AuthenticatorStateUpdate(AuthenticatorStateUpdate),
+        #[serde(rename = "EndOfEpochTransaction", alias = "end_of_epoch")]
         EndOfEpoch {
             commands: Vec<EndOfEpochTransactionKind>,
         },
+        #[serde(alias = "randomness_state_update")]
         RandomnessStateUpdate(RandomnessStateUpdate),
+        #[serde(alias = "consensus_commit_prologue_v2")]
         ConsensusCommitPrologueV2(ConsensusCommitPrologueV2),
+        #[serde(alias = "consensus_commit_prologue_v3")]
         ConsensusCommitPrologueV3(ConsensusCommitPrologueV3),
+        #[serde(alias = "consensus_commit_prologue_v4")]
         ConsensusCommitPrologueV4(ConsensusCommitPrologueV4),
     }
 
@@ -509,6 +569,7 @@ mod end_of_epoch {
 mod version_assignments {
     use super::*;
     use crate::transaction::CanceledTransaction;
+    use crate::transaction::CanceledTransactionV2;
     use crate::transaction::ConsensusDeterminedVersionAssignments;
 
     #[derive(serde_derive::Serialize)]
@@ -517,6 +578,9 @@ mod version_assignments {
         CanceledTransactions {
             canceled_transactions: &'a Vec<CanceledTransaction>,
         },
+        CanceledTransactionsV2 {
+            canceled_transactions: &'a Vec<CanceledTransactionV2>,
+        },
     }
 
     #[derive(serde_derive::Deserialize)]
@@ -525,6 +589,9 @@ mod version_assignments {
         CanceledTransactions {
             canceled_transactions: Vec<CanceledTransaction>,
         },
+        CanceledTransactionsV2 {
+            canceled_transactions: Vec<CanceledTransactionV2>,
+        },
     }
 
     #[derive(serde_derive::Serialize)]
@@ -532,6 +599,9 @@ mod version_assignments {
         CanceledTransactions {
             canceled_transactions: &'a Vec<CanceledTransaction>,
         },
+        CanceledTransactionsV2 {
+            canceled_transactions: &'a Vec<CanceledTransactionV2>,
+        },
     }
 
     #[derive(serde_derive::Deserialize)]
@@ -539,6 +609,9 @@ mod version_assignments {
         CanceledTransactions {
             canceled_transactions: Vec<CanceledTransaction>,
         },
+        CanceledTransactionsV2 {
+            canceled_transactions: Vec<CanceledTransactionV2>,
+        },
     }
 
     impl Serialize for ConsensusDeterminedVersionAssignments {
@@ -553,6 +626,11 @@ mod version_assignments {
                     } => ReadableConsensusDeterminedVersionAssignmentsRef::CanceledTransactions {
                         canceled_transactions,
                     },
+                    Self::CanceledTransactionsV2 {
+                        canceled_transactions,
+                    } => ReadableConsensusDeterminedVersionAssignmentsRef::CanceledTransactionsV2 {
+                        canceled_transactions,
+                    },
                 };
                 readable.serialize(serializer)
             } else {
@@ -562,6 +640,11 @@ mod version_assignments {
                     } => BinaryConsensusDeterminedVersionAssignmentsRef::CanceledTransactions {
                         canceled_transactions,
                     },
+                    Self::CanceledTransactionsV2 {
+                        canceled_transactions,
+                    } => BinaryConsensusDeterminedVersionAssignmentsRef::CanceledTransactionsV2 {
+                        canceled_transactions,
+                    },
                 };
                 binary.serialize(serializer)
             }
@@ -581,6 +664,11 @@ mod version_assignments {
                         } => Self::CanceledTransactions {
                             canceled_transactions,
                         },
+                        ReadableConsensusDeterminedVersionAssignments::CanceledTransactionsV2 {
+                            canceled_transactions,
+                        } => Self::CanceledTransactionsV2 {
+                            canceled_transactions,
+                        },
                     },
                 )
             } else {
@@ -591,6 +679,11 @@ mod version_assignments {
                         } => Self::CanceledTransactions {
                             canceled_transactions,
                         },
+                        BinaryConsensusDeterminedVersionAssignments::CanceledTransactionsV2 {
+                            canceled_transactions,
+                        } => Self::CanceledTransactionsV2 {
+                            canceled_transactions,
+                        },
                     },
                 )
             }
@@ -936,12 +1029,15 @@ mod command {
     }
 }
 
+pub(crate) use signed_transaction::LenientSignedTransactionWithIntentMessage;
 pub(crate) use signed_transaction::SignedTransactionWithIntentMessage;
 
 mod signed_transaction {
     use serde::ser::SerializeSeq;
 
     use super::*;
+    use crate::transaction::LenientSignedTransaction;
+    use crate::transaction::SenderSignedData;
     use crate::transaction::SignedTransaction;
     use crate::transaction::Transaction;
     use crate::UserSignature;
@@ -990,18 +1086,21 @@ mod signed_transaction {
 
     pub(crate) struct SignedTransactionWithIntentMessage;
 
+    /// Same as [`SignedTransactionWithIntentMessage`], for a [`LenientSignedTransaction`].
+    pub(crate) struct LenientSignedTransactionWithIntentMessage;
+
     #[derive(serde_derive::Serialize)]
-    struct BinarySignedTransactionWithIntentMessageRef<'a> {
+    struct BinarySignedTransactionWithIntentMessageRef<'a, S = UserSignature> {
         #[serde(with = "::serde_with::As::<IntentMessageWrappedTransaction>")]
         transaction: &'a Transaction,
-        signatures: &'a Vec<UserSignature>,
+        signatures: &'a Vec<S>,
     }
 
     #[derive(serde_derive::Deserialize)]
-    struct BinarySignedTransactionWithIntentMessage {
+    struct BinarySignedTransactionWithIntentMessage<S = UserSignature> {
         #[serde(with = "::serde_with::As::<IntentMessageWrappedTransaction>")]
         transaction: Transaction,
-        signatures: Vec<UserSignature>,
+        signatures: Vec<S>,
     }
 
     impl SerializeAs<SignedTransaction> for SignedTransactionWithIntentMessage {
@@ -1039,38 +1138,112 @@ mod signed_transaction {
             if deserializer.is_human_readable() {
                 SignedTransaction::deserialize(deserializer)
             } else {
-                struct V;
-                impl<'de> serde::de::Visitor<'de> for V {
-                    type Value = SignedTransaction;
+                SenderSignedData::deserialize(deserializer)?
+                    .into_transaction()
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    }
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                        formatter.write_str("expected a sequence with length 1")
-                    }
+    impl SerializeAs<LenientSignedTransaction> for LenientSignedTransactionWithIntentMessage {
+        fn serialize_as<S>(
+            transaction: &LenientSignedTransaction,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                transaction.serialize(serializer)
+            } else {
+                let binary = BinarySignedTransactionWithIntentMessageRef {
+                    transaction: &transaction.transaction,
+                    signatures: &transaction.signatures,
+                };
 
-                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-                    where
-                        A: serde::de::SeqAccess<'de>,
-                    {
-                        if seq.size_hint().is_some_and(|size| size != 1) {
-                            return Err(serde::de::Error::custom(
-                                "expected a sequence with length 1",
-                            ));
-                        }
+                let mut s = serializer.serialize_seq(Some(1))?;
+                s.serialize_element(&binary)?;
+                s.end()
+            }
+        }
+    }
 
-                        let BinarySignedTransactionWithIntentMessage {
-                            transaction,
-                            signatures,
-                        } = seq.next_element()?.ok_or_else(|| {
-                            serde::de::Error::custom("expected a sequence with length 1")
-                        })?;
-                        Ok(SignedTransaction {
-                            transaction,
-                            signatures,
-                        })
-                    }
+    impl<'de> DeserializeAs<'de, LenientSignedTransaction>
+        for LenientSignedTransactionWithIntentMessage
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<LenientSignedTransaction, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                LenientSignedTransaction::deserialize(deserializer)
+            } else {
+                let mut transactions = Vec::<
+                    BinarySignedTransactionWithIntentMessage<crate::LenientUserSignature>,
+                >::deserialize(deserializer)?;
+                if transactions.len() != 1 {
+                    return Err(serde::de::Error::custom(
+                        crate::transaction::InvalidSenderSignedData(transactions.len()),
+                    ));
+                }
+                let BinarySignedTransactionWithIntentMessage {
+                    transaction,
+                    signatures,
+                } = transactions.remove(0);
+                Ok(LenientSignedTransaction {
+                    transaction,
+                    signatures,
+                })
+            }
+        }
+    }
+
+    impl Serialize for SenderSignedData {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                self.transactions().serialize(serializer)
+            } else {
+                let mut s = serializer.serialize_seq(Some(self.transactions().len()))?;
+                for transaction in self.transactions() {
+                    s.serialize_element(&BinarySignedTransactionWithIntentMessageRef {
+                        transaction: &transaction.transaction,
+                        signatures: &transaction.signatures,
+                    })?;
                 }
+                s.end()
+            }
+        }
+    }
 
-                deserializer.deserialize_seq(V)
+    impl<'de> Deserialize<'de> for SenderSignedData {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                Vec::<SignedTransaction>::deserialize(deserializer).map(Self::new)
+            } else {
+                Vec::<BinarySignedTransactionWithIntentMessage>::deserialize(deserializer).map(
+                    |transactions| {
+                        Self::new(
+                            transactions
+                                .into_iter()
+                                .map(
+                                    |BinarySignedTransactionWithIntentMessage {
+                                         transaction,
+                                         signatures,
+                                     }| SignedTransaction {
+                                        transaction,
+                                        signatures,
+                                    },
+                                )
+                                .collect(),
+                        )
+                    },
+                )
             }
         }
     }
@@ -1152,9 +1325,12 @@ mod test {
     use base64ct::Base64;
     use base64ct::Encoding;
 
+    use crate::test_util::PTB;
     use crate::transaction::Argument;
     use crate::transaction::Input;
     use crate::transaction::Transaction;
+    use crate::transaction::TransactionBlockBytes;
+    use crate::transaction::TransactionKind;
     use crate::ObjectDigest;
     use crate::ObjectId;
     use crate::ObjectReference;
@@ -1162,6 +1338,11 @@ mod test {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
 
+    const GENESIS_TRANSACTION: &str = include_str!("fixtures/genesis-transaction");
+    const CONSENSUS_PROLOGUE: &str = "AAMAAAAAAAAAAAIAAAAAAAAAtkjHeocBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAAAAAAAAAAAAAAAAAAAAA==";
+    const EPOCH_CHANGE: &str = "AAUCAmkBAAAAAAAAmSrgAQAAAAAAagEAAAAAAAApAAAAAAAAALAQCoNLLwAAnNn0sywGAABsVBEfSC0AAKQnlhd1AAAAzve+vo4BAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAA=";
+    const AUTHENTICATOR_STATE_UPDATE: &str = include_str!("fixtures/authenticator_state_update");
+
     #[test]
     fn argument() {
         let test_cases = [
@@ -1245,12 +1426,6 @@ mod test {
 
     #[test]
     fn transaction_fixtures() {
-        const GENESIS_TRANSACTION: &str = include_str!("fixtures/genesis-transaction");
-        const CONSENSUS_PROLOGUE: &str = "AAMAAAAAAAAAAAIAAAAAAAAAtkjHeocBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAAAAAAAAAAAAAAAAAAAAA==";
-        const EPOCH_CHANGE: &str = "AAUCAmkBAAAAAAAAmSrgAQAAAAAAagEAAAAAAAApAAAAAAAAALAQCoNLLwAAnNn0sywGAABsVBEfSC0AAKQnlhd1AAAAzve+vo4BAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAA=";
-        const AUTHENTICATOR_STATE_UPDATE: &str =
-            include_str!("fixtures/authenticator_state_update");
-        const PTB: &str = "AAADAQFEBbUNeR/TNGdU6Bcaqra8LtJsLEbv3QM8FLMK5QesMyx96QEAAAAAAQAIVsakAAAAAAABALyyokbZ/8ynfWQer6UyP1DpeCnPU1NC7AyFNJSaTztnQF40BQAAAAAgffPXh5XuG6TWjHk6qC5w9k2a+41oTWfm0sC1FOYRqsEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAN7pB2Nsb2JfdjIMY2FuY2VsX29yZGVyAgcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgNzdWkDU1VJAAddSzAlBmRcN/8TO5jEtQpa4UhBZZc41tcz1Z0NIXqTvwRjb2luBENPSU4AAwEAAAEBAAECAPgh00g/x3Jeuvqlo9Ejc9SZAb384UhPIZ2qcGajDfd9ASXQjpFOD6mfycbzwD1wc+IOkCXQ8rHQo/Vi5SDOGMR/Jl40BQAAAAAgV7P1E0IMKon5uI82R/0arWLt+dc1ng/4VwKDqpTCxHT4IdNIP8dyXrr6paPRI3PUmQG9/OFITyGdqnBmow33fe4CAAAAAAAAAMqaOwAAAAAA";
         const WORMHOLE_PYTH_TRANSACTION: &str = include_str!("fixtures/wormhole-pyth-transaction");
 
         for fixture in [
@@ -1270,4 +1445,114 @@ mod test {
             assert_eq!(tx, serde_json::from_str(&json).unwrap());
         }
     }
+
+    #[test]
+    fn transaction_block_bytes() {
+        let response = format!(
+            r#"{{
+                "txBytes": "{PTB}",
+                "gas": [],
+                "inputObjects": []
+            }}"#
+        );
+        let response: TransactionBlockBytes = serde_json::from_str(&response).unwrap();
+
+        let tx = response.transaction().unwrap();
+        assert!(matches!(
+            tx.kind,
+            TransactionKind::ProgrammableTransaction(_)
+        ));
+        assert_eq!(Transaction::try_from(&response).unwrap(), tx);
+        assert_eq!(tx.to_base64(), PTB);
+        assert_eq!(Transaction::from_bytes(&tx.to_bytes()).unwrap(), tx);
+
+        Transaction::from_base64("not base64!").unwrap_err();
+        Transaction::from_base64(&PTB[..PTB.len() - 4]).unwrap_err();
+    }
+
+    #[test]
+    fn transaction_kind_discriminators() {
+        for (fixture, kind_name) in [
+            (PTB, "ProgrammableTransaction"),
+            (EPOCH_CHANGE, "EndOfEpochTransaction"),
+        ] {
+            let tx = Transaction::from_base64(fixture).unwrap();
+            assert_eq!(tx.kind.kind_name(), kind_name);
+
+            let mut json = serde_json::to_value(&tx.kind).unwrap();
+            assert_eq!(json["kind"], kind_name);
+
+            // The previous snake_case discriminators are still accepted
+            let legacy = match kind_name {
+                "ProgrammableTransaction" => "programmable_transaction",
+                _ => "end_of_epoch",
+            };
+            json["kind"] = legacy.into();
+            assert_eq!(
+                serde_json::from_value::<TransactionKind>(json).unwrap(),
+                tx.kind
+            );
+        }
+    }
+
+    #[test]
+    fn system_transaction_fixtures() {
+        for fixture in [
+            GENESIS_TRANSACTION,
+            CONSENSUS_PROLOGUE,
+            EPOCH_CHANGE,
+            AUTHENTICATOR_STATE_UPDATE,
+        ] {
+            let fixture = Base64::decode_vec(fixture.trim()).unwrap();
+            let tx: Transaction = bcs::from_bytes(&fixture).unwrap();
+            assert!(tx.kind.is_system());
+            tx.validate_system_transaction().unwrap();
+
+            let reconstructed = Transaction::new_system(tx.kind.clone());
+            assert_eq!(bcs::to_bytes(&reconstructed).unwrap(), fixture);
+
+            let mut bad = tx;
+            bad.gas_payment.budget = 1;
+            bad.validate_system_transaction().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn required_gas_budget_floor() {
+        let config = crate::test_util::protocol_config();
+
+        let tx: Transaction = bcs::from_bytes(&Base64::decode_vec(PTB).unwrap()).unwrap();
+        assert!(!tx.kind.is_system());
+        assert_eq!(tx.kind.required_gas_budget_floor(&config), 1_000);
+        // user transactions aren't checked against the system gas payment
+        tx.validate_system_transaction().unwrap();
+
+        let system = Transaction::new_system(TransactionKind::EndOfEpoch(Vec::new()));
+        assert_eq!(system.kind.required_gas_budget_floor(&config), 0);
+    }
+
+    #[test]
+    fn sender_signed_data() {
+        use crate::transaction::SenderSignedData;
+        use crate::transaction::SignedTransaction;
+
+        let transaction = Base64::decode_vec(GENESIS_TRANSACTION.trim()).unwrap();
+        let signed = SignedTransaction::new(bcs::from_bytes(&transaction).unwrap(), vec![]);
+
+        // One transaction, wrapped in its intent message and followed by no signatures
+        let data = SenderSignedData::from(signed.clone());
+        let mut expected = vec![1, 0, 0, 0];
+        expected.extend_from_slice(&transaction);
+        expected.push(0);
+        assert_eq!(bcs::to_bytes(&data).unwrap(), expected);
+        assert_eq!(data.validate(), Ok(&signed));
+
+        // Multiple transactions decode, but aren't valid
+        let data = SenderSignedData::new(vec![signed.clone(), signed]);
+        let bytes = bcs::to_bytes(&data).unwrap();
+        let decoded: SenderSignedData = bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.validate().unwrap_err().count(), 2);
+        decoded.into_transaction().unwrap_err();
+    }
 }
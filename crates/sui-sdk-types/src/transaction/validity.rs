@@ -0,0 +1,873 @@
+use std::collections::BTreeSet;
+
+use super::Argument;
+use super::Command;
+use super::EndOfEpochTransactionKind;
+use super::Input;
+use super::ProgrammableTransaction;
+use super::Transaction;
+use super::TransactionKind;
+use crate::ObjectId;
+use crate::ProtocolConfig;
+use crate::ProtocolFeatures;
+use crate::TransactionLimits;
+
+impl Transaction {
+    /// Check this transaction against the limits and rules a fullnode enforces before accepting
+    /// a user transaction, without access to the state of the network.
+    ///
+    /// This rejects system transactions, malformed gas payments and programmable transactions
+    /// failing [`ProgrammableTransaction::validity_check`]. Checks which depend on the network,
+    /// like the reference gas price, are left to [`GasPayment::validate`](super::GasPayment::validate).
+    pub fn validity_check(&self) -> Result<(), InvalidTransaction> {
+        let TransactionKind::ProgrammableTransaction(transaction) = &self.kind else {
+            return Err(InvalidTransaction::SystemTransaction(self.kind.kind_name()));
+        };
+        self.check_programmable(transaction, &TransactionLimits::default())
+    }
+
+    /// Check this transaction against the rules of the protocol version `config` applies to, e.g.
+    /// when replaying a historical checkpoint.
+    ///
+    /// Unlike [`Transaction::validity_check`], system transactions are accepted as long as their
+    /// kind is enabled. Programmable transactions must only use enabled commands, stay within the
+    /// limits of `config` and have a budget between their
+    /// [floor](TransactionKind::required_gas_budget_floor) and `max_tx_gas`.
+    pub fn check_against(&self, config: &ProtocolConfig) -> Result<(), InvalidTransaction> {
+        let features = &config.features;
+        let enabled = match &self.kind {
+            TransactionKind::ProgrammableTransaction(_)
+            | TransactionKind::ChangeEpoch(_)
+            | TransactionKind::Genesis(_)
+            | TransactionKind::ConsensusCommitPrologue(_) => true,
+            TransactionKind::AuthenticatorStateUpdate(_) => features.authenticator_state,
+            TransactionKind::EndOfEpoch(kinds) => {
+                if let Some(kind) = kinds
+                    .iter()
+                    .find(|kind| !end_of_epoch_enabled(kind, features))
+                {
+                    return Err(InvalidTransaction::KindNotEnabled(end_of_epoch_name(kind)));
+                }
+                features.end_of_epoch_transaction
+            }
+            TransactionKind::RandomnessStateUpdate(_) => features.random_beacon,
+            TransactionKind::ConsensusCommitPrologueV2(_) => {
+                features.consensus_commit_prologue_version >= 2
+            }
+            TransactionKind::ConsensusCommitPrologueV3(_) => {
+                features.consensus_commit_prologue_version >= 3
+            }
+            TransactionKind::ConsensusCommitPrologueV4(_) => {
+                features.consensus_commit_prologue_version >= 4
+            }
+        };
+        if !enabled {
+            return Err(InvalidTransaction::KindNotEnabled(self.kind.kind_name()));
+        }
+
+        let TransactionKind::ProgrammableTransaction(transaction) = &self.kind else {
+            return Ok(());
+        };
+
+        let budget = self.gas_payment.budget;
+        let min = self
+            .kind
+            .required_gas_budget_floor(config)
+            .saturating_mul(self.gas_payment.price);
+        if budget < min {
+            return Err(InvalidTransaction::BudgetTooLow { budget, min });
+        }
+        if budget > config.max_tx_gas {
+            return Err(InvalidTransaction::BudgetTooHigh {
+                budget,
+                max: config.max_tx_gas,
+            });
+        }
+
+        if !features.random_beacon {
+            if let Some(input) = transaction.inputs.iter().position(is_random) {
+                return Err(InvalidTransaction::RandomNotEnabled { input });
+            }
+        }
+
+        if !features.package_upgrades {
+            if let Some(command) = transaction
+                .commands
+                .iter()
+                .position(|command| matches!(command, Command::Upgrade(_)))
+            {
+                return Err(InvalidTransaction::CommandNotEnabled {
+                    command,
+                    name: "Upgrade",
+                });
+            }
+        }
+
+        self.check_programmable(transaction, &config.limits)
+    }
+
+    fn check_programmable(
+        &self,
+        transaction: &ProgrammableTransaction,
+        limits: &TransactionLimits,
+    ) -> Result<(), InvalidTransaction> {
+        let gas = &self.gas_payment.objects;
+        if gas.len() > limits.max_gas_objects {
+            return Err(InvalidTransaction::TooManyGasObjects {
+                count: gas.len(),
+                max: limits.max_gas_objects,
+            });
+        }
+        let mut gas_ids = BTreeSet::new();
+        for object in gas {
+            if !gas_ids.insert(*object.object_id()) {
+                return Err(InvalidTransaction::DuplicateObject(*object.object_id()));
+            }
+        }
+
+        transaction.check_limits(limits)?;
+
+        for input in &transaction.inputs {
+            if let Some(id) = input_object_id(input).filter(|id| gas_ids.contains(id)) {
+                return Err(InvalidTransaction::GasObjectUsedAsInput(id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ProgrammableTransaction {
+    /// The maximum number of commands of a transaction.
+    pub const MAX_COMMANDS: usize = 1024;
+
+    /// The maximum number of object inputs of a transaction.
+    pub const MAX_INPUT_OBJECTS: usize = 2048;
+
+    /// The maximum size of a pure input, in bytes.
+    pub const MAX_PURE_ARGUMENT_SIZE: usize = 16 * 1024;
+
+    /// The maximum number of arguments of a command.
+    pub const MAX_ARGUMENTS: usize = 512;
+
+    /// The maximum number of type arguments of a move call.
+    pub const MAX_TYPE_ARGUMENTS: usize = 16;
+
+    /// The maximum number of modules in a published or upgraded package.
+    pub const MAX_MODULES_IN_PUBLISH: usize = 64;
+
+    /// The maximum number of publish and upgrade commands of a transaction.
+    pub const MAX_PUBLISH_OR_UPGRADE: usize = 5;
+
+    /// Check the inputs and commands of this transaction against the limits and rules a fullnode
+    /// enforces before accepting it.
+    ///
+    /// Besides the size limits, every argument must refer to an existing input or to the result
+    /// of an earlier command, no object can be used as more than one input, and receiving inputs
+    /// can only be passed to move calls. The [`Random`](ObjectId::RANDOM) object must be an
+    /// immutable input, and once a move call has used it only `TransferObjects` and `MergeCoins`
+    /// commands can follow, so that the outcome of the randomness can't be inspected and the
+    /// transaction aborted by later commands.
+    pub fn validity_check(&self) -> Result<(), InvalidTransaction> {
+        self.check_limits(&TransactionLimits::default())
+    }
+
+    fn check_limits(&self, limits: &TransactionLimits) -> Result<(), InvalidTransaction> {
+        if self.commands.is_empty() {
+            return Err(InvalidTransaction::NoCommands);
+        }
+        if self.commands.len() > limits.max_commands {
+            return Err(InvalidTransaction::TooManyCommands {
+                count: self.commands.len(),
+                max: limits.max_commands,
+            });
+        }
+
+        let mut objects = BTreeSet::new();
+        for (index, input) in self.inputs.iter().enumerate() {
+            if let Input::Pure { value } = input {
+                if value.len() > limits.max_pure_argument_size {
+                    return Err(InvalidTransaction::PureArgumentTooLarge {
+                        input: index,
+                        size: value.len(),
+                        max: limits.max_pure_argument_size,
+                    });
+                }
+            }
+            if let Some(id) = input_object_id(input) {
+                if !objects.insert(id) {
+                    return Err(InvalidTransaction::DuplicateObject(id));
+                }
+            }
+        }
+        if objects.len() > limits.max_input_objects {
+            return Err(InvalidTransaction::TooManyInputObjects {
+                count: objects.len(),
+                max: limits.max_input_objects,
+            });
+        }
+
+        let mut publishes = 0;
+        for (index, command) in self.commands.iter().enumerate() {
+            self.check_command(index, command, limits)?;
+            if matches!(command, Command::Publish(_) | Command::Upgrade(_)) {
+                publishes += 1;
+            }
+        }
+        if publishes > limits.max_publish_or_upgrade {
+            return Err(InvalidTransaction::TooManyPublishes {
+                count: publishes,
+                max: limits.max_publish_or_upgrade,
+            });
+        }
+
+        self.check_random()
+    }
+
+    fn check_random(&self) -> Result<(), InvalidTransaction> {
+        let Some(random) = self.inputs.iter().position(is_random) else {
+            return Ok(());
+        };
+        if let Some(Input::Shared { mutable: true, .. }) = self.inputs.get(random) {
+            return Err(InvalidTransaction::MutableRandom { input: random });
+        }
+
+        let uses_random = |command: &Command| match command {
+            Command::MoveCall(call) => call.arguments.contains(&Argument::Input(random as u16)),
+            _ => false,
+        };
+        let Some(random_command) = self.commands.iter().position(uses_random) else {
+            return Ok(());
+        };
+        if let Some(command) = self.commands[random_command + 1..]
+            .iter()
+            .position(|command| {
+                !matches!(
+                    command,
+                    Command::TransferObjects(_) | Command::MergeCoins(_)
+                )
+            })
+        {
+            return Err(InvalidTransaction::CommandAfterRandom {
+                random_command,
+                command: random_command + 1 + command,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_command(
+        &self,
+        index: usize,
+        command: &Command,
+        limits: &TransactionLimits,
+    ) -> Result<(), InvalidTransaction> {
+        // The arguments of the command, and whether it's missing the ones it can't do without.
+        let (arguments, empty) = match command {
+            Command::MoveCall(call) => {
+                if call.type_arguments.len() > limits.max_type_arguments {
+                    return Err(InvalidTransaction::TooManyTypeArguments {
+                        command: index,
+                        count: call.type_arguments.len(),
+                        max: limits.max_type_arguments,
+                    });
+                }
+                (call.arguments.clone(), false)
+            }
+            Command::TransferObjects(transfer) => {
+                let mut arguments = transfer.objects.clone();
+                arguments.push(transfer.address);
+                (arguments, transfer.objects.is_empty())
+            }
+            Command::SplitCoins(split) => {
+                let mut arguments = vec![split.coin];
+                arguments.extend(&split.amounts);
+                (arguments, split.amounts.is_empty())
+            }
+            Command::MergeCoins(merge) => {
+                let mut arguments = vec![merge.coin];
+                arguments.extend(&merge.coins_to_merge);
+                (arguments, merge.coins_to_merge.is_empty())
+            }
+            Command::MakeMoveVector(vector) => {
+                // Without elements, the type of the vector can't be inferred.
+                let empty = vector.type_.is_none() && vector.elements.is_empty();
+                (vector.elements.clone(), empty)
+            }
+            Command::Publish(publish) => {
+                Self::check_modules(index, &publish.modules, limits)?;
+                (vec![], false)
+            }
+            Command::Upgrade(upgrade) => {
+                Self::check_modules(index, &upgrade.modules, limits)?;
+                (vec![upgrade.ticket], false)
+            }
+        };
+
+        if empty {
+            return Err(InvalidTransaction::EmptyCommand { command: index });
+        }
+
+        if arguments.len() > limits.max_arguments {
+            return Err(InvalidTransaction::TooManyArguments {
+                command: index,
+                count: arguments.len(),
+                max: limits.max_arguments,
+            });
+        }
+
+        for argument in arguments {
+            let valid = match argument {
+                Argument::Gas => true,
+                Argument::Input(input) => match self.inputs.get(input as usize) {
+                    Some(Input::Receiving(_)) => {
+                        if !matches!(command, Command::MoveCall(_)) {
+                            return Err(InvalidTransaction::ReceivingArgumentMisuse {
+                                command: index,
+                            });
+                        }
+                        true
+                    }
+                    Some(_) => true,
+                    None => false,
+                },
+                Argument::Result(result) | Argument::NestedResult(result, _) => {
+                    (result as usize) < index
+                }
+            };
+            if !valid {
+                return Err(InvalidTransaction::InvalidArgument {
+                    command: index,
+                    argument,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_modules(
+        index: usize,
+        modules: &[Vec<u8>],
+        limits: &TransactionLimits,
+    ) -> Result<(), InvalidTransaction> {
+        if modules.is_empty() {
+            return Err(InvalidTransaction::EmptyCommand { command: index });
+        }
+        if modules.len() > limits.max_modules_in_publish {
+            return Err(InvalidTransaction::TooManyModules {
+                command: index,
+                count: modules.len(),
+                max: limits.max_modules_in_publish,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn input_object_id(input: &Input) -> Option<ObjectId> {
+    match input {
+        Input::Pure { .. } => None,
+        Input::ImmutableOrOwned(reference) | Input::Receiving(reference) => {
+            Some(*reference.object_id())
+        }
+        Input::Shared { object_id, .. } => Some(*object_id),
+    }
+}
+
+/// Whether `input` is the [`Random`](ObjectId::RANDOM) shared object.
+fn is_random(input: &Input) -> bool {
+    matches!(input, Input::Shared { object_id, .. } if *object_id == ObjectId::RANDOM)
+}
+
+/// Whether the given operation run at the end of an epoch is enabled.
+fn end_of_epoch_enabled(kind: &EndOfEpochTransactionKind, features: &ProtocolFeatures) -> bool {
+    match kind {
+        EndOfEpochTransactionKind::ChangeEpoch(_) => true,
+        EndOfEpochTransactionKind::AuthenticatorStateCreate
+        | EndOfEpochTransactionKind::AuthenticatorStateExpire(_) => features.authenticator_state,
+        EndOfEpochTransactionKind::RandomnessStateCreate => features.random_beacon,
+        EndOfEpochTransactionKind::DenyListStateCreate => features.coin_deny_list,
+        EndOfEpochTransactionKind::BridgeStateCreate { .. }
+        | EndOfEpochTransactionKind::BridgeCommitteeInit { .. } => features.bridge,
+    }
+}
+
+fn end_of_epoch_name(kind: &EndOfEpochTransactionKind) -> &'static str {
+    match kind {
+        EndOfEpochTransactionKind::ChangeEpoch(_) => "ChangeEpoch",
+        EndOfEpochTransactionKind::AuthenticatorStateCreate => "AuthenticatorStateCreate",
+        EndOfEpochTransactionKind::AuthenticatorStateExpire(_) => "AuthenticatorStateExpire",
+        EndOfEpochTransactionKind::RandomnessStateCreate => "RandomnessStateCreate",
+        EndOfEpochTransactionKind::DenyListStateCreate => "DenyListStateCreate",
+        EndOfEpochTransactionKind::BridgeStateCreate { .. } => "BridgeStateCreate",
+        EndOfEpochTransactionKind::BridgeCommitteeInit { .. } => "BridgeCommitteeInit",
+    }
+}
+
+/// Error returned when a transaction would be rejected by a fullnode regardless of the state of
+/// the network, or when it breaks the rules of a [`ProtocolConfig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidTransaction {
+    /// Users can't submit system transactions, of the given kind.
+    SystemTransaction(&'static str),
+    /// The given kind of transaction, or operation run at the end of an epoch, isn't enabled.
+    KindNotEnabled(&'static str),
+    /// A command of the given kind isn't enabled.
+    CommandNotEnabled { command: usize, name: &'static str },
+    /// The budget is below the minimum budget of the transaction.
+    BudgetTooLow { budget: u64, min: u64 },
+    /// The budget is above the maximum budget of a transaction.
+    BudgetTooHigh { budget: u64, max: u64 },
+    /// The transaction has no commands.
+    NoCommands,
+    /// The transaction has more than `max` commands.
+    TooManyCommands { count: usize, max: usize },
+    /// The transaction has more than `max` object inputs.
+    TooManyInputObjects { count: usize, max: usize },
+    /// A pure input is larger than `max` bytes.
+    PureArgumentTooLarge {
+        input: usize,
+        size: usize,
+        max: usize,
+    },
+    /// The same object is used as more than one input, or more than one gas object.
+    DuplicateObject(ObjectId),
+    /// A gas object is also used as an input.
+    GasObjectUsedAsInput(ObjectId),
+    /// The gas payment uses more than `max` objects.
+    TooManyGasObjects { count: usize, max: usize },
+    /// A command which needs some arguments, or modules, has none.
+    EmptyCommand { command: usize },
+    /// A command has more than `max` arguments.
+    TooManyArguments {
+        command: usize,
+        count: usize,
+        max: usize,
+    },
+    /// A move call has more than `max` type arguments.
+    TooManyTypeArguments {
+        command: usize,
+        count: usize,
+        max: usize,
+    },
+    /// A package has more than `max` modules.
+    TooManyModules {
+        command: usize,
+        count: usize,
+        max: usize,
+    },
+    /// The transaction has more than `max` publish and upgrade commands.
+    TooManyPublishes { count: usize, max: usize },
+    /// An argument refers to an input which doesn't exist, or to the result of a command which
+    /// doesn't run before the one using it.
+    InvalidArgument { command: usize, argument: Argument },
+    /// A receiving input is passed to a command other than a move call.
+    ReceivingArgumentMisuse { command: usize },
+    /// The `Random` object is used as an input, but on-chain randomness isn't enabled.
+    RandomNotEnabled { input: usize },
+    /// The `Random` object is used as a mutable input.
+    MutableRandom { input: usize },
+    /// A command other than `TransferObjects` or `MergeCoins` follows the move call using the
+    /// `Random` object.
+    CommandAfterRandom {
+        random_command: usize,
+        command: usize,
+    },
+}
+
+impl std::fmt::Display for InvalidTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SystemTransaction(kind) => {
+                write!(f, "{kind} transactions can't be submitted by users")
+            }
+            Self::KindNotEnabled(kind) => write!(f, "{kind} isn't enabled"),
+            Self::CommandNotEnabled { command, name } => {
+                write!(f, "command {command} is a {name}, which isn't enabled")
+            }
+            Self::BudgetTooLow { budget, min } => {
+                write!(f, "budget {budget} is below the minimum of {min}")
+            }
+            Self::BudgetTooHigh { budget, max } => {
+                write!(f, "budget {budget} exceeds the maximum of {max}")
+            }
+            Self::NoCommands => write!(f, "transaction has no commands"),
+            Self::TooManyCommands { count, max } => {
+                write!(f, "{count} commands exceed the maximum of {max}")
+            }
+            Self::TooManyInputObjects { count, max } => {
+                write!(f, "{count} input objects exceed the maximum of {max}")
+            }
+            Self::PureArgumentTooLarge { input, size, max } => write!(
+                f,
+                "pure input {input} of {size} bytes exceeds the maximum of {max} bytes"
+            ),
+            Self::DuplicateObject(id) => write!(f, "object {id} is used more than once"),
+            Self::GasObjectUsedAsInput(id) => {
+                write!(f, "gas object {id} is also used as an input")
+            }
+            Self::TooManyGasObjects { count, max } => {
+                write!(f, "{count} gas objects exceed the maximum of {max}")
+            }
+            Self::EmptyCommand { command } => write!(f, "command {command} is empty"),
+            Self::TooManyArguments {
+                command,
+                count,
+                max,
+            } => write!(
+                f,
+                "{count} arguments of command {command} exceed the maximum of {max}"
+            ),
+            Self::TooManyTypeArguments {
+                command,
+                count,
+                max,
+            } => write!(
+                f,
+                "{count} type arguments of command {command} exceed the maximum of {max}"
+            ),
+            Self::TooManyModules {
+                command,
+                count,
+                max,
+            } => write!(
+                f,
+                "{count} modules published by command {command} exceed the maximum of {max}"
+            ),
+            Self::TooManyPublishes { count, max } => write!(
+                f,
+                "{count} publish and upgrade commands exceed the maximum of {max}"
+            ),
+            Self::InvalidArgument { command, argument } => {
+                write!(f, "invalid argument {argument:?} of command {command}")
+            }
+            Self::ReceivingArgumentMisuse { command } => write!(
+                f,
+                "receiving input used by command {command}, which isn't a move call"
+            ),
+            Self::RandomNotEnabled { input } => write!(
+                f,
+                "input {input} is the Random object, but randomness isn't enabled"
+            ),
+            Self::MutableRandom { input } => {
+                write!(f, "input {input} is the Random object, used mutably")
+            }
+            Self::CommandAfterRandom {
+                random_command,
+                command,
+            } => write!(
+                f,
+                "command {command} follows command {random_command} using the Random object, \
+                 but only TransferObjects and MergeCoins can"
+            ),
+        }
+    }
+}
+
+impl crate::error::ErrorCode for InvalidTransaction {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::SystemTransaction(_) => "system_transaction",
+            Self::KindNotEnabled(_) => "kind_not_enabled",
+            Self::CommandNotEnabled { .. } => "command_not_enabled",
+            Self::BudgetTooLow { .. } => "budget_too_low",
+            Self::BudgetTooHigh { .. } => "budget_too_high",
+            Self::NoCommands => "no_commands",
+            Self::TooManyCommands { .. } => "too_many_commands",
+            Self::TooManyInputObjects { .. } => "too_many_input_objects",
+            Self::PureArgumentTooLarge { .. } => "pure_argument_too_large",
+            Self::DuplicateObject(_) => "duplicate_object",
+            Self::GasObjectUsedAsInput(_) => "gas_object_used_as_input",
+            Self::TooManyGasObjects { .. } => "too_many_gas_objects",
+            Self::EmptyCommand { .. } => "empty_command",
+            Self::TooManyArguments { .. } => "too_many_arguments",
+            Self::TooManyTypeArguments { .. } => "too_many_type_arguments",
+            Self::TooManyModules { .. } => "too_many_modules",
+            Self::TooManyPublishes { .. } => "too_many_publishes",
+            Self::InvalidArgument { .. } => "invalid_argument",
+            Self::ReceivingArgumentMisuse { .. } => "receiving_argument_misuse",
+            Self::RandomNotEnabled { .. } => "random_not_enabled",
+            Self::MutableRandom { .. } => "mutable_random",
+            Self::CommandAfterRandom { .. } => "command_after_random",
+        }
+    }
+}
+
+impl std::error::Error for InvalidTransaction {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::protocol_config;
+    use crate::Address;
+    use crate::GasPayment;
+    use crate::MoveCall;
+    use crate::ObjectDigest;
+    use crate::ObjectReference;
+    use crate::SplitCoins;
+    use crate::TransactionExpiration;
+    use crate::TransferObjects;
+    use crate::Upgrade;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn object(byte: u8) -> ObjectReference {
+        ObjectReference::new(ObjectId::new([byte; 32]), 1, ObjectDigest::ZERO)
+    }
+
+    fn transaction(inputs: Vec<Input>, commands: Vec<Command>) -> Transaction {
+        Transaction {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs,
+                commands,
+            }),
+            sender: Address::TWO,
+            gas_payment: GasPayment {
+                objects: vec![object(0xff)],
+                owner: Address::TWO,
+                price: 1_000,
+                budget: 5_000_000,
+            },
+            expiration: TransactionExpiration::None,
+        }
+    }
+
+    #[test]
+    fn validity_check() {
+        let transfer = Command::TransferObjects(TransferObjects {
+            objects: vec![Argument::Input(0), Argument::Result(0)],
+            address: Argument::Input(1),
+        });
+        let split = Command::SplitCoins(SplitCoins {
+            coin: Argument::Gas,
+            amounts: vec![Argument::Input(1)],
+        });
+        let inputs = vec![
+            Input::ImmutableOrOwned(object(1)),
+            Input::Pure {
+                value: Address::TWO.as_bytes().to_vec(),
+            },
+        ];
+        transaction(inputs.clone(), vec![split.clone(), transfer.clone()])
+            .validity_check()
+            .unwrap();
+
+        // Results can only be used by later commands
+        assert_eq!(
+            transaction(inputs.clone(), vec![transfer.clone(), split.clone()]).validity_check(),
+            Err(InvalidTransaction::InvalidArgument {
+                command: 0,
+                argument: Argument::Result(0)
+            })
+        );
+
+        // Inputs must exist
+        assert_eq!(
+            transaction(inputs[..1].to_vec(), vec![split.clone()]).validity_check(),
+            Err(InvalidTransaction::InvalidArgument {
+                command: 0,
+                argument: Argument::Input(1)
+            })
+        );
+
+        assert_eq!(
+            transaction(inputs.clone(), vec![]).validity_check(),
+            Err(InvalidTransaction::NoCommands)
+        );
+        assert_eq!(
+            transaction(
+                inputs.clone(),
+                vec![Command::SplitCoins(SplitCoins {
+                    coin: Argument::Gas,
+                    amounts: vec![],
+                })]
+            )
+            .validity_check(),
+            Err(InvalidTransaction::EmptyCommand { command: 0 })
+        );
+
+        let mut duplicated = inputs.clone();
+        duplicated.push(Input::Shared {
+            object_id: ObjectId::new([1; 32]),
+            initial_shared_version: 1,
+            mutable: true,
+        });
+        assert_eq!(
+            transaction(duplicated, vec![split.clone()]).validity_check(),
+            Err(InvalidTransaction::DuplicateObject(ObjectId::new([1; 32])))
+        );
+
+        let mut gas = transaction(inputs.clone(), vec![split.clone()]);
+        gas.gas_payment.objects = vec![object(1)];
+        assert_eq!(
+            gas.validity_check(),
+            Err(InvalidTransaction::GasObjectUsedAsInput(ObjectId::new(
+                [1; 32]
+            )))
+        );
+
+        let large = vec![Input::Pure {
+            value: vec![0; ProgrammableTransaction::MAX_PURE_ARGUMENT_SIZE + 1],
+        }];
+        assert_eq!(
+            transaction(large, vec![split.clone()]).validity_check(),
+            Err(InvalidTransaction::PureArgumentTooLarge {
+                input: 0,
+                size: ProgrammableTransaction::MAX_PURE_ARGUMENT_SIZE + 1,
+                max: ProgrammableTransaction::MAX_PURE_ARGUMENT_SIZE,
+            })
+        );
+
+        // Receiving inputs can be passed to move calls only
+        let receiving = vec![Input::Receiving(object(2))];
+        let call = Command::MoveCall(MoveCall {
+            package: ObjectId::from(Address::TWO),
+            module: "transfer".parse().unwrap(),
+            function: "receive".parse().unwrap(),
+            type_arguments: vec![],
+            arguments: vec![Argument::Input(0)],
+        });
+        transaction(receiving.clone(), vec![call])
+            .validity_check()
+            .unwrap();
+        assert_eq!(
+            transaction(
+                receiving,
+                vec![Command::MergeCoins(crate::MergeCoins {
+                    coin: Argument::Gas,
+                    coins_to_merge: vec![Argument::Input(0)],
+                })]
+            )
+            .validity_check(),
+            Err(InvalidTransaction::ReceivingArgumentMisuse { command: 0 })
+        );
+
+        let system = Transaction::new_system(TransactionKind::EndOfEpoch(vec![]));
+        assert_eq!(
+            system.validity_check(),
+            Err(InvalidTransaction::SystemTransaction(
+                "EndOfEpochTransaction"
+            ))
+        );
+    }
+
+    #[test]
+    fn random() {
+        let random = |mutable| Input::Shared {
+            object_id: ObjectId::RANDOM,
+            initial_shared_version: 42,
+            mutable,
+        };
+        let call = Command::MoveCall(MoveCall {
+            package: ObjectId::from(Address::TWO),
+            module: "lottery".parse().unwrap(),
+            function: "draw".parse().unwrap(),
+            type_arguments: vec![],
+            arguments: vec![Argument::Input(0)],
+        });
+        let transfer = Command::TransferObjects(TransferObjects {
+            objects: vec![Argument::Result(0)],
+            address: Argument::Input(1),
+        });
+        let split = Command::SplitCoins(SplitCoins {
+            coin: Argument::Gas,
+            amounts: vec![Argument::Input(1)],
+        });
+        let inputs = vec![
+            random(false),
+            Input::Pure {
+                value: Address::TWO.as_bytes().to_vec(),
+            },
+        ];
+
+        // Commands using randomness can be preceded by anything
+        transaction(
+            inputs.clone(),
+            vec![split.clone(), call.clone(), transfer.clone()],
+        )
+        .validity_check()
+        .unwrap();
+
+        assert_eq!(
+            transaction(inputs.clone(), vec![call.clone(), split.clone(), transfer])
+                .validity_check(),
+            Err(InvalidTransaction::CommandAfterRandom {
+                random_command: 0,
+                command: 1
+            })
+        );
+        assert_eq!(
+            transaction(vec![random(true), inputs[1].clone()], vec![call.clone()]).validity_check(),
+            Err(InvalidTransaction::MutableRandom { input: 0 })
+        );
+
+        let mut config = protocol_config();
+        let tx = transaction(inputs, vec![call]);
+        tx.check_against(&config).unwrap();
+        config.features.random_beacon = false;
+        assert_eq!(
+            tx.check_against(&config),
+            Err(InvalidTransaction::RandomNotEnabled { input: 0 })
+        );
+    }
+
+    #[test]
+    fn check_against() {
+        let mut config = protocol_config();
+        let upgrade = Command::Upgrade(Upgrade {
+            modules: vec![vec![0]],
+            dependencies: vec![],
+            package: ObjectId::from(Address::TWO),
+            ticket: Argument::Input(0),
+        });
+        let tx = transaction(vec![Input::ImmutableOrOwned(object(1))], vec![upgrade]);
+        tx.check_against(&config).unwrap();
+
+        let mut low = tx.clone();
+        low.gas_payment.budget = 999_999;
+        assert_eq!(
+            low.check_against(&config),
+            Err(InvalidTransaction::BudgetTooLow {
+                budget: 999_999,
+                min: 1_000_000
+            })
+        );
+
+        config.limits.max_modules_in_publish = 0;
+        assert_eq!(
+            tx.check_against(&config),
+            Err(InvalidTransaction::TooManyModules {
+                command: 0,
+                count: 1,
+                max: 0
+            })
+        );
+
+        config.features.package_upgrades = false;
+        assert_eq!(
+            tx.check_against(&config),
+            Err(InvalidTransaction::CommandNotEnabled {
+                command: 0,
+                name: "Upgrade"
+            })
+        );
+
+        // System transactions are checked against the enabled features only
+        let end_of_epoch = Transaction::new_system(TransactionKind::EndOfEpoch(vec![
+            EndOfEpochTransactionKind::RandomnessStateCreate,
+        ]));
+        end_of_epoch.check_against(&config).unwrap();
+        config.features.random_beacon = false;
+        assert_eq!(
+            end_of_epoch.check_against(&config),
+            Err(InvalidTransaction::KindNotEnabled("RandomnessStateCreate"))
+        );
+        config.features.end_of_epoch_transaction = false;
+        assert_eq!(
+            Transaction::new_system(TransactionKind::EndOfEpoch(vec![])).check_against(&config),
+            Err(InvalidTransaction::KindNotEnabled("EndOfEpochTransaction"))
+        );
+    }
+}
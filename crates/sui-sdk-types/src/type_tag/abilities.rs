@@ -0,0 +1,320 @@
+use super::StructTag;
+use super::TypeTag;
+
+/// An ability of a Move type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub enum Ability {
+    /// Values of the type can be copied.
+    Copy,
+    /// Values of the type can be dropped.
+    Drop,
+    /// Values of the type can be stored inside of other values.
+    Store,
+    /// The type can be used as the type of an object.
+    Key,
+}
+
+impl Ability {
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl std::fmt::Display for Ability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ability = match self {
+            Self::Copy => "copy",
+            Self::Drop => "drop",
+            Self::Store => "store",
+            Self::Key => "key",
+        };
+        f.write_str(ability)
+    }
+}
+
+/// A set of [`Ability`]s.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct AbilitySet(#[cfg_attr(feature = "proptest", strategy(0u8..16))] u8);
+
+impl AbilitySet {
+    const ALL: [Ability; 4] = [Ability::Copy, Ability::Drop, Ability::Store, Ability::Key];
+
+    pub const EMPTY: Self = Self(0);
+
+    /// The abilities of primitive types, i.e. `copy`, `drop`, and `store`.
+    pub const PRIMITIVES: Self =
+        Self(Ability::Copy.bit() | Ability::Drop.bit() | Ability::Store.bit());
+
+    /// The abilities of `signer`, i.e. only `drop`.
+    pub const SIGNER: Self = Self(Ability::Drop.bit());
+
+    pub const fn has(self, ability: Ability) -> bool {
+        self.0 & ability.bit() != 0
+    }
+
+    #[must_use]
+    pub const fn with(self, ability: Ability) -> Self {
+        Self(self.0 | ability.bit())
+    }
+
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Ability> {
+        Self::ALL
+            .into_iter()
+            .filter(move |ability| self.has(*ability))
+    }
+}
+
+impl FromIterator<Ability> for AbilitySet {
+    fn from_iter<T: IntoIterator<Item = Ability>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl std::fmt::Debug for AbilitySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl std::fmt::Display for AbilitySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, ability) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{ability}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The abilities declared by a struct or enum, as found in the normalized form of the module
+/// defining it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DatatypeAbilities {
+    pub abilities: AbilitySet,
+
+    /// Whether each of the type parameters of the datatype is phantom.
+    ///
+    /// Phantom type parameters don't constrain the abilities of an instantiation of the datatype.
+    pub phantom_type_params: Vec<bool>,
+}
+
+/// A source of the declared abilities of datatypes, e.g. a cache of normalized modules.
+pub trait AbilityResolver {
+    /// Return the abilities declared by the datatype `tag` is an instantiation of, or `None` if
+    /// they aren't known.
+    ///
+    /// The type parameters of `tag` should be ignored.
+    fn datatype_abilities(&self, tag: &StructTag) -> Option<DatatypeAbilities>;
+}
+
+impl<F> AbilityResolver for F
+where
+    F: Fn(&StructTag) -> Option<DatatypeAbilities>,
+{
+    fn datatype_abilities(&self, tag: &StructTag) -> Option<DatatypeAbilities> {
+        self(tag)
+    }
+}
+
+impl TypeTag {
+    /// Checks if this is one of the primitive types, i.e. an integer, `bool`, `address` or
+    /// `signer`.
+    pub fn is_primitive(&self) -> bool {
+        !matches!(self, Self::Vector(_) | Self::Struct(_))
+    }
+
+    pub fn is_vector(&self) -> bool {
+        matches!(self, Self::Vector(_))
+    }
+
+    pub fn is_struct(&self) -> bool {
+        matches!(self, Self::Struct(_))
+    }
+
+    /// Returns the type of the elements of this type if it is a vector.
+    pub fn vector_element(&self) -> Option<&TypeTag> {
+        match self {
+            Self::Vector(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&StructTag> {
+        match self {
+            Self::Struct(tag) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Returns the type arguments of this type if it is a struct, and an empty slice otherwise.
+    pub fn type_params(&self) -> &[TypeTag] {
+        self.as_struct()
+            .map(|tag| tag.type_params.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Resolve the abilities of this type, following the rules of the Move type system.
+    ///
+    /// Returns `None` if the declared abilities of a datatype this type refers to aren't known
+    /// to `resolver`, or don't match the number of its type arguments.
+    pub fn abilities<R: AbilityResolver + ?Sized>(&self, resolver: &R) -> Option<AbilitySet> {
+        match self {
+            Self::Signer => Some(AbilitySet::SIGNER),
+            Self::Vector(element) => Some(
+                element
+                    .abilities(resolver)?
+                    .intersection(AbilitySet::PRIMITIVES),
+            ),
+            Self::Struct(tag) => tag.abilities(resolver),
+            _ => Some(AbilitySet::PRIMITIVES),
+        }
+    }
+}
+
+impl StructTag {
+    /// Resolve the abilities of this instantiation of a datatype.
+    ///
+    /// An instantiation has the `copy`, `drop` and `store` abilities declared by the datatype if
+    /// all of its non-phantom type arguments have them too, and has the `key` ability declared by
+    /// the datatype if all of its non-phantom type arguments have `store`.
+    pub fn abilities<R: AbilityResolver + ?Sized>(&self, resolver: &R) -> Option<AbilitySet> {
+        let declared = resolver.datatype_abilities(self)?;
+        if declared.phantom_type_params.len() != self.type_params.len() {
+            return None;
+        }
+
+        let mut abilities = declared.abilities;
+        for (param, is_phantom) in self.type_params.iter().zip(declared.phantom_type_params) {
+            if is_phantom {
+                continue;
+            }
+            let param = param.abilities(resolver)?;
+            let mut allowed = param.intersection(AbilitySet::PRIMITIVES);
+            if param.has(Ability::Store) {
+                allowed = allowed.with(Ability::Key);
+            }
+            abilities = abilities.intersection(allowed);
+        }
+        Some(abilities)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn resolver(tag: &StructTag) -> Option<DatatypeAbilities> {
+        use Ability::*;
+
+        let (abilities, phantom_type_params) = match (tag.module.as_str(), tag.name.as_str()) {
+            ("object", "UID") => (vec![Store], vec![]),
+            ("coin", "Coin") => (vec![Key, Store], vec![true]),
+            ("option", "Option") => (vec![Copy, Drop, Store], vec![false]),
+            ("table", "Table") => (vec![Key, Store], vec![true, true]),
+            ("hot_potato", "Receipt") => (vec![], vec![]),
+            _ => return None,
+        };
+        Some(DatatypeAbilities {
+            abilities: abilities.into_iter().collect(),
+            phantom_type_params,
+        })
+    }
+
+    #[test]
+    fn introspection() {
+        let coin: TypeTag = "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap();
+        assert!(coin.is_struct() && !coin.is_primitive() && !coin.is_vector());
+        assert_eq!(coin.type_params(), &["0x2::sui::SUI".parse().unwrap()]);
+        assert_eq!(
+            coin.as_struct().unwrap().is_coin(),
+            coin.type_params().first()
+        );
+
+        let vector: TypeTag = "vector<u8>".parse().unwrap();
+        assert!(vector.is_vector());
+        assert_eq!(vector.vector_element(), Some(&TypeTag::U8));
+        assert!(vector.type_params().is_empty());
+
+        for primitive in [
+            TypeTag::U8,
+            TypeTag::U256,
+            TypeTag::Address,
+            TypeTag::Signer,
+        ] {
+            assert!(primitive.is_primitive());
+            assert!(primitive.as_struct().is_none());
+        }
+    }
+
+    #[test]
+    fn abilities() {
+        let abilities = |s: &str| s.parse::<TypeTag>().unwrap().abilities(&resolver);
+        let set = |abilities: &[Ability]| Some(abilities.iter().copied().collect::<AbilitySet>());
+        use Ability::*;
+
+        assert_eq!(abilities("u64"), set(&[Copy, Drop, Store]));
+        assert_eq!(abilities("signer"), set(&[Drop]));
+        assert_eq!(abilities("vector<signer>"), set(&[Drop]));
+
+        // Phantom type parameters don't constrain abilities
+        assert_eq!(
+            abilities("0x2::coin::Coin<0x2::hot_potato::Receipt>"),
+            set(&[Key, Store])
+        );
+        // Non-phantom type parameters do
+        assert_eq!(
+            abilities("0x1::option::Option<u64>"),
+            set(&[Copy, Drop, Store])
+        );
+        assert_eq!(
+            abilities("0x1::option::Option<0x2::coin::Coin<0x2::sui::SUI>>"),
+            set(&[Store])
+        );
+        assert_eq!(
+            abilities("vector<0x1::option::Option<0x2::hot_potato::Receipt>>"),
+            set(&[])
+        );
+
+        // Unknown datatypes and arity mismatches can't be resolved
+        assert_eq!(abilities("0x2::unknown::Unknown"), None);
+        assert_eq!(
+            abilities("0x1::option::Option<0x2::unknown::Unknown>"),
+            None
+        );
+        assert_eq!(abilities("0x2::table::Table<u64>"), None);
+    }
+
+    #[test]
+    fn ability_set() {
+        let set: AbilitySet = [Ability::Key, Ability::Store].into_iter().collect();
+        assert!(set.has(Ability::Key) && !set.has(Ability::Copy));
+        assert!(set.is_subset(set.union(AbilitySet::PRIMITIVES)));
+        assert_eq!(
+            set.intersection(AbilitySet::PRIMITIVES),
+            AbilitySet::EMPTY.with(Ability::Store)
+        );
+        assert_eq!(set.to_string(), "store, key");
+        assert_eq!(format!("{set:?}"), "{Store, Key}");
+    }
+}
@@ -1,9 +1,15 @@
+mod abilities;
 mod parse;
 
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod serialization;
 
+pub use abilities::Ability;
+pub use abilities::AbilityResolver;
+pub use abilities::AbilitySet;
+pub use abilities::DatatypeAbilities;
+
 use super::Address;
 
 /// Type of a move value
@@ -99,10 +105,22 @@ impl std::fmt::Display for TypeParseError {
     }
 }
 
+impl crate::error::ErrorCode for TypeParseError {
+    fn code(&self) -> &'static str {
+        "invalid_type_tag"
+    }
+}
+
 impl std::error::Error for TypeParseError {}
 
 /// A move identifier
 ///
+/// Identifiers are made of ASCII letters, digits and underscores, start with a letter or with an
+/// underscore followed by at least one other character, and are at most 128 characters long.
+///
+/// These are the rules the bytecode verifier applies, so keywords of the Move language are valid
+/// identifiers, see [`Identifier::new_source`] for the stricter rules of Move source code.
+///
 /// # BCS
 ///
 /// The BCS serialized form for this type is defined by the following ABNF:
@@ -128,6 +146,7 @@ pub struct Identifier(
 );
 
 impl Identifier {
+    /// Create an identifier, checking that it follows the rules of Move identifiers.
     pub fn new<T: AsRef<str>>(identifier: T) -> Result<Self, TypeParseError> {
         parse::parse_identifier(identifier.as_ref())
             .map(|ident| Self(ident.into()))
@@ -136,6 +155,34 @@ impl Identifier {
             })
     }
 
+    /// Create an identifier, checking that it follows the rules of Move source code identifiers.
+    ///
+    /// On top of the rules checked by [`Identifier::new`], this rejects the keywords of the Move
+    /// language, e.g. `fun` or `struct`, which can't be declared in source code.
+    pub fn new_source<T: AsRef<str>>(identifier: T) -> Result<Self, TypeParseError> {
+        if parse::is_keyword(identifier.as_ref()) {
+            return Err(TypeParseError {
+                source: identifier.as_ref().into(),
+            });
+        }
+
+        Self::new(identifier)
+    }
+
+    /// Create an identifier without checking that it follows the rules of Move identifiers.
+    ///
+    /// This is an escape hatch for names which are known to be valid, or which have to be used as
+    /// is, e.g. when they come from a source which has already validated them. An invalid
+    /// identifier is rejected by the network, and won't round trip through serialization.
+    pub fn new_unchecked<T: Into<Box<str>>>(identifier: T) -> Self {
+        Self(identifier.into())
+    }
+
+    /// Whether `identifier` follows the rules of Move identifiers.
+    pub fn is_valid(identifier: &str) -> bool {
+        parse::parse_identifier(identifier).is_ok()
+    }
+
     pub fn into_inner(self) -> Box<str> {
         self.0
     }
@@ -19,6 +19,43 @@ use winnow::Parser;
 // static ALLOWED_IDENTIFIERS: &str = r"(?:[a-zA-Z][a-zA-Z0-9_]*)|(?:_[a-zA-Z0-9_]+)";
 static MAX_IDENTIFIER_LENGTH: usize = 128;
 
+/// The keywords of the Move language, which can't be used as identifiers in Move source code.
+static KEYWORDS: &[&str] = &[
+    "abort",
+    "acquires",
+    "as",
+    "break",
+    "const",
+    "continue",
+    "copy",
+    "else",
+    "enum",
+    "false",
+    "for",
+    "friend",
+    "fun",
+    "if",
+    "invariant",
+    "let",
+    "loop",
+    "match",
+    "module",
+    "move",
+    "mut",
+    "native",
+    "public",
+    "return",
+    "spec",
+    "struct",
+    "true",
+    "use",
+    "while",
+];
+
+pub(super) fn is_keyword(identifier: &str) -> bool {
+    KEYWORDS.contains(&identifier)
+}
+
 pub(super) fn parse_identifier(mut input: &str) -> ModalResult<&str> {
     (identifier, eof).take().parse_next(&mut input)
 }
@@ -231,4 +268,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_identifier_validation() {
+        for valid in [
+            "a", "A_", "_a", "__", "x1", "vector", "u8", "address", "Self",
+        ] {
+            assert!(Identifier::is_valid(valid), "{valid} is valid");
+        }
+        let too_long = "a".repeat(MAX_IDENTIFIER_LENGTH + 1);
+        for invalid in ["", "_", "1a", "a-b", "a b", "é", &too_long] {
+            assert!(!Identifier::is_valid(invalid), "{invalid} is invalid");
+            Identifier::new(invalid).unwrap_err();
+            Identifier::new_source(invalid).unwrap_err();
+        }
+        assert!(Identifier::is_valid(&"a".repeat(MAX_IDENTIFIER_LENGTH)));
+
+        // Keywords are valid in bytecode, but not in source code
+        for keyword in ["fun", "struct", "match", "module"] {
+            assert!(Identifier::is_valid(keyword), "{keyword} is valid");
+            Identifier::new(keyword).unwrap();
+            Identifier::new_source(keyword).unwrap_err();
+        }
+        Identifier::new_source("modules").unwrap();
+        parse_struct_tag("0x1::module::Foo").unwrap();
+
+        assert_eq!(Identifier::new_unchecked("1a").as_str(), "1a");
+    }
 }
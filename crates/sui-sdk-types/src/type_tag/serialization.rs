@@ -57,6 +57,56 @@ impl SerializedTypeTagVariant {
             unknown => return Err(unknown),
         })
     }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => Self::Bool,
+            "u8" => Self::U8,
+            "u64" => Self::U64,
+            "u128" => Self::U128,
+            "address" => Self::Address,
+            "signer" => Self::Signer,
+            "vector" => Self::Vector,
+            "struct" => Self::Struct,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u256" => Self::U256,
+            _ => return None,
+        })
+    }
+}
+
+// Variants are identified by their index in BCS, but self-describing formats (e.g. the compact
+// JSON profile) identify them by name, so accept both.
+impl<'de> Deserialize<'de> for SerializedTypeTagVariant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VariantVisitor;
+
+        impl Visitor<'_> for VariantVisitor {
+            type Value = SerializedTypeTagVariant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("TypeTag variant")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                u32::try_from(v)
+                    .ok()
+                    .and_then(|v| SerializedTypeTagVariant::new(v).ok())
+                    .ok_or_else(|| E::custom(format!("unknown variant {v}")))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                SerializedTypeTagVariant::from_name(v)
+                    .ok_or_else(|| E::custom(format!("unknown variant {v}")))
+            }
+        }
+
+        deserializer.deserialize_identifier(VariantVisitor)
+    }
 }
 
 impl Serialize for TypeTag {
@@ -162,10 +212,8 @@ impl<'de> Visitor<'de> for TypeTagVisitor {
     {
         use serde::de::VariantAccess;
 
-        let (variant, deserializer) = data.variant::<u32>()?;
-        match SerializedTypeTagVariant::new(variant)
-            .map_err(|e| serde::de::Error::custom(format!("unknown variant {e}")))?
-        {
+        let (variant, deserializer) = data.variant::<SerializedTypeTagVariant>()?;
+        match variant {
             SerializedTypeTagVariant::Bool => deserializer.unit_variant().map(|_| TypeTag::Bool),
             SerializedTypeTagVariant::U8 => deserializer.unit_variant().map(|_| TypeTag::U8),
             SerializedTypeTagVariant::U64 => deserializer.unit_variant().map(|_| TypeTag::U64),
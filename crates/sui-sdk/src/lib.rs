@@ -0,0 +1,43 @@
+//! Rust SDK for the [Sui] blockchain.
+//!
+//! [Sui]: https://sui.io
+//!
+//! This crate re-exports the crates making up the SDK, so that they can be used through a single
+//! dependency with a consistent set of versions. The core types of [`sui_sdk_types`] are exported
+//! at the root of the crate, while the other subsystems are exported as modules.
+//!
+//! # Feature flags
+//!
+//! By default, only the core types are available. Below is a list of the available feature flags.
+//!
+//! - `serde`, `rand`, `hash`, `hash-simd`: Enable the features of the same name of
+//!   [`sui_sdk_types`].
+//! - `crypto`: Enables the [`crypto`] module, for signing and verifying transactions and
+//!   messages.
+//! - `ed25519`, `secp256k1`, `secp256r1`, `passkey`, `zklogin`: Enable the `crypto` feature and
+//!   the support of the signature scheme of the same name.
+//! - `bls12381`: Enables the `crypto` feature and the verification of validator signatures, e.g.
+//!   of checkpoints and committees.
+//! - `client`: Enables the [`client`] module, a GraphQL client for the Sui RPC, along with input
+//!   resolution in the transaction builder when the `transaction-builder` feature is enabled.
+//! - `transaction-builder`: Enables the [`transaction_builder`] module, for building
+//!   programmable transactions.
+//!
+//! Embedded users, or anyone who only needs the types, can depend on [`sui_sdk_types`] directly,
+//! which has no async or TLS dependencies.
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+
+pub use sui_sdk_types::*;
+
+#[cfg(feature = "crypto")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "crypto")))]
+pub use sui_crypto as crypto;
+
+#[cfg(feature = "client")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "client")))]
+pub use sui_graphql_client as client;
+
+#[cfg(feature = "transaction-builder")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "transaction-builder")))]
+pub use sui_transaction_builder as transaction_builder;
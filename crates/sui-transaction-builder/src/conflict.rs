@@ -0,0 +1,562 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of object version conflicts reported when submitting a transaction.
+//!
+//! A transaction referencing an owned object at a version which is no longer the latest one is
+//! rejected by validators, either because the version was already consumed or because another
+//! transaction holds a lock on it. Such a conflict can mean two very different things:
+//!
+//! - the reference is merely stale, e.g. the object was used by a previous transaction of the same
+//!   sender, and the transaction can be rebuilt with the latest reference and retried;
+//! - the object was spent, i.e. deleted, wrapped, or transferred to someone else, and retrying
+//!   can't succeed.
+//!
+//! [`VersionConflict::parse`] extracts the conflicting object from an error message, and
+//! [`ConflictResolution`] tells the two cases apart based on the effects of the transaction which
+//! consumed the object, or on the current state of the object. With the `client` feature,
+//! [`execute_with_conflict_resolution`] automates the refresh and retry loop.
+
+use sui_types::Address;
+use sui_types::Input;
+use sui_types::Object;
+use sui_types::ObjectId;
+use sui_types::ObjectOut;
+use sui_types::ObjectReference;
+use sui_types::Owner;
+use sui_types::Transaction;
+use sui_types::TransactionDigest;
+use sui_types::TransactionEffects;
+use sui_types::TransactionKind;
+
+/// An object version conflict reported when submitting a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VersionConflict {
+    /// The object whose referenced version is unavailable.
+    pub object_id: ObjectId,
+
+    /// The transaction which consumed, or holds a lock on, the referenced version, if reported.
+    pub transaction: Option<TransactionDigest>,
+}
+
+impl VersionConflict {
+    /// Extract a version conflict from the message of an error returned when submitting a
+    /// transaction.
+    ///
+    /// This recognizes the messages of the "object version unavailable for consumption" and
+    /// "object already locked by a different transaction" errors: the conflicting object is the
+    /// first full length object id of the message, and for lock conflicts the conflicting
+    /// transaction is the first transaction digest following "already locked". `None` is returned
+    /// if the message doesn't describe a version conflict.
+    pub fn parse(message: &str) -> Option<Self> {
+        let lowercase = message.to_ascii_lowercase();
+        let locked = lowercase.find("already locked");
+        if locked.is_none()
+            && !(lowercase.contains("not available for consumption")
+                || lowercase.contains("version unavailable"))
+        {
+            return None;
+        }
+
+        let object_id = tokens(message).find_map(|token| {
+            (token.len() == 2 + 2 * ObjectId::LENGTH && token.starts_with("0x"))
+                .then(|| token.parse().ok())
+                .flatten()
+        })?;
+        // Offsets in the lowercase message are valid in the message since only ASCII characters
+        // are changed
+        let transaction = locked.and_then(|start| {
+            tokens(&message[start..]).find_map(|token| token.parse::<TransactionDigest>().ok())
+        });
+
+        Some(Self {
+            object_id,
+            transaction,
+        })
+    }
+}
+
+/// The alphanumeric words of `text`.
+fn tokens(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+}
+
+/// How a [`VersionConflict`] should be resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// The object still belongs to one of the signers at a newer version, the transaction should
+    /// be rebuilt with this reference and retried.
+    Refresh(ObjectReference),
+
+    /// The object was deleted, wrapped, or now belongs to someone else.
+    Spent {
+        object_id: ObjectId,
+        transaction: Option<TransactionDigest>,
+    },
+}
+
+impl ConflictResolution {
+    /// Resolve a conflict on `object_id` from the `effects` of the transaction which consumed the
+    /// referenced version.
+    ///
+    /// `signers` are the addresses allowed to own the object for the transaction to be retried,
+    /// usually the result of [`Transaction::required_signers`]. `None` is returned if `effects`
+    /// don't touch the object, e.g. because the transaction only holds a lock on it without being
+    /// executed yet.
+    ///
+    /// [`Transaction::required_signers`]: sui_types::Transaction::required_signers
+    pub fn from_effects(
+        object_id: ObjectId,
+        effects: &TransactionEffects,
+        signers: &[Address],
+    ) -> Option<Self> {
+        let transaction = *effects.transaction_digest();
+        let spent = Self::Spent {
+            object_id,
+            transaction: Some(transaction),
+        };
+        let resolve = |reference, owner: &Owner| {
+            if is_usable_by(owner, signers) {
+                Self::Refresh(reference)
+            } else {
+                spent.clone()
+            }
+        };
+
+        match effects {
+            TransactionEffects::V1(e) => {
+                let removed = e
+                    .deleted
+                    .iter()
+                    .chain(&e.wrapped)
+                    .chain(&e.unwrapped_then_deleted)
+                    .any(|reference| *reference.object_id() == object_id);
+                if removed {
+                    return Some(spent.clone());
+                }
+
+                e.created
+                    .iter()
+                    .chain(&e.mutated)
+                    .chain(&e.unwrapped)
+                    .find(|written| *written.reference.object_id() == object_id)
+                    .map(|written| resolve(written.reference.clone(), &written.owner))
+            }
+            TransactionEffects::V2(e) => {
+                let change = e
+                    .changed_objects
+                    .iter()
+                    .find(|change| change.object_id == object_id)?;
+                let resolution = match &change.output_state {
+                    ObjectOut::NotExist => spent.clone(),
+                    ObjectOut::ObjectWrite { digest, owner } => resolve(
+                        ObjectReference::new(object_id, e.lamport_version, *digest),
+                        owner,
+                    ),
+                    ObjectOut::PackageWrite { version, digest } => {
+                        Self::Refresh(ObjectReference::new(object_id, *version, *digest))
+                    }
+                };
+                Some(resolution)
+            }
+        }
+    }
+
+    /// Resolve a conflict from the current state of the conflicting object, or from its absence
+    /// if it no longer exists.
+    pub fn from_object(object_id: ObjectId, object: Option<&Object>, signers: &[Address]) -> Self {
+        match object {
+            Some(object) if is_usable_by(object.owner(), signers) => Self::Refresh(
+                ObjectReference::new(object_id, object.version(), object.digest()),
+            ),
+            _ => Self::Spent {
+                object_id,
+                transaction: None,
+            },
+        }
+    }
+}
+
+/// Whether `transaction` already uses `reference` as an owned or immutable input, or as gas.
+#[cfg_attr(not(feature = "client"), allow(dead_code))]
+fn references(transaction: &Transaction, reference: &ObjectReference) -> bool {
+    let inputs = match &transaction.kind {
+        TransactionKind::ProgrammableTransaction(ptb) => ptb.inputs.as_slice(),
+        _ => &[],
+    };
+    transaction.gas_payment.objects.contains(reference)
+        || inputs
+            .iter()
+            .any(|input| matches!(input, Input::ImmutableOrOwned(r) if r == reference))
+}
+
+/// Whether an object with `owner` can be used as an owned or immutable input by `signers`.
+fn is_usable_by(owner: &Owner, signers: &[Address]) -> bool {
+    match owner {
+        Owner::Address(address) => signers.contains(address),
+        Owner::Immutable => true,
+        Owner::Object(_) | Owner::Shared(_) => false,
+    }
+}
+
+#[cfg(feature = "client")]
+pub use client::execute_with_conflict_resolution;
+#[cfg(feature = "client")]
+pub use client::ConflictError;
+#[cfg(feature = "client")]
+pub use client::RetryPolicy;
+
+#[cfg(feature = "client")]
+mod client {
+    use sui_graphql_client::Client;
+    use sui_types::ObjectId;
+    use sui_types::Transaction;
+    use sui_types::TransactionDigest;
+    use sui_types::TransactionEffects;
+    use sui_types::UserSignature;
+
+    use super::references;
+    use super::ConflictResolution;
+    use super::VersionConflict;
+    use crate::TransactionBuilder;
+
+    #[derive(thiserror::Error, Debug)]
+    #[non_exhaustive]
+    pub enum ConflictError {
+        #[error("Builder error: {0}")]
+        Builder(#[from] crate::error::Error),
+        #[error("Client error: {0}")]
+        Client(#[from] sui_graphql_client::error::Error),
+        #[error("Signing error: {0}")]
+        Signing(Box<dyn std::error::Error + Send + Sync>),
+        #[error("Missing effects for transaction {0}")]
+        MissingEffects(TransactionDigest),
+        #[error("Object {object_id} was spent{}", transaction.map(|t| format!(" by transaction {t}")).unwrap_or_default())]
+        ObjectSpent {
+            object_id: ObjectId,
+            transaction: Option<TransactionDigest>,
+        },
+        #[error("Object {object_id} is locked at its latest version{}", transaction.map(|t| format!(" by transaction {t}")).unwrap_or_default())]
+        ObjectLocked {
+            object_id: ObjectId,
+            transaction: Option<TransactionDigest>,
+        },
+        #[error("Object {0} input isn't one of the transaction's owned inputs")]
+        UnknownObject(ObjectId),
+        #[error("Version conflicts persisted after {0} attempts")]
+        TooManyAttempts(u32),
+    }
+
+    impl sui_types::ErrorCode for ConflictError {
+        fn code(&self) -> &'static str {
+            match self {
+                ConflictError::Builder(e) => sui_types::ErrorCode::code(e),
+                ConflictError::Client(e) => sui_types::ErrorCode::code(e),
+                ConflictError::Signing(_) => "signing_error",
+                ConflictError::MissingEffects(_) => "missing_effects",
+                ConflictError::ObjectSpent { .. } => "object_spent",
+                ConflictError::ObjectLocked { .. } => "object_locked",
+                ConflictError::UnknownObject(_) => "unknown_object",
+                ConflictError::TooManyAttempts(_) => "too_many_attempts",
+            }
+        }
+    }
+
+    impl serde::Serialize for ConflictError {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            sui_types::error::serialize(self, serializer)
+        }
+    }
+
+    /// How many times a transaction is rebuilt and retried after a version conflict.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RetryPolicy {
+        pub max_attempts: u32,
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            Self { max_attempts: 3 }
+        }
+    }
+
+    /// Build, sign, and execute the transaction of `builder`, refreshing stale object references
+    /// and retrying when the submission fails with a version conflict.
+    ///
+    /// Conflicts are resolved with the effects of the conflicting transaction when the error
+    /// reports it, and with the latest version of the object otherwise. Spent objects abort the
+    /// submission with [`ConflictError::ObjectSpent`], and objects whose latest version is already
+    /// locked by another transaction, e.g. after an equivocation, with
+    /// [`ConflictError::ObjectLocked`]. `sign` is called with every transaction
+    /// built, since refreshing a reference changes the transaction and invalidates previous
+    /// signatures.
+    pub async fn execute_with_conflict_resolution<F, E>(
+        client: &Client,
+        mut builder: TransactionBuilder,
+        mut sign: F,
+        policy: RetryPolicy,
+    ) -> Result<TransactionEffects, ConflictError>
+    where
+        F: FnMut(&Transaction) -> Result<Vec<UserSignature>, E>,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        for _ in 0..policy.max_attempts.max(1) {
+            let transaction = builder.clone().finish()?;
+            let signatures = sign(&transaction).map_err(|e| ConflictError::Signing(e.into()))?;
+
+            let error = match client.execute_tx(signatures, &transaction).await {
+                Ok(effects) => {
+                    return effects.ok_or(ConflictError::MissingEffects(transaction.digest()))
+                }
+                Err(error) => error,
+            };
+            let Some(conflict) = VersionConflict::parse(&error.to_string()) else {
+                return Err(error.into());
+            };
+
+            match resolve(client, &transaction, &conflict).await? {
+                ConflictResolution::Refresh(reference) => {
+                    // Retrying with the same reference would conflict again, the latest version
+                    // of the object is locked by another transaction
+                    if references(&transaction, &reference) {
+                        return Err(ConflictError::ObjectLocked {
+                            object_id: conflict.object_id,
+                            transaction: conflict.transaction,
+                        });
+                    }
+                    if !builder.refresh_object_reference(&reference) {
+                        return Err(ConflictError::UnknownObject(conflict.object_id));
+                    }
+                }
+                ConflictResolution::Spent {
+                    object_id,
+                    transaction,
+                } => {
+                    return Err(ConflictError::ObjectSpent {
+                        object_id,
+                        transaction,
+                    })
+                }
+            }
+        }
+
+        Err(ConflictError::TooManyAttempts(policy.max_attempts.max(1)))
+    }
+
+    async fn resolve(
+        client: &Client,
+        transaction: &Transaction,
+        conflict: &VersionConflict,
+    ) -> Result<ConflictResolution, ConflictError> {
+        let signers = transaction.required_signers();
+
+        if let Some(digest) = conflict.transaction {
+            if let Some(effects) = client.transaction_effects(digest).await? {
+                if let Some(resolution) =
+                    ConflictResolution::from_effects(conflict.object_id, &effects, &signers)
+                {
+                    return Ok(resolution);
+                }
+            }
+        }
+
+        let object = client.object(conflict.object_id.into(), None).await?;
+        Ok(ConflictResolution::from_object(
+            conflict.object_id,
+            object.as_ref(),
+            &signers,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sui_types::ChangedObject;
+    use sui_types::ExecutionStatus;
+    use sui_types::GasCostSummary;
+    use sui_types::IdOperation;
+    use sui_types::ObjectDigest;
+    use sui_types::ObjectIn;
+    use sui_types::TransactionEffectsV2;
+
+    use super::*;
+
+    const OBJECT: &str = "0x2c8d603bc51326b8c13cef9dd07031a408a48dddb541963357661df5d3204809";
+
+    fn effects(object_id: ObjectId, output_state: ObjectOut) -> TransactionEffects {
+        TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+            status: ExecutionStatus::Success,
+            epoch: 0,
+            gas_used: GasCostSummary::default(),
+            transaction_digest: TransactionDigest::new([7; 32]),
+            gas_object_index: None,
+            events_digest: None,
+            dependencies: vec![],
+            lamport_version: 12,
+            changed_objects: vec![ChangedObject {
+                object_id,
+                input_state: ObjectIn::Exist {
+                    version: 11,
+                    digest: ObjectDigest::ZERO,
+                    owner: Owner::Address(Address::ZERO),
+                },
+                output_state,
+                id_operation: IdOperation::None,
+            }],
+            unchanged_shared_objects: vec![],
+            auxiliary_data_digest: None,
+        }))
+    }
+
+    #[test]
+    fn parse_conflicts() {
+        let object_id: ObjectId = OBJECT.parse().unwrap();
+        let digest = TransactionDigest::new([7; 32]);
+
+        let unavailable = format!(
+            "Transaction validator signing failed due to issues with transaction inputs: Object ID \
+             {OBJECT} Version 0xb Digest 9WHxP7fNjpTPMrpKgJXfAVq8ViYXw2GoAjgBCeCB8vAR is not \
+             available for consumption, current version: 0xc"
+        );
+        assert_eq!(
+            VersionConflict::parse(&unavailable),
+            Some(VersionConflict {
+                object_id,
+                transaction: None,
+            })
+        );
+
+        let locked = format!(
+            "Object ({OBJECT}, SequenceNumber(11), o#9WHxP7fNjpTPMrpKgJXfAVq8ViYXw2GoAjgBCeCB8vAR) \
+             already locked by a different transaction: TransactionDigest({digest})"
+        );
+        assert_eq!(
+            VersionConflict::parse(&locked),
+            Some(VersionConflict {
+                object_id,
+                transaction: Some(digest),
+            })
+        );
+
+        assert_eq!(
+            VersionConflict::parse(&format!("Object {OBJECT} not found")),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_from_effects() {
+        let object_id: ObjectId = OBJECT.parse().unwrap();
+        let sender = Address::ZERO;
+        let digest = ObjectDigest::new([1; 32]);
+
+        // Used by a previous transaction of the sender
+        let written = effects(
+            object_id,
+            ObjectOut::ObjectWrite {
+                digest,
+                owner: Owner::Address(sender),
+            },
+        );
+        assert_eq!(
+            ConflictResolution::from_effects(object_id, &written, &[sender]),
+            Some(ConflictResolution::Refresh(ObjectReference::new(
+                object_id, 12, digest
+            )))
+        );
+
+        // Transferred away
+        let spent = Some(ConflictResolution::Spent {
+            object_id,
+            transaction: Some(TransactionDigest::new([7; 32])),
+        });
+        let transferred = effects(
+            object_id,
+            ObjectOut::ObjectWrite {
+                digest,
+                owner: Owner::Address("0x1".parse().unwrap()),
+            },
+        );
+        assert_eq!(
+            ConflictResolution::from_effects(object_id, &transferred, &[sender]),
+            spent
+        );
+
+        // Deleted
+        let deleted = effects(object_id, ObjectOut::NotExist);
+        assert_eq!(
+            ConflictResolution::from_effects(object_id, &deleted, &[sender]),
+            spent
+        );
+
+        // Not touched by the transaction
+        assert_eq!(
+            ConflictResolution::from_effects(ObjectId::ZERO, &deleted, &[sender]),
+            None
+        );
+        assert_eq!(
+            ConflictResolution::from_object(object_id, None, &[sender]),
+            ConflictResolution::Spent {
+                object_id,
+                transaction: None
+            }
+        );
+    }
+
+    #[test]
+    fn refresh_inputs() {
+        use crate::unresolved::Input;
+        use crate::TransactionBuilder;
+
+        let object_id: ObjectId = OBJECT.parse().unwrap();
+        let stale = Input::owned(object_id, 11, ObjectDigest::ZERO);
+        let mut tx = TransactionBuilder::new();
+        tx.set_sender(Address::ZERO);
+        tx.set_gas_budget(1_000_000);
+        tx.set_gas_price(1000);
+        tx.add_gas_objects(vec![Input::owned(ObjectId::ZERO, 1, ObjectDigest::ZERO)]);
+        let object = tx.input(stale);
+        tx.transfer_objects(vec![object], tx.gas());
+
+        let fresh = ObjectReference::new(object_id, 12, ObjectDigest::new([1; 32]));
+        assert!(tx.refresh_object_reference(&fresh));
+        assert!(!tx.refresh_object_reference(&ObjectReference::new(
+            "0x1".parse().unwrap(),
+            1,
+            ObjectDigest::ZERO
+        )));
+
+        let sui_types::TransactionKind::ProgrammableTransaction(ptb) = tx.finish().unwrap().kind
+        else {
+            unreachable!()
+        };
+        assert_eq!(ptb.inputs, [sui_types::Input::ImmutableOrOwned(fresh)]);
+    }
+
+    #[test]
+    fn locked_references() {
+        use crate::unresolved::Input;
+        use crate::TransactionBuilder;
+
+        let object_id: ObjectId = OBJECT.parse().unwrap();
+        let current = ObjectReference::new(object_id, 12, ObjectDigest::new([1; 32]));
+        let gas = ObjectReference::new(ObjectId::ZERO, 1, ObjectDigest::ZERO);
+        let mut tx = TransactionBuilder::new();
+        tx.set_sender(Address::ZERO);
+        tx.set_gas_budget(1_000_000);
+        tx.set_gas_price(1000);
+        tx.add_gas_objects(vec![Input::owned(ObjectId::ZERO, 1, ObjectDigest::ZERO)]);
+        let object = tx.input(Input::owned(object_id, 12, ObjectDigest::new([1; 32])));
+        tx.transfer_objects(vec![object], tx.gas());
+        let transaction = tx.finish().unwrap();
+
+        // A conflict resolved to the reference already in use can't be retried
+        assert!(references(&transaction, &current));
+        assert!(references(&transaction, &gas));
+        assert!(!references(
+            &transaction,
+            &ObjectReference::new(object_id, 13, ObjectDigest::ZERO)
+        ));
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base64ct::Error as Base64Error;
+use sui_types::Argument;
 use sui_types::ObjectId;
 
 #[derive(thiserror::Error, Debug, Clone)]
@@ -37,4 +38,44 @@ pub enum Error {
     SharedObjectMutability(ObjectId),
     #[error("Unsupported literal")]
     UnsupportedLiteral,
+    #[error("Argument {0:?} doesn't refer to an input or to the result of a previous command")]
+    InvalidArgument(Argument),
+    #[error("The gas coin can only be used by value to transfer it")]
+    GasCoinByValue,
+    #[error("Missing type for a Move vector which is empty or starts with a pure input")]
+    MissingVectorType,
+    #[error("Invalid element {index} of Move vector: {reason}")]
+    InvalidVectorElement { index: usize, reason: &'static str },
+}
+
+impl sui_types::ErrorCode for Error {
+    fn code(&self) -> &'static str {
+        match self {
+            Error::Input(_) => "invalid_input",
+            Error::WrongGasObject => "wrong_gas_object",
+            Error::Decoding(_) => "decoding_error",
+            Error::MissingObjectId => "missing_object_id",
+            Error::MissingVersion(_) => "missing_version",
+            Error::MissingDigest(_) => "missing_digest",
+            Error::MissingSender => "missing_sender",
+            Error::MissingGasObjects => "missing_gas_objects",
+            Error::MissingGasBudget => "missing_gas_budget",
+            Error::MissingGasPrice => "missing_gas_price",
+            Error::MissingObjectKind(_) => "missing_object_kind",
+            Error::MissingInitialSharedVersion(_) => "missing_initial_shared_version",
+            Error::MissingPureValue => "missing_pure_value",
+            Error::SharedObjectMutability(_) => "unknown_shared_object_mutability",
+            Error::UnsupportedLiteral => "unsupported_literal",
+            Error::InvalidArgument(_) => "invalid_argument",
+            Error::GasCoinByValue => "gas_coin_by_value",
+            Error::MissingVectorType => "missing_vector_type",
+            Error::InvalidVectorElement { .. } => "invalid_vector_element",
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        sui_types::error::serialize(self, serializer)
+    }
 }
@@ -0,0 +1,537 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! End-to-end flows composed from the builder, the GraphQL client, and the signers.
+//!
+//! Each function in this module performs a complete and commonly needed flow: selecting a gas
+//! coin, building the transaction, signing it, executing it, and waiting for it to be indexed.
+//! They are meant as living documentation of how the pieces of the SDK fit together, and are
+//! exercised against a localnet by the tests of this module.
+
+use std::time::Duration;
+
+use sui_crypto::ed25519::Ed25519PrivateKey;
+use sui_crypto::zklogin::Jwt;
+use sui_crypto::SignatureError;
+use sui_crypto::Signer;
+use sui_crypto::SuiSigner;
+use sui_graphql_client::Client;
+use sui_graphql_client::PaginationFilter;
+use sui_types::Address;
+use sui_types::EpochId;
+use sui_types::ExecutionStatus;
+use sui_types::IdOperation;
+use sui_types::Identifier;
+use sui_types::ObjectId;
+use sui_types::ObjectOut;
+use sui_types::Owner;
+use sui_types::SimpleSignature;
+use sui_types::Transaction;
+use sui_types::TransactionDigest;
+use sui_types::TransactionEffects;
+use sui_types::UserSignature;
+use sui_types::ZkLoginAuthenticator;
+use sui_types::ZkLoginInputs;
+use sui_types::ZkLoginPublicIdentifier;
+
+use crate::unresolved::Input;
+use crate::Function;
+use crate::Serialized;
+use crate::TransactionBuilder;
+
+/// The gas budget used by the flows in this module. Any unused part of the budget is refunded.
+pub const GAS_BUDGET: u64 = 500_000_000;
+
+/// How long to wait for an executed transaction to be indexed before giving up.
+pub const INDEXING_TIMEOUT: Duration = Duration::from_secs(30);
+
+const SUI_COIN_TYPE: &str = "0x2::coin::Coin<0x2::sui::SUI>";
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Builder error: {0}")]
+    Builder(#[from] crate::error::Error),
+    #[error("Client error: {0}")]
+    Client(#[from] sui_graphql_client::error::Error),
+    #[error("Signature error: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("Missing reference gas price")]
+    MissingGasPrice,
+    #[error("No SUI coin owned by {0} has a balance of at least {1}")]
+    InsufficientBalance(Address, u64),
+    #[error("Object {0} not found")]
+    ObjectNotFound(ObjectId),
+    #[error("Missing effects for transaction {0}")]
+    MissingEffects(TransactionDigest),
+    #[error("Transaction {0} failed: {1:?}")]
+    ExecutionFailed(TransactionDigest, ExecutionStatus),
+    #[error("Transaction {0} didn't create a {1}")]
+    NothingCreated(TransactionDigest, &'static str),
+    #[error("Transaction {0} wasn't indexed within {1:?}")]
+    NotIndexed(TransactionDigest, Duration),
+}
+
+impl sui_types::ErrorCode for Error {
+    fn code(&self) -> &'static str {
+        match self {
+            Error::Builder(e) => sui_types::ErrorCode::code(e),
+            Error::Client(e) => sui_types::ErrorCode::code(e),
+            Error::Signature(_) => "signature_error",
+            Error::MissingGasPrice => "missing_gas_price",
+            Error::InsufficientBalance(..) => "insufficient_balance",
+            Error::ObjectNotFound(_) => "object_not_found",
+            Error::MissingEffects(_) => "missing_effects",
+            Error::ExecutionFailed(..) => "execution_failed",
+            Error::NothingCreated(..) => "nothing_created",
+            Error::NotIndexed(..) => "not_indexed",
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        sui_types::error::serialize(self, serializer)
+    }
+}
+
+/// Transfer `amount` MIST from the address of `key` to `recipient`.
+pub async fn transfer_sui(
+    client: &Client,
+    key: &Ed25519PrivateKey,
+    recipient: Address,
+    amount: u64,
+) -> Result<TransactionEffects, Error> {
+    let sender = key.public_key().derive_address();
+    let tx = build_transfer_sui(client, sender, recipient, amount).await?;
+    let signature = key.sign_transaction(&tx)?;
+
+    execute(client, &tx, signature).await
+}
+
+/// Mint an NFT by calling `function`, and transfer it to the address of `key`.
+///
+/// `function` is expected to take the `name`, `description` and `url` of the NFT as Move
+/// `String`s, followed by the `TxContext`, and to return the NFT, which must have `store`.
+///
+/// Returns the id of the minted NFT along with the effects of the transaction.
+pub async fn mint_nft(
+    client: &Client,
+    key: &Ed25519PrivateKey,
+    function: Function,
+    name: &str,
+    description: &str,
+    url: &str,
+) -> Result<(ObjectId, TransactionEffects), Error> {
+    let sender = key.public_key().derive_address();
+    let mut tx = prepare(client, sender, 0).await?;
+
+    let arguments = vec![
+        tx.input(Serialized(&name)),
+        tx.input(Serialized(&description)),
+        tx.input(Serialized(&url)),
+    ];
+    let nft = tx.move_call(function, arguments);
+    let sender_input = tx.input(Serialized(&sender));
+    tx.transfer_objects(vec![nft], sender_input);
+
+    let tx = tx.finish()?;
+    let signature = key.sign_transaction(&tx)?;
+    let effects = execute(client, &tx, signature).await?;
+
+    let (nft_id, _) = created_objects(&effects)
+        .find(|(_, is_package)| !is_package)
+        .ok_or(Error::NothingCreated(tx.digest(), "NFT"))?;
+    Ok((nft_id, effects))
+}
+
+/// Publish a package, then call `module::function` from it in a second transaction.
+///
+/// The `UpgradeCap` of the package, as well as any values returned by the call, are transferred
+/// to the address of `key`.
+///
+/// Returns the id of the published package along with the effects of the call.
+pub async fn publish_and_call(
+    client: &Client,
+    key: &Ed25519PrivateKey,
+    modules: Vec<Vec<u8>>,
+    dependencies: Vec<ObjectId>,
+    module: Identifier,
+    function: Identifier,
+    arguments: Vec<Input>,
+) -> Result<(ObjectId, TransactionEffects), Error> {
+    let sender = key.public_key().derive_address();
+
+    let mut tx = prepare(client, sender, 0).await?;
+    let upgrade_cap = tx.publish(modules, dependencies);
+    let sender_input = tx.input(Serialized(&sender));
+    tx.transfer_objects(vec![upgrade_cap], sender_input);
+
+    let tx = tx.finish()?;
+    let signature = key.sign_transaction(&tx)?;
+    let effects = execute(client, &tx, signature).await?;
+    let (package_id, _) = created_objects(&effects)
+        .find(|(_, is_package)| *is_package)
+        .ok_or(Error::NothingCreated(tx.digest(), "package"))?;
+
+    let mut tx = prepare(client, sender, 0).await?;
+    let arguments = arguments.into_iter().map(|input| tx.input(input)).collect();
+    let result = tx.move_call(
+        Function::new(package_id.into(), module, function, vec![]),
+        arguments,
+    );
+    let sender_input = tx.input(Serialized(&sender));
+    tx.transfer_objects(vec![result], sender_input);
+
+    let tx = tx.finish()?;
+    let signature = key.sign_transaction(&tx)?;
+    let effects = execute(client, &tx, signature).await?;
+
+    Ok((package_id, effects))
+}
+
+/// Transfer `amount` MIST to `recipient` from the zkLogin address of the user who logged in with
+/// `jwt`.
+///
+/// `inputs` are the proof and inputs returned by a zkLogin prover for `jwt` and the ephemeral
+/// key, valid until `max_epoch`. The transaction is signed by `ephemeral_key`, and the signature
+/// is wrapped in a [`ZkLoginAuthenticator`] attesting that the key belongs to the user.
+pub async fn zklogin_login_and_send(
+    client: &Client,
+    jwt: &Jwt,
+    inputs: ZkLoginInputs,
+    max_epoch: EpochId,
+    ephemeral_key: &Ed25519PrivateKey,
+    recipient: Address,
+    amount: u64,
+) -> Result<TransactionEffects, Error> {
+    let sender = zklogin_address(jwt, &inputs)?;
+    let tx = build_transfer_sui(client, sender, recipient, amount).await?;
+    let signature = zklogin_sign(&tx, inputs, max_epoch, ephemeral_key)?;
+
+    execute(client, &tx, signature).await
+}
+
+/// Sign `tx` with `ephemeral_key`, wrapping the signature in a [`ZkLoginAuthenticator`].
+fn zklogin_sign(
+    tx: &Transaction,
+    inputs: ZkLoginInputs,
+    max_epoch: EpochId,
+    ephemeral_key: &Ed25519PrivateKey,
+) -> Result<UserSignature, SignatureError> {
+    let signature: SimpleSignature = ephemeral_key.try_sign(&tx.signing_digest())?;
+    Ok(UserSignature::ZkLogin(Box::new(ZkLoginAuthenticator {
+        inputs,
+        max_epoch,
+        signature,
+    })))
+}
+
+/// The address of the user who logged in with `jwt`, as attested to by `inputs`.
+pub fn zklogin_address(jwt: &Jwt, inputs: &ZkLoginInputs) -> Result<Address, SignatureError> {
+    ZkLoginPublicIdentifier::new(jwt.iss().to_owned(), inputs.address_seed.clone())
+        .map(|identifier| identifier.derive_address_padded())
+        .ok_or_else(|| SignatureError::from_source("invalid iss"))
+}
+
+async fn build_transfer_sui(
+    client: &Client,
+    sender: Address,
+    recipient: Address,
+    amount: u64,
+) -> Result<Transaction, Error> {
+    let mut tx = prepare(client, sender, amount).await?;
+
+    let amount = tx.input(Serialized(&amount));
+    let coin = tx.split_coins(tx.gas(), vec![amount]);
+    let recipient = tx.input(Serialized(&recipient));
+    tx.transfer_objects(vec![coin], recipient);
+
+    Ok(tx.finish()?)
+}
+
+/// Create a builder for a transaction sent by `sender`, with the gas price and budget set and a
+/// gas coin holding at least `amount` on top of the budget.
+async fn prepare(
+    client: &Client,
+    sender: Address,
+    amount: u64,
+) -> Result<TransactionBuilder, Error> {
+    let gas_price = client
+        .reference_gas_price(None)
+        .await?
+        .ok_or(Error::MissingGasPrice)?;
+
+    let required = amount.saturating_add(GAS_BUDGET);
+    let mut filter = PaginationFilter::default();
+    let gas_coin = loop {
+        let coins = client.coins(sender, Some(SUI_COIN_TYPE), filter).await?;
+        if let Some(coin) = coins.data().iter().find(|coin| coin.balance() >= required) {
+            break *coin.id();
+        }
+
+        if !coins.page_info().has_next_page {
+            return Err(Error::InsufficientBalance(sender, required));
+        }
+        filter = PaginationFilter {
+            cursor: coins.end_cursor(),
+            ..PaginationFilter::default()
+        };
+    };
+    let gas_object = client
+        .object(gas_coin.into(), None)
+        .await?
+        .ok_or(Error::ObjectNotFound(gas_coin))?;
+
+    let mut tx = TransactionBuilder::new();
+    tx.set_sender(sender);
+    tx.set_gas_price(gas_price);
+    tx.set_gas_budget(GAS_BUDGET);
+    tx.add_gas_objects(vec![Input::from(&gas_object).with_owned_kind()]);
+    Ok(tx)
+}
+
+/// Execute `tx`, check that it succeeded, and wait for it to be indexed so that the objects it
+/// changed can be used by subsequent transactions.
+///
+/// The indexer is polled with an exponential backoff, for at most [`INDEXING_TIMEOUT`].
+async fn execute(
+    client: &Client,
+    tx: &Transaction,
+    signature: UserSignature,
+) -> Result<TransactionEffects, Error> {
+    let digest = tx.digest();
+    let effects = client
+        .execute_tx(vec![signature], tx)
+        .await?
+        .ok_or(Error::MissingEffects(digest))?;
+
+    if effects.status() != &ExecutionStatus::Success {
+        return Err(Error::ExecutionFailed(digest, effects.status().clone()));
+    }
+
+    let deadline = tokio::time::Instant::now() + INDEXING_TIMEOUT;
+    let mut delay = Duration::from_millis(100);
+    while client.transaction(digest).await?.is_none() {
+        if tokio::time::Instant::now() + delay > deadline {
+            return Err(Error::NotIndexed(digest, INDEXING_TIMEOUT));
+        }
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(Duration::from_secs(2));
+    }
+
+    Ok(effects)
+}
+
+/// The ids of the objects created by a transaction, along with whether they are packages.
+fn created_objects(effects: &TransactionEffects) -> impl Iterator<Item = (ObjectId, bool)> + '_ {
+    let (v1, v2) = match effects {
+        TransactionEffects::V1(effects) => (Some(effects), None),
+        TransactionEffects::V2(effects) => (None, Some(effects)),
+    };
+
+    let v1 = v1.into_iter().flat_map(|effects| {
+        effects.created.iter().map(|created| {
+            (
+                *created.reference.object_id(),
+                created.owner == Owner::Immutable,
+            )
+        })
+    });
+    let v2 = v2.into_iter().flat_map(|effects| {
+        effects
+            .changed_objects
+            .iter()
+            .filter(|changed| changed.id_operation == IdOperation::Created)
+            .map(|changed| {
+                (
+                    changed.object_id,
+                    matches!(changed.output_state, ObjectOut::PackageWrite { .. }),
+                )
+            })
+    });
+
+    v1.chain(v2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_crypto::zklogin::ZkloginVerifier;
+    use sui_crypto::SuiVerifier;
+    use sui_graphql_client::faucet::FaucetClient;
+    use sui_types::GasPayment;
+    use sui_types::Jwk;
+    use sui_types::ProgrammableTransaction;
+    use sui_types::TransactionExpiration;
+    use sui_types::TransactionKind;
+
+    /// Generate a new key and fund its address from the localnet faucet.
+    async fn funded_key(client: &Client) -> Ed25519PrivateKey {
+        let key = Ed25519PrivateKey::generate(rand::thread_rng());
+        let address = key.public_key().derive_address();
+        let coins = FaucetClient::local()
+            .request_and_wait(address)
+            .await
+            .unwrap()
+            .unwrap()
+            .sent;
+        let digest = coins.first().unwrap().transfer_tx_digest;
+        while client.transaction(digest).await.unwrap().is_none() {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        key
+    }
+
+    #[tokio::test]
+    async fn test_transfer_sui() {
+        let client = Client::new_localhost();
+        let key = funded_key(&client).await;
+        let recipient = Address::generate(rand::thread_rng());
+
+        transfer_sui(&client, &key, recipient, 1_000_000_000)
+            .await
+            .unwrap();
+
+        let coins = client
+            .coins(recipient, None, PaginationFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(coins.data().len(), 1);
+        assert_eq!(coins.data()[0].balance(), 1_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_publish_and_mint_nft() {
+        let client = Client::new_localhost();
+        let key = funded_key(&client).await;
+        let package = crate::tests::move_package_data("package_test_example_v1.json");
+
+        let arguments = ["first", "minted by publish_and_call", "https://sui.io"]
+            .iter()
+            .map(|s| Serialized(s).into())
+            .collect();
+        let (package_id, _) = publish_and_call(
+            &client,
+            &key,
+            package.modules,
+            package.dependencies,
+            "nft".parse().unwrap(),
+            "mint".parse().unwrap(),
+            arguments,
+        )
+        .await
+        .unwrap();
+
+        let function = Function::new(
+            package_id.into(),
+            "nft".parse().unwrap(),
+            "mint".parse().unwrap(),
+            vec![],
+        );
+        let (nft_id, _) = mint_nft(
+            &client,
+            &key,
+            function,
+            "second",
+            "minted by mint_nft",
+            "https://sui.io",
+        )
+        .await
+        .unwrap();
+
+        let nft = client.object(nft_id.into(), None).await.unwrap().unwrap();
+        assert_eq!(
+            nft.owner(),
+            &Owner::Address(key.public_key().derive_address())
+        );
+    }
+
+    /// A zkLogin proof for the twitch test issuer, the ephemeral key it was generated for, and
+    /// the JWK which signed the JWT it was generated from.
+    fn zklogin_material() -> (ZkLoginInputs, Ed25519PrivateKey, Jwk) {
+        let inputs = serde_json::json!({
+            "proof_points": {
+                "a": [
+                    "17318089125952421736342263717932719437717844282410187957984751939942898251250",
+                    "11373966645469122582074082295985388258840681618268593976697325892280915681207",
+                    "1"
+                ],
+                "b": [
+                    [
+                        "5939871147348834997361720122238980177152303274311047249905942384915768690895",
+                        "4533568271134785278731234570361482651996740791888285864966884032717049811708"
+                    ],
+                    [
+                        "10564387285071555469753990661410840118635925466597037018058770041347518461368",
+                        "12597323547277579144698496372242615368085801313343155735511330003884767957854"
+                    ],
+                    ["1","0"]
+                ],
+                "c": [
+                    "15791589472556826263231644728873337629015269984699404073623603352537678813171",
+                    "4547866499248881449676161158024748060485373250029423904113017422539037162527",
+                    "1"
+                ]
+            },
+            "iss_base64_details": {
+                "value": "wiaXNzIjoiaHR0cHM6Ly9pZC50d2l0Y2gudHYvb2F1dGgyIiw",
+                "index_mod_4": 2
+            },
+            "header_base64": "eyJhbGciOiJSUzI1NiIsInR5cCI6IkpXVCIsImtpZCI6IjEifQ",
+            "address_seed": "20794788559620669596206457022966176986688727876128223628113916380927502737911"
+        });
+        let key = Ed25519PrivateKey::new([
+            155, 244, 154, 106, 7, 85, 249, 83, 129, 31, 206, 18, 95, 38, 131, 213, 4, 41, 195,
+            187, 73, 224, 116, 20, 126, 0, 137, 165, 46, 174, 21, 95,
+        ]);
+        let jwk = serde_json::from_str(r#"{"alg":"RS256","e":"AQAB","kid":"1","kty":"RSA","n":"6lq9MQ-q6hcxr7kOUp-tHlHtdcDsVLwVIw13iXUCvuDOeCi0VSuxCCUY6UmMjy53dX00ih2E4Y4UvlrmmurK0eG26b-HMNNAvCGsVXHU3RcRhVoHDaOwHwU72j7bpHn9XbP3Q3jebX6KIfNbei2MiR0Wyb8RZHE-aZhRYO8_-k9G2GycTpvc-2GBsP8VHLUKKfAs2B6sW3q3ymU6M0L-cFXkZ9fHkn9ejs-sqZPhMJxtBPBxoUIUQFTgv4VXTSv914f_YkNw-EjuwbgwXMvpyr06EyfImxHoxsZkFYB-qBYHtaMxTnFsZBr6fn8Ha2JqT1hoP7Z5r5wxDu3GQhKkHw","use":"sig"}"#).unwrap();
+
+        (serde_json::from_value(inputs).unwrap(), key, jwk)
+    }
+
+    #[test]
+    fn zklogin_signing() {
+        use base64ct::Encoding;
+
+        let (inputs, key, jwk) = zklogin_material();
+        let jwt = Jwt::parse(&format!(
+            "{}.{}.c2lnbmF0dXJl",
+            inputs.header_base64,
+            base64ct::Base64UrlUnpadded::encode_string(
+                br#"{"iss":"https://id.twitch.tv/oauth2","aud":"client","sub":"user"}"#
+            ),
+        ))
+        .unwrap();
+        let sender = zklogin_address(&jwt, &inputs).unwrap();
+
+        let tx = Transaction {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs: vec![],
+                commands: vec![],
+            }),
+            sender,
+            gas_payment: GasPayment {
+                objects: vec![],
+                owner: sender,
+                price: 1000,
+                budget: GAS_BUDGET,
+            },
+            expiration: TransactionExpiration::None,
+        };
+        let signature = zklogin_sign(&tx, inputs, 10, &key).unwrap();
+
+        let mut verifier = ZkloginVerifier::new_dev();
+        verifier.jwks_mut().insert(jwt.jwk_id(), jwk);
+        verifier.verify_transaction(&tx, &signature).unwrap();
+
+        // The proof is bound to the epoch it was generated for
+        let UserSignature::ZkLogin(mut authenticator) = signature else {
+            unreachable!()
+        };
+        authenticator.max_epoch = 11;
+        verifier
+            .verify_transaction(&tx, &UserSignature::ZkLogin(authenticator))
+            .unwrap_err();
+    }
+}
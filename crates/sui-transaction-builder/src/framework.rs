@@ -0,0 +1,300 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Ready-made [`Command::MoveCall`]s for common entry points of the Sui framework (`0x2`).
+//!
+//! Each helper takes its arguments in the same order as the Move function it calls and fills in
+//! the type arguments, so that the resulting command can be added to a transaction with
+//! [`TransactionBuilder::command`]:
+//!
+//! ```
+//! use sui_transaction_builder::framework::coin;
+//! use sui_transaction_builder::TransactionBuilder;
+//!
+//! let mut tx = TransactionBuilder::new();
+//! # let treasury_cap = tx.gas();
+//! let amount = tx.input(sui_transaction_builder::Serialized(&1_000u64));
+//! let coin_type = "0x1234::my_coin::MY_COIN".parse().unwrap();
+//! let coin = tx.command(coin::mint(coin_type, treasury_cap, amount));
+//! ```
+//!
+//! Note that Move functions returning references, like `vec_map::get`, can't be called from a
+//! programmable transaction and so have no helper.
+//!
+//! [`TransactionBuilder::command`]: crate::TransactionBuilder::command
+
+use sui_types::Address;
+use sui_types::Argument;
+use sui_types::Command;
+use sui_types::Identifier;
+use sui_types::MoveCall;
+use sui_types::TypeTag;
+
+fn move_call(
+    module: &str,
+    function: &str,
+    type_arguments: Vec<TypeTag>,
+    arguments: Vec<Argument>,
+) -> Command {
+    Command::MoveCall(MoveCall {
+        package: Address::TWO.into(),
+        module: Identifier::new(module).expect("valid framework module name"),
+        function: Identifier::new(function).expect("valid framework function name"),
+        type_arguments,
+        arguments,
+    })
+}
+
+/// Calls into `0x2::coin`, where `coin_type` is the `T` of `Coin<T>`.
+pub mod coin {
+    use super::*;
+
+    /// `coin::mint<T>(cap: &mut TreasuryCap<T>, value: u64): Coin<T>`
+    pub fn mint(coin_type: TypeTag, treasury_cap: Argument, amount: Argument) -> Command {
+        move_call("coin", "mint", vec![coin_type], vec![treasury_cap, amount])
+    }
+
+    /// `coin::mint_and_transfer<T>(cap: &mut TreasuryCap<T>, amount: u64, recipient: address)`
+    pub fn mint_and_transfer(
+        coin_type: TypeTag,
+        treasury_cap: Argument,
+        amount: Argument,
+        recipient: Argument,
+    ) -> Command {
+        move_call(
+            "coin",
+            "mint_and_transfer",
+            vec![coin_type],
+            vec![treasury_cap, amount, recipient],
+        )
+    }
+
+    /// `coin::burn<T>(cap: &mut TreasuryCap<T>, c: Coin<T>): u64`
+    pub fn burn(coin_type: TypeTag, treasury_cap: Argument, coin: Argument) -> Command {
+        move_call("coin", "burn", vec![coin_type], vec![treasury_cap, coin])
+    }
+
+    /// `coin::value<T>(self: &Coin<T>): u64`
+    pub fn value(coin_type: TypeTag, coin: Argument) -> Command {
+        move_call("coin", "value", vec![coin_type], vec![coin])
+    }
+}
+
+/// Calls into `0x2::transfer`, where `object_type` is the type of the object being transferred.
+pub mod transfer {
+    use super::*;
+
+    /// `transfer::public_transfer<T: key + store>(obj: T, recipient: address)`
+    pub fn public_transfer(object_type: TypeTag, object: Argument, recipient: Argument) -> Command {
+        move_call(
+            "transfer",
+            "public_transfer",
+            vec![object_type],
+            vec![object, recipient],
+        )
+    }
+
+    /// `transfer::public_share_object<T: key + store>(obj: T)`
+    pub fn public_share_object(object_type: TypeTag, object: Argument) -> Command {
+        move_call(
+            "transfer",
+            "public_share_object",
+            vec![object_type],
+            vec![object],
+        )
+    }
+
+    /// `transfer::public_freeze_object<T: key + store>(obj: T)`
+    pub fn public_freeze_object(object_type: TypeTag, object: Argument) -> Command {
+        move_call(
+            "transfer",
+            "public_freeze_object",
+            vec![object_type],
+            vec![object],
+        )
+    }
+}
+
+/// Calls into `0x2::package`.
+pub mod package {
+    use super::*;
+
+    /// `package::make_immutable(cap: UpgradeCap)`
+    pub fn make_immutable(upgrade_cap: Argument) -> Command {
+        move_call("package", "make_immutable", vec![], vec![upgrade_cap])
+    }
+}
+
+/// Calls into `0x2::vec_set`, where `key_type` is the `K` of `VecSet<K>`.
+pub mod vec_set {
+    use super::*;
+
+    /// `vec_set::empty<K: copy + drop>(): VecSet<K>`
+    pub fn empty(key_type: TypeTag) -> Command {
+        move_call("vec_set", "empty", vec![key_type], vec![])
+    }
+
+    /// `vec_set::singleton<K: copy + drop>(key: K): VecSet<K>`
+    pub fn singleton(key_type: TypeTag, key: Argument) -> Command {
+        move_call("vec_set", "singleton", vec![key_type], vec![key])
+    }
+
+    /// `vec_set::insert<K: copy + drop>(self: &mut VecSet<K>, key: K)`
+    pub fn insert(key_type: TypeTag, set: Argument, key: Argument) -> Command {
+        move_call("vec_set", "insert", vec![key_type], vec![set, key])
+    }
+
+    /// `vec_set::remove<K: copy + drop>(self: &mut VecSet<K>, key: &K)`
+    pub fn remove(key_type: TypeTag, set: Argument, key: Argument) -> Command {
+        move_call("vec_set", "remove", vec![key_type], vec![set, key])
+    }
+
+    /// `vec_set::contains<K: copy + drop>(self: &VecSet<K>, key: &K): bool`
+    pub fn contains(key_type: TypeTag, set: Argument, key: Argument) -> Command {
+        move_call("vec_set", "contains", vec![key_type], vec![set, key])
+    }
+
+    /// `vec_set::size<K: copy + drop>(self: &VecSet<K>): u64`
+    pub fn size(key_type: TypeTag, set: Argument) -> Command {
+        move_call("vec_set", "size", vec![key_type], vec![set])
+    }
+}
+
+/// Calls into `0x2::vec_map`, where `key_type` and `value_type` are the `K` and `V` of
+/// `VecMap<K, V>`.
+pub mod vec_map {
+    use super::*;
+
+    /// `vec_map::empty<K: copy, V>(): VecMap<K, V>`
+    pub fn empty(key_type: TypeTag, value_type: TypeTag) -> Command {
+        move_call("vec_map", "empty", vec![key_type, value_type], vec![])
+    }
+
+    /// `vec_map::insert<K: copy, V>(self: &mut VecMap<K, V>, key: K, value: V)`
+    pub fn insert(
+        key_type: TypeTag,
+        value_type: TypeTag,
+        map: Argument,
+        key: Argument,
+        value: Argument,
+    ) -> Command {
+        move_call(
+            "vec_map",
+            "insert",
+            vec![key_type, value_type],
+            vec![map, key, value],
+        )
+    }
+
+    /// `vec_map::remove<K: copy, V>(self: &mut VecMap<K, V>, key: &K): (K, V)`
+    ///
+    /// The removed key and value are the two nested results of the command.
+    pub fn remove(key_type: TypeTag, value_type: TypeTag, map: Argument, key: Argument) -> Command {
+        move_call(
+            "vec_map",
+            "remove",
+            vec![key_type, value_type],
+            vec![map, key],
+        )
+    }
+
+    /// `vec_map::contains<K: copy, V>(self: &VecMap<K, V>, key: &K): bool`
+    pub fn contains(
+        key_type: TypeTag,
+        value_type: TypeTag,
+        map: Argument,
+        key: Argument,
+    ) -> Command {
+        move_call(
+            "vec_map",
+            "contains",
+            vec![key_type, value_type],
+            vec![map, key],
+        )
+    }
+
+    /// `vec_map::size<K: copy, V>(self: &VecMap<K, V>): u64`
+    pub fn size(key_type: TypeTag, value_type: TypeTag, map: Argument) -> Command {
+        move_call("vec_map", "size", vec![key_type, value_type], vec![map])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unresolved::Input;
+    use crate::Serialized;
+    use crate::TransactionBuilder;
+
+    fn function(command: &Command) -> (&str, &str, &[TypeTag], &[Argument]) {
+        let Command::MoveCall(call) = command else {
+            panic!("not a move call: {command:?}");
+        };
+        assert_eq!(call.package, Address::TWO.into());
+        (
+            call.module.as_str(),
+            call.function.as_str(),
+            &call.type_arguments,
+            &call.arguments,
+        )
+    }
+
+    #[test]
+    fn presets() {
+        let coin_type: TypeTag = "0x1234::my_coin::MY_COIN".parse().unwrap();
+        let (cap, amount) = (Argument::Input(0), Argument::Input(1));
+
+        assert_eq!(
+            function(&coin::mint(coin_type.clone(), cap, amount)),
+            ("coin", "mint", &[coin_type.clone()][..], &[cap, amount][..])
+        );
+        assert_eq!(
+            function(&coin::burn(coin_type.clone(), cap, Argument::Result(0))),
+            (
+                "coin",
+                "burn",
+                &[coin_type.clone()][..],
+                &[cap, Argument::Result(0)][..]
+            )
+        );
+        assert_eq!(
+            function(&package::make_immutable(Argument::Result(0))),
+            (
+                "package",
+                "make_immutable",
+                &[][..],
+                &[Argument::Result(0)][..]
+            )
+        );
+        assert_eq!(
+            function(&vec_map::empty(TypeTag::Address, TypeTag::U64)),
+            (
+                "vec_map",
+                "empty",
+                &[TypeTag::Address, TypeTag::U64][..],
+                &[][..]
+            )
+        );
+    }
+
+    #[test]
+    fn mint_and_transfer() {
+        let coin_type: TypeTag = "0x1234::my_coin::MY_COIN".parse().unwrap();
+        let recipient: Address = "0xabc".parse().unwrap();
+
+        let mut tx = TransactionBuilder::new();
+        let cap = tx.input(Input::by_id("0x42".parse().unwrap()));
+        let amount = tx.input(Serialized(&1_000u64));
+        let recipient = tx.input(Serialized(&recipient));
+        let coin = tx.command(coin::mint(coin_type.clone(), cap, amount));
+        assert_eq!(coin, Argument::Result(0));
+        let coin_type = format!("0x2::coin::Coin<{coin_type}>").parse().unwrap();
+        tx.command(transfer::public_transfer(coin_type, coin, recipient));
+
+        assert_eq!(tx.commands.len(), 2);
+        assert_eq!(
+            function(&tx.commands[1]).3,
+            &[Argument::Result(0), Argument::Input(2)][..]
+        );
+    }
+}
@@ -1,7 +1,13 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod conflict;
 mod error;
+#[cfg(feature = "examples")]
+pub mod examples;
+pub mod framework;
+pub mod probe;
+pub mod resolver;
 pub mod unresolved;
 
 use error::Error;
@@ -153,6 +159,14 @@ impl TransactionBuilder {
         Argument::Result(self.commands.len() as u16 - 1)
     }
 
+    /// Add an already constructed command, e.g. one of the [`framework`] presets.
+    ///
+    /// The return value is a result argument that can be used in subsequent commands.
+    pub fn command(&mut self, command: Command) -> Argument {
+        self.commands.push(command);
+        Argument::Result(self.commands.len() as u16 - 1)
+    }
+
     /// Transfer a list of objects to the given address, without producing any result.
     pub fn transfer_objects(&mut self, objects: Vec<Argument>, address: Argument) {
         let cmd = Command::TransferObjects(TransferObjects { objects, address });
@@ -186,6 +200,60 @@ impl TransactionBuilder {
         Argument::Result(self.commands.len() as u16 - 1)
     }
 
+    /// Make a move vector from a list of elements, like [`TransactionBuilder::make_move_vec`], but
+    /// checking the elements beforehand so that mistakes are reported by the builder rather than
+    /// as a `CommandArgumentError` once executed.
+    ///
+    /// The type of the vector can be left unspecified if it can be inferred by the network from
+    /// its first element, i.e. if the vector isn't empty and its first element isn't a pure input.
+    /// An error is returned if:
+    ///  - no type is given but it can't be inferred
+    ///  - the elements mix pure inputs and objects, or aren't compatible with the given type
+    ///  - an element is the gas coin, which can only be used by value to transfer it
+    ///  - an element refers to an input or the result of a command which doesn't exist
+    pub fn make_move_vector(
+        &mut self,
+        type_: Option<TypeTag>,
+        elements: Vec<Argument>,
+    ) -> Result<Argument, Error> {
+        let kinds = elements
+            .iter()
+            .map(|element| self.vector_element_kind(element))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if type_.is_none() && matches!(kinds.first(), None | Some(VectorElementKind::Pure)) {
+            return Err(Error::MissingVectorType);
+        }
+
+        let invalid = |index, reason| Err(Error::InvalidVectorElement { index, reason });
+        // Whether the elements must be pure values, or objects, if that is known
+        let mut expected = type_.as_ref().and_then(is_pure_type);
+        for (index, kind) in kinds.into_iter().enumerate() {
+            let is_pure = match kind {
+                VectorElementKind::Pure => true,
+                VectorElementKind::Object => false,
+                VectorElementKind::Unknown => continue,
+            };
+            match expected {
+                Some(true) if !is_pure => {
+                    return invalid(
+                        index,
+                        "an object can't be an element of a vector of pure values",
+                    )
+                }
+                Some(false) if is_pure => {
+                    return invalid(
+                        index,
+                        "a pure input can only be an element of a vector of pure values",
+                    )
+                }
+                _ => expected = Some(is_pure),
+            }
+        }
+
+        Ok(self.make_move_vec(type_, elements))
+    }
+
     /// Publish a list of modules with the given dependencies. The result is the
     /// `0x2::package::UpgradeCap` Move type. Note that the upgrade capability needs to be handled
     /// after this call:
@@ -210,7 +278,7 @@ impl TransactionBuilder {
     ///
     ///  - `modules`: is the modules' bytecode for the modules to be published
     ///  - `dependencies`: is the list of IDs of the transitive dependencies of the package to be
-    ///     upgraded
+    ///    upgraded
     ///  - `package`: is the ID of the current package being upgraded
     ///  - `ticket`: is the upgrade ticket
     ///
@@ -285,6 +353,132 @@ impl TransactionBuilder {
         Argument::Result(self.commands.len() as u16 - 1)
     }
 
+    /// Fill in the `initial_shared_version` of the shared object inputs which don't have one.
+    ///
+    /// System objects whose initial version is the same on every network, like the `Clock`, are
+    /// resolved from the [`NetworkMetadata`] without consulting the `resolver`.
+    ///
+    /// [`NetworkMetadata`]: sui_types::network::NetworkMetadata
+    pub async fn resolve_shared_object_versions<R>(&mut self, resolver: &R) -> Result<(), R::Error>
+    where
+        R: resolver::SharedObjectVersionResolver,
+    {
+        for input in &mut self.inputs {
+            if input.kind != Some(unresolved::InputKind::Shared) || input.version.is_some() {
+                continue;
+            }
+            let Some(object_id) = input.object_id else {
+                continue;
+            };
+
+            let version = match resolver::system_initial_shared_version(&object_id) {
+                Some(version) => version,
+                None => resolver.initial_shared_version(object_id).await?,
+            };
+            input.version = Some(version);
+        }
+
+        Ok(())
+    }
+
+    /// Simulate the commands added so far and return the BCS serialized value of `argument`, the
+    /// result of one of them.
+    ///
+    /// Only the commands up to the one producing `argument` are simulated, which requires the
+    /// sender to be set and the inputs they use to be resolved. Gas isn't needed.
+    pub async fn probe<S>(
+        &self,
+        simulator: &S,
+        argument: Argument,
+    ) -> Result<Vec<u8>, probe::ProbeError<S::Error>>
+    where
+        S: probe::Simulator,
+    {
+        let (command, index) = match argument {
+            Argument::Result(command) => (command, 0),
+            Argument::NestedResult(command, index) => (command, index),
+            Argument::Gas | Argument::Input(_) => {
+                return Err(probe::ProbeError::NotAResult(argument))
+            }
+        };
+        if command as usize >= self.commands.len() {
+            return Err(Error::InvalidArgument(argument).into());
+        }
+        let sender = self.sender.ok_or(Error::MissingSender)?;
+
+        let transaction = sui_types::ProgrammableTransaction {
+            inputs: self
+                .inputs
+                .iter()
+                .cloned()
+                .map(try_from_unresolved_input_arg)
+                .collect::<Result<Vec<_>, _>>()?,
+            commands: self.commands[..=command as usize].to_vec(),
+        };
+        let values = simulator
+            .simulate(&transaction, sender)
+            .await
+            .map_err(probe::ProbeError::Simulator)?;
+
+        values
+            .into_iter()
+            .nth(command as usize)
+            .and_then(|values| values.into_iter().nth(index as usize))
+            .ok_or(probe::ProbeError::MissingValue { command, index })
+    }
+
+    /// Simulate the commands added so far and decode the value of `argument`, the result of one
+    /// of them, e.g. to use it as a pure input of a later command.
+    pub async fn probe_value<S, T>(
+        &self,
+        simulator: &S,
+        argument: Argument,
+    ) -> Result<T, probe::ProbeError<S::Error>>
+    where
+        S: probe::Simulator,
+        T: serde::de::DeserializeOwned,
+    {
+        let value = self.probe(simulator, argument).await?;
+        bcs::from_bytes(&value).map_err(|source| probe::ProbeError::Decoding { argument, source })
+    }
+
+    /// Simulate the commands added so far and add the value of `argument`, the result of one of
+    /// them, as a pure input of the transaction, returning that input.
+    ///
+    /// This is the way to pass a result to a command which only accepts pure inputs, the input
+    /// holding the value observed by the simulation rather than the one the transaction will
+    /// produce once executed.
+    pub async fn probe_input<S>(
+        &mut self,
+        simulator: &S,
+        argument: Argument,
+    ) -> Result<Argument, probe::ProbeError<S::Error>>
+    where
+        S: probe::Simulator,
+    {
+        let value = self.probe(simulator, argument).await?;
+        Ok(self.input(RawBytes(value)))
+    }
+
+    /// Replace the version and digest of the owned, immutable, or receiving inputs and gas
+    /// objects referring to the object of `reference`, e.g. after a version conflict.
+    ///
+    /// Returns whether any input was updated.
+    pub fn refresh_object_reference(&mut self, reference: &ObjectReference) -> bool {
+        let mut refreshed = false;
+        for input in self.inputs.iter_mut().chain(&mut self.gas) {
+            if input.object_id != Some(*reference.object_id())
+                || input.kind == Some(unresolved::InputKind::Shared)
+            {
+                continue;
+            }
+            input.version = Some(reference.version());
+            input.digest = Some(*reference.digest());
+            refreshed = true;
+        }
+        refreshed
+    }
+
     /// Assuming everything is resolved, convert this transaction into the
     /// resolved form. Returns a [`Transaction`] if successful, or an `Error` if not.
     pub fn finish(self) -> Result<Transaction, Error> {
@@ -350,6 +544,74 @@ impl Function {
     }
 }
 
+/// What is known about the value of an element of a Move vector before the transaction is executed.
+enum VectorElementKind {
+    Pure,
+    Object,
+    /// The result of a command, whose type isn't known.
+    Unknown,
+}
+
+impl TransactionBuilder {
+    fn vector_element_kind(&self, argument: &Argument) -> Result<VectorElementKind, Error> {
+        let command = match argument {
+            Argument::Gas => return Err(Error::GasCoinByValue),
+            Argument::Input(index) => {
+                let input = self
+                    .inputs
+                    .get(*index as usize)
+                    .ok_or(Error::InvalidArgument(*argument))?;
+                return Ok(match input.kind {
+                    Some(unresolved::InputKind::Pure | unresolved::InputKind::Literal) => {
+                        VectorElementKind::Pure
+                    }
+                    Some(_) => VectorElementKind::Object,
+                    None if input.object_id.is_some() => VectorElementKind::Object,
+                    None => VectorElementKind::Pure,
+                });
+            }
+            Argument::Result(index) | Argument::NestedResult(index, _) => self
+                .commands
+                .get(*index as usize)
+                .ok_or(Error::InvalidArgument(*argument))?,
+        };
+
+        Ok(match command {
+            Command::SplitCoins(_) => VectorElementKind::Object,
+            _ => VectorElementKind::Unknown,
+        })
+    }
+}
+
+/// Whether inputs of type `type_` must be pure values, or objects, if that is known.
+///
+/// Only primitives, vectors of them, and a few types from the framework, like strings or
+/// `0x2::object::ID`, can be pure inputs. Other structs are either objects or can only be created
+/// by Move calls.
+fn is_pure_type(type_: &TypeTag) -> Option<bool> {
+    if let Some(element) = type_.vector_element() {
+        return is_pure_type(element);
+    }
+    let Some(tag) = type_.as_struct() else {
+        return Some(*type_ != TypeTag::Signer);
+    };
+
+    let name = (tag.module.as_str(), tag.name.as_str());
+    if tag.address == Address::ONE {
+        match name {
+            ("string", "String") | ("ascii", "String") => return Some(true),
+            ("option", "Option") => return tag.type_params.first().and_then(is_pure_type),
+            _ => {}
+        }
+    }
+    if tag.address == Address::TWO && name == ("object", "ID") {
+        return Some(true);
+    }
+    // Structs which aren't objects can't be inputs at all, so it is enough to know that they
+    // can't be pure values
+    Some(false)
+}
+
 impl From<RawBytes> for unresolved::Input {
     fn from(raw: RawBytes) -> Self {
         Self {
@@ -504,11 +766,11 @@ mod tests {
 
     /// Type corresponding to the output of `sui move build --dump-bytecode-as-base64`
     #[derive(serde::Deserialize, Debug)]
-    struct MovePackageData {
+    pub(crate) struct MovePackageData {
         #[serde(deserialize_with = "bcs_from_str")]
-        modules: Vec<Vec<u8>>,
+        pub(crate) modules: Vec<Vec<u8>>,
         #[serde(deserialize_with = "deps_from_str")]
-        dependencies: Vec<ObjectId>,
+        pub(crate) dependencies: Vec<ObjectId>,
         digest: Vec<u8>,
     }
 
@@ -537,7 +799,7 @@ mod tests {
     /// in the tests directory.
     /// The json files are generated automatically when running `make test-with-localnet` in the
     /// root of the sui-transaction-builder crate.
-    fn move_package_data(file: &str) -> MovePackageData {
+    pub(crate) fn move_package_data(file: &str) -> MovePackageData {
         let data = std::fs::read_to_string(file)
             .with_context(|| {
                 format!(
@@ -707,6 +969,33 @@ mod tests {
         wait_for_tx_and_check_effects_status_success(&client, tx.digest(), effects).await;
     }
 
+    #[tokio::test]
+    async fn test_idempotent_execution() {
+        let client = Client::new_localhost();
+        let mut tx = TransactionBuilder::new();
+        let (_, pk, _) = helper_setup(&mut tx, &client).await;
+        let recipient = tx.input(Serialized(&Address::generate(rand::thread_rng())));
+        let amount = tx.input(Serialized(&1_000u64));
+        let coin = tx.split_coins(tx.gas(), vec![amount]);
+        tx.transfer_objects(vec![coin], recipient);
+
+        let tx = tx.finish().unwrap();
+        let sig = pk.sign_transaction(&tx).unwrap();
+        let first = client
+            .execute_tx_idempotent(vec![sig.clone()], &tx)
+            .await
+            .unwrap();
+        assert_eq!(first.digest, tx.digest());
+        assert_eq!(first.effects.status(), &ExecutionStatus::Success);
+        assert!(!first.already_executed);
+
+        // Submitting the same transaction again once it's indexed yields the same effects
+        wait_for_tx(&client, tx.digest()).await;
+        let second = client.execute_tx_idempotent(vec![sig], &tx).await.unwrap();
+        assert_eq!(second.effects.digest(), first.effects.digest());
+        assert!(second.already_executed);
+    }
+
     #[tokio::test]
     async fn test_split_transfer() {
         let client = Client::new_localhost();
@@ -800,6 +1089,72 @@ mod tests {
         assert_eq!(coins_after.data().len(), 2);
     }
 
+    #[test]
+    fn make_move_vector_validation() {
+        use crate::error::Error;
+
+        let coin_type: TypeTag = "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap();
+        let mut tx = TransactionBuilder::new();
+        let pure = tx.input(Serialized(&1u64));
+        let object = tx.input(Input::by_id(ObjectId::ZERO));
+        let coins = tx.split_coins(object, vec![pure]);
+
+        // Types which can be inferred from the first element don't need to be given
+        tx.make_move_vector(None, vec![object, coins.nested(0).unwrap()])
+            .unwrap();
+        tx.make_move_vector(Some(TypeTag::U64), vec![pure, pure])
+            .unwrap();
+        tx.make_move_vector(Some(TypeTag::U64), vec![]).unwrap();
+        tx.make_move_vector(Some(coin_type.clone()), vec![object])
+            .unwrap();
+        let vector = tx
+            .make_move_vector(
+                Some("0x1::option::Option<vector<u8>>".parse().unwrap()),
+                vec![pure],
+            )
+            .unwrap();
+        // The type of the results of most commands isn't known
+        tx.make_move_vector(None, vec![vector, pure]).unwrap();
+
+        // Types which can't be inferred
+        assert!(matches!(
+            tx.make_move_vector(None, vec![]),
+            Err(Error::MissingVectorType)
+        ));
+        assert!(matches!(
+            tx.make_move_vector(None, vec![pure, pure]),
+            Err(Error::MissingVectorType)
+        ));
+
+        // Heterogeneous elements
+        assert!(matches!(
+            tx.make_move_vector(None, vec![object, pure]),
+            Err(Error::InvalidVectorElement { index: 1, .. })
+        ));
+        assert!(matches!(
+            tx.make_move_vector(Some(TypeTag::U64), vec![pure, object]),
+            Err(Error::InvalidVectorElement { index: 1, .. })
+        ));
+        assert!(matches!(
+            tx.make_move_vector(Some(coin_type), vec![pure]),
+            Err(Error::InvalidVectorElement { index: 0, .. })
+        ));
+
+        // Invalid arguments
+        assert!(matches!(
+            tx.make_move_vector(None, vec![tx.gas()]),
+            Err(Error::GasCoinByValue)
+        ));
+        assert!(matches!(
+            tx.make_move_vector(Some(TypeTag::U64), vec![sui_types::Argument::Input(10)]),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            tx.make_move_vector(Some(TypeTag::U64), vec![sui_types::Argument::Result(100)]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_make_move_vec() {
         let client = Client::new_localhost();
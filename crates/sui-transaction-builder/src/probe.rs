@@ -0,0 +1,261 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Evaluation of the results of commands while a transaction is being built.
+//!
+//! Some transactions need the value produced by one of their commands to build the commands
+//! which follow it, e.g. the balance of a coin returned by a Move call, to split it in half. The
+//! builder can [`probe`] such a result: the commands added so far are simulated, without being
+//! committed, by a [`Simulator`], and the value returned by the command is fed back to the
+//! caller, which can then use it as a pure input of later commands, e.g. with
+//! [`probe_input`].
+//!
+//! The simulation only sees the state of the chain at the time it runs, so the values it returns
+//! can differ from the ones the transaction will observe once executed.
+//!
+//! [`probe`]: crate::TransactionBuilder::probe
+//! [`probe_input`]: crate::TransactionBuilder::probe_input
+
+use std::future::Future;
+
+use sui_types::Address;
+use sui_types::Argument;
+use sui_types::ProgrammableTransaction;
+
+use crate::error::Error;
+
+/// A way to execute transactions without committing their effects, returning the values of
+/// their commands, e.g. a dev inspect or dry run of the transaction.
+pub trait Simulator {
+    type Error;
+
+    /// Execute `transaction` on behalf of `sender` and return, for each of its commands, the BCS
+    /// serialized values it returned.
+    fn simulate(
+        &self,
+        transaction: &ProgrammableTransaction,
+        sender: Address,
+    ) -> impl Future<Output = Result<Vec<Vec<Vec<u8>>>, Self::Error>> + Send;
+}
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ProbeError<E> {
+    #[error("Builder error: {0}")]
+    Builder(#[from] Error),
+    #[error("Simulation error: {0}")]
+    Simulator(E),
+    #[error("Argument {0:?} isn't the result of a command")]
+    NotAResult(Argument),
+    #[error("Command {command} didn't return a value at index {index}")]
+    MissingValue { command: u16, index: u16 },
+    #[error("Cannot decode the value of {argument:?}: {source}")]
+    Decoding {
+        argument: Argument,
+        source: bcs::Error,
+    },
+}
+
+impl<E: std::fmt::Display> sui_types::ErrorCode for ProbeError<E> {
+    fn code(&self) -> &'static str {
+        match self {
+            ProbeError::Builder(e) => sui_types::ErrorCode::code(e),
+            ProbeError::Simulator(_) => "simulation_error",
+            ProbeError::NotAResult(_) => "not_a_result",
+            ProbeError::MissingValue { .. } => "missing_value",
+            ProbeError::Decoding { .. } => "decoding_error",
+        }
+    }
+}
+
+impl<E: std::fmt::Display> serde::Serialize for ProbeError<E> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        sui_types::error::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "client")]
+pub use client::ClientSimulator;
+#[cfg(feature = "client")]
+pub use client::SimulatorError;
+
+#[cfg(feature = "client")]
+mod client {
+    use sui_graphql_client::query_types::TransactionMetadata;
+    use sui_graphql_client::Client;
+    use sui_types::Address;
+    use sui_types::ProgrammableTransaction;
+    use sui_types::TransactionKind;
+
+    use super::Simulator;
+
+    #[derive(thiserror::Error, Debug)]
+    #[non_exhaustive]
+    pub enum SimulatorError {
+        #[error("Client error: {0}")]
+        Client(#[from] sui_graphql_client::error::Error),
+        #[error("Execution error: {0}")]
+        Execution(String),
+    }
+
+    impl sui_types::ErrorCode for SimulatorError {
+        fn code(&self) -> &'static str {
+            match self {
+                SimulatorError::Client(e) => sui_types::ErrorCode::code(e),
+                SimulatorError::Execution(_) => "execution_error",
+            }
+        }
+    }
+
+    impl serde::Serialize for SimulatorError {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            sui_types::error::serialize(self, serializer)
+        }
+    }
+
+    /// A [`Simulator`] which dry runs transactions with a GraphQL service.
+    ///
+    /// Transactions are dry run with checks skipped, as a dev inspect would, so that they don't
+    /// need gas and can call any function.
+    pub struct ClientSimulator {
+        client: Client,
+    }
+
+    impl ClientSimulator {
+        pub fn new(client: Client) -> Self {
+            Self { client }
+        }
+
+        pub fn client(&self) -> &Client {
+            &self.client
+        }
+    }
+
+    impl Simulator for ClientSimulator {
+        type Error = SimulatorError;
+
+        async fn simulate(
+            &self,
+            transaction: &ProgrammableTransaction,
+            sender: Address,
+        ) -> Result<Vec<Vec<Vec<u8>>>, Self::Error> {
+            let metadata = TransactionMetadata {
+                gas_budget: None,
+                gas_objects: None,
+                gas_price: None,
+                gas_sponsor: None,
+                sender: Some(sender),
+            };
+            let result = self
+                .client
+                .dry_run_tx_kind(
+                    &TransactionKind::ProgrammableTransaction(transaction.clone()),
+                    Some(true),
+                    metadata,
+                )
+                .await?;
+
+            match result.error {
+                Some(error) => Err(SimulatorError::Execution(error)),
+                None => Ok(result.return_values),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::Function;
+    use crate::Serialized;
+    use crate::TransactionBuilder;
+
+    /// A simulator returning the same values for every command, which records the number of
+    /// commands of the transactions it simulates.
+    #[derive(Default)]
+    struct FixedSimulator {
+        simulated: Mutex<Vec<usize>>,
+    }
+
+    impl Simulator for FixedSimulator {
+        type Error = std::convert::Infallible;
+
+        async fn simulate(
+            &self,
+            transaction: &ProgrammableTransaction,
+            _sender: Address,
+        ) -> Result<Vec<Vec<Vec<u8>>>, Self::Error> {
+            let commands = transaction.commands.len();
+            self.simulated.lock().unwrap().push(commands);
+            let values = vec![
+                bcs::to_bytes(&1_000u64).unwrap(),
+                bcs::to_bytes(&true).unwrap(),
+            ];
+            Ok(vec![values; commands])
+        }
+    }
+
+    #[tokio::test]
+    async fn probe() {
+        let simulator = FixedSimulator::default();
+        let function = || {
+            Function::new(
+                Address::TWO,
+                "coin".parse().unwrap(),
+                "value".parse().unwrap(),
+                vec![],
+            )
+        };
+
+        let mut tx = TransactionBuilder::new();
+        let input = tx.input(Serialized(&42u64));
+        let first = tx.move_call(function(), vec![input]);
+        tx.move_call(function(), vec![input]);
+
+        // The sender is needed to simulate the transaction
+        assert!(matches!(
+            tx.probe(&simulator, first).await,
+            Err(ProbeError::Builder(Error::MissingSender))
+        ));
+        tx.set_sender(Address::ZERO);
+
+        let balance: u64 = tx.probe_value(&simulator, first).await.unwrap();
+        assert_eq!(balance, 1_000);
+        let flag: bool = tx
+            .probe_value(&simulator, Argument::NestedResult(1, 1))
+            .await
+            .unwrap();
+        assert!(flag);
+        // Only the commands up to the probed one are simulated
+        assert_eq!(*simulator.simulated.lock().unwrap(), vec![1, 2]);
+
+        assert!(matches!(
+            tx.probe(&simulator, input).await,
+            Err(ProbeError::NotAResult(_))
+        ));
+        assert!(matches!(
+            tx.probe(&simulator, Argument::NestedResult(0, 2)).await,
+            Err(ProbeError::MissingValue {
+                command: 0,
+                index: 2
+            })
+        ));
+        assert!(matches!(
+            tx.probe(&simulator, Argument::Result(2)).await,
+            Err(ProbeError::Builder(Error::InvalidArgument(_)))
+        ));
+
+        // The probed value is added as a pure input
+        let probed = tx.probe_input(&simulator, first).await.unwrap();
+        assert_eq!(probed, Argument::Input(1));
+        let input = crate::try_from_unresolved_input_arg(tx.inputs[1].clone()).unwrap();
+        assert_eq!(
+            input,
+            sui_types::Input::Pure {
+                value: bcs::to_bytes(&1_000u64).unwrap()
+            }
+        );
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of the `initial_shared_version` of shared object inputs.
+//!
+//! Shared object inputs need to reference the version at which the object was shared, which is
+//! easy to get wrong when passed in manually. Instead, shared inputs can be created without a
+//! version, e.g. with [`Input::by_id`] and [`Input::with_shared_kind`], and filled in by
+//! [`TransactionBuilder::resolve_shared_object_versions`] using a
+//! [`SharedObjectVersionResolver`].
+//!
+//! [`Input::by_id`]: crate::unresolved::Input::by_id
+//! [`Input::with_shared_kind`]: crate::unresolved::Input::with_shared_kind
+//! [`TransactionBuilder::resolve_shared_object_versions`]: crate::TransactionBuilder::resolve_shared_object_versions
+
+use std::future::Future;
+
+use sui_types::network::NetworkMetadata;
+use sui_types::token::Network;
+use sui_types::ObjectId;
+use sui_types::Version;
+
+/// Return the `initial_shared_version` of `object_id` if it is a system shared object with the
+/// same initial version on every network, i.e. one of the [`NetworkMetadata::system_objects`].
+///
+/// Other system objects, like the `Random` or `DenyList` objects, were created after genesis and
+/// so their initial version depends on the network.
+pub fn system_initial_shared_version(object_id: &ObjectId) -> Option<Version> {
+    // The system objects known statically are the same on every network, so the metadata of any
+    // of them will do
+    NetworkMetadata::for_network(Network::Mainnet).system_initial_shared_version(object_id)
+}
+
+/// A source of the `initial_shared_version` of shared objects.
+pub trait SharedObjectVersionResolver {
+    type Error;
+
+    /// Return the version at which `object_id` was shared, or an error if the object doesn't
+    /// exist or isn't shared.
+    fn initial_shared_version(
+        &self,
+        object_id: ObjectId,
+    ) -> impl Future<Output = Result<Version, Self::Error>> + Send;
+}
+
+#[cfg(feature = "client")]
+pub use client::ClientSharedObjectResolver;
+#[cfg(feature = "client")]
+pub use client::ResolverError;
+
+#[cfg(feature = "client")]
+mod client {
+    use std::num::NonZeroUsize;
+    use std::sync::Mutex;
+
+    use lru::LruCache;
+    use sui_graphql_client::Client;
+    use sui_types::ObjectId;
+    use sui_types::Owner;
+    use sui_types::Version;
+
+    use super::system_initial_shared_version;
+    use super::SharedObjectVersionResolver;
+
+    #[derive(thiserror::Error, Debug)]
+    #[non_exhaustive]
+    pub enum ResolverError {
+        #[error("Client error: {0}")]
+        Client(#[from] sui_graphql_client::error::Error),
+        #[error("Object {0} not found")]
+        NotFound(ObjectId),
+        #[error("Object {0} is not shared")]
+        NotShared(ObjectId),
+    }
+
+    impl sui_types::ErrorCode for ResolverError {
+        fn code(&self) -> &'static str {
+            match self {
+                ResolverError::Client(e) => sui_types::ErrorCode::code(e),
+                ResolverError::NotFound(_) => "object_not_found",
+                ResolverError::NotShared(_) => "object_not_shared",
+            }
+        }
+    }
+
+    impl serde::Serialize for ResolverError {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            sui_types::error::serialize(self, serializer)
+        }
+    }
+
+    /// A [`SharedObjectVersionResolver`] which fetches objects from a GraphQL service.
+    ///
+    /// System objects with a well-known initial version are resolved without making a request.
+    /// Since the initial version of a shared object never changes, the versions of the other
+    /// objects are kept in a least recently used cache once fetched.
+    pub struct ClientSharedObjectResolver {
+        client: Client,
+        cache: Mutex<LruCache<ObjectId, Version>>,
+    }
+
+    impl ClientSharedObjectResolver {
+        /// The number of versions cached by [`ClientSharedObjectResolver::new`].
+        pub const DEFAULT_CACHE_SIZE: usize = 1024;
+
+        pub fn new(client: Client) -> Self {
+            Self::with_cache_size(client, Self::DEFAULT_CACHE_SIZE)
+        }
+
+        /// Create a resolver caching the versions of up to `cache_size` objects.
+        pub fn with_cache_size(client: Client, cache_size: usize) -> Self {
+            let cache_size = NonZeroUsize::new(cache_size).unwrap_or(NonZeroUsize::MIN);
+            Self {
+                client,
+                cache: Mutex::new(LruCache::new(cache_size)),
+            }
+        }
+
+        pub fn client(&self) -> &Client {
+            &self.client
+        }
+    }
+
+    impl SharedObjectVersionResolver for ClientSharedObjectResolver {
+        type Error = ResolverError;
+
+        async fn initial_shared_version(
+            &self,
+            object_id: ObjectId,
+        ) -> Result<Version, Self::Error> {
+            if let Some(version) = system_initial_shared_version(&object_id) {
+                return Ok(version);
+            }
+            if let Some(version) = self.cache.lock().unwrap().get(&object_id) {
+                return Ok(*version);
+            }
+
+            let object = self
+                .client
+                .object(object_id.into(), None)
+                .await?
+                .ok_or(ResolverError::NotFound(object_id))?;
+            let Owner::Shared(version) = object.owner() else {
+                return Err(ResolverError::NotShared(object_id));
+            };
+
+            self.cache.lock().unwrap().put(object_id, *version);
+            Ok(*version)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use sui_types::Address;
+
+    use super::*;
+    use crate::unresolved::Input;
+    use crate::Function;
+    use crate::TransactionBuilder;
+
+    /// A resolver backed by a fixed set of objects, which records the objects it was asked for.
+    #[derive(Default)]
+    struct MapResolver {
+        versions: HashMap<ObjectId, Version>,
+        requested: Mutex<Vec<ObjectId>>,
+    }
+
+    impl SharedObjectVersionResolver for MapResolver {
+        type Error = ObjectId;
+
+        async fn initial_shared_version(&self, object_id: ObjectId) -> Result<Version, ObjectId> {
+            self.requested.lock().unwrap().push(object_id);
+            self.versions.get(&object_id).copied().ok_or(object_id)
+        }
+    }
+
+    #[test]
+    fn system_objects() {
+        assert_eq!(
+            system_initial_shared_version(&"0x6".parse().unwrap()),
+            Some(1)
+        );
+        assert_eq!(
+            system_initial_shared_version(&"0x5".parse().unwrap()),
+            Some(1)
+        );
+        assert_eq!(system_initial_shared_version(&"0x8".parse().unwrap()), None);
+    }
+
+    #[tokio::test]
+    async fn resolve_shared_object_versions() {
+        let pool: ObjectId = "0x1234".parse().unwrap();
+        let mut resolver = MapResolver::default();
+        resolver.versions.insert(pool, 42);
+
+        let mut tx = TransactionBuilder::new();
+        let clock = tx.input(Input::by_id("0x6".parse().unwrap()).with_shared_kind());
+        let pool_input = tx.input(Input::by_id(pool).with_shared_kind().by_mut());
+        let explicit = tx.input(Input::shared("0x99".parse().unwrap(), 7, false));
+        tx.move_call(
+            Function::new(
+                Address::TWO,
+                "pool".parse().unwrap(),
+                "swap".parse().unwrap(),
+                vec![],
+            ),
+            vec![pool_input, clock, explicit],
+        );
+
+        tx.resolve_shared_object_versions(&resolver).await.unwrap();
+        assert_eq!(*resolver.requested.lock().unwrap(), vec![pool]);
+
+        let versions: Vec<_> = tx.inputs.iter().map(|input| input.version).collect();
+        assert_eq!(versions, vec![Some(1), Some(42), Some(7)]);
+
+        let mut tx = TransactionBuilder::new();
+        let missing: ObjectId = "0x4321".parse().unwrap();
+        tx.input(Input::by_id(missing).with_shared_kind());
+        assert_eq!(
+            tx.resolve_shared_object_versions(&resolver).await,
+            Err(missing)
+        );
+    }
+}
@@ -0,0 +1,20 @@
+module test_example::nft {
+use std::string::String;
+
+    public struct Nft has key, store {
+        id: UID,
+        name: String,
+        description: String,
+        url: String,
+    }
+
+    /// Mints a new Nft with the provided metadata
+    public fun mint(name: String, description: String, url: String, ctx: &mut TxContext): Nft {
+        Nft {
+            id: object::new(ctx),
+            name,
+            description,
+            url,
+        }
+    }
+}